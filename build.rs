@@ -0,0 +1,37 @@
+//! Build script.
+//!
+//! It sets the `TORRUST_TRACKER_GIT_COMMIT` environment variable with the git
+//! commit the tracker is built from, so it can be shown in the tracker API.
+//!
+//! The commit can also be provided with the same environment variable, for
+//! example, when the tracker is built without the git repository. It's
+//! `unknown` if it can't be found.
+use std::process::Command;
+
+const GIT_COMMIT: &str = "TORRUST_TRACKER_GIT_COMMIT";
+
+fn main() {
+    println!("cargo:rerun-if-env-changed={GIT_COMMIT}");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs/heads");
+
+    let git_commit = std::env::var(GIT_COMMIT)
+        .ok()
+        .filter(|commit| !commit.is_empty())
+        .or_else(git_head_commit)
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env={GIT_COMMIT}={git_commit}");
+}
+
+fn git_head_commit() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "HEAD"]).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    (!commit.is_empty()).then_some(commit)
+}
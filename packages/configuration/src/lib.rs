@@ -7,7 +7,6 @@
 pub mod v2_0_0;
 pub mod validator;
 
-use std::collections::HashMap;
 use std::env;
 use std::sync::Arc;
 use std::time::Duration;
@@ -45,7 +44,10 @@ pub type Database = v2_0_0::database::Database;
 pub type Driver = v2_0_0::database::Driver;
 pub type Threshold = v2_0_0::logging::Threshold;
 
-pub type AccessTokens = HashMap<String, String>;
+pub type AccessTokens = v2_0_0::tracker_api::AccessTokens;
+pub type AccessToken = v2_0_0::tracker_api::AccessToken;
+pub type Role = v2_0_0::tracker_api::Role;
+pub type AuthThrottling = v2_0_0::tracker_api::AuthThrottling;
 
 pub const LATEST_VERSION: &str = "2.0.0";
 
@@ -13,10 +13,26 @@ pub struct Core {
     #[serde(default = "Core::default_announce_policy")]
     pub announce_policy: AnnouncePolicy,
 
+    /// Interval in seconds that the job will run to update the breakdown of
+    /// active peers by client software. `0` disables the job.
+    #[serde(default = "Core::default_clients_breakdown_interval")]
+    pub clients_breakdown_interval: u64,
+
     /// Database configuration.
     #[serde(default = "Core::default_database")]
     pub database: Database,
 
+    /// Interval in seconds that the job will run to remove the expired
+    /// authentication keys. The job only runs when the tracker is private and
+    /// it checks the keys expiration. `0` disables the job.
+    #[serde(default = "Core::default_expired_keys_cleanup_interval")]
+    pub expired_keys_cleanup_interval: u64,
+
+    /// Number of seconds an authentication key is kept after it has expired,
+    /// before the cleanup job removes it.
+    #[serde(default = "Core::default_expired_keys_grace_period")]
+    pub expired_keys_grace_period: u64,
+
     /// Interval in seconds that the cleanup job will run to remove inactive
     /// peers from the torrent peer list.
     #[serde(default = "Core::default_inactive_peer_cleanup_interval")]
@@ -30,6 +46,14 @@ pub struct Core {
     #[serde(default = "Core::default_network")]
     pub net: Network,
 
+    /// Number of announce requests per second, UDP and HTTP, above which the
+    /// tracker is overloaded. The rate is the average of the last minute.
+    /// While the tracker is overloaded the announce intervals sent to the
+    /// clients are doubled, so they announce less often. It requires the
+    /// `tracker_usage_statistics`. `0` disables the overload protection.
+    #[serde(default = "Core::default_overload_announce_rate")]
+    pub overload_announce_rate: u64,
+
     /// When `true` clients require a key to connect and use the tracker.
     #[serde(default = "Core::default_private")]
     pub private: bool,
@@ -38,6 +62,12 @@ pub struct Core {
     #[serde(default = "Core::default_private_mode")]
     pub private_mode: Option<PrivateMode>,
 
+    /// Interval in seconds that the job will run to persist the usage
+    /// statistics into the database. They are also persisted when the tracker
+    /// shuts down and restored when it starts. `0` disables the persistence.
+    #[serde(default = "Core::default_statistics_persistence_interval")]
+    pub statistics_persistence_interval: u64,
+
     /// Tracker policy configuration.
     #[serde(default = "Core::default_tracker_policy")]
     pub tracker_policy: TrackerPolicy,
@@ -55,12 +85,17 @@ impl Default for Core {
     fn default() -> Self {
         Self {
             announce_policy: Self::default_announce_policy(),
+            clients_breakdown_interval: Self::default_clients_breakdown_interval(),
             database: Self::default_database(),
+            expired_keys_cleanup_interval: Self::default_expired_keys_cleanup_interval(),
+            expired_keys_grace_period: Self::default_expired_keys_grace_period(),
             inactive_peer_cleanup_interval: Self::default_inactive_peer_cleanup_interval(),
             listed: Self::default_listed(),
             net: Self::default_network(),
+            overload_announce_rate: Self::default_overload_announce_rate(),
             private: Self::default_private(),
             private_mode: Self::default_private_mode(),
+            statistics_persistence_interval: Self::default_statistics_persistence_interval(),
             tracker_policy: Self::default_tracker_policy(),
             tracker_usage_statistics: Self::default_tracker_usage_statistics(),
         }
@@ -72,10 +107,22 @@ impl Core {
         AnnouncePolicy::default()
     }
 
+    fn default_clients_breakdown_interval() -> u64 {
+        60
+    }
+
     fn default_database() -> Database {
         Database::default()
     }
 
+    fn default_expired_keys_cleanup_interval() -> u64 {
+        3600
+    }
+
+    fn default_expired_keys_grace_period() -> u64 {
+        0
+    }
+
     fn default_inactive_peer_cleanup_interval() -> u64 {
         600
     }
@@ -88,6 +135,10 @@ impl Core {
         Network::default()
     }
 
+    fn default_overload_announce_rate() -> u64 {
+        0
+    }
+
     fn default_private() -> bool {
         false
     }
@@ -100,6 +151,10 @@ impl Core {
         }
     }
 
+    fn default_statistics_persistence_interval() -> u64 {
+        300
+    }
+
     fn default_tracker_policy() -> TrackerPolicy {
         TrackerPolicy::default()
     }
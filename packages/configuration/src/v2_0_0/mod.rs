@@ -199,9 +199,14 @@
 //! threshold = "info"
 //!
 //! [core]
+//! clients_breakdown_interval = 60
+//! expired_keys_cleanup_interval = 3600
+//! expired_keys_grace_period = 0
 //! inactive_peer_cleanup_interval = 600
 //! listed = false
+//! overload_announce_rate = 0
 //! private = false
+//! statistics_persistence_interval = 300
 //! tracker_usage_statistics = true
 //!
 //! [core.announce_policy]
@@ -223,9 +228,17 @@
 //!
 //! [http_api]
 //! bind_address = "127.0.0.1:1212"
+//! metrics_require_token = true
+//! audit_log_retention = 90
+//! trusted_proxies = []
 //!
 //! [http_api.access_tokens]
 //! admin = "MyAccessToken"
+//!
+//! [http_api.auth_throttling]
+//! max_failures = 10
+//! failure_window = 60
+//! lockout_duration = 300
 //! [health_check_api]
 //! bind_address = "127.0.0.1:1313"
 //!```
@@ -263,6 +276,18 @@ const CONFIG_OVERRIDE_PREFIX: &str = "TORRUST_TRACKER_CONFIG_OVERRIDE_";
 /// Path separator in env var names for nested values in configuration.
 const CONFIG_OVERRIDE_SEPARATOR: &str = "__";
 
+/// The configuration options that can be changed while the tracker is
+/// running. Changing any other option requires a restart.
+///
+/// The options inside them can also be changed. For example:
+/// `core.announce_policy.interval`.
+pub const RELOADABLE_OPTIONS: [&str; 4] = [
+    "core.announce_policy",
+    "core.tracker_policy",
+    "http_api.access_tokens",
+    "logging.threshold",
+];
+
 /// Core configuration for the tracker.
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Default, Clone)]
 pub struct Configuration {
@@ -409,6 +434,27 @@ impl Configuration {
         serde_json::to_string_pretty(self).expect("Could not encode JSON value")
     }
 
+    /// Returns the options that have a different value in the `other`
+    /// configuration, for example: `core.announce_policy.interval`. Only the
+    /// option paths are returned, not the values, so secrets are not exposed.
+    ///
+    /// Lists, like the `udp_trackers`, are compared as a single option.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the configurations can't be converted to JSON.
+    #[must_use]
+    pub fn changed_options(&self, other: &Configuration) -> Vec<String> {
+        let this = serde_json::to_value(self).expect("Could not encode JSON value");
+        let other = serde_json::to_value(other).expect("Could not encode JSON value");
+
+        let mut changed_options = vec![];
+
+        collect_changed_options("", &this, &other, &mut changed_options);
+
+        changed_options
+    }
+
     /// Masks secrets in the configuration.
     #[must_use]
     pub fn mask_secrets(mut self) -> Self {
@@ -428,12 +474,53 @@ impl Validator for Configuration {
     }
 }
 
+/// Returns `true` if the option can be changed while the tracker is running.
+/// See [`RELOADABLE_OPTIONS`].
+#[must_use]
+pub fn is_reloadable(option: &str) -> bool {
+    RELOADABLE_OPTIONS.iter().any(|reloadable| {
+        option
+            .strip_prefix(reloadable)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    })
+}
+
+fn collect_changed_options(path: &str, this: &serde_json::Value, other: &serde_json::Value, changed_options: &mut Vec<String>) {
+    match (this, other) {
+        (serde_json::Value::Object(this), serde_json::Value::Object(other)) => {
+            let mut keys: Vec<&String> = this.keys().chain(other.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let option = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+
+                match (this.get(key), other.get(key)) {
+                    (Some(this), Some(other)) => collect_changed_options(&option, this, other, changed_options),
+                    _ => changed_options.push(option),
+                }
+            }
+        }
+        (this, other) => {
+            if this != other {
+                changed_options.push(path.to_string());
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use std::net::{IpAddr, Ipv4Addr};
 
-    use crate::v2_0_0::Configuration;
+    use crate::v2_0_0::tracker_api::AccessToken;
+    use crate::v2_0_0::udp_tracker::UdpTracker;
+    use crate::v2_0_0::{is_reloadable, Configuration};
     use crate::Info;
 
     #[cfg(test)]
@@ -447,9 +534,14 @@ mod tests {
                                 threshold = "info"
 
                                 [core]
+                                clients_breakdown_interval = 60
+                                expired_keys_cleanup_interval = 3600
+                                expired_keys_grace_period = 0
                                 inactive_peer_cleanup_interval = 600
                                 listed = false
+                                overload_announce_rate = 0
                                 private = false
+                                statistics_persistence_interval = 300
                                 tracker_usage_statistics = true
 
                                 [core.announce_policy]
@@ -662,11 +754,49 @@ mod tests {
             let configuration = Configuration::load(&info).expect("Could not load configuration from file");
 
             assert_eq!(
-                configuration.http_api.unwrap().access_tokens.get("admin"),
-                Some("NewToken".to_owned()).as_ref()
+                configuration
+                    .http_api
+                    .unwrap()
+                    .access_tokens
+                    .get("admin")
+                    .map(AccessToken::secret),
+                Some("NewToken")
             );
 
             Ok(())
         });
     }
+
+    #[test]
+    fn configuration_should_list_the_changed_options() {
+        let configuration = Configuration::default();
+
+        let mut other = configuration.clone();
+        other.core.announce_policy.interval = 300;
+        other.core.net.external_ip = Some(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)));
+        other.udp_trackers = Some(vec![UdpTracker::default()]);
+
+        assert_eq!(
+            configuration.changed_options(&other),
+            vec![
+                "core.announce_policy.interval".to_string(),
+                "core.net.external_ip".to_string(),
+                "udp_trackers".to_string()
+            ]
+        );
+        assert!(configuration.changed_options(&configuration.clone()).is_empty());
+    }
+
+    #[test]
+    fn only_the_policies_the_api_access_tokens_and_the_log_threshold_should_be_reloadable() {
+        assert!(is_reloadable("core.announce_policy"));
+        assert!(is_reloadable("core.tracker_policy.max_peer_timeout"));
+        assert!(is_reloadable("http_api.access_tokens.admin"));
+        assert!(is_reloadable("logging.threshold"));
+
+        assert!(!is_reloadable("core.net.external_ip"));
+        assert!(!is_reloadable("http_api"));
+        assert!(!is_reloadable("http_api.bind_address"));
+        assert!(!is_reloadable("core.announce_policy_v2"));
+    }
 }
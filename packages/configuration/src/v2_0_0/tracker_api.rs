@@ -6,7 +6,92 @@ use serde_with::serde_as;
 
 use crate::TslConfig;
 
-pub type AccessTokens = HashMap<String, String>;
+/// Access tokens for the HTTP API by label.
+pub type AccessTokens = HashMap<String, AccessToken>;
+
+/// An access token for the HTTP API.
+///
+/// It can be a plain string, which is a token with the [`Role::Admin`] role
+/// that never expires, or a table with the token, the role and, optionally,
+/// the time the token expires:
+///
+/// ```toml
+/// [http_api.access_tokens]
+/// admin = "MyAccessToken"
+/// stats = { token = "MyStatsToken", role = "stats_reader", valid_until = 1767225600 }
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(untagged)]
+pub enum AccessToken {
+    /// An admin token without expiration.
+    Secret(String),
+    /// A token with a role and an optional expiration time.
+    Scoped(ScopedAccessToken),
+}
+
+impl AccessToken {
+    /// The token sent by the API clients.
+    #[must_use]
+    pub fn secret(&self) -> &str {
+        match self {
+            AccessToken::Secret(token) => token,
+            AccessToken::Scoped(scoped) => &scoped.token,
+        }
+    }
+
+    #[must_use]
+    pub fn role(&self) -> Role {
+        match self {
+            AccessToken::Secret(_) => Role::Admin,
+            AccessToken::Scoped(scoped) => scoped.role,
+        }
+    }
+
+    /// Unix time (in seconds) when the token expires, if it expires.
+    #[must_use]
+    pub fn valid_until(&self) -> Option<u64> {
+        match self {
+            AccessToken::Secret(_) => None,
+            AccessToken::Scoped(scoped) => scoped.valid_until,
+        }
+    }
+
+    /// Whether the token has expired at the given unix time (in seconds).
+    #[must_use]
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        self.valid_until().is_some_and(|valid_until| now >= valid_until)
+    }
+}
+
+/// An access token with a [`Role`] and an optional expiration time.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ScopedAccessToken {
+    /// The token sent by the API clients.
+    pub token: String,
+
+    /// What the token is allowed to do.
+    pub role: Role,
+
+    /// Unix time (in seconds) when the token expires. The token never expires
+    /// if it's not set.
+    #[serde(default)]
+    pub valid_until: Option<u64>,
+}
+
+/// The role of an HTTP API access token. It defines which endpoints the token
+/// can be used for.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// It can only read the tracker statistics and metrics.
+    StatsReader,
+    /// It can read the statistics and the torrents.
+    TorrentViewer,
+    /// It can read the statistics and manage the authentication keys.
+    KeyManager,
+    /// It can use all the endpoints.
+    Admin,
+}
 
 /// Configuration for the HTTP API.
 #[serde_as]
@@ -24,11 +109,37 @@ pub struct HttpApi {
     pub tsl_config: Option<TslConfig>,
 
     /// Access tokens for the HTTP API. The key is a label identifying the
-    /// token and the value is the token itself. The token is used to
-    /// authenticate the user. All tokens are valid for all endpoints and have
-    /// all permissions.
+    /// token and the value is the token itself, optionally with a [`Role`]
+    /// and an expiration time (see [`AccessToken`]). The token is used to
+    /// authenticate the user. Tokens without a role are admin tokens.
     #[serde(default = "HttpApi::default_access_tokens")]
     pub access_tokens: AccessTokens,
+
+    /// Whether the Prometheus metrics endpoint (`GET /metrics`) requires an
+    /// access token like the rest of the API endpoints. Set it to `false` to
+    /// allow scraping the metrics without a token.
+    #[serde(default = "HttpApi::default_metrics_require_token")]
+    pub metrics_require_token: bool,
+
+    /// Number of days the entries of the audit log of administrative actions
+    /// are kept. Older entries are removed periodically. `0` keeps them
+    /// forever.
+    #[serde(default = "HttpApi::default_audit_log_retention")]
+    pub audit_log_retention: u64,
+
+    /// Protection against clients guessing the access tokens. See
+    /// [`AuthThrottling`].
+    #[serde(default = "HttpApi::default_auth_throttling")]
+    pub auth_throttling: AuthThrottling,
+
+    /// IPs of the reverse proxies in front of the HTTP API. The client IP of
+    /// the requests coming from them is taken from the rightmost IP in the
+    /// `X-Forwarded-For` header. The client IP of any other request is the IP
+    /// of the connection, even if the header is present. It does not depend
+    /// on the `core.net.on_reverse_proxy` option, which only applies to the
+    /// HTTP trackers.
+    #[serde(default = "HttpApi::default_trusted_proxies")]
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 impl Default for HttpApi {
@@ -37,6 +148,10 @@ impl Default for HttpApi {
             bind_address: Self::default_bind_address(),
             tsl_config: Self::default_tsl_config(),
             access_tokens: Self::default_access_tokens(),
+            metrics_require_token: Self::default_metrics_require_token(),
+            audit_log_retention: Self::default_audit_log_retention(),
+            auth_throttling: Self::default_auth_throttling(),
+            trusted_proxies: Self::default_trusted_proxies(),
         }
     }
 }
@@ -55,20 +170,124 @@ impl HttpApi {
         [].iter().cloned().collect()
     }
 
+    fn default_metrics_require_token() -> bool {
+        true
+    }
+
+    fn default_audit_log_retention() -> u64 {
+        90
+    }
+
+    fn default_auth_throttling() -> AuthThrottling {
+        AuthThrottling::default()
+    }
+
+    fn default_trusted_proxies() -> Vec<IpAddr> {
+        Vec::new()
+    }
+
+    /// It adds an admin token without expiration.
     pub fn add_token(&mut self, key: &str, token: &str) {
-        self.access_tokens.insert(key.to_string(), token.to_string());
+        self.access_tokens
+            .insert(key.to_string(), AccessToken::Secret(token.to_string()));
+    }
+
+    /// It adds a token with a role and an optional expiration time (unix time
+    /// in seconds).
+    pub fn add_scoped_token(&mut self, key: &str, token: &str, role: Role, valid_until: Option<u64>) {
+        self.access_tokens.insert(
+            key.to_string(),
+            AccessToken::Scoped(ScopedAccessToken {
+                token: token.to_string(),
+                role,
+                valid_until,
+            }),
+        );
     }
 
     pub fn mask_secrets(&mut self) {
-        for token in self.access_tokens.values_mut() {
-            *token = "***".to_string();
+        for access_token in self.access_tokens.values_mut() {
+            match access_token {
+                AccessToken::Secret(token) => *token = "***".to_string(),
+                AccessToken::Scoped(scoped) => scoped.token = "***".to_string(),
+            }
         }
     }
 }
 
+/// Limits to the invalid access tokens a client can send to the HTTP API.
+///
+/// Clients are identified by their IP address. When a client sends
+/// `max_failures` invalid tokens within `failure_window` seconds, all its
+/// requests are rejected for `lockout_duration` seconds, even the ones with a
+/// valid token.
+///
+/// ```toml
+/// [http_api.auth_throttling]
+/// max_failures = 10
+/// failure_window = 60
+/// lockout_duration = 300
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub struct AuthThrottling {
+    /// Number of invalid tokens that locks a client out. `0` disables the
+    /// lockout.
+    #[serde(default = "AuthThrottling::default_max_failures")]
+    pub max_failures: u32,
+
+    /// Time window, in seconds, in which the invalid tokens are counted.
+    #[serde(default = "AuthThrottling::default_failure_window")]
+    pub failure_window: u64,
+
+    /// Time, in seconds, a client is locked out.
+    #[serde(default = "AuthThrottling::default_lockout_duration")]
+    pub lockout_duration: u64,
+}
+
+impl Default for AuthThrottling {
+    fn default() -> Self {
+        Self {
+            max_failures: Self::default_max_failures(),
+            failure_window: Self::default_failure_window(),
+            lockout_duration: Self::default_lockout_duration(),
+        }
+    }
+}
+
+impl AuthThrottling {
+    fn default_max_failures() -> u32 {
+        10
+    }
+
+    fn default_failure_window() -> u64 {
+        60
+    }
+
+    fn default_lockout_duration() -> u64 {
+        300
+    }
+
+    /// Whether the clients are locked out after too many invalid tokens.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.max_failures > 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::v2_0_0::tracker_api::HttpApi;
+    use crate::v2_0_0::tracker_api::{AccessToken, AuthThrottling, HttpApi, Role};
+
+    #[test]
+    fn auth_throttling_should_be_enabled_by_default_and_disabled_without_max_failures() {
+        let mut throttling = AuthThrottling::default();
+
+        assert!(throttling.is_enabled());
+
+        throttling.max_failures = 0;
+
+        assert!(!throttling.is_enabled());
+    }
 
     #[test]
     fn default_http_api_configuration_should_not_contains_any_token() {
@@ -83,6 +302,71 @@ mod tests {
 
         configuration.add_token("admin", "MyAccessToken");
 
-        assert!(configuration.access_tokens.values().any(|t| t == "MyAccessToken"));
+        assert!(configuration.access_tokens.values().any(|t| t.secret() == "MyAccessToken"));
+    }
+
+    #[test]
+    fn a_plain_access_token_should_be_an_admin_token_without_expiration() {
+        let token = AccessToken::Secret("MyAccessToken".to_string());
+
+        assert_eq!(token.role(), Role::Admin);
+        assert_eq!(token.valid_until(), None);
+        assert!(!token.is_expired_at(u64::MAX));
+    }
+
+    #[test]
+    fn http_api_configuration_should_allow_adding_tokens_with_a_role_and_an_expiration_time() {
+        let mut configuration = HttpApi::default();
+
+        configuration.add_scoped_token("stats", "MyStatsToken", Role::StatsReader, Some(1000));
+
+        let token = &configuration.access_tokens["stats"];
+
+        assert_eq!(token.secret(), "MyStatsToken");
+        assert_eq!(token.role(), Role::StatsReader);
+        assert!(!token.is_expired_at(999));
+        assert!(token.is_expired_at(1000));
+    }
+
+    #[test]
+    fn access_tokens_should_be_deserialized_from_plain_strings_or_tables() {
+        let configuration: HttpApi = toml::from_str(
+            r#"
+            [access_tokens]
+            admin = "MyAccessToken"
+            viewer = { token = "MyViewerToken", role = "torrent_viewer" }
+            keys = { token = "MyKeysToken", role = "key_manager", valid_until = 1767225600 }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            configuration.access_tokens["admin"],
+            AccessToken::Secret("MyAccessToken".to_string())
+        );
+        assert_eq!(configuration.access_tokens["viewer"].role(), Role::TorrentViewer);
+        assert_eq!(configuration.access_tokens["viewer"].valid_until(), None);
+        assert_eq!(configuration.access_tokens["keys"].role(), Role::KeyManager);
+        assert_eq!(configuration.access_tokens["keys"].valid_until(), Some(1_767_225_600));
+    }
+
+    #[test]
+    fn masking_the_secrets_should_hide_all_the_tokens() {
+        let mut configuration = HttpApi::default();
+
+        configuration.add_token("admin", "MyAccessToken");
+        configuration.add_scoped_token("stats", "MyStatsToken", Role::StatsReader, None);
+
+        configuration.mask_secrets();
+
+        assert!(configuration.access_tokens.values().all(|t| t.secret() == "***"));
+        assert_eq!(configuration.access_tokens["stats"].role(), Role::StatsReader);
+    }
+
+    #[test]
+    fn default_http_api_configuration_should_require_a_token_for_the_metrics_endpoint() {
+        let configuration = HttpApi::default();
+
+        assert!(configuration.metrics_require_token);
     }
 }
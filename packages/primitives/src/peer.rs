@@ -351,6 +351,16 @@ impl Id {
         let peer_id = tdyne_peer_id::PeerId::from(self.0);
         tdyne_peer_id_registry::parse(peer_id).ok().map(|parsed| parsed.client)
     }
+
+    /// It returns the client version, if the client encodes it in the peer id
+    /// and it can be parsed.
+    #[must_use]
+    pub fn get_client_version(&self) -> Option<String> {
+        let peer_id = tdyne_peer_id::PeerId::from(self.0);
+        tdyne_peer_id_registry::parse(peer_id)
+            .ok()
+            .and_then(|parsed| parsed.version.ok().flatten())
+    }
 }
 
 impl Serialize for Id {
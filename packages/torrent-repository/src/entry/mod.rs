@@ -1,7 +1,8 @@
 use std::fmt::Debug;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
+use aquatic_udp_protocol::PeerId;
 use torrust_tracker_configuration::TrackerPolicy;
 use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
 use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
@@ -33,6 +34,9 @@ pub trait Entry {
     /// Get all swarm peers, optionally limiting the result.
     fn get_peers(&self, limit: Option<usize>) -> Vec<Arc<peer::Peer>>;
 
+    /// It returns the peer with the given ID, if it's in the swarm.
+    fn get_peer(&self, peer_id: &PeerId) -> Option<Arc<peer::Peer>>;
+
     /// It returns the list of peers for a given peer client, optionally limiting the
     /// result.
     ///
@@ -48,6 +52,15 @@ pub trait Entry {
 
     /// It removes peer from the swarm that have not been updated for more than `current_cutoff` seconds
     fn remove_inactive_peers(&mut self, current_cutoff: DurationSinceUnixEpoch);
+
+    /// It removes a peer from the swarm and returns true if the peer was in the swarm.
+    ///
+    /// The number of complete downloads is not changed.
+    fn remove_peer(&mut self, peer_id: &PeerId) -> bool;
+
+    /// It removes all the peers with the given IP address from the swarm and
+    /// returns how many were removed.
+    fn remove_peers_with_ip(&mut self, ip: &IpAddr) -> usize;
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -57,9 +70,12 @@ pub trait EntrySync {
     fn peers_is_empty(&self) -> bool;
     fn get_peers_len(&self) -> usize;
     fn get_peers(&self, limit: Option<usize>) -> Vec<Arc<peer::Peer>>;
+    fn get_peer(&self, peer_id: &PeerId) -> Option<Arc<peer::Peer>>;
     fn get_peers_for_client(&self, client: &SocketAddr, limit: Option<usize>) -> Vec<Arc<peer::Peer>>;
     fn upsert_peer(&self, peer: &peer::Peer) -> bool;
     fn remove_inactive_peers(&self, current_cutoff: DurationSinceUnixEpoch);
+    fn remove_peer(&self, peer_id: &PeerId) -> bool;
+    fn remove_peers_with_ip(&self, ip: &IpAddr) -> usize;
 }
 
 #[allow(clippy::module_name_repetitions)]
@@ -69,6 +85,7 @@ pub trait EntryAsync {
     fn peers_is_empty(&self) -> impl std::future::Future<Output = bool> + Send;
     fn get_peers_len(&self) -> impl std::future::Future<Output = usize> + Send;
     fn get_peers(&self, limit: Option<usize>) -> impl std::future::Future<Output = Vec<Arc<peer::Peer>>> + Send;
+    fn get_peer(&self, peer_id: &PeerId) -> impl std::future::Future<Output = Option<Arc<peer::Peer>>> + Send;
     fn get_peers_for_client(
         &self,
         client: &SocketAddr,
@@ -76,6 +93,8 @@ pub trait EntryAsync {
     ) -> impl std::future::Future<Output = Vec<Arc<peer::Peer>>> + Send;
     fn upsert_peer(self, peer: &peer::Peer) -> impl std::future::Future<Output = bool> + Send;
     fn remove_inactive_peers(self, current_cutoff: DurationSinceUnixEpoch) -> impl std::future::Future<Output = ()> + Send;
+    fn remove_peer(self, peer_id: &PeerId) -> impl std::future::Future<Output = bool> + Send;
+    fn remove_peers_with_ip(self, ip: &IpAddr) -> impl std::future::Future<Output = usize> + Send;
 }
 
 /// A data structure containing all the information about a torrent in the tracker.
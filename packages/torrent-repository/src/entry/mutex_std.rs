@@ -1,6 +1,7 @@
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
+use aquatic_udp_protocol::PeerId;
 use torrust_tracker_configuration::TrackerPolicy;
 use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
 use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
@@ -29,6 +30,10 @@ impl EntrySync for EntryMutexStd {
         self.lock().expect("it should get lock").get_peers(limit)
     }
 
+    fn get_peer(&self, peer_id: &PeerId) -> Option<Arc<peer::Peer>> {
+        self.lock().expect("it should get lock").get_peer(peer_id)
+    }
+
     fn get_peers_for_client(&self, client: &SocketAddr, limit: Option<usize>) -> Vec<Arc<peer::Peer>> {
         self.lock().expect("it should get lock").get_peers_for_client(client, limit)
     }
@@ -42,6 +47,14 @@ impl EntrySync for EntryMutexStd {
             .expect("it should lock the entry")
             .remove_inactive_peers(current_cutoff);
     }
+
+    fn remove_peer(&self, peer_id: &PeerId) -> bool {
+        self.lock().expect("it should lock the entry").remove_peer(peer_id)
+    }
+
+    fn remove_peers_with_ip(&self, ip: &IpAddr) -> usize {
+        self.lock().expect("it should lock the entry").remove_peers_with_ip(ip)
+    }
 }
 
 impl From<EntrySingle> for EntryMutexStd {
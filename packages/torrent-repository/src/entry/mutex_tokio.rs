@@ -1,6 +1,7 @@
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
+use aquatic_udp_protocol::PeerId;
 use torrust_tracker_configuration::TrackerPolicy;
 use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
 use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
@@ -29,6 +30,10 @@ impl EntryAsync for EntryMutexTokio {
         self.lock().await.get_peers(limit)
     }
 
+    async fn get_peer(&self, peer_id: &PeerId) -> Option<Arc<peer::Peer>> {
+        self.lock().await.get_peer(peer_id)
+    }
+
     async fn get_peers_for_client(&self, client: &SocketAddr, limit: Option<usize>) -> Vec<Arc<peer::Peer>> {
         self.lock().await.get_peers_for_client(client, limit)
     }
@@ -40,6 +45,14 @@ impl EntryAsync for EntryMutexTokio {
     async fn remove_inactive_peers(self, current_cutoff: DurationSinceUnixEpoch) {
         self.lock().await.remove_inactive_peers(current_cutoff);
     }
+
+    async fn remove_peer(self, peer_id: &PeerId) -> bool {
+        self.lock().await.remove_peer(peer_id)
+    }
+
+    async fn remove_peers_with_ip(self, ip: &IpAddr) -> usize {
+        self.lock().await.remove_peers_with_ip(ip)
+    }
 }
 
 impl From<EntrySingle> for EntryMutexTokio {
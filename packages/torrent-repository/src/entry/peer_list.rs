@@ -1,5 +1,5 @@
 //! A peer list.
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
 use aquatic_udp_protocol::PeerId;
@@ -34,6 +34,17 @@ impl PeerList {
         self.peers.remove(key)
     }
 
+    /// It removes all the peers with the given IP address and returns how
+    /// many were removed.
+    pub fn remove_peers_with_ip(&mut self, ip: &IpAddr) -> usize {
+        let len = self.peers.len();
+
+        self.peers
+            .retain(|_, peer| peer::ReadInfo::get_address(peer.as_ref()).ip() != *ip);
+
+        len - self.peers.len()
+    }
+
     pub fn remove_inactive_peers(&mut self, current_cutoff: DurationSinceUnixEpoch) {
         self.peers
             .retain(|_, peer| peer::ReadInfo::get_updated(peer) > current_cutoff);
@@ -189,6 +200,26 @@ mod tests {
             assert_eq!(peer_list.get(&peer.peer_id), None);
         }
 
+        #[test]
+        fn allow_removing_all_the_peers_with_a_given_ip() {
+            let mut peer_list = PeerList::default();
+
+            for (id, ip) in [
+                (b"-qB00000000000000001", Ipv4Addr::new(127, 0, 0, 1)),
+                (b"-qB00000000000000002", Ipv4Addr::new(127, 0, 0, 1)),
+                (b"-qB00000000000000003", Ipv4Addr::new(127, 0, 0, 2)),
+            ] {
+                let peer = PeerBuilder::default()
+                    .with_peer_id(&PeerId(*id))
+                    .with_peer_addr(&SocketAddr::new(IpAddr::V4(ip), 6969))
+                    .build();
+                peer_list.upsert(peer.into());
+            }
+
+            assert_eq!(peer_list.remove_peers_with_ip(&IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))), 2);
+            assert_eq!(peer_list.len(), 1);
+        }
+
         #[test]
         fn allow_getting_all_peers_excluding_peers_with_a_given_address() {
             let mut peer_list = PeerList::default();
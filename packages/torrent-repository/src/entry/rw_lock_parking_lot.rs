@@ -1,6 +1,7 @@
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
+use aquatic_udp_protocol::PeerId;
 use torrust_tracker_configuration::TrackerPolicy;
 use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
 use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
@@ -29,6 +30,10 @@ impl EntrySync for EntryRwLockParkingLot {
         self.read().get_peers(limit)
     }
 
+    fn get_peer(&self, peer_id: &PeerId) -> Option<Arc<peer::Peer>> {
+        self.read().get_peer(peer_id)
+    }
+
     fn get_peers_for_client(&self, client: &SocketAddr, limit: Option<usize>) -> Vec<Arc<peer::Peer>> {
         self.read().get_peers_for_client(client, limit)
     }
@@ -40,6 +45,14 @@ impl EntrySync for EntryRwLockParkingLot {
     fn remove_inactive_peers(&self, current_cutoff: DurationSinceUnixEpoch) {
         self.write().remove_inactive_peers(current_cutoff);
     }
+
+    fn remove_peer(&self, peer_id: &PeerId) -> bool {
+        self.write().remove_peer(peer_id)
+    }
+
+    fn remove_peers_with_ip(&self, ip: &IpAddr) -> usize {
+        self.write().remove_peers_with_ip(ip)
+    }
 }
 
 impl From<EntrySingle> for EntryRwLockParkingLot {
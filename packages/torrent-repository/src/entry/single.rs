@@ -1,7 +1,7 @@
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
-use aquatic_udp_protocol::AnnounceEvent;
+use aquatic_udp_protocol::{AnnounceEvent, PeerId};
 use torrust_tracker_configuration::TrackerPolicy;
 use torrust_tracker_primitives::peer::{self};
 use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
@@ -46,6 +46,10 @@ impl Entry for EntrySingle {
         self.swarm.get_all(limit)
     }
 
+    fn get_peer(&self, peer_id: &PeerId) -> Option<Arc<peer::Peer>> {
+        self.swarm.get(peer_id).cloned()
+    }
+
     fn get_peers_for_client(&self, client: &SocketAddr, limit: Option<usize>) -> Vec<Arc<peer::Peer>> {
         self.swarm.get_peers_excluding_addr(client, limit)
     }
@@ -76,4 +80,12 @@ impl Entry for EntrySingle {
     fn remove_inactive_peers(&mut self, current_cutoff: DurationSinceUnixEpoch) {
         self.swarm.remove_inactive_peers(current_cutoff);
     }
+
+    fn remove_peer(&mut self, peer_id: &PeerId) -> bool {
+        self.swarm.remove(peer_id).is_some()
+    }
+
+    fn remove_peers_with_ip(&mut self, ip: &IpAddr) -> usize {
+        self.swarm.remove_peers_with_ip(ip)
+    }
 }
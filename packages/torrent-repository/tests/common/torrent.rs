@@ -1,6 +1,7 @@
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
+use aquatic_udp_protocol::PeerId;
 use torrust_tracker_configuration::TrackerPolicy;
 use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
 use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
@@ -69,6 +70,16 @@ impl Torrent {
         }
     }
 
+    pub(crate) async fn get_peer(&self, peer_id: &PeerId) -> Option<Arc<peer::Peer>> {
+        match self {
+            Torrent::Single(entry) => entry.get_peer(peer_id),
+            Torrent::MutexStd(entry) => entry.get_peer(peer_id),
+            Torrent::MutexTokio(entry) => entry.clone().get_peer(peer_id).await,
+            Torrent::MutexParkingLot(entry) => entry.get_peer(peer_id),
+            Torrent::RwLockParkingLot(entry) => entry.get_peer(peer_id),
+        }
+    }
+
     pub(crate) async fn get_peers_for_client(&self, client: &SocketAddr, limit: Option<usize>) -> Vec<Arc<peer::Peer>> {
         match self {
             Torrent::Single(entry) => entry.get_peers_for_client(client, limit),
@@ -98,4 +109,24 @@ impl Torrent {
             Torrent::RwLockParkingLot(entry) => entry.remove_inactive_peers(current_cutoff),
         }
     }
+
+    pub(crate) async fn remove_peer(&mut self, peer_id: &PeerId) -> bool {
+        match self {
+            Torrent::Single(entry) => entry.remove_peer(peer_id),
+            Torrent::MutexStd(entry) => entry.remove_peer(peer_id),
+            Torrent::MutexTokio(entry) => entry.clone().remove_peer(peer_id).await,
+            Torrent::MutexParkingLot(entry) => entry.remove_peer(peer_id),
+            Torrent::RwLockParkingLot(entry) => entry.remove_peer(peer_id),
+        }
+    }
+
+    pub(crate) async fn remove_peers_with_ip(&mut self, ip: &IpAddr) -> usize {
+        match self {
+            Torrent::Single(entry) => entry.remove_peers_with_ip(ip),
+            Torrent::MutexStd(entry) => entry.remove_peers_with_ip(ip),
+            Torrent::MutexTokio(entry) => entry.clone().remove_peers_with_ip(ip).await,
+            Torrent::MutexParkingLot(entry) => entry.remove_peers_with_ip(ip),
+            Torrent::RwLockParkingLot(entry) => entry.remove_peers_with_ip(ip),
+        }
+    }
 }
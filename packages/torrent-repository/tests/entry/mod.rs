@@ -441,3 +441,63 @@ async fn it_should_remove_inactive_peers_beyond_cutoff(
 
     assert_eq!(torrent.get_peers_len().await, peers.len());
 }
+
+#[rstest]
+#[case::started(&Makes::Started)]
+#[case::completed(&Makes::Completed)]
+#[case::downloaded(&Makes::Downloaded)]
+#[case::three(&Makes::Three)]
+#[tokio::test]
+async fn it_should_get_a_peer_by_id(
+    #[values(single(), mutex_std(), mutex_tokio(), mutex_parking_lot(), rw_lock_parking_lot())] mut torrent: Torrent,
+    #[case] makes: &Makes,
+) {
+    let peers = make(&mut torrent, makes).await;
+
+    assert_eq!(torrent.get_peer(&peers[0].peer_id).await.as_deref(), Some(&peers[0]));
+    assert_eq!(torrent.get_peer(&a_started_peer(-1).peer_id).await, None);
+}
+
+#[rstest]
+#[case::started(&Makes::Started)]
+#[case::completed(&Makes::Completed)]
+#[case::downloaded(&Makes::Downloaded)]
+#[case::three(&Makes::Three)]
+#[tokio::test]
+async fn it_should_remove_a_peer_by_id_without_changing_the_downloaded_statistic(
+    #[values(single(), mutex_std(), mutex_tokio(), mutex_parking_lot(), rw_lock_parking_lot())] mut torrent: Torrent,
+    #[case] makes: &Makes,
+) {
+    let peers = make(&mut torrent, makes).await;
+
+    let downloaded = torrent.get_stats().await.downloaded;
+
+    assert!(torrent.remove_peer(&peers[0].peer_id).await);
+    assert!(!torrent.remove_peer(&peers[0].peer_id).await);
+
+    assert_eq!(torrent.get_peers_len().await, peers.len() - 1);
+    assert_eq!(torrent.get_stats().await.downloaded, downloaded);
+}
+
+#[rstest]
+#[case::empty(&Makes::Empty)]
+#[case::started(&Makes::Started)]
+#[case::three(&Makes::Three)]
+#[tokio::test]
+async fn it_should_remove_the_peers_with_a_given_ip(
+    #[values(single(), mutex_std(), mutex_tokio(), mutex_parking_lot(), rw_lock_parking_lot())] mut torrent: Torrent,
+    #[case] makes: &Makes,
+) {
+    let peers = make(&mut torrent, makes).await;
+
+    let ip = IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1));
+
+    for id in [-1, -2] {
+        let mut peer = a_started_peer(id);
+        peer.peer_addr = SocketAddr::new(ip, 8080);
+        torrent.upsert_peer(&peer).await;
+    }
+
+    assert_eq!(torrent.remove_peers_with_ip(&ip).await, 2);
+    assert_eq!(torrent.get_peers_len().await, peers.len());
+}
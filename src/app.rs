@@ -18,6 +18,9 @@
 //! Optional jobs:
 //!
 //! - Torrent cleaner: it removes inactive peers and (optionally) peerless torrents.
+//! - Clients breakdown: it updates the breakdown of active peers by client software.
+//! - Statistics persistence: it saves the usage statistics into the database.
+//! - Audit log cleanup: it removes the old entries of the audit log of the tracker API.
 //! - UDP trackers: the user can enable multiple UDP tracker on several ports.
 //! - HTTP trackers: the user can enable multiple HTTP tracker on several ports.
 //! - Tracker REST API: the tracker API can be enabled/disabled.
@@ -27,7 +30,11 @@ use tokio::task::JoinHandle;
 use torrust_tracker_configuration::Configuration;
 use tracing::instrument;
 
-use crate::bootstrap::jobs::{health_check_api, http_tracker, torrent_cleanup, tracker_apis, udp_tracker};
+use crate::bootstrap::jobs::{
+    audit_log_cleanup, clients_breakdown, expired_keys_cleanup, health_check_api, http_tracker, statistics_persistence,
+    torrent_cleanup, tracker_apis, udp_tracker,
+};
+use crate::bootstrap::reload::ConfigReloader;
 use crate::servers::registar::Registar;
 use crate::{core, servers};
 
@@ -37,8 +44,9 @@ use crate::{core, servers};
 ///
 /// - Can't retrieve tracker keys from database.
 /// - Can't load whitelist from database.
-#[instrument(skip(config, tracker))]
-pub async fn start(config: &Configuration, tracker: Arc<core::Tracker>) -> Vec<JoinHandle<()>> {
+/// - Can't load usage statistics from database.
+#[instrument(skip(config, tracker, reloader))]
+pub async fn start(config: &Configuration, tracker: Arc<core::Tracker>, reloader: Arc<ConfigReloader>) -> Vec<JoinHandle<()>> {
     if config.http_api.is_none()
         && (config.udp_trackers.is_none() || config.udp_trackers.as_ref().map_or(true, std::vec::Vec::is_empty))
         && (config.http_trackers.is_none() || config.http_trackers.as_ref().map_or(true, std::vec::Vec::is_empty))
@@ -66,6 +74,14 @@ pub async fn start(config: &Configuration, tracker: Arc<core::Tracker>) -> Vec<J
             .expect("Could not load whitelist from database.");
     }
 
+    // Load usage statistics
+    if config.core.statistics_persistence_interval > 0 {
+        tracker
+            .load_statistics_from_database()
+            .await
+            .expect("Could not load usage statistics from database.");
+    }
+
     // Start the UDP blocks
     if let Some(udp_trackers) = &config.udp_trackers {
         for udp_tracker_config in udp_trackers {
@@ -105,13 +121,20 @@ pub async fn start(config: &Configuration, tracker: Arc<core::Tracker>) -> Vec<J
         if let Some(job) = tracker_apis::start_job(
             http_api_config,
             tracker.clone(),
+            reloader,
             registar.give_form(),
+            registar.entries(),
             servers::apis::Version::V1,
         )
         .await
         {
             jobs.push(job);
         };
+
+        // Start runner to remove the old entries of the audit log, every interval
+        if http_api_config.audit_log_retention > 0 {
+            jobs.push(audit_log_cleanup::start_job(http_api_config, &tracker));
+        }
     } else {
         tracing::info!("No API block in configuration");
     }
@@ -121,6 +144,21 @@ pub async fn start(config: &Configuration, tracker: Arc<core::Tracker>) -> Vec<J
         jobs.push(torrent_cleanup::start_job(&config.core, &tracker));
     }
 
+    // Start runner to remove the expired authentication keys, every interval
+    if tracker.is_private() && tracker.checks_keys_expiration() && config.core.expired_keys_cleanup_interval > 0 {
+        jobs.push(expired_keys_cleanup::start_job(&config.core, &tracker));
+    }
+
+    // Start runner to update the breakdown of peers by client software, every interval
+    if config.core.clients_breakdown_interval > 0 {
+        jobs.push(clients_breakdown::start_job(&config.core, &tracker));
+    }
+
+    // Start runner to persist the usage statistics, every interval and at shutdown
+    if config.core.statistics_persistence_interval > 0 {
+        jobs.push(statistics_persistence::start_job(&config.core, &tracker));
+    }
+
     // Start Health Check API
     jobs.push(health_check_api::start_job(&config.health_check_api, registar.entries()).await);
 
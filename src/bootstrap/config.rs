@@ -2,7 +2,7 @@
 //!
 //! All environment variables are prefixed with `TORRUST_TRACKER_`.
 
-use torrust_tracker_configuration::{Configuration, Info};
+use torrust_tracker_configuration::{Configuration, Error, Info};
 
 pub const DEFAULT_PATH_CONFIG: &str = "./share/default/config/tracker.development.sqlite3.toml";
 
@@ -27,6 +27,20 @@ pub fn initialize_configuration() -> Configuration {
     Configuration::load(&info).expect("error loading configuration from sources")
 }
 
+/// It loads the application configuration from the environment like
+/// [`initialize_configuration`], but it returns the error instead of
+/// panicking. It's used to reload the configuration while the tracker is
+/// running.
+///
+/// # Errors
+///
+/// Will return an error if it can't load the configuration from either
+/// `./tracker.toml` file or the env var `TORRUST_TRACKER_CONFIG_TOML`.
+pub fn load_configuration() -> Result<Configuration, Error> {
+    let info = Info::new(DEFAULT_PATH_CONFIG.to_string())?;
+    Configuration::load(&info)
+}
+
 #[cfg(test)]
 mod tests {
 
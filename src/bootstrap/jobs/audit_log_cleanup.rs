@@ -0,0 +1,56 @@
+//! Job that runs a task on intervals to remove the old entries of the audit
+//! log.
+//!
+//! The tracker API records the administrative actions in the [audit log](crate::core::audit).
+//! This job removes the entries older than `audit_log_retention` days. It's a
+//! customizable HTTP API option. Setting it to `0` keeps the entries forever
+//! and the job is not started.
+//!
+//! Refer to [`torrust-tracker-configuration documentation`](https://docs.rs/torrust-tracker-configuration) for more info about that option.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use torrust_tracker_configuration::HttpApi;
+use tracing::instrument;
+
+use crate::core;
+use crate::core::statistics::SECONDS_PER_DAY;
+
+/// How often the old entries are removed.
+pub const CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// It starts a job for removing the old entries of the audit log.
+///
+/// The task is executed every [`CLEANUP_INTERVAL`], starting when the
+/// tracker starts.
+#[must_use]
+#[instrument(skip(config, tracker))]
+pub fn start_job(config: &HttpApi, tracker: &Arc<core::Tracker>) -> JoinHandle<()> {
+    let weak_tracker = std::sync::Arc::downgrade(tracker);
+    let retention = Duration::from_secs(config.audit_log_retention * SECONDS_PER_DAY);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {
+                    tracing::info!("Stopping audit log cleanup job..");
+                    break;
+                }
+                _ = interval.tick() => {
+                    if let Some(tracker) = weak_tracker.upgrade() {
+                        match tracker.remove_expired_audit_log_entries(retention) {
+                            Ok(removed) => tracing::debug!("Removed {removed} old audit log entries"),
+                            Err(err) => tracing::error!("Could not remove the old audit log entries: {err}"),
+                        }
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
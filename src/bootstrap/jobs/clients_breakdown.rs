@@ -0,0 +1,54 @@
+//! Job that runs a task on intervals to update the breakdown of the active
+//! peers by client software.
+//!
+//! The breakdown is built visiting all the peers, so it's not built for each
+//! API request. Refer to [`core::clients`](crate::core::clients) for more info.
+//!
+//! The job runs every `clients_breakdown_interval` seconds. It's a customizable
+//! core tracker option. Setting it to `0` disables the job.
+//!
+//! Refer to [`torrust-tracker-configuration documentation`](https://docs.rs/torrust-tracker-configuration) for more info about that option.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use tokio::task::JoinHandle;
+use torrust_tracker_configuration::Core;
+use tracing::instrument;
+
+use crate::core;
+
+/// It starts a job for updating the breakdown of active peers by client
+/// software.
+///
+/// The task is executed on a `clients_breakdown_interval`.
+#[must_use]
+#[instrument(skip(config, tracker))]
+pub fn start_job(config: &Core, tracker: &Arc<core::Tracker>) -> JoinHandle<()> {
+    let weak_tracker = std::sync::Arc::downgrade(tracker);
+    let interval = config.clients_breakdown_interval;
+
+    tokio::spawn(async move {
+        let interval = std::time::Duration::from_secs(interval);
+        let mut interval = tokio::time::interval(interval);
+
+        loop {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {
+                    tracing::info!("Stopping clients breakdown job..");
+                    break;
+                }
+                _ = interval.tick() => {
+                    if let Some(tracker) = weak_tracker.upgrade() {
+                        let start_time = Utc::now().time();
+                        tracing::debug!("Updating clients breakdown..");
+                        tracker.update_clients_breakdown().await;
+                        tracing::debug!("Updated clients breakdown in: {}ms", (Utc::now().time() - start_time).num_milliseconds());
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
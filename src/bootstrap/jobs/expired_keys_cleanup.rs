@@ -0,0 +1,56 @@
+//! Job that runs a task on intervals to remove the expired authentication keys.
+//!
+//! When the tracker is private and it checks the keys expiration, the expired
+//! keys are rejected but they are not removed. This job removes the keys that
+//! expired more than `expired_keys_grace_period` seconds ago, both from memory
+//! and from the database. Permanent keys are never removed.
+//!
+//! The job runs every `expired_keys_cleanup_interval` seconds. Both are
+//! customizable core tracker options.
+//!
+//! Refer to [`torrust-tracker-configuration documentation`](https://docs.rs/torrust-tracker-configuration) for more info about those options.
+
+use std::sync::Arc;
+
+use tokio::task::JoinHandle;
+use torrust_tracker_configuration::Core;
+use tracing::instrument;
+
+use crate::core;
+
+/// It starts a job for removing the expired authentication keys.
+///
+/// The task is executed on an `expired_keys_cleanup_interval`.
+///
+/// Refer to [`torrust-tracker-configuration documentation`](https://docs.rs/torrust-tracker-configuration) for more info about that option.
+#[must_use]
+#[instrument(skip(config, tracker))]
+pub fn start_job(config: &Core, tracker: &Arc<core::Tracker>) -> JoinHandle<()> {
+    let weak_tracker = std::sync::Arc::downgrade(tracker);
+    let interval = config.expired_keys_cleanup_interval;
+
+    tokio::spawn(async move {
+        let interval = std::time::Duration::from_secs(interval);
+        let mut interval = tokio::time::interval(interval);
+        interval.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {
+                    tracing::info!("Stopping expired keys cleanup job..");
+                    break;
+                }
+                _ = interval.tick() => {
+                    if let Some(tracker) = weak_tracker.upgrade() {
+                        match tracker.remove_expired_auth_keys().await {
+                            Ok(removed) => tracing::info!("Removed {removed} expired authentication keys"),
+                            Err(err) => tracing::error!("Could not remove the expired authentication keys: {err}"),
+                        }
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
@@ -6,8 +6,12 @@
 //! 2. Launch all the application services as concurrent jobs.
 //!
 //! This modules contains all the functions needed to start those jobs.
+pub mod audit_log_cleanup;
+pub mod clients_breakdown;
+pub mod expired_keys_cleanup;
 pub mod health_check_api;
 pub mod http_tracker;
+pub mod statistics_persistence;
 pub mod torrent_cleanup;
 pub mod tracker_apis;
 pub mod udp_tracker;
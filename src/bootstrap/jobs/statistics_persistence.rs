@@ -0,0 +1,65 @@
+//! Job that runs a task on intervals to persist the usage statistics.
+//!
+//! The tracker counters (number of requests handled, error responses, etcetera)
+//! are kept in memory. This job saves them into the database, so they are not
+//! reset when the tracker restarts, and adds how much they have increased to
+//! the daily totals. Refer to [`core::statistics`](crate::core::statistics)
+//! for more info.
+//!
+//! The job runs every `statistics_persistence_interval` seconds and one last
+//! time when the tracker shuts down. It's a customizable core tracker option.
+//! Setting it to `0` disables the job.
+//!
+//! Refer to [`torrust-tracker-configuration documentation`](https://docs.rs/torrust-tracker-configuration) for more info about that option.
+
+use std::sync::Arc;
+
+use tokio::task::JoinHandle;
+use torrust_tracker_configuration::Core;
+use tracing::instrument;
+
+use crate::core;
+
+/// It starts a job for persisting the usage statistics.
+///
+/// The task is executed on a `statistics_persistence_interval` and when the
+/// tracker receives the shutdown signal.
+#[must_use]
+#[instrument(skip(config, tracker))]
+pub fn start_job(config: &Core, tracker: &Arc<core::Tracker>) -> JoinHandle<()> {
+    let weak_tracker = std::sync::Arc::downgrade(tracker);
+    let interval = config.statistics_persistence_interval;
+
+    tokio::spawn(async move {
+        let interval = std::time::Duration::from_secs(interval);
+        let mut interval = tokio::time::interval(interval);
+        interval.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {
+                    tracing::info!("Stopping statistics persistence job..");
+                    if let Some(tracker) = weak_tracker.upgrade() {
+                        persist(&tracker).await;
+                    }
+                    break;
+                }
+                _ = interval.tick() => {
+                    if let Some(tracker) = weak_tracker.upgrade() {
+                        persist(&tracker).await;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    })
+}
+
+async fn persist(tracker: &core::Tracker) {
+    tracing::debug!("Persisting usage statistics..");
+    match tracker.persist_statistics().await {
+        Ok(()) => tracing::debug!("Persisted usage statistics"),
+        Err(err) => tracing::error!("Could not persist usage statistics: {err}"),
+    }
+}
@@ -25,14 +25,15 @@ use std::sync::Arc;
 
 use axum_server::tls_rustls::RustlsConfig;
 use tokio::task::JoinHandle;
-use torrust_tracker_configuration::{AccessTokens, HttpApi};
+use torrust_tracker_configuration::HttpApi;
 use tracing::instrument;
 
 use super::make_rust_tls;
+use crate::bootstrap::reload::ConfigReloader;
 use crate::core;
 use crate::servers::apis::server::{ApiServer, Launcher};
 use crate::servers::apis::Version;
-use crate::servers::registar::ServiceRegistrationForm;
+use crate::servers::registar::{ServiceRegistrationForm, ServiceRegistry};
 
 /// This is the message that the "launcher" spawned task sends to the main
 /// application process to notify the API server was successfully started.
@@ -54,11 +55,13 @@ pub struct ApiServerJobStarted();
 /// It would panic if unable to send the  `ApiServerJobStarted` notice.
 ///
 ///
-#[instrument(skip(config, tracker, form))]
+#[instrument(skip(config, tracker, reloader, form, registry))]
 pub async fn start_job(
     config: &HttpApi,
     tracker: Arc<core::Tracker>,
+    reloader: Arc<ConfigReloader>,
     form: ServiceRegistrationForm,
+    registry: ServiceRegistry,
     version: Version,
 ) -> Option<JoinHandle<()>> {
     let bind_to = config.bind_address;
@@ -67,24 +70,26 @@ pub async fn start_job(
         .await
         .map(|tls| tls.expect("it should have a valid tracker api tls configuration"));
 
-    let access_tokens = Arc::new(config.access_tokens.clone());
+    let config = Arc::new(config.clone());
 
     match version {
-        Version::V1 => Some(start_v1(bind_to, tls, tracker.clone(), form, access_tokens).await),
+        Version::V1 => Some(start_v1(bind_to, tls, tracker.clone(), reloader, form, registry, config).await),
     }
 }
 
 #[allow(clippy::async_yields_async)]
-#[instrument(skip(socket, tls, tracker, form, access_tokens))]
+#[instrument(skip(socket, tls, tracker, reloader, form, registry, config))]
 async fn start_v1(
     socket: SocketAddr,
     tls: Option<RustlsConfig>,
     tracker: Arc<core::Tracker>,
+    reloader: Arc<ConfigReloader>,
     form: ServiceRegistrationForm,
-    access_tokens: Arc<AccessTokens>,
+    registry: ServiceRegistry,
+    config: Arc<HttpApi>,
 ) -> JoinHandle<()> {
     let server = ApiServer::new(Launcher::new(socket, tls))
-        .start(tracker, form, access_tokens)
+        .start(tracker, form, config, reloader, registry)
         .await
         .expect("it should be able to start to the tracker api");
 
@@ -102,6 +107,7 @@ mod tests {
 
     use crate::bootstrap::app::initialize_with_configuration;
     use crate::bootstrap::jobs::tracker_apis::start_job;
+    use crate::bootstrap::reload::ConfigReloader;
     use crate::servers::apis::Version;
     use crate::servers::registar::Registar;
 
@@ -110,9 +116,11 @@ mod tests {
         let cfg = Arc::new(ephemeral_public());
         let config = &cfg.http_api.clone().unwrap();
         let tracker = initialize_with_configuration(&cfg);
+        let reloader = Arc::new(ConfigReloader::new(&cfg, tracker.clone()));
         let version = Version::V1;
+        let registar = Registar::default();
 
-        start_job(config, tracker, Registar::default().give_form(), version)
+        start_job(config, tracker, reloader, registar.give_form(), registar.entries(), version)
             .await
             .expect("it should be able to join to the tracker api start-job");
     }
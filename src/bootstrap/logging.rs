@@ -11,13 +11,21 @@
 //! - `Trace`
 //!
 //! Refer to the [configuration crate documentation](https://docs.rs/torrust-tracker-configuration) to know how to change log settings.
-use std::sync::Once;
+//!
+//! The threshold can be changed while the application is running with
+//! [`reload_threshold`], unless logging was initially disabled (`Off`).
+use std::sync::{Once, OnceLock};
 
 use torrust_tracker_configuration::{Configuration, Threshold};
 use tracing::level_filters::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload, Layer, Registry};
 
 static INIT: Once = Once::new();
 
+static RELOAD_HANDLE: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
 /// It redirects the log info to the standard output with the log threshold
 /// defined in the configuration.
 pub fn setup(cfg: &Configuration) {
@@ -43,16 +51,42 @@ fn map_to_tracing_level_filter(threshold: &Threshold) -> LevelFilter {
     }
 }
 
+/// Returns `true` if the log threshold can be changed without restarting the
+/// application. It can't when logging was not initialized.
+pub fn is_threshold_reloadable() -> bool {
+    RELOAD_HANDLE.get().is_some()
+}
+
+/// It changes the log threshold of the running application.
+///
+/// It does nothing if logging was not initialized. Use
+/// [`is_threshold_reloadable`] to check it beforehand.
+pub fn reload_threshold(threshold: &Threshold) {
+    let Some(handle) = RELOAD_HANDLE.get() else {
+        return;
+    };
+
+    if let Err(err) = handle.reload(map_to_tracing_level_filter(threshold)) {
+        tracing::error!("Unable to change the log threshold to {threshold:?}: {err}");
+    }
+}
+
 fn tracing_stdout_init(filter: LevelFilter, style: &TraceStyle) {
-    let builder = tracing_subscriber::fmt().with_max_level(filter).with_ansi(true);
+    let (filter, handle) = reload::Layer::new(filter);
 
-    let () = match style {
-        TraceStyle::Default => builder.init(),
-        TraceStyle::Pretty(display_filename) => builder.pretty().with_file(*display_filename).init(),
-        TraceStyle::Compact => builder.compact().init(),
-        TraceStyle::Json => builder.json().init(),
+    let layer = fmt::layer().with_ansi(true);
+
+    let layer = match style {
+        TraceStyle::Default => layer.boxed(),
+        TraceStyle::Pretty(display_filename) => layer.pretty().with_file(*display_filename).boxed(),
+        TraceStyle::Compact => layer.compact().boxed(),
+        TraceStyle::Json => layer.json().boxed(),
     };
 
+    tracing_subscriber::registry().with(filter).with(layer).init();
+
+    drop(RELOAD_HANDLE.set(handle));
+
     tracing::info!("Logging initialized");
 }
 
@@ -0,0 +1,250 @@
+//! Reload the configuration while the tracker is running.
+//!
+//! Only a subset of the configuration can be changed without restarting the
+//! tracker. See [`RELOADABLE_OPTIONS`](torrust_tracker_configuration::v2_0_0::RELOADABLE_OPTIONS):
+//!
+//! - `core.announce_policy`
+//! - `core.tracker_policy`
+//! - `http_api.access_tokens`
+//! - `logging.threshold`
+//!
+//! The reload is triggered by sending a `SIGHUP` signal to the tracker process
+//! or with the tracker API. The configuration is loaded again from the same
+//! sources used when the tracker started, and validated. If any other option
+//! has changed, nothing is applied and the options that require a restart are
+//! reported, for example, the bind addresses of the services.
+//!
+//! > **NOTICE**: the log threshold can't be changed when logging was disabled
+//! > (`off`) at startup.
+use std::sync::{Arc, Mutex, RwLock};
+
+use torrust_tracker_configuration::v2_0_0::is_reloadable;
+use torrust_tracker_configuration::validator::{SemanticValidationError, Validator};
+use torrust_tracker_configuration::{AccessTokens, Configuration};
+
+use super::config::load_configuration;
+use super::logging;
+use crate::core::Tracker;
+
+/// Errors that can occur reloading the configuration.
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Unable to load the configuration: {source}")]
+    Load { source: torrust_tracker_configuration::Error },
+
+    #[error("Invalid configuration: {source}")]
+    Invalid { source: SemanticValidationError },
+
+    #[error("These options can't be changed without restarting the tracker: {}", options.join(", "))]
+    RestartRequired { options: Vec<String> },
+}
+
+/// It applies the changes in the configuration to the running services.
+pub struct ConfigReloader {
+    /// The configuration the services are running with.
+    current: Mutex<Configuration>,
+
+    /// The tracker whose policies are changed.
+    tracker: Arc<Tracker>,
+
+    /// The tokens used by the tracker API to authenticate the requests.
+    api_access_tokens: Arc<RwLock<AccessTokens>>,
+}
+
+impl ConfigReloader {
+    #[must_use]
+    pub fn new(configuration: &Configuration, tracker: Arc<Tracker>) -> Self {
+        let access_tokens = configuration
+            .http_api
+            .as_ref()
+            .map(|http_api| http_api.access_tokens.clone())
+            .unwrap_or_default();
+
+        Self {
+            current: Mutex::new(configuration.clone()),
+            tracker,
+            api_access_tokens: Arc::new(RwLock::new(access_tokens)),
+        }
+    }
+
+    /// The tokens the tracker API has to use to authenticate the requests.
+    /// They are replaced when the configuration is reloaded.
+    #[must_use]
+    pub fn api_access_tokens(&self) -> Arc<RwLock<AccessTokens>> {
+        self.api_access_tokens.clone()
+    }
+
+    /// The configuration the services are running with, including the
+    /// changes applied since the tracker started.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the current configuration lock is poisoned.
+    #[must_use]
+    pub fn current(&self) -> Configuration {
+        self.current.lock().expect("it should get the current configuration").clone()
+    }
+
+    /// It loads the configuration again from the environment and applies the
+    /// changes. It returns the options that have changed.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the configuration can't be loaded or applied.
+    /// See [`ConfigReloader::apply`].
+    pub fn reload(&self) -> Result<Vec<String>, Error> {
+        let configuration = load_configuration().map_err(|source| Error::Load { source })?;
+
+        self.apply(configuration)
+    }
+
+    /// It applies the new configuration to the running services and returns
+    /// the options that have changed. Changes are only applied if all of them
+    /// can be applied without restarting the tracker.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the new configuration is not valid or any of
+    /// the changed options requires a restart.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the current configuration or the access tokens locks are
+    /// poisoned.
+    pub fn apply(&self, configuration: Configuration) -> Result<Vec<String>, Error> {
+        configuration.validate().map_err(|source| Error::Invalid { source })?;
+
+        let mut current = self.current.lock().expect("it should get the current configuration");
+
+        let changed_options = current.changed_options(&configuration);
+
+        let restart_required: Vec<String> = changed_options
+            .iter()
+            .filter(|option| {
+                !is_reloadable(option) || (option.as_str() == "logging.threshold" && !logging::is_threshold_reloadable())
+            })
+            .cloned()
+            .collect();
+
+        if !restart_required.is_empty() {
+            return Err(Error::RestartRequired {
+                options: restart_required,
+            });
+        }
+
+        if changed_options.is_empty() {
+            tracing::info!("Configuration reloaded, nothing has changed");
+            return Ok(changed_options);
+        }
+
+        self.tracker
+            .update_policies(configuration.core.announce_policy, configuration.core.tracker_policy.clone());
+
+        if let Some(http_api) = &configuration.http_api {
+            http_api
+                .access_tokens
+                .clone_into(&mut self.api_access_tokens.write().expect("it should update the access tokens"));
+        }
+
+        logging::reload_threshold(&configuration.logging.threshold);
+
+        tracing::info!("Configuration reloaded, changed options: {}", changed_options.join(", "));
+
+        *current = configuration;
+
+        Ok(changed_options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use torrust_tracker_configuration::v2_0_0::core::PrivateMode;
+    use torrust_tracker_configuration::Configuration;
+    use torrust_tracker_test_helpers::configuration::ephemeral_public;
+
+    use crate::bootstrap::app::initialize_with_configuration;
+    use crate::bootstrap::reload::{ConfigReloader, Error};
+
+    fn reloader() -> (Configuration, ConfigReloader) {
+        let configuration = ephemeral_public();
+        let tracker = initialize_with_configuration(&configuration);
+
+        let reloader = ConfigReloader::new(&configuration, tracker);
+
+        (configuration, reloader)
+    }
+
+    #[test]
+    fn it_should_change_the_tracker_policies() {
+        let (configuration, reloader) = reloader();
+
+        let mut new_configuration = configuration.clone();
+        new_configuration.core.announce_policy.interval += 60;
+        new_configuration.core.tracker_policy.max_peer_timeout += 60;
+
+        let changed_options = reloader.apply(new_configuration.clone()).unwrap();
+
+        assert_eq!(
+            changed_options,
+            vec![
+                "core.announce_policy.interval".to_string(),
+                "core.tracker_policy.max_peer_timeout".to_string()
+            ]
+        );
+        assert_eq!(reloader.tracker.get_announce_policy(), new_configuration.core.announce_policy);
+        assert_eq!(reloader.tracker.get_tracker_policy(), new_configuration.core.tracker_policy);
+    }
+
+    #[test]
+    fn it_should_change_the_api_access_tokens() {
+        let (configuration, reloader) = reloader();
+
+        let mut new_configuration = configuration.clone();
+        new_configuration
+            .http_api
+            .as_mut()
+            .unwrap()
+            .add_token("new_admin", "NewAccessToken");
+
+        let changed_options = reloader.apply(new_configuration.clone()).unwrap();
+
+        assert_eq!(changed_options, vec!["http_api.access_tokens.new_admin".to_string()]);
+        assert_eq!(
+            *reloader.api_access_tokens().read().unwrap(),
+            new_configuration.http_api.unwrap().access_tokens
+        );
+    }
+
+    #[test]
+    fn it_should_not_report_any_change_when_the_configuration_is_the_same() {
+        let (configuration, reloader) = reloader();
+
+        assert!(reloader.apply(configuration).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_should_refuse_the_changes_that_require_a_restart() {
+        let (configuration, reloader) = reloader();
+
+        let mut new_configuration = configuration.clone();
+        new_configuration.core.announce_policy.interval += 60;
+        new_configuration.http_api.as_mut().unwrap().bind_address.set_port(1);
+
+        let result = reloader.apply(new_configuration);
+
+        assert!(
+            matches!(result, Err(Error::RestartRequired { options }) if options == vec!["http_api.bind_address".to_string()])
+        );
+        assert_eq!(reloader.tracker.get_announce_policy(), configuration.core.announce_policy);
+    }
+
+    #[test]
+    fn it_should_refuse_an_invalid_configuration() {
+        let (configuration, reloader) = reloader();
+
+        let mut new_configuration = configuration.clone();
+        new_configuration.core.private_mode = Some(PrivateMode::default());
+
+        assert!(matches!(reloader.apply(new_configuration), Err(Error::Invalid { .. })));
+    }
+}
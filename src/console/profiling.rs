@@ -157,10 +157,12 @@
 //! kcachegrind callgrind.out
 //! ```
 use std::env;
+use std::sync::Arc;
 use std::time::Duration;
 
 use tokio::time::sleep;
 
+use crate::bootstrap::reload::ConfigReloader;
 use crate::{app, bootstrap};
 
 pub async fn run() {
@@ -181,7 +183,9 @@ pub async fn run() {
 
     let (config, tracker) = bootstrap::app::setup();
 
-    let jobs = app::start(&config, tracker).await;
+    let reloader = Arc::new(ConfigReloader::new(&config, tracker.clone()));
+
+    let jobs = app::start(&config, tracker, reloader).await;
 
     // Run the tracker for a fixed duration
     let run_duration = sleep(Duration::from_secs(duration_secs));
@@ -0,0 +1,78 @@
+//! Audit log of the administrative actions.
+//!
+//! The tracker API records every request that changes the tracker state, for
+//! example, adding a torrent to the whitelist or reloading the keys. Each
+//! [`Entry`] holds who did it (the label of the API access token), what was
+//! done, on which target, the result and when.
+//!
+//! The log is append-only: entries are never changed, and they are only
+//! removed when they are older than the retention period. See
+//! [`audit_log_cleanup`](crate::bootstrap::jobs::audit_log_cleanup) job.
+//!
+//! The entries are stored in the database. Refer to the [`databases`](crate::core::databases)
+//! module for more information about persistence.
+use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+/// An administrative action done with the tracker API.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Entry {
+    /// When the action was done. Only the seconds are persisted.
+    pub timestamp: DurationSinceUnixEpoch,
+    /// The label of the access token used to authenticate the request. For
+    /// example: `admin`.
+    pub label: String,
+    /// The request method and route. For example: `POST /api/v1/whitelist/:info_hash`.
+    pub action: String,
+    /// The path params of the request, separated by `/`. For example: the
+    /// info-hash of the whitelisted torrent. It's empty for actions without
+    /// a target.
+    pub target: String,
+    /// The status code of the response.
+    pub result: u16,
+}
+
+impl Entry {
+    /// Returns `true` if the action was done. Rejected and failed actions are
+    /// also recorded.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.result)
+    }
+}
+
+/// A page of the audit log, from the newest entry.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Page {
+    pub entries: Vec<Entry>,
+    /// The number of entries in the whole log.
+    pub total: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+    use super::Entry;
+
+    fn entry_with_result(result: u16) -> Entry {
+        Entry {
+            timestamp: DurationSinceUnixEpoch::from_secs(1_729_326_371),
+            label: "admin".to_string(),
+            action: "POST /api/v1/whitelist/:info_hash".to_string(),
+            target: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+            result,
+        }
+    }
+
+    #[test]
+    fn it_should_be_successful_when_the_response_status_is_2xx() {
+        assert!(entry_with_result(200).is_success());
+        assert!(entry_with_result(204).is_success());
+    }
+
+    #[test]
+    fn it_should_not_be_successful_when_the_response_status_is_not_2xx() {
+        assert!(!entry_with_result(403).is_success());
+        assert!(!entry_with_result(500).is_success());
+    }
+}
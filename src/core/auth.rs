@@ -36,11 +36,15 @@
 //! assert!(auth::verify_key_expiration(&expiring_key).is_ok());
 //! ```
 
+use std::collections::HashSet;
 use std::panic::Location;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
 
+use aquatic_udp_protocol::PeerId;
+use bittorrent_primitives::info_hash::InfoHash;
+use dashmap::DashMap;
 use derive_more::Display;
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
@@ -161,6 +165,83 @@ impl PeerKey {
     pub fn expiry_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
         self.valid_until.map(convert_from_timestamp_to_datetime_utc)
     }
+
+    /// Whether the key has expired at the given time. Permanent keys never
+    /// expire.
+    #[must_use]
+    pub fn is_expired_at(&self, now: DurationSinceUnixEpoch) -> bool {
+        self.valid_until.is_some_and(|valid_until| valid_until < now)
+    }
+}
+
+/// Criteria to select authentication keys. Unset criteria match all the keys.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KeyFilter {
+    /// Only the keys that have expired (`true`) or have not expired (`false`).
+    pub expired: Option<bool>,
+    /// Only the permanent keys (`true`) or the expiring keys (`false`).
+    pub permanent: Option<bool>,
+    /// Only the keys that expire before this time. Permanent keys never match.
+    pub expiring_before: Option<DurationSinceUnixEpoch>,
+}
+
+impl KeyFilter {
+    /// Whether the key meets all the criteria at the given time.
+    #[must_use]
+    pub fn matches(&self, peer_key: &PeerKey, now: DurationSinceUnixEpoch) -> bool {
+        self.expired.map_or(true, |expired| peer_key.is_expired_at(now) == expired)
+            && self
+                .permanent
+                .map_or(true, |permanent| peer_key.valid_until.is_none() == permanent)
+            && self.expiring_before.map_or(true, |expiring_before| {
+                peer_key.valid_until.is_some_and(|valid_until| valid_until < expiring_before)
+            })
+    }
+}
+
+/// How an authentication key has been used since the tracker started.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KeyUsage {
+    /// The last time the key was used to authenticate a request.
+    pub last_used: Option<DurationSinceUnixEpoch>,
+    /// The peers that announced with the key. They may not be in the swarms
+    /// anymore.
+    pub peers: HashSet<(InfoHash, PeerId)>,
+}
+
+/// The [`KeyUsage`] of all the authentication keys used since the tracker
+/// started. It's not persisted.
+#[derive(Debug, Default)]
+pub struct KeyUsages {
+    usages: DashMap<Key, KeyUsage>,
+}
+
+impl KeyUsages {
+    /// It records that the key has been used to authenticate a request.
+    pub fn record_use(&self, key: &Key, now: DurationSinceUnixEpoch) {
+        self.usages.entry(key.clone()).or_default().last_used = Some(now);
+    }
+
+    /// It records that a peer announced with the key.
+    pub fn record_announce(&self, key: &Key, info_hash: InfoHash, peer_id: PeerId) {
+        self.usages.entry(key.clone()).or_default().peers.insert((info_hash, peer_id));
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &Key) -> Option<KeyUsage> {
+        self.usages.get(key).map(|usage| usage.clone())
+    }
+
+    pub fn remove(&self, key: &Key) {
+        self.usages.remove(key);
+    }
+
+    /// It forgets the peers that are not active anymore.
+    pub fn retain_active_peers(&self, is_active: impl Fn(&InfoHash, &PeerId) -> bool) {
+        for mut usage in self.usages.iter_mut() {
+            usage.peers.retain(|(info_hash, peer_id)| is_active(info_hash, peer_id));
+        }
+    }
 }
 
 /// A token used for authentication.
@@ -343,4 +424,70 @@ mod tests {
             assert!(auth::verify_key_expiration(&expiring_key).is_err());
         }
     }
+
+    mod key_filter {
+        use std::time::Duration;
+
+        use crate::core::auth::{Key, KeyFilter, PeerKey};
+
+        fn permanent_key() -> PeerKey {
+            PeerKey {
+                key: "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ".parse::<Key>().unwrap(),
+                valid_until: None,
+            }
+        }
+
+        fn key_valid_until(secs: u64) -> PeerKey {
+            PeerKey {
+                key: "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ".parse::<Key>().unwrap(),
+                valid_until: Some(Duration::from_secs(secs)),
+            }
+        }
+
+        #[test]
+        fn the_default_filter_should_match_all_the_keys() {
+            let now = Duration::from_secs(100);
+
+            assert!(KeyFilter::default().matches(&permanent_key(), now));
+            assert!(KeyFilter::default().matches(&key_valid_until(50), now));
+        }
+
+        #[test]
+        fn it_should_filter_the_keys_by_expiration() {
+            let now = Duration::from_secs(100);
+            let expired = KeyFilter {
+                expired: Some(true),
+                ..Default::default()
+            };
+
+            assert!(expired.matches(&key_valid_until(50), now));
+            assert!(!expired.matches(&key_valid_until(150), now));
+            assert!(!expired.matches(&permanent_key(), now));
+        }
+
+        #[test]
+        fn it_should_filter_the_permanent_keys() {
+            let now = Duration::from_secs(100);
+            let permanent = KeyFilter {
+                permanent: Some(true),
+                ..Default::default()
+            };
+
+            assert!(permanent.matches(&permanent_key(), now));
+            assert!(!permanent.matches(&key_valid_until(150), now));
+        }
+
+        #[test]
+        fn it_should_filter_the_keys_expiring_before_a_given_time() {
+            let now = Duration::from_secs(100);
+            let expiring_before = KeyFilter {
+                expiring_before: Some(Duration::from_secs(200)),
+                ..Default::default()
+            };
+
+            assert!(expiring_before.matches(&key_valid_until(150), now));
+            assert!(!expiring_before.matches(&key_valid_until(250), now));
+            assert!(!expiring_before.matches(&permanent_key(), now));
+        }
+    }
 }
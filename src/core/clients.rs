@@ -0,0 +1,136 @@
+//! Breakdown of the active peers by client software.
+//!
+//! The client software and version are decoded from the peer id using the
+//! [`peer::Id::get_client_name`] and [`peer::Id::get_client_version`]
+//! functions. Peers whose client can not be decoded are counted as
+//! [`UNKNOWN`].
+//!
+//! Building the breakdown for the whole tracker requires visiting all the
+//! peers, so it is not done for each request. A periodic job
+//! (see [`clients_breakdown`](crate::bootstrap::jobs::clients_breakdown))
+//! updates it every `clients_breakdown_interval` seconds and stores it in the
+//! [`statistics::Repo`](crate::core::statistics::Repo), together with the
+//! breakdown for each torrent built in the same pass.
+use std::collections::BTreeMap;
+
+use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
+
+/// Name used for the clients and versions that can not be decoded.
+pub const UNKNOWN: &str = "unknown";
+
+/// Number of active peers for each client software.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ClientsBreakdown {
+    /// Clients by name.
+    pub clients: BTreeMap<String, Client>,
+    /// When the breakdown was built. `None` if it has not been built yet.
+    pub updated_at: Option<DurationSinceUnixEpoch>,
+}
+
+/// Number of active peers using one client software.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Client {
+    /// Number of peers that have completed the download.
+    pub seeders: u64,
+    /// Number of peers that are still downloading.
+    pub leechers: u64,
+    /// Number of peers by client version.
+    pub versions: BTreeMap<String, u64>,
+}
+
+impl Client {
+    #[must_use]
+    pub fn peers(&self) -> u64 {
+        self.seeders + self.leechers
+    }
+}
+
+impl ClientsBreakdown {
+    /// It builds the breakdown for the given peers.
+    #[must_use]
+    pub fn from_peers<'a>(peers: impl IntoIterator<Item = &'a peer::Peer>, updated_at: DurationSinceUnixEpoch) -> Self {
+        let mut breakdown = Self {
+            clients: BTreeMap::new(),
+            updated_at: Some(updated_at),
+        };
+
+        for peer in peers {
+            breakdown.add(peer);
+        }
+
+        breakdown
+    }
+
+    /// It adds a peer to the breakdown.
+    pub fn add(&mut self, peer: &peer::Peer) {
+        let peer_id = peer::Id::from(peer.peer_id);
+
+        let name = peer_id.get_client_name().unwrap_or_else(|| UNKNOWN.to_string());
+        let version = peer_id.get_client_version().unwrap_or_else(|| UNKNOWN.to_string());
+
+        let client = self.clients.entry(name).or_default();
+
+        if peer.is_seeder() {
+            client.seeders += 1;
+        } else {
+            client.leechers += 1;
+        }
+
+        *client.versions.entry(version).or_default() += 1;
+    }
+
+    /// Total number of peers in the breakdown.
+    #[must_use]
+    pub fn peers(&self) -> u64 {
+        self.clients.values().map(Client::peers).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aquatic_udp_protocol::PeerId;
+    use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+    use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
+
+    use super::{ClientsBreakdown, UNKNOWN};
+
+    fn peer_with_id(peer_id: &[u8; 20], left: i64) -> peer::Peer {
+        PeerBuilder::default()
+            .with_peer_id(&PeerId(*peer_id))
+            .with_bytes_pending_to_download(left)
+            .into()
+    }
+
+    #[test]
+    fn it_should_count_the_peers_by_client_and_version() {
+        let peers = [
+            peer_with_id(b"-qB4500-000000000001", 0),
+            peer_with_id(b"-qB4500-000000000002", 100),
+            peer_with_id(b"-qB4630-000000000003", 100),
+        ];
+
+        let breakdown = ClientsBreakdown::from_peers(&peers, DurationSinceUnixEpoch::from_secs(1));
+
+        assert_eq!(breakdown.clients.len(), 1);
+        assert_eq!(breakdown.peers(), 3);
+
+        let (name, client) = breakdown.clients.iter().next().unwrap();
+
+        assert_eq!(name, "qBittorrent");
+        assert_eq!(client.seeders, 1);
+        assert_eq!(client.leechers, 2);
+        assert_eq!(client.versions.values().sum::<u64>(), 3);
+        assert_eq!(client.versions.len(), 2);
+        assert_eq!(breakdown.updated_at, Some(DurationSinceUnixEpoch::from_secs(1)));
+    }
+
+    #[test]
+    fn it_should_count_the_peers_whose_client_can_not_be_decoded_as_unknown() {
+        let peers = [peer_with_id(&[0; 20], 0)];
+
+        let breakdown = ClientsBreakdown::from_peers(&peers, DurationSinceUnixEpoch::from_secs(1));
+
+        assert_eq!(breakdown.clients[UNKNOWN].peers(), 1);
+        assert_eq!(breakdown.clients[UNKNOWN].versions[UNKNOWN], 1);
+    }
+}
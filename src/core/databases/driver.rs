@@ -5,6 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::error::Error;
+use super::memory::Memory;
 use super::mysql::Mysql;
 use super::sqlite::Sqlite;
 use super::{Builder, Database};
@@ -23,6 +24,8 @@ pub enum Driver {
     Sqlite3,
     /// The `MySQL` database driver.
     MySQL,
+    /// The in-memory database driver. Nothing is persisted.
+    Memory,
 }
 
 /// It builds a new database driver.
@@ -49,6 +52,17 @@ pub enum Driver {
 /// let database = databases::driver::build(&db_driver, &db_path);
 /// ```
 ///
+/// Example for the in-memory driver (the path is ignored):
+///
+/// ```rust,no_run
+/// use torrust_tracker::core::databases;
+/// use torrust_tracker::core::databases::driver::Driver;
+///
+/// let db_driver = Driver::Memory;
+/// let db_path = String::new();
+/// let database = databases::driver::build(&db_driver, &db_path);
+/// ```
+///
 /// Refer to the [configuration documentation](https://docs.rs/torrust-tracker-configuration)
 /// for more information about the database configuration.
 ///
@@ -65,6 +79,7 @@ pub fn build(driver: &Driver, db_path: &str) -> Result<Box<dyn Database>, Error>
     let database = match driver {
         Driver::Sqlite3 => Builder::<Sqlite>::build(db_path),
         Driver::MySQL => Builder::<Mysql>::build(db_path),
+        Driver::Memory => Builder::<Memory>::build(db_path),
     }?;
 
     database.create_database_tables().expect("Could not create database tables.");
@@ -5,9 +5,14 @@ use std::panic::Location;
 use std::sync::Arc;
 
 use r2d2_mysql::mysql::UrlError;
+use r2d2_sqlite::rusqlite::ffi::{SQLITE_CONSTRAINT_PRIMARYKEY, SQLITE_CONSTRAINT_UNIQUE};
 use torrust_tracker_located_error::{DynError, Located, LocatedError};
 
 use super::driver::Driver;
+use super::memory::QueryError;
+
+/// `MySQL` error code for a duplicate entry in a unique index.
+const ER_DUP_ENTRY: u16 = 1062;
 
 #[derive(thiserror::Error, Debug, Clone)]
 pub enum Error {
@@ -25,6 +30,13 @@ pub enum Error {
         driver: Driver,
     },
 
+    /// The record already exists. For example, when adding a key twice.
+    #[error("The {driver} record already exists: {source}")]
+    UniqueConstraintFailed {
+        source: LocatedError<'static, dyn std::error::Error + Send + Sync>,
+        driver: Driver,
+    },
+
     /// Unable to insert a record into the database
     #[error("Unable to insert record into {driver} database, {location}")]
     InsertFailed {
@@ -63,6 +75,14 @@ impl From<r2d2_sqlite::rusqlite::Error> for Error {
                 source: (Arc::new(err) as DynError).into(),
                 driver: Driver::Sqlite3,
             },
+            r2d2_sqlite::rusqlite::Error::SqliteFailure(failure, _)
+                if matches!(failure.extended_code, SQLITE_CONSTRAINT_UNIQUE | SQLITE_CONSTRAINT_PRIMARYKEY) =>
+            {
+                Error::UniqueConstraintFailed {
+                    source: (Arc::new(err) as DynError).into(),
+                    driver: Driver::Sqlite3,
+                }
+            }
             _ => Error::InvalidQuery {
                 source: (Arc::new(err) as DynError).into(),
                 driver: Driver::Sqlite3,
@@ -74,10 +94,36 @@ impl From<r2d2_sqlite::rusqlite::Error> for Error {
 impl From<r2d2_mysql::mysql::Error> for Error {
     #[track_caller]
     fn from(err: r2d2_mysql::mysql::Error) -> Self {
+        let duplicate_entry = matches!(&err, r2d2_mysql::mysql::Error::MySqlError(e) if e.code == ER_DUP_ENTRY);
+
         let e: DynError = Arc::new(err);
-        Error::InvalidQuery {
-            source: e.into(),
-            driver: Driver::MySQL,
+
+        if duplicate_entry {
+            Error::UniqueConstraintFailed {
+                source: e.into(),
+                driver: Driver::MySQL,
+            }
+        } else {
+            Error::InvalidQuery {
+                source: e.into(),
+                driver: Driver::MySQL,
+            }
+        }
+    }
+}
+
+impl From<QueryError> for Error {
+    #[track_caller]
+    fn from(err: QueryError) -> Self {
+        match err {
+            QueryError::UniqueConstraintFailed { .. } => Error::UniqueConstraintFailed {
+                source: (Arc::new(err) as DynError).into(),
+                driver: Driver::Memory,
+            },
+            QueryError::NoSuchTable { .. } => Error::InvalidQuery {
+                source: (Arc::new(err) as DynError).into(),
+                driver: Driver::Memory,
+            },
         }
     }
 }
@@ -0,0 +1,627 @@
+//! The in-memory database driver.
+//!
+//! It keeps all the tables in memory, so nothing survives a tracker restart.
+//! It behaves like the SQL drivers: inserting a duplicate record or querying
+//! the tables after dropping them returns an error.
+use std::collections::{HashMap, HashSet};
+use std::panic::Location;
+use std::sync::RwLock;
+
+use bittorrent_primitives::info_hash::InfoHash;
+use thiserror::Error;
+use torrust_tracker_primitives::{DurationSinceUnixEpoch, PersistentTorrents};
+
+use super::driver::Driver;
+use super::{Database, Error};
+use crate::core::audit;
+use crate::core::auth::{self, Key};
+use crate::core::statistics::{DailyCounters, PersistentCounters};
+
+const DRIVER: Driver = Driver::Memory;
+
+/// Errors returned by the in-memory "queries".
+///
+/// They mimic the errors returned by the SQL drivers.
+#[derive(Error, Debug)]
+pub enum QueryError {
+    #[error("UNIQUE constraint failed: {table}.{column}")]
+    UniqueConstraintFailed { table: &'static str, column: &'static str },
+
+    #[error("no such table: {table}")]
+    NoSuchTable { table: &'static str },
+}
+
+#[derive(Default)]
+struct Tables {
+    torrents: PersistentTorrents,
+    whitelist: HashSet<InfoHash>,
+    keys: HashMap<Key, auth::PeerKey>,
+    statistics: PersistentCounters,
+    daily_statistics: DailyCounters,
+    /// In insertion order, from the oldest entry.
+    audit_log: Vec<audit::Entry>,
+}
+
+pub struct Memory {
+    /// `None` when the tables have not been created yet or they have been dropped.
+    tables: RwLock<Option<Tables>>,
+}
+
+impl Memory {
+    fn read<R>(&self, table: &'static str, query: impl FnOnce(&Tables) -> Result<R, Error>) -> Result<R, Error> {
+        match self.tables.read().expect("it should get the read lock").as_ref() {
+            Some(tables) => query(tables),
+            None => Err(QueryError::NoSuchTable { table }.into()),
+        }
+    }
+
+    fn write<R>(&self, table: &'static str, query: impl FnOnce(&mut Tables) -> Result<R, Error>) -> Result<R, Error> {
+        match self.tables.write().expect("it should get the write lock").as_mut() {
+            Some(tables) => query(tables),
+            None => Err(QueryError::NoSuchTable { table }.into()),
+        }
+    }
+}
+
+/// The SQL drivers store timestamps as seconds, so the sub-second part is lost.
+fn truncate_to_seconds(auth_key: &auth::PeerKey) -> auth::PeerKey {
+    auth::PeerKey {
+        key: auth_key.key.clone(),
+        valid_until: auth_key
+            .valid_until
+            .map(|valid_until| DurationSinceUnixEpoch::from_secs(valid_until.as_secs())),
+    }
+}
+
+impl Database for Memory {
+    /// It instantiates a new in-memory database driver. The `db_path` is ignored.
+    ///
+    /// Refer to [`databases::Database::new`](crate::core::databases::Database::new).
+    ///
+    /// # Errors
+    ///
+    /// This function never returns an error.
+    fn new(_db_path: &str) -> Result<Memory, Error> {
+        Ok(Memory {
+            tables: RwLock::new(None),
+        })
+    }
+
+    /// Refer to [`databases::Database::create_database_tables`](crate::core::databases::Database::create_database_tables).
+    fn create_database_tables(&self) -> Result<(), Error> {
+        let mut tables = self.tables.write().expect("it should get the write lock");
+
+        if tables.is_none() {
+            *tables = Some(Tables::default());
+        }
+
+        Ok(())
+    }
+
+    /// Refer to [`databases::Database::drop_database_tables`](crate::core::databases::Database::drop_database_tables).
+    fn drop_database_tables(&self) -> Result<(), Error> {
+        let mut tables = self.tables.write().expect("it should get the write lock");
+
+        match tables.take() {
+            Some(_) => Ok(()),
+            None => Err(QueryError::NoSuchTable { table: "whitelist" }.into()),
+        }
+    }
+
+    /// Refer to [`databases::Database::load_persistent_torrents`](crate::core::databases::Database::load_persistent_torrents).
+    fn load_persistent_torrents(&self) -> Result<PersistentTorrents, Error> {
+        self.read("torrents", |tables| Ok(tables.torrents.clone()))
+    }
+
+    /// Refer to [`databases::Database::load_keys`](crate::core::databases::Database::load_keys).
+    fn load_keys(&self) -> Result<Vec<auth::PeerKey>, Error> {
+        self.read("keys", |tables| Ok(tables.keys.values().cloned().collect()))
+    }
+
+    /// Refer to [`databases::Database::load_whitelist`](crate::core::databases::Database::load_whitelist).
+    fn load_whitelist(&self) -> Result<Vec<InfoHash>, Error> {
+        self.read("whitelist", |tables| Ok(tables.whitelist.iter().copied().collect()))
+    }
+
+    /// Refer to [`databases::Database::save_persistent_torrent`](crate::core::databases::Database::save_persistent_torrent).
+    fn save_persistent_torrent(&self, info_hash: &InfoHash, completed: u32) -> Result<(), Error> {
+        self.write("torrents", |tables| {
+            tables.torrents.insert(*info_hash, completed);
+            Ok(())
+        })
+    }
+
+    /// Refer to [`databases::Database::remove_persistent_torrent`](crate::core::databases::Database::remove_persistent_torrent).
+    fn remove_persistent_torrent(&self, info_hash: &InfoHash) -> Result<(), Error> {
+        self.write("torrents", |tables| {
+            tables.torrents.remove(info_hash);
+            Ok(())
+        })
+    }
+
+    /// Refer to [`databases::Database::get_info_hash_from_whitelist`](crate::core::databases::Database::get_info_hash_from_whitelist).
+    fn get_info_hash_from_whitelist(&self, info_hash: InfoHash) -> Result<Option<InfoHash>, Error> {
+        self.read("whitelist", |tables| Ok(tables.whitelist.get(&info_hash).copied()))
+    }
+
+    /// Refer to [`databases::Database::add_info_hash_to_whitelist`](crate::core::databases::Database::add_info_hash_to_whitelist).
+    fn add_info_hash_to_whitelist(&self, info_hash: InfoHash) -> Result<usize, Error> {
+        self.write("whitelist", |tables| {
+            if tables.whitelist.insert(info_hash) {
+                Ok(1)
+            } else {
+                Err(QueryError::UniqueConstraintFailed {
+                    table: "whitelist",
+                    column: "info_hash",
+                }
+                .into())
+            }
+        })
+    }
+
+    /// Refer to [`databases::Database::remove_info_hash_from_whitelist`](crate::core::databases::Database::remove_info_hash_from_whitelist).
+    fn remove_info_hash_from_whitelist(&self, info_hash: InfoHash) -> Result<usize, Error> {
+        self.write("whitelist", |tables| {
+            if tables.whitelist.remove(&info_hash) {
+                Ok(1)
+            } else {
+                Err(Error::DeleteFailed {
+                    location: Location::caller(),
+                    error_code: 0,
+                    driver: DRIVER,
+                })
+            }
+        })
+    }
+
+    /// Refer to [`databases::Database::add_info_hashes_to_whitelist`](crate::core::databases::Database::add_info_hashes_to_whitelist).
+    fn add_info_hashes_to_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, Error> {
+        self.write("whitelist", |tables| {
+            Ok(info_hashes
+                .iter()
+                .map(|info_hash| tables.whitelist.insert(*info_hash))
+                .collect())
+        })
+    }
+
+    /// Refer to [`databases::Database::remove_info_hashes_from_whitelist`](crate::core::databases::Database::remove_info_hashes_from_whitelist).
+    fn remove_info_hashes_from_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, Error> {
+        self.write("whitelist", |tables| {
+            Ok(info_hashes
+                .iter()
+                .map(|info_hash| tables.whitelist.remove(info_hash))
+                .collect())
+        })
+    }
+
+    /// Refer to [`databases::Database::get_key_from_keys`](crate::core::databases::Database::get_key_from_keys).
+    fn get_key_from_keys(&self, key: &Key) -> Result<Option<auth::PeerKey>, Error> {
+        self.read("keys", |tables| Ok(tables.keys.get(key).cloned()))
+    }
+
+    /// Refer to [`databases::Database::add_key_to_keys`](crate::core::databases::Database::add_key_to_keys).
+    fn add_key_to_keys(&self, auth_key: &auth::PeerKey) -> Result<usize, Error> {
+        self.write("keys", |tables| {
+            if tables.keys.contains_key(&auth_key.key) {
+                return Err(QueryError::UniqueConstraintFailed {
+                    table: "keys",
+                    column: "key",
+                }
+                .into());
+            }
+
+            tables.keys.insert(auth_key.key.clone(), truncate_to_seconds(auth_key));
+
+            Ok(1)
+        })
+    }
+
+    /// Refer to [`databases::Database::add_keys_to_keys`](crate::core::databases::Database::add_keys_to_keys).
+    fn add_keys_to_keys(&self, auth_keys: &[auth::PeerKey]) -> Result<usize, Error> {
+        self.write("keys", |tables| {
+            let mut new_keys = HashSet::new();
+
+            // All the keys are checked before adding any of them, like in a
+            // transaction.
+            for auth_key in auth_keys {
+                if tables.keys.contains_key(&auth_key.key) || !new_keys.insert(&auth_key.key) {
+                    return Err(QueryError::UniqueConstraintFailed {
+                        table: "keys",
+                        column: "key",
+                    }
+                    .into());
+                }
+            }
+
+            for auth_key in auth_keys {
+                tables.keys.insert(auth_key.key.clone(), truncate_to_seconds(auth_key));
+            }
+
+            Ok(auth_keys.len())
+        })
+    }
+
+    /// Refer to [`databases::Database::remove_key_from_keys`](crate::core::databases::Database::remove_key_from_keys).
+    fn remove_key_from_keys(&self, key: &Key) -> Result<usize, Error> {
+        self.write("keys", |tables| match tables.keys.remove(key) {
+            Some(_) => Ok(1),
+            None => Err(Error::DeleteFailed {
+                location: Location::caller(),
+                error_code: 0,
+                driver: DRIVER,
+            }),
+        })
+    }
+
+    /// Refer to [`databases::Database::remove_keys_expired_before`](crate::core::databases::Database::remove_keys_expired_before).
+    fn remove_keys_expired_before(&self, timestamp: u64) -> Result<usize, Error> {
+        self.write("keys", |tables| {
+            let before = tables.keys.len();
+            tables.keys.retain(|_, peer_key| {
+                peer_key
+                    .valid_until
+                    .map_or(true, |valid_until| valid_until.as_secs() >= timestamp)
+            });
+            Ok(before - tables.keys.len())
+        })
+    }
+
+    /// Refer to [`databases::Database::load_statistics`](crate::core::databases::Database::load_statistics).
+    fn load_statistics(&self) -> Result<PersistentCounters, Error> {
+        self.read("statistics", |tables| Ok(tables.statistics.clone()))
+    }
+
+    /// Refer to [`databases::Database::save_statistics`](crate::core::databases::Database::save_statistics).
+    fn save_statistics(&self, counters: &PersistentCounters) -> Result<(), Error> {
+        self.write("statistics", |tables| {
+            tables
+                .statistics
+                .extend(counters.iter().map(|(counter, value)| (counter.clone(), *value)));
+            Ok(())
+        })
+    }
+
+    /// Refer to [`databases::Database::increase_daily_statistics`](crate::core::databases::Database::increase_daily_statistics).
+    fn increase_daily_statistics(&self, day: u64, increments: &PersistentCounters) -> Result<(), Error> {
+        self.write("daily_statistics", |tables| {
+            let daily_counters = tables.daily_statistics.entry(day).or_default();
+            for (counter, increment) in increments {
+                *daily_counters.entry(counter.clone()).or_default() += increment;
+            }
+            Ok(())
+        })
+    }
+
+    /// Refer to [`databases::Database::load_daily_statistics`](crate::core::databases::Database::load_daily_statistics).
+    fn load_daily_statistics(&self, since_day: u64) -> Result<DailyCounters, Error> {
+        self.read("daily_statistics", |tables| {
+            Ok(tables
+                .daily_statistics
+                .range(since_day..)
+                .map(|(day, counters)| (*day, counters.clone()))
+                .collect())
+        })
+    }
+
+    /// Refer to [`databases::Database::add_audit_log_entry`](crate::core::databases::Database::add_audit_log_entry).
+    fn add_audit_log_entry(&self, entry: &audit::Entry) -> Result<(), Error> {
+        self.write("audit_log", |tables| {
+            tables.audit_log.push(audit::Entry {
+                timestamp: DurationSinceUnixEpoch::from_secs(entry.timestamp.as_secs()),
+                ..entry.clone()
+            });
+            Ok(())
+        })
+    }
+
+    /// Refer to [`databases::Database::load_audit_log`](crate::core::databases::Database::load_audit_log).
+    fn load_audit_log(&self, offset: u32, limit: u32) -> Result<Vec<audit::Entry>, Error> {
+        self.read("audit_log", |tables| {
+            Ok(tables
+                .audit_log
+                .iter()
+                .rev()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .cloned()
+                .collect())
+        })
+    }
+
+    /// Refer to [`databases::Database::count_audit_log_entries`](crate::core::databases::Database::count_audit_log_entries).
+    fn count_audit_log_entries(&self) -> Result<u64, Error> {
+        self.read("audit_log", |tables| Ok(tables.audit_log.len() as u64))
+    }
+
+    /// Refer to [`databases::Database::remove_audit_log_entries_before`](crate::core::databases::Database::remove_audit_log_entries_before).
+    fn remove_audit_log_entries_before(&self, timestamp: u64) -> Result<usize, Error> {
+        self.write("audit_log", |tables| {
+            let before = tables.audit_log.len();
+            tables.audit_log.retain(|entry| entry.timestamp.as_secs() >= timestamp);
+            Ok(before - tables.audit_log.len())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use bittorrent_primitives::info_hash::InfoHash;
+    use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+    use crate::core::databases::driver::{build, Driver};
+    use crate::core::databases::error::Error;
+    use crate::core::databases::Database;
+    use crate::core::statistics::PersistentCounters;
+    use crate::core::{audit, auth};
+
+    fn database() -> Box<dyn Database> {
+        build(&Driver::Memory, "").unwrap()
+    }
+
+    fn sample_info_hash() -> InfoHash {
+        InfoHash::from_str("3b245504cf5f11bbdbe1201cea6a6bf45aee1bc0").unwrap()
+    }
+
+    #[test]
+    fn it_should_save_and_load_the_persistent_torrents() {
+        let database = database();
+
+        database.save_persistent_torrent(&sample_info_hash(), 1).unwrap();
+        database.save_persistent_torrent(&sample_info_hash(), 2).unwrap();
+
+        let torrents = database.load_persistent_torrents().unwrap();
+
+        assert_eq!(torrents.get(&sample_info_hash()), Some(&2));
+    }
+
+    #[test]
+    fn it_should_remove_a_persistent_torrent() {
+        let database = database();
+
+        database.save_persistent_torrent(&sample_info_hash(), 1).unwrap();
+        database.remove_persistent_torrent(&sample_info_hash()).unwrap();
+
+        // Removing it again does not fail
+        database.remove_persistent_torrent(&sample_info_hash()).unwrap();
+
+        assert!(database.load_persistent_torrents().unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_should_add_and_remove_torrents_from_the_whitelist() {
+        let database = database();
+
+        database.add_info_hash_to_whitelist(sample_info_hash()).unwrap();
+
+        assert!(database.is_info_hash_whitelisted(sample_info_hash()).unwrap());
+        assert_eq!(database.load_whitelist().unwrap(), vec![sample_info_hash()]);
+
+        database.remove_info_hash_from_whitelist(sample_info_hash()).unwrap();
+
+        assert!(!database.is_info_hash_whitelisted(sample_info_hash()).unwrap());
+    }
+
+    #[test]
+    fn it_should_fail_adding_a_torrent_to_the_whitelist_twice() {
+        let database = database();
+
+        database.add_info_hash_to_whitelist(sample_info_hash()).unwrap();
+
+        let result = database.add_info_hash_to_whitelist(sample_info_hash());
+
+        assert!(matches!(result, Err(Error::UniqueConstraintFailed { .. })));
+    }
+
+    #[test]
+    fn it_should_fail_removing_a_torrent_that_is_not_whitelisted() {
+        let database = database();
+
+        let result = database.remove_info_hash_from_whitelist(sample_info_hash());
+
+        assert!(matches!(result, Err(Error::DeleteFailed { error_code: 0, .. })));
+    }
+
+    #[test]
+    fn it_should_add_and_remove_torrents_from_the_whitelist_in_bulk() {
+        let database = database();
+
+        let other_info_hash = InfoHash::from([1u8; 20]);
+
+        database.add_info_hash_to_whitelist(sample_info_hash()).unwrap();
+
+        assert_eq!(
+            database
+                .add_info_hashes_to_whitelist(&[sample_info_hash(), other_info_hash])
+                .unwrap(),
+            vec![false, true]
+        );
+        assert!(database.is_info_hash_whitelisted(other_info_hash).unwrap());
+
+        assert_eq!(
+            database
+                .remove_info_hashes_from_whitelist(&[other_info_hash, other_info_hash])
+                .unwrap(),
+            vec![true, false]
+        );
+        assert!(!database.is_info_hash_whitelisted(other_info_hash).unwrap());
+    }
+
+    #[test]
+    fn it_should_add_keys_in_bulk() {
+        let database = database();
+
+        let peer_keys = vec![auth::generate_permanent_key(), auth::generate_permanent_key()];
+
+        assert_eq!(database.add_keys_to_keys(&peer_keys).unwrap(), 2);
+        assert_eq!(database.load_keys().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn it_should_not_add_any_key_in_bulk_when_one_of_them_already_exists() {
+        let database = database();
+
+        let existing_key = auth::generate_permanent_key();
+
+        database.add_key_to_keys(&existing_key).unwrap();
+
+        let result = database.add_keys_to_keys(&[auth::generate_permanent_key(), existing_key]);
+
+        assert!(matches!(result, Err(Error::UniqueConstraintFailed { .. })));
+        assert_eq!(database.load_keys().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn it_should_add_and_remove_keys() {
+        let database = database();
+
+        let peer_key = auth::generate_key(Some(Duration::from_secs(60)));
+
+        database.add_key_to_keys(&peer_key).unwrap();
+
+        let stored_key = database.get_key_from_keys(&peer_key.key).unwrap().unwrap();
+
+        assert_eq!(stored_key.key, peer_key.key);
+        assert_eq!(
+            stored_key.valid_until.map(|valid_until| valid_until.as_secs()),
+            peer_key.valid_until.map(|valid_until| valid_until.as_secs())
+        );
+
+        database.remove_key_from_keys(&peer_key.key).unwrap();
+
+        assert!(database.get_key_from_keys(&peer_key.key).unwrap().is_none());
+    }
+
+    #[test]
+    fn it_should_fail_adding_a_duplicate_key() {
+        let database = database();
+
+        let peer_key = auth::generate_permanent_key();
+
+        database.add_key_to_keys(&peer_key).unwrap();
+
+        let result = database.add_key_to_keys(&peer_key);
+
+        assert!(matches!(result, Err(Error::UniqueConstraintFailed { .. })));
+    }
+
+    #[test]
+    fn it_should_fail_removing_a_non_existent_key() {
+        let database = database();
+
+        let result = database.remove_key_from_keys(&auth::generate_permanent_key().key);
+
+        assert!(matches!(result, Err(Error::DeleteFailed { error_code: 0, .. })));
+    }
+
+    #[test]
+    fn it_should_remove_only_the_keys_that_expired_before_a_given_time() {
+        let database = database();
+
+        let expired_key = auth::PeerKey {
+            key: auth::generate_permanent_key().key,
+            valid_until: Some(DurationSinceUnixEpoch::from_secs(99)),
+        };
+        let valid_key = auth::PeerKey {
+            key: auth::generate_permanent_key().key,
+            valid_until: Some(DurationSinceUnixEpoch::from_secs(100)),
+        };
+        let permanent_key = auth::generate_permanent_key();
+
+        database
+            .add_keys_to_keys(&[expired_key.clone(), valid_key.clone(), permanent_key.clone()])
+            .unwrap();
+
+        assert_eq!(database.remove_keys_expired_before(100).unwrap(), 1);
+
+        assert!(database.get_key_from_keys(&expired_key.key).unwrap().is_none());
+        assert!(database.get_key_from_keys(&valid_key.key).unwrap().is_some());
+        assert!(database.get_key_from_keys(&permanent_key.key).unwrap().is_some());
+    }
+
+    #[test]
+    fn it_should_fail_any_query_after_dropping_the_tables() {
+        let database = database();
+
+        database.drop_database_tables().unwrap();
+
+        assert!(database.load_whitelist().is_err());
+        assert!(database.load_keys().is_err());
+        assert!(database.load_persistent_torrents().is_err());
+        assert!(database.drop_database_tables().is_err());
+    }
+
+    #[test]
+    fn it_should_save_and_load_the_usage_statistics() {
+        let database = database();
+
+        database
+            .save_statistics(&PersistentCounters::from([("udp4_announces_handled".to_string(), 1)]))
+            .unwrap();
+        database
+            .save_statistics(&PersistentCounters::from([("udp4_announces_handled".to_string(), 2)]))
+            .unwrap();
+
+        assert_eq!(
+            database.load_statistics().unwrap(),
+            PersistentCounters::from([("udp4_announces_handled".to_string(), 2)])
+        );
+    }
+
+    #[test]
+    fn it_should_add_the_increments_to_the_daily_usage_statistics() {
+        let database = database();
+
+        let increments = PersistentCounters::from([("udp4_announces_handled".to_string(), 1)]);
+
+        database.increase_daily_statistics(1, &increments).unwrap();
+        database.increase_daily_statistics(2, &increments).unwrap();
+        database.increase_daily_statistics(2, &increments).unwrap();
+
+        let daily_statistics = database.load_daily_statistics(2).unwrap();
+
+        assert_eq!(daily_statistics.len(), 1);
+        assert_eq!(daily_statistics[&2]["udp4_announces_handled"], 2);
+    }
+
+    fn sample_audit_log_entry(timestamp: u64, target: &str) -> audit::Entry {
+        audit::Entry {
+            timestamp: DurationSinceUnixEpoch::from_secs(timestamp),
+            label: "admin".to_string(),
+            action: "POST /api/v1/whitelist/:info_hash".to_string(),
+            target: target.to_string(),
+            result: 200,
+        }
+    }
+
+    #[test]
+    fn it_should_load_the_audit_log_from_the_newest_entry() {
+        let database = database();
+
+        database.add_audit_log_entry(&sample_audit_log_entry(1, "first")).unwrap();
+        database.add_audit_log_entry(&sample_audit_log_entry(2, "second")).unwrap();
+        database.add_audit_log_entry(&sample_audit_log_entry(3, "third")).unwrap();
+
+        assert_eq!(
+            database.load_audit_log(1, 10).unwrap(),
+            vec![sample_audit_log_entry(2, "second"), sample_audit_log_entry(1, "first")]
+        );
+        assert_eq!(database.count_audit_log_entries().unwrap(), 3);
+    }
+
+    #[test]
+    fn it_should_remove_the_audit_log_entries_older_than_a_timestamp() {
+        let database = database();
+
+        database.add_audit_log_entry(&sample_audit_log_entry(1, "first")).unwrap();
+        database.add_audit_log_entry(&sample_audit_log_entry(2, "second")).unwrap();
+
+        assert_eq!(database.remove_audit_log_entries_before(2).unwrap(), 1);
+        assert_eq!(
+            database.load_audit_log(0, 10).unwrap(),
+            vec![sample_audit_log_entry(2, "second")]
+        );
+    }
+}
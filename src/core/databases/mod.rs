@@ -2,10 +2,14 @@
 //!
 //! Persistence is currently implemented with one [`Database`] trait.
 //!
-//! There are two implementations of the trait (two drivers):
+//! There are three implementations of the trait (three drivers):
 //!
 //! - [`Mysql`](crate::core::databases::mysql::Mysql)
 //! - [`Sqlite`](crate::core::databases::sqlite::Sqlite)
+//! - [`Memory`](crate::core::databases::memory::Memory)
+//!
+//! The `Memory` driver does not persist anything. It's useful for testing and
+//! for stateless trackers that receive the whitelist and keys only from the API.
 //!
 //! > **NOTICE**: There are no database migrations. If there are any changes,
 //! > we will implemented them or provide a script to migrate to the new schema.
@@ -15,6 +19,9 @@
 //! - [Torrent metrics](#torrent-metrics)
 //! - [Torrent whitelist](torrent-whitelist)
 //! - [Authentication keys](authentication-keys)
+//! - [Usage statistics](usage-statistics)
+//! - [Daily usage statistics](daily-usage-statistics)
+//! - [Audit log](audit-log)
 //!
 //! # Torrent metrics
 //!
@@ -43,8 +50,39 @@
 //! `valid_until` | 1672419840                       | Timestamp for the expiring date  
 //!
 //! > **NOTICE**: All keys must have an expiration date.
+//!
+//! # Usage statistics
+//!
+//! Field     | Sample data                | Description
+//! ---|---|---
+//! `id`      | 1                          | Autoincrement id
+//! `counter` | `udp4_announces_handled`   | Counter name. See [`Metrics::counters`](crate::core::statistics::Metrics::counters)
+//! `value`   | 1000                       | Value of the counter when it was persisted
+//!
+//! # Daily usage statistics
+//!
+//! Field     | Sample data                | Description
+//! ---|---|---
+//! `id`      | 1                          | Autoincrement id
+//! `day`     | 19800                      | Days since Unix Epoch
+//! `counter` | `udp4_announces_handled`   | Counter name
+//! `value`   | 100                        | How much the counter increased that day
+//!
+//! # Audit log
+//!
+//! Field       | Sample data                          | Description
+//! ---|---|---
+//! `id`        | 1                                    | Autoincrement id
+//! `timestamp` | 1729326371                           | When the action was done
+//! `label`     | `admin`                              | Label of the API access token
+//! `action`    | `POST /api/v1/whitelist/:info_hash`  | Request method and route
+//! `target`    | `c1277613db1d28709b034a017ab2cae4be07ae10` | Path params of the request
+//! `result`    | 200                                  | Status code of the response
+//!
+//! See [`audit`](crate::core::audit) module.
 pub mod driver;
 pub mod error;
+pub mod memory;
 pub mod mysql;
 pub mod sqlite;
 
@@ -54,7 +92,9 @@ use bittorrent_primitives::info_hash::InfoHash;
 use torrust_tracker_primitives::PersistentTorrents;
 
 use self::error::Error;
+use crate::core::audit;
 use crate::core::auth::{self, Key};
+use crate::core::statistics::{DailyCounters, PersistentCounters};
 
 struct Builder<T>
 where
@@ -135,6 +175,16 @@ pub trait Database: Sync + Send {
     /// Will return `Err` if unable to save.
     fn save_persistent_torrent(&self, info_hash: &InfoHash, downloaded: u32) -> Result<(), Error>;
 
+    /// It removes the torrent metrics data from the database. It does not
+    /// fail if the torrent is not in the database.
+    ///
+    /// # Context: Torrent Metrics
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to delete.
+    fn remove_persistent_torrent(&self, info_hash: &InfoHash) -> Result<(), Error>;
+
     // Whitelist
 
     /// It loads the whitelisted torrents from the database.
@@ -186,6 +236,30 @@ pub trait Database: Sync + Send {
     /// Will return `Err` if unable to save.
     fn remove_info_hash_from_whitelist(&self, info_hash: InfoHash) -> Result<usize, Error>;
 
+    /// It adds the torrents to the whitelist in a single transaction. The
+    /// torrents that are already whitelisted are skipped.
+    ///
+    /// It returns, for each torrent, whether it was added.
+    ///
+    /// # Context: Whitelist
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to save. No torrent is added in that case.
+    fn add_info_hashes_to_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, Error>;
+
+    /// It removes the torrents from the whitelist in a single transaction.
+    /// The torrents that are not whitelisted are skipped.
+    ///
+    /// It returns, for each torrent, whether it was removed.
+    ///
+    /// # Context: Whitelist
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to save. No torrent is removed in that case.
+    fn remove_info_hashes_from_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, Error>;
+
     // Authentication keys
 
     /// It loads the expiring authentication keys from the database.
@@ -218,6 +292,17 @@ pub trait Database: Sync + Send {
     /// Will return `Err` if unable to save.
     fn add_key_to_keys(&self, auth_key: &auth::PeerKey) -> Result<usize, Error>;
 
+    /// It adds the authentication keys to the database in a single
+    /// transaction.
+    ///
+    /// # Context: Authentication Keys
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to save, for example, if one of the keys
+    /// already exists. No key is added in that case.
+    fn add_keys_to_keys(&self, auth_keys: &[auth::PeerKey]) -> Result<usize, Error>;
+
     /// It removes an expiring authentication key from the database.
     ///
     /// # Context: Authentication Keys
@@ -226,4 +311,95 @@ pub trait Database: Sync + Send {
     ///
     /// Will return `Err` if unable to load.
     fn remove_key_from_keys(&self, key: &Key) -> Result<usize, Error>;
+
+    /// It removes the authentication keys that expired before `timestamp`
+    /// (seconds since Unix Epoch) and returns how many were removed. Permanent
+    /// keys are never removed.
+    ///
+    /// # Context: Authentication Keys
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to delete.
+    fn remove_keys_expired_before(&self, timestamp: u64) -> Result<usize, Error>;
+
+    // Usage statistics
+
+    /// It loads the persisted value of the usage statistics counters.
+    ///
+    /// # Context: Usage Statistics
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to load.
+    fn load_statistics(&self) -> Result<PersistentCounters, Error>;
+
+    /// It saves the current value of the usage statistics counters,
+    /// replacing the previous values.
+    ///
+    /// # Context: Usage Statistics
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to save.
+    fn save_statistics(&self, counters: &PersistentCounters) -> Result<(), Error>;
+
+    /// It adds the increments to the daily totals of the usage statistics
+    /// counters for the given `day` (days since Unix Epoch).
+    ///
+    /// # Context: Usage Statistics
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to save.
+    fn increase_daily_statistics(&self, day: u64, increments: &PersistentCounters) -> Result<(), Error>;
+
+    /// It loads the daily totals of the usage statistics counters from the
+    /// given `day` (days since Unix Epoch) on.
+    ///
+    /// # Context: Usage Statistics
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to load.
+    fn load_daily_statistics(&self, since_day: u64) -> Result<DailyCounters, Error>;
+
+    // Audit log
+
+    /// It appends an entry to the audit log.
+    ///
+    /// # Context: Audit log
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to save.
+    fn add_audit_log_entry(&self, entry: &audit::Entry) -> Result<(), Error>;
+
+    /// It loads a page of the audit log, from the newest entry.
+    ///
+    /// # Context: Audit log
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to load.
+    fn load_audit_log(&self, offset: u32, limit: u32) -> Result<Vec<audit::Entry>, Error>;
+
+    /// It returns the number of entries in the audit log.
+    ///
+    /// # Context: Audit log
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to count.
+    fn count_audit_log_entries(&self) -> Result<u64, Error>;
+
+    /// It removes the audit log entries older than `timestamp` (seconds since
+    /// Unix Epoch) and returns how many were removed.
+    ///
+    /// # Context: Audit log
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to delete.
+    fn remove_audit_log_entries_before(&self, timestamp: u64) -> Result<usize, Error>;
 }
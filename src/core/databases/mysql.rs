@@ -5,13 +5,15 @@ use std::time::Duration;
 use bittorrent_primitives::info_hash::InfoHash;
 use r2d2::Pool;
 use r2d2_mysql::mysql::prelude::Queryable;
-use r2d2_mysql::mysql::{params, Opts, OptsBuilder};
+use r2d2_mysql::mysql::{params, Opts, OptsBuilder, TxOpts};
 use r2d2_mysql::MySqlConnectionManager;
-use torrust_tracker_primitives::PersistentTorrents;
+use torrust_tracker_primitives::{DurationSinceUnixEpoch, PersistentTorrents};
 
 use super::driver::Driver;
 use super::{Database, Error};
+use crate::core::audit;
 use crate::core::auth::{self, Key};
+use crate::core::statistics::{DailyCounters, PersistentCounters};
 use crate::shared::bit_torrent::common::AUTH_KEY_LENGTH;
 
 const DRIVER: Driver = Driver::MySQL;
@@ -66,6 +68,35 @@ impl Database for Mysql {
             i8::try_from(AUTH_KEY_LENGTH).expect("auth::Auth Key Length Should fit within a i8!")
         );
 
+        let create_statistics_table = "
+        CREATE TABLE IF NOT EXISTS `statistics` (
+            id integer PRIMARY KEY AUTO_INCREMENT,
+            counter VARCHAR(64) NOT NULL UNIQUE,
+            value BIGINT UNSIGNED DEFAULT 0 NOT NULL
+        );"
+        .to_string();
+
+        let create_daily_statistics_table = "
+        CREATE TABLE IF NOT EXISTS `daily_statistics` (
+            id integer PRIMARY KEY AUTO_INCREMENT,
+            day INTEGER NOT NULL,
+            counter VARCHAR(64) NOT NULL,
+            value BIGINT UNSIGNED DEFAULT 0 NOT NULL,
+            UNIQUE (day, counter)
+        );"
+        .to_string();
+
+        let create_audit_log_table = "
+        CREATE TABLE IF NOT EXISTS `audit_log` (
+            id integer PRIMARY KEY AUTO_INCREMENT,
+            timestamp BIGINT UNSIGNED NOT NULL,
+            label VARCHAR(255) NOT NULL,
+            action VARCHAR(255) NOT NULL,
+            target VARCHAR(255) NOT NULL,
+            result SMALLINT UNSIGNED NOT NULL
+        );"
+        .to_string();
+
         let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
 
         conn.query_drop(&create_torrents_table)
@@ -73,6 +104,12 @@ impl Database for Mysql {
         conn.query_drop(&create_keys_table).expect("Could not create keys table.");
         conn.query_drop(&create_whitelist_table)
             .expect("Could not create whitelist table.");
+        conn.query_drop(&create_statistics_table)
+            .expect("Could not create statistics table.");
+        conn.query_drop(&create_daily_statistics_table)
+            .expect("Could not create daily_statistics table.");
+        conn.query_drop(&create_audit_log_table)
+            .expect("Could not create audit_log table.");
 
         Ok(())
     }
@@ -91,6 +128,18 @@ impl Database for Mysql {
             DROP TABLE `keys`;"
             .to_string();
 
+        let drop_statistics_table = "
+            DROP TABLE `statistics`;"
+            .to_string();
+
+        let drop_daily_statistics_table = "
+            DROP TABLE `daily_statistics`;"
+            .to_string();
+
+        let drop_audit_log_table = "
+            DROP TABLE `audit_log`;"
+            .to_string();
+
         let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
 
         conn.query_drop(&drop_whitelist_table)
@@ -98,6 +147,12 @@ impl Database for Mysql {
         conn.query_drop(&drop_torrents_table)
             .expect("Could not drop `torrents` table.");
         conn.query_drop(&drop_keys_table).expect("Could not drop `keys` table.");
+        conn.query_drop(&drop_statistics_table)
+            .expect("Could not drop `statistics` table.");
+        conn.query_drop(&drop_daily_statistics_table)
+            .expect("Could not drop `daily_statistics` table.");
+        conn.query_drop(&drop_audit_log_table)
+            .expect("Could not drop `audit_log` table.");
 
         Ok(())
     }
@@ -162,6 +217,17 @@ impl Database for Mysql {
         Ok(conn.exec_drop(COMMAND, params! { info_hash_str, completed })?)
     }
 
+    /// Refer to [`databases::Database::remove_persistent_torrent`](crate::core::databases::Database::remove_persistent_torrent).
+    fn remove_persistent_torrent(&self, info_hash: &InfoHash) -> Result<(), Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let info_hash = info_hash.to_string();
+
+        conn.exec_drop("DELETE FROM torrents WHERE info_hash = :info_hash", params! { info_hash })?;
+
+        Ok(())
+    }
+
     /// Refer to [`databases::Database::get_info_hash_from_whitelist`](crate::core::databases::Database::get_info_hash_from_whitelist).
     fn get_info_hash_from_whitelist(&self, info_hash: InfoHash) -> Result<Option<InfoHash>, Error> {
         let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
@@ -201,6 +267,51 @@ impl Database for Mysql {
         Ok(1)
     }
 
+    /// Refer to [`databases::Database::add_info_hashes_to_whitelist`](crate::core::databases::Database::add_info_hashes_to_whitelist).
+    fn add_info_hashes_to_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let mut tx = conn.start_transaction(TxOpts::default())?;
+
+        let mut added = Vec::with_capacity(info_hashes.len());
+
+        for info_hash in info_hashes {
+            let info_hash = info_hash.to_string();
+
+            tx.exec_drop(
+                "INSERT IGNORE INTO whitelist (info_hash) VALUES (:info_hash)",
+                params! { info_hash },
+            )?;
+
+            added.push(tx.affected_rows() == 1);
+        }
+
+        tx.commit()?;
+
+        Ok(added)
+    }
+
+    /// Refer to [`databases::Database::remove_info_hashes_from_whitelist`](crate::core::databases::Database::remove_info_hashes_from_whitelist).
+    fn remove_info_hashes_from_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let mut tx = conn.start_transaction(TxOpts::default())?;
+
+        let mut removed = Vec::with_capacity(info_hashes.len());
+
+        for info_hash in info_hashes {
+            let info_hash = info_hash.to_string();
+
+            tx.exec_drop("DELETE FROM whitelist WHERE info_hash = :info_hash", params! { info_hash })?;
+
+            removed.push(tx.affected_rows() == 1);
+        }
+
+        tx.commit()?;
+
+        Ok(removed)
+    }
+
     /// Refer to [`databases::Database::get_key_from_keys`](crate::core::databases::Database::get_key_from_keys).
     fn get_key_from_keys(&self, key: &Key) -> Result<Option<auth::PeerKey>, Error> {
         let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
@@ -242,6 +353,27 @@ impl Database for Mysql {
         Ok(1)
     }
 
+    /// Refer to [`databases::Database::add_keys_to_keys`](crate::core::databases::Database::add_keys_to_keys).
+    fn add_keys_to_keys(&self, auth_keys: &[auth::PeerKey]) -> Result<usize, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let mut tx = conn.start_transaction(TxOpts::default())?;
+
+        tx.exec_batch(
+            "INSERT INTO `keys` (`key`, valid_until) VALUES (:key, :valid_until)",
+            auth_keys.iter().map(|auth_key| {
+                let key = auth_key.key.to_string();
+                let valid_until = auth_key.valid_until.map(|valid_until| valid_until.as_secs().to_string());
+
+                params! { key, valid_until }
+            }),
+        )?;
+
+        tx.commit()?;
+
+        Ok(auth_keys.len())
+    }
+
     /// Refer to [`databases::Database::remove_key_from_keys`](crate::core::databases::Database::remove_key_from_keys).
     fn remove_key_from_keys(&self, key: &Key) -> Result<usize, Error> {
         let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
@@ -250,4 +382,131 @@ impl Database for Mysql {
 
         Ok(1)
     }
+
+    /// Refer to [`databases::Database::remove_keys_expired_before`](crate::core::databases::Database::remove_keys_expired_before).
+    fn remove_keys_expired_before(&self, timestamp: u64) -> Result<usize, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        conn.exec_drop(
+            "DELETE FROM `keys` WHERE valid_until IS NOT NULL AND valid_until < :timestamp",
+            params! { timestamp },
+        )?;
+
+        Ok(usize::try_from(conn.affected_rows()).expect("the number of removed keys should fit in a usize"))
+    }
+
+    /// Refer to [`databases::Database::load_statistics`](crate::core::databases::Database::load_statistics).
+    fn load_statistics(&self) -> Result<PersistentCounters, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let counters = conn.query_map(
+            "SELECT counter, value FROM `statistics`",
+            |(counter, value): (String, u64)| (counter, value),
+        )?;
+
+        Ok(counters.into_iter().collect())
+    }
+
+    /// Refer to [`databases::Database::save_statistics`](crate::core::databases::Database::save_statistics).
+    fn save_statistics(&self, counters: &PersistentCounters) -> Result<(), Error> {
+        const COMMAND: &str =
+            "INSERT INTO `statistics` (counter, value) VALUES (:counter, :value) ON DUPLICATE KEY UPDATE value = VALUES(value)";
+
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let mut tx = conn.start_transaction(TxOpts::default())?;
+
+        tx.exec_batch(COMMAND, counters.iter().map(|(counter, value)| params! { counter, value }))?;
+
+        Ok(tx.commit()?)
+    }
+
+    /// Refer to [`databases::Database::increase_daily_statistics`](crate::core::databases::Database::increase_daily_statistics).
+    fn increase_daily_statistics(&self, day: u64, increments: &PersistentCounters) -> Result<(), Error> {
+        const COMMAND: &str = "INSERT INTO `daily_statistics` (day, counter, value) VALUES (:day, :counter, :value) ON DUPLICATE KEY UPDATE value = value + VALUES(value)";
+
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let mut tx = conn.start_transaction(TxOpts::default())?;
+
+        tx.exec_batch(
+            COMMAND,
+            increments.iter().map(|(counter, value)| params! { day, counter, value }),
+        )?;
+
+        Ok(tx.commit()?)
+    }
+
+    /// Refer to [`databases::Database::load_daily_statistics`](crate::core::databases::Database::load_daily_statistics).
+    fn load_daily_statistics(&self, since_day: u64) -> Result<DailyCounters, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let rows = conn.exec_map(
+            "SELECT day, counter, value FROM `daily_statistics` WHERE day >= :since_day",
+            params! { since_day },
+            |(day, counter, value): (u64, String, u64)| (day, counter, value),
+        )?;
+
+        let mut daily_counters = DailyCounters::new();
+
+        for (day, counter, value) in rows {
+            daily_counters.entry(day).or_default().insert(counter, value);
+        }
+
+        Ok(daily_counters)
+    }
+
+    /// Refer to [`databases::Database::add_audit_log_entry`](crate::core::databases::Database::add_audit_log_entry).
+    fn add_audit_log_entry(&self, entry: &audit::Entry) -> Result<(), Error> {
+        const COMMAND: &str =
+            "INSERT INTO `audit_log` (timestamp, label, action, target, result) VALUES (:timestamp, :label, :action, :target, :result)";
+
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let timestamp = entry.timestamp.as_secs();
+
+        conn.exec_drop(
+            COMMAND,
+            params! { timestamp, "label" => &entry.label, "action" => &entry.action, "target" => &entry.target, "result" => entry.result },
+        )?;
+
+        Ok(())
+    }
+
+    /// Refer to [`databases::Database::load_audit_log`](crate::core::databases::Database::load_audit_log).
+    fn load_audit_log(&self, offset: u32, limit: u32) -> Result<Vec<audit::Entry>, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let entries = conn.exec_map(
+            "SELECT timestamp, label, action, target, result FROM `audit_log` ORDER BY id DESC LIMIT :limit OFFSET :offset",
+            params! { limit, offset },
+            |(timestamp, label, action, target, result): (u64, String, String, String, u16)| audit::Entry {
+                timestamp: DurationSinceUnixEpoch::from_secs(timestamp),
+                label,
+                action,
+                target,
+                result,
+            },
+        )?;
+
+        Ok(entries)
+    }
+
+    /// Refer to [`databases::Database::count_audit_log_entries`](crate::core::databases::Database::count_audit_log_entries).
+    fn count_audit_log_entries(&self) -> Result<u64, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let count = conn.query_first::<u64, _>("SELECT COUNT(*) FROM `audit_log`")?;
+
+        Ok(count.unwrap_or_default())
+    }
+
+    /// Refer to [`databases::Database::remove_audit_log_entries_before`](crate::core::databases::Database::remove_audit_log_entries_before).
+    fn remove_audit_log_entries_before(&self, timestamp: u64) -> Result<usize, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        conn.exec_drop("DELETE FROM `audit_log` WHERE timestamp < :timestamp", params! { timestamp })?;
+
+        Ok(usize::try_from(conn.affected_rows()).expect("the number of removed entries should fit in a usize"))
+    }
 }
@@ -11,7 +11,9 @@ use torrust_tracker_primitives::{DurationSinceUnixEpoch, PersistentTorrents};
 
 use super::driver::Driver;
 use super::{Database, Error};
+use crate::core::audit;
 use crate::core::auth::{self, Key};
+use crate::core::statistics::{DailyCounters, PersistentCounters};
 
 const DRIVER: Driver = Driver::Sqlite3;
 
@@ -57,11 +59,43 @@ impl Database for Sqlite {
          );"
         .to_string();
 
+        let create_statistics_table = "
+        CREATE TABLE IF NOT EXISTS statistics (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            counter TEXT NOT NULL UNIQUE,
+            value INTEGER DEFAULT 0 NOT NULL
+        );"
+        .to_string();
+
+        let create_daily_statistics_table = "
+        CREATE TABLE IF NOT EXISTS daily_statistics (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            day INTEGER NOT NULL,
+            counter TEXT NOT NULL,
+            value INTEGER DEFAULT 0 NOT NULL,
+            UNIQUE (day, counter)
+        );"
+        .to_string();
+
+        let create_audit_log_table = "
+        CREATE TABLE IF NOT EXISTS audit_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            timestamp INTEGER NOT NULL,
+            label TEXT NOT NULL,
+            action TEXT NOT NULL,
+            target TEXT NOT NULL,
+            result INTEGER NOT NULL
+        );"
+        .to_string();
+
         let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
 
         conn.execute(&create_whitelist_table, [])?;
         conn.execute(&create_keys_table, [])?;
         conn.execute(&create_torrents_table, [])?;
+        conn.execute(&create_statistics_table, [])?;
+        conn.execute(&create_daily_statistics_table, [])?;
+        conn.execute(&create_audit_log_table, [])?;
 
         Ok(())
     }
@@ -80,11 +114,26 @@ impl Database for Sqlite {
         DROP TABLE keys;"
             .to_string();
 
+        let drop_statistics_table = "
+        DROP TABLE statistics;"
+            .to_string();
+
+        let drop_daily_statistics_table = "
+        DROP TABLE daily_statistics;"
+            .to_string();
+
+        let drop_audit_log_table = "
+        DROP TABLE audit_log;"
+            .to_string();
+
         let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
 
         conn.execute(&drop_whitelist_table, [])
             .and_then(|_| conn.execute(&drop_torrents_table, []))
-            .and_then(|_| conn.execute(&drop_keys_table, []))?;
+            .and_then(|_| conn.execute(&drop_keys_table, []))
+            .and_then(|_| conn.execute(&drop_statistics_table, []))
+            .and_then(|_| conn.execute(&drop_daily_statistics_table, []))
+            .and_then(|_| conn.execute(&drop_audit_log_table, []))?;
 
         Ok(())
     }
@@ -168,6 +217,15 @@ impl Database for Sqlite {
         }
     }
 
+    /// Refer to [`databases::Database::remove_persistent_torrent`](crate::core::databases::Database::remove_persistent_torrent).
+    fn remove_persistent_torrent(&self, info_hash: &InfoHash) -> Result<(), Error> {
+        let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        conn.execute("DELETE FROM torrents WHERE info_hash = ?", [info_hash.to_string()])?;
+
+        Ok(())
+    }
+
     /// Refer to [`databases::Database::get_info_hash_from_whitelist`](crate::core::databases::Database::get_info_hash_from_whitelist).
     fn get_info_hash_from_whitelist(&self, info_hash: InfoHash) -> Result<Option<InfoHash>, Error> {
         let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
@@ -215,6 +273,45 @@ impl Database for Sqlite {
         }
     }
 
+    /// Refer to [`databases::Database::add_info_hashes_to_whitelist`](crate::core::databases::Database::add_info_hashes_to_whitelist).
+    fn add_info_hashes_to_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let tx = conn.transaction()?;
+
+        let mut added = Vec::with_capacity(info_hashes.len());
+
+        for info_hash in info_hashes {
+            let insert = tx.execute(
+                "INSERT OR IGNORE INTO whitelist (info_hash) VALUES (?)",
+                [info_hash.to_string()],
+            )?;
+            added.push(insert == 1);
+        }
+
+        tx.commit()?;
+
+        Ok(added)
+    }
+
+    /// Refer to [`databases::Database::remove_info_hashes_from_whitelist`](crate::core::databases::Database::remove_info_hashes_from_whitelist).
+    fn remove_info_hashes_from_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let tx = conn.transaction()?;
+
+        let mut removed = Vec::with_capacity(info_hashes.len());
+
+        for info_hash in info_hashes {
+            let deleted = tx.execute("DELETE FROM whitelist WHERE info_hash = ?", [info_hash.to_string()])?;
+            removed.push(deleted == 1);
+        }
+
+        tx.commit()?;
+
+        Ok(removed)
+    }
+
     /// Refer to [`databases::Database::get_key_from_keys`](crate::core::databases::Database::get_key_from_keys).
     fn get_key_from_keys(&self, key: &Key) -> Result<Option<auth::PeerKey>, Error> {
         let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
@@ -267,6 +364,27 @@ impl Database for Sqlite {
         }
     }
 
+    /// Refer to [`databases::Database::add_keys_to_keys`](crate::core::databases::Database::add_keys_to_keys).
+    fn add_keys_to_keys(&self, auth_keys: &[auth::PeerKey]) -> Result<usize, Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let tx = conn.transaction()?;
+
+        for auth_key in auth_keys {
+            tx.execute(
+                "INSERT INTO keys (key, valid_until) VALUES (?1, ?2)",
+                params![
+                    auth_key.key.to_string(),
+                    auth_key.valid_until.map(|valid_until| valid_until.as_secs().to_string())
+                ],
+            )?;
+        }
+
+        tx.commit()?;
+
+        Ok(auth_keys.len())
+    }
+
     /// Refer to [`databases::Database::remove_key_from_keys`](crate::core::databases::Database::remove_key_from_keys).
     fn remove_key_from_keys(&self, key: &Key) -> Result<usize, Error> {
         let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
@@ -284,4 +402,142 @@ impl Database for Sqlite {
             })
         }
     }
+
+    /// Refer to [`databases::Database::remove_keys_expired_before`](crate::core::databases::Database::remove_keys_expired_before).
+    fn remove_keys_expired_before(&self, timestamp: u64) -> Result<usize, Error> {
+        let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        Ok(conn.execute(
+            "DELETE FROM keys WHERE valid_until IS NOT NULL AND valid_until < ?",
+            [timestamp.to_string()],
+        )?)
+    }
+
+    /// Refer to [`databases::Database::load_statistics`](crate::core::databases::Database::load_statistics).
+    fn load_statistics(&self) -> Result<PersistentCounters, Error> {
+        let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let mut stmt = conn.prepare("SELECT counter, value FROM statistics")?;
+
+        let counters_iter = stmt.query_map([], |row| {
+            let counter: String = row.get(0)?;
+            let value: i64 = row.get(1)?;
+            Ok((counter, value.unsigned_abs()))
+        })?;
+
+        Ok(counters_iter.filter_map(std::result::Result::ok).collect())
+    }
+
+    /// Refer to [`databases::Database::save_statistics`](crate::core::databases::Database::save_statistics).
+    fn save_statistics(&self, counters: &PersistentCounters) -> Result<(), Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let tx = conn.transaction()?;
+
+        for (counter, value) in counters {
+            tx.execute(
+                "INSERT INTO statistics (counter, value) VALUES (?1, ?2) ON CONFLICT(counter) DO UPDATE SET value = ?2",
+                [counter.clone(), value.to_string()],
+            )?;
+        }
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Refer to [`databases::Database::increase_daily_statistics`](crate::core::databases::Database::increase_daily_statistics).
+    fn increase_daily_statistics(&self, day: u64, increments: &PersistentCounters) -> Result<(), Error> {
+        let mut conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let tx = conn.transaction()?;
+
+        for (counter, increment) in increments {
+            tx.execute(
+                "INSERT INTO daily_statistics (day, counter, value) VALUES (?1, ?2, ?3) ON CONFLICT(day, counter) DO UPDATE SET value = value + ?3",
+                [day.to_string(), counter.clone(), increment.to_string()],
+            )?;
+        }
+
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Refer to [`databases::Database::load_daily_statistics`](crate::core::databases::Database::load_daily_statistics).
+    fn load_daily_statistics(&self, since_day: u64) -> Result<DailyCounters, Error> {
+        let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let mut stmt = conn.prepare("SELECT day, counter, value FROM daily_statistics WHERE day >= ?")?;
+
+        let rows_iter = stmt.query_map([since_day.to_string()], |row| {
+            let day: i64 = row.get(0)?;
+            let counter: String = row.get(1)?;
+            let value: i64 = row.get(2)?;
+            Ok((day.unsigned_abs(), counter, value.unsigned_abs()))
+        })?;
+
+        let mut daily_counters = DailyCounters::new();
+
+        for (day, counter, value) in rows_iter.filter_map(std::result::Result::ok) {
+            daily_counters.entry(day).or_default().insert(counter, value);
+        }
+
+        Ok(daily_counters)
+    }
+
+    /// Refer to [`databases::Database::add_audit_log_entry`](crate::core::databases::Database::add_audit_log_entry).
+    fn add_audit_log_entry(&self, entry: &audit::Entry) -> Result<(), Error> {
+        let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        conn.execute(
+            "INSERT INTO audit_log (timestamp, label, action, target, result) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                entry.timestamp.as_secs().to_string(),
+                entry.label,
+                entry.action,
+                entry.target,
+                entry.result
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Refer to [`databases::Database::load_audit_log`](crate::core::databases::Database::load_audit_log).
+    fn load_audit_log(&self, offset: u32, limit: u32) -> Result<Vec<audit::Entry>, Error> {
+        let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let mut stmt =
+            conn.prepare("SELECT timestamp, label, action, target, result FROM audit_log ORDER BY id DESC LIMIT ?1 OFFSET ?2")?;
+
+        let entries_iter = stmt.query_map([limit, offset], |row| {
+            let timestamp: i64 = row.get(0)?;
+            Ok(audit::Entry {
+                timestamp: DurationSinceUnixEpoch::from_secs(timestamp.unsigned_abs()),
+                label: row.get(1)?,
+                action: row.get(2)?,
+                target: row.get(3)?,
+                result: row.get(4)?,
+            })
+        })?;
+
+        Ok(entries_iter.filter_map(std::result::Result::ok).collect())
+    }
+
+    /// Refer to [`databases::Database::count_audit_log_entries`](crate::core::databases::Database::count_audit_log_entries).
+    fn count_audit_log_entries(&self) -> Result<u64, Error> {
+        let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM audit_log", [], |row| row.get(0))?;
+
+        Ok(count.unsigned_abs())
+    }
+
+    /// Refer to [`databases::Database::remove_audit_log_entries_before`](crate::core::databases::Database::remove_audit_log_entries_before).
+    fn remove_audit_log_entries_before(&self, timestamp: u64) -> Result<usize, Error> {
+        let conn = self.pool.get().map_err(|e| (e, DRIVER))?;
+
+        Ok(conn.execute("DELETE FROM audit_log WHERE timestamp < ?", [timestamp.to_string()])?)
+    }
 }
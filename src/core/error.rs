@@ -48,6 +48,9 @@ pub enum PeerKeyError {
         source: LocatedError<'static, ParseKeyError>,
     },
 
+    #[error("The key already exists: {key}")]
+    DuplicateKey { key: String },
+
     #[error("Can't persist key: {source}")]
     DatabaseError {
         source: LocatedError<'static, databases::error::Error>,
@@ -0,0 +1,363 @@
+//! Live tracker events.
+//!
+//! The tracker publishes an [`Event`] when:
+//!
+//! - It handles an `announce` request.
+//! - A peer completes the download of a torrent.
+//! - A torrent is announced for the first time.
+//! - The HTTP or UDP tracker returns an error response.
+//!
+//! The events are not stored. They are only sent to the current subscribers,
+//! for example, the API clients watching the tracker activity in a dashboard.
+//!
+//! Each [`Subscription`] has its own [`Filter`] and its own position in a
+//! buffer of [`BUFFER_CAPACITY`] events. Publishing an event never waits for
+//! the subscribers: when a subscriber does not keep up, it skips the oldest
+//! events and it's notified with the number of skipped events. That way a slow
+//! consumer can not slow down the `announce` requests. When there are no
+//! subscribers, the events are not even built.
+use std::fmt;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use aquatic_udp_protocol::{AnnounceEvent, PeerId};
+use bittorrent_primitives::info_hash::InfoHash;
+use thiserror::Error;
+use tokio::sync::broadcast::{self, error::RecvError};
+use torrust_tracker_clock::clock::Time;
+use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+use super::statistics::{ErrorReason, Scheme};
+use crate::CurrentClock;
+
+/// The number of events kept for the subscribers that have not received them
+/// yet.
+pub const BUFFER_CAPACITY: usize = 1024;
+
+/// A tracker event.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Event {
+    /// A peer announced itself.
+    Announce {
+        scheme: Scheme,
+        info_hash: InfoHash,
+        peer_id: PeerId,
+        peer_addr: SocketAddr,
+        event: AnnounceEvent,
+    },
+    /// A peer completed the download. The torrent `completed` counter was
+    /// increased.
+    Completed {
+        scheme: Scheme,
+        info_hash: InfoHash,
+        peer_id: PeerId,
+    },
+    /// A new torrent was added to the tracker.
+    TorrentAdded { scheme: Scheme, info_hash: InfoHash },
+    /// The tracker returned an error response.
+    Error { scheme: Scheme, reason: ErrorReason },
+}
+
+impl Event {
+    #[must_use]
+    pub fn kind(&self) -> Kind {
+        match self {
+            Event::Announce { .. } => Kind::Announce,
+            Event::Completed { .. } => Kind::Completed,
+            Event::TorrentAdded { .. } => Kind::TorrentAdded,
+            Event::Error { .. } => Kind::Error,
+        }
+    }
+
+    /// The protocol of the tracker that handled the request.
+    #[must_use]
+    pub fn scheme(&self) -> Scheme {
+        match self {
+            Event::Announce { scheme, .. }
+            | Event::Completed { scheme, .. }
+            | Event::TorrentAdded { scheme, .. }
+            | Event::Error { scheme, .. } => *scheme,
+        }
+    }
+
+    /// The torrent the event is about. Errors are not related to a torrent.
+    #[must_use]
+    pub fn info_hash(&self) -> Option<InfoHash> {
+        match self {
+            Event::Announce { info_hash, .. } | Event::Completed { info_hash, .. } | Event::TorrentAdded { info_hash, .. } => {
+                Some(*info_hash)
+            }
+            Event::Error { .. } => None,
+        }
+    }
+}
+
+/// The type of [`Event`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Kind {
+    Announce,
+    Completed,
+    TorrentAdded,
+    Error,
+}
+
+impl Kind {
+    pub const ALL: [Kind; 4] = [Kind::Announce, Kind::Completed, Kind::TorrentAdded, Kind::Error];
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::Announce => write!(f, "announce"),
+            Kind::Completed => write!(f, "completed"),
+            Kind::TorrentAdded => write!(f, "torrent_added"),
+            Kind::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("unknown event type {0}, expected one of: announce, completed, torrent_added, error")]
+pub struct ParseKindError(String);
+
+impl FromStr for Kind {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Kind::ALL
+            .into_iter()
+            .find(|kind| kind.to_string() == s)
+            .ok_or_else(|| ParseKindError(s.to_string()))
+    }
+}
+
+/// An [`Event`] with the time it was published.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TimedEvent {
+    pub time: DurationSinceUnixEpoch,
+    pub event: Event,
+}
+
+/// The events a subscriber wants to receive.
+///
+/// Each list is a set of alternatives. An empty list matches any value, so the
+/// default filter matches all the events.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Filter {
+    /// Only the events for these torrents. It excludes the errors.
+    pub info_hashes: Vec<InfoHash>,
+    /// Only these types of events.
+    pub kinds: Vec<Kind>,
+    /// Only the events from the trackers using these protocols.
+    pub schemes: Vec<Scheme>,
+}
+
+impl Filter {
+    #[must_use]
+    pub fn matches(&self, event: &Event) -> bool {
+        (self.kinds.is_empty() || self.kinds.contains(&event.kind()))
+            && (self.schemes.is_empty() || self.schemes.contains(&event.scheme()))
+            && (self.info_hashes.is_empty()
+                || event
+                    .info_hash()
+                    .is_some_and(|info_hash| self.info_hashes.contains(&info_hash)))
+    }
+}
+
+/// What a [`Subscription`] receives.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Received {
+    /// An event that matches the subscription filter.
+    Event(TimedEvent),
+    /// The subscriber did not keep up and this number of events (matching
+    /// the filter or not) were skipped.
+    Lagged(u64),
+}
+
+/// It sends the tracker events to all the current subscribers.
+pub struct Broadcaster {
+    sender: broadcast::Sender<TimedEvent>,
+}
+
+impl Default for Broadcaster {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(BUFFER_CAPACITY);
+
+        Self { sender }
+    }
+}
+
+impl Broadcaster {
+    /// Returns `true` if someone is listening to the events.
+    #[must_use]
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    /// It sends the event to all the current subscribers. It does nothing
+    /// if there are no subscribers.
+    pub fn publish(&self, event: Event) {
+        if self.has_subscribers() {
+            // The only possible error is that all the subscribers left.
+            drop(self.sender.send(TimedEvent {
+                time: CurrentClock::now(),
+                event,
+            }));
+        }
+    }
+
+    /// It returns a subscription to the events published from now on.
+    #[must_use]
+    pub fn subscribe(&self, filter: Filter) -> Subscription {
+        Subscription {
+            receiver: self.sender.subscribe(),
+            filter,
+        }
+    }
+}
+
+/// A subscription to the tracker events. The subscriber leaves when it's
+/// dropped.
+pub struct Subscription {
+    receiver: broadcast::Receiver<TimedEvent>,
+    filter: Filter,
+}
+
+impl Subscription {
+    /// It waits for the next event matching the filter.
+    ///
+    /// It returns `None` when the tracker stops publishing events.
+    pub async fn next(&mut self) -> Option<Received> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(timed_event) if self.filter.matches(&timed_event.event) => return Some(Received::Event(timed_event)),
+                Ok(_) => {}
+                Err(RecvError::Lagged(skipped)) => return Some(Received::Lagged(skipped)),
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::str::FromStr;
+
+    use aquatic_udp_protocol::{AnnounceEvent, PeerId};
+    use bittorrent_primitives::info_hash::InfoHash;
+
+    use super::{Broadcaster, Event, Filter, Kind, Received, BUFFER_CAPACITY};
+    use crate::core::statistics::{ErrorReason, Scheme};
+
+    fn info_hash(n: u8) -> InfoHash {
+        InfoHash::from([n; 20])
+    }
+
+    fn announce(scheme: Scheme, info_hash: InfoHash) -> Event {
+        Event::Announce {
+            scheme,
+            info_hash,
+            peer_id: PeerId(*b"-qB00000000000000001"),
+            peer_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080),
+            event: AnnounceEvent::Started,
+        }
+    }
+
+    fn error(scheme: Scheme) -> Event {
+        Event::Error {
+            scheme,
+            reason: ErrorReason::BadRequest,
+        }
+    }
+
+    fn received_event(received: Option<Received>) -> Option<Event> {
+        match received {
+            Some(Received::Event(timed_event)) => Some(timed_event.event),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn it_should_parse_the_event_kinds() {
+        for kind in Kind::ALL {
+            assert_eq!(Kind::from_str(&kind.to_string()), Ok(kind));
+        }
+
+        assert!(Kind::from_str("scrape").is_err());
+    }
+
+    #[test]
+    fn the_default_filter_should_match_all_the_events() {
+        let filter = Filter::default();
+
+        assert!(filter.matches(&announce(Scheme::Http, info_hash(1))));
+        assert!(filter.matches(&error(Scheme::Udp)));
+    }
+
+    #[test]
+    fn the_filter_should_match_the_events_with_any_of_the_given_values() {
+        let filter = Filter {
+            info_hashes: vec![info_hash(1), info_hash(2)],
+            kinds: vec![Kind::Announce],
+            schemes: vec![Scheme::Udp],
+        };
+
+        assert!(filter.matches(&announce(Scheme::Udp, info_hash(2))));
+        assert!(!filter.matches(&announce(Scheme::Udp, info_hash(3))));
+        assert!(!filter.matches(&announce(Scheme::Http, info_hash(1))));
+        assert!(!filter.matches(&Event::TorrentAdded {
+            scheme: Scheme::Udp,
+            info_hash: info_hash(1)
+        }));
+    }
+
+    #[test]
+    fn the_errors_should_not_match_a_filter_by_info_hash() {
+        let filter = Filter {
+            info_hashes: vec![info_hash(1)],
+            ..Default::default()
+        };
+
+        assert!(!filter.matches(&error(Scheme::Http)));
+    }
+
+    #[tokio::test]
+    async fn a_subscriber_should_only_receive_the_events_matching_its_filter() {
+        let broadcaster = Broadcaster::default();
+
+        let mut subscription = broadcaster.subscribe(Filter {
+            schemes: vec![Scheme::Http],
+            ..Default::default()
+        });
+
+        broadcaster.publish(error(Scheme::Udp));
+        broadcaster.publish(error(Scheme::Http));
+
+        assert_eq!(received_event(subscription.next().await), Some(error(Scheme::Http)));
+    }
+
+    #[test]
+    fn it_should_not_publish_the_events_when_there_are_no_subscribers() {
+        let broadcaster = Broadcaster::default();
+
+        broadcaster.publish(error(Scheme::Http));
+
+        assert!(!broadcaster.has_subscribers());
+        assert_eq!(broadcaster.sender.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn a_slow_subscriber_should_skip_the_oldest_events_without_blocking_the_publisher() {
+        let broadcaster = Broadcaster::default();
+
+        let mut subscription = broadcaster.subscribe(Filter::default());
+
+        for _ in 0..BUFFER_CAPACITY + 10 {
+            broadcaster.publish(error(Scheme::Http));
+        }
+
+        assert_eq!(subscription.next().await, Some(Received::Lagged(10)));
+        assert_eq!(received_event(subscription.next().await), Some(error(Scheme::Http)));
+    }
+}
@@ -28,6 +28,7 @@
 //! - [Services](#services)
 //! - [Authentication](#authentication)
 //! - [Statistics](#statistics)
+//! - [Live events](#live-events)
 //! - [Persistence](#persistence)
 //!
 //! # Tracker
@@ -104,6 +105,12 @@
 //!     pub peers: Vec<peer::Peer>,
 //!     pub swarm_stats: SwarmMetadata,
 //!     pub policy: AnnouncePolicy, // the tracker announce policy.
+//!     pub swarm_change: SwarmChange,
+//! }
+//!
+//! pub struct SwarmChange {
+//!     pub torrent_added: bool, // The torrent was not in the tracker before the request
+//!     pub completed: bool,     // The peer completed the download
 //! }
 //!
 //! pub struct SwarmMetadata {
@@ -317,9 +324,14 @@
 //! threshold = "debug"
 //!
 //! [core]
+//! clients_breakdown_interval = 60
+//! expired_keys_cleanup_interval = 3600
+//! expired_keys_grace_period = 0
 //! inactive_peer_cleanup_interval = 600
 //! listed = false
+//! overload_announce_rate = 0
 //! private = false
+//! statistics_persistence_interval = 300
 //! tracker_usage_statistics = true
 //!
 //! [core.announce_policy]
@@ -422,57 +434,95 @@
 //! For example, the HTTP tracker would send an event like the following when it handles an `announce` request received from a peer using IP version 4.
 //!
 //! ```text
-//! tracker.send_stats_event(statistics::Event::Tcp4Announce).await
+//! tracker.send_stats_event(statistics::Event::Request {
+//!     scheme: statistics::Scheme::Http,
+//!     ip_version: statistics::IpVersion::V4,
+//!     kind: statistics::RequestKind::Announce,
+//!     listener: server_socket_addr,
+//! }).await
 //! ```
 //!
+//! The `listener` is the socket address the HTTP tracker is bound to. It's used
+//! to keep the counters for each tracker listener.
+//!
 //! Refer to [`statistics`] module for more information about statistics.
 //!
+//! # Live events
+//!
+//! Besides counting them, the `Tracker` publishes some events as they happen,
+//! so they can be watched live: announces, completed downloads, new torrents
+//! and error responses. The HTTP and UDP trackers publish the events for an
+//! `announce` request after handling it:
+//!
+//! ```text
+//! let announce_data = tracker.announce(&info_hash, &mut peer, &peer_ip, &peers_wanted);
+//!
+//! tracker.publish_announce_events(statistics::Scheme::Http, &info_hash, &peer, &announce_data);
+//! ```
+//!
+//! The errors are published when the error statistics events are sent.
+//!
+//! Refer to [`events`] module for more information about live events.
+//!
 //! # Persistence
 //!
 //! Right now the `Tracker` is responsible for storing and load data into and
 //! from the database, when persistence is enabled.
 //!
-//! There are three types of persistent object:
+//! There are five types of persistent object:
 //!
 //! - Authentication keys (only expiring keys)
 //! - Torrent whitelist
 //! - Torrent metrics
+//! - Usage statistics (the counters and their daily totals)
+//! - The [`audit`] log of administrative actions done with the tracker API
 //!
 //! Refer to [`databases`] module for more information about persistence.
+pub mod audit;
 pub mod auth;
+pub mod clients;
 pub mod databases;
 pub mod error;
+pub mod events;
 pub mod services;
 pub mod statistics;
 pub mod torrent;
+pub mod whitelist;
 
 pub mod peer_tests;
 
 use std::cmp::max;
-use std::collections::HashMap;
-use std::net::IpAddr;
+use std::collections::{BTreeSet, HashMap};
+use std::net::{IpAddr, SocketAddr};
 use std::panic::Location;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
+use aquatic_udp_protocol::{AnnounceEvent, PeerId};
 use auth::PeerKey;
 use bittorrent_primitives::info_hash::InfoHash;
+use dashmap::DashMap;
 use databases::driver::Driver;
 use derive_more::Constructor;
 use error::PeerKeyError;
 use tokio::sync::mpsc::error::SendError;
 use torrust_tracker_clock::clock::Time;
 use torrust_tracker_configuration::v2_0_0::database;
-use torrust_tracker_configuration::{AnnouncePolicy, Core, TORRENT_PEERS_LIMIT};
+use torrust_tracker_configuration::{AnnouncePolicy, Core, TrackerPolicy, TORRENT_PEERS_LIMIT};
 use torrust_tracker_located_error::Located;
+use torrust_tracker_primitives::pagination::Pagination;
 use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
 use torrust_tracker_primitives::torrent_metrics::TorrentsMetrics;
-use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
+use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch, PersistentTorrents};
 use torrust_tracker_torrent_repository::entry::EntrySync;
 use torrust_tracker_torrent_repository::repository::Repository;
 
-use self::auth::Key;
+use self::auth::{Key, KeyFilter, KeyUsage, KeyUsages};
+use self::clients::ClientsBreakdown;
 use self::error::Error;
+use self::torrent::activity::{Activities, Ranking, TorrentActivity};
+use self::torrent::peer_index::{IpNetwork, PeerIndex, PeerMatch, PeerSearch};
 use self::torrent::Torrents;
 use crate::core::databases::Database;
 use crate::CurrentClock;
@@ -490,24 +540,116 @@ pub struct Tracker {
     /// The tracker configuration.
     config: Core,
 
-    /// A database driver implementation: [`Sqlite3`](crate::core::databases::sqlite)
-    /// or [`MySQL`](crate::core::databases::mysql)
+    /// The announce policy. It can be changed while the tracker is running.
+    announce_policy: std::sync::RwLock<AnnouncePolicy>,
+
+    /// The tracker policy. It can be changed while the tracker is running.
+    policy: std::sync::RwLock<TrackerPolicy>,
+
+    /// A database driver implementation: [`Sqlite3`](crate::core::databases::sqlite),
+    /// [`MySQL`](crate::core::databases::mysql) or [`Memory`](crate::core::databases::memory)
     database: Arc<Box<dyn Database>>,
 
     /// Tracker users' keys. Only for private trackers.
     keys: tokio::sync::RwLock<std::collections::HashMap<Key, auth::PeerKey>>,
 
+    /// How the keys have been used since the tracker started.
+    key_usages: KeyUsages,
+
     /// The list of allowed torrents. Only for listed trackers.
     whitelist: tokio::sync::RwLock<std::collections::HashSet<InfoHash>>,
 
     /// The in-memory torrents repository.
     torrents: Arc<Torrents>,
 
+    /// The completed downloads of the torrents removed from memory keeping
+    /// their persisted stats. They are restored when the torrents are
+    /// announced again.
+    removed_torrents: DashMap<InfoHash, u32>,
+
+    /// The activity counters used to rank the torrents.
+    activities: Activities,
+
+    /// The secondary index used to search the peers in all the swarms.
+    peer_index: PeerIndex,
+
     /// Service to send stats events.
     stats_event_sender: Option<Box<dyn statistics::EventSender>>,
 
     /// The in-memory stats repo.
     stats_repository: statistics::Repo,
+
+    /// The last result of the overload check.
+    overload: Overload,
+
+    /// Service to send the live events to the subscribers.
+    events: events::Broadcaster,
+
+    /// When the tracker was created.
+    started_at: DurationSinceUnixEpoch,
+}
+
+/// The last result of the [overload check](Tracker::is_overloaded).
+///
+/// The announce rate is calculated at most once per second because it adds up
+/// the counters of all the statistics shards.
+#[derive(Debug)]
+struct Overload {
+    /// The second the last result was stored in.
+    checked_at: AtomicU64,
+    /// The second the last check was started in.
+    checking_at: AtomicU64,
+    overloaded: AtomicBool,
+}
+
+impl Default for Overload {
+    fn default() -> Self {
+        Self {
+            checked_at: AtomicU64::new(u64::MAX),
+            checking_at: AtomicU64::new(u64::MAX),
+            overloaded: AtomicBool::new(false),
+        }
+    }
+}
+
+impl Overload {
+    /// It returns the result of the last check if it was done in the same
+    /// second. Otherwise, it runs the `check` again.
+    ///
+    /// Only one caller runs the `check` each second. The other callers get
+    /// the result of the previous check while it's running.
+    fn get_or_check(&self, now_secs: u64, check: impl FnOnce() -> bool) -> bool {
+        if self.checked_at.load(Ordering::Acquire) == now_secs {
+            return self.overloaded.load(Ordering::Acquire);
+        }
+
+        let checking_at = self.checking_at.load(Ordering::Acquire);
+
+        if checking_at == now_secs
+            || self
+                .checking_at
+                .compare_exchange(checking_at, now_secs, Ordering::AcqRel, Ordering::Acquire)
+                .is_err()
+        {
+            return self.overloaded.load(Ordering::Acquire);
+        }
+
+        let overloaded = check();
+
+        if self.overloaded.swap(overloaded, Ordering::AcqRel) != overloaded {
+            if overloaded {
+                tracing::warn!("The tracker is overloaded, the announce intervals are doubled");
+            } else {
+                tracing::info!("The tracker is no longer overloaded");
+            }
+        }
+
+        // The result is stored before the second, so the callers that see
+        // the second get the result of its check.
+        self.checked_at.store(now_secs, Ordering::Release);
+
+        overloaded
+    }
 }
 
 /// Structure that holds the data returned by the `announce` request.
@@ -519,6 +661,17 @@ pub struct AnnounceData {
     /// Swarm statistics
     pub stats: SwarmMetadata,
     pub policy: AnnouncePolicy,
+    /// How the `announce` request changed the swarm.
+    pub swarm_change: SwarmChange,
+}
+
+/// How an `announce` request changed the swarm, besides updating the peer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct SwarmChange {
+    /// The torrent was not in the tracker before the request.
+    pub torrent_added: bool,
+    /// The peer completed the download, so the `completed` counter increased.
+    pub completed: bool,
 }
 
 /// How many peers the peer announcing wants in the announce response.
@@ -629,21 +782,41 @@ impl Tracker {
         let driver = match config.database.driver {
             database::Driver::Sqlite3 => Driver::Sqlite3,
             database::Driver::MySQL => Driver::MySQL,
+            database::Driver::Memory => Driver::Memory,
         };
 
         let database = Arc::new(databases::driver::build(&driver, &config.database.path)?);
 
         Ok(Tracker {
             config: config.clone(),
+            announce_policy: std::sync::RwLock::new(config.announce_policy),
+            policy: std::sync::RwLock::new(config.tracker_policy.clone()),
             keys: tokio::sync::RwLock::new(std::collections::HashMap::new()),
             whitelist: tokio::sync::RwLock::new(std::collections::HashSet::new()),
             torrents: Arc::default(),
+            removed_torrents: DashMap::default(),
+            activities: Activities::default(),
+            peer_index: PeerIndex::default(),
+            key_usages: KeyUsages::default(),
             stats_event_sender,
             stats_repository,
+            overload: Overload::default(),
             database,
+            events: events::Broadcaster::default(),
+            started_at: CurrentClock::now(),
         })
     }
 
+    /// When the tracker was started, in seconds since Unix Epoch.
+    pub fn started_at(&self) -> DurationSinceUnixEpoch {
+        self.started_at
+    }
+
+    /// How long the tracker has been running.
+    pub fn uptime(&self) -> Duration {
+        CurrentClock::now().saturating_sub(self.started_at)
+    }
+
     /// Returns `true` is the tracker is in public mode.
     pub fn is_public(&self) -> bool {
         !self.config.private
@@ -664,13 +837,83 @@ impl Tracker {
         self.is_private()
     }
 
+    /// Returns `true` if the tracker rejects the expired authentication keys.
+    pub fn checks_keys_expiration(&self) -> bool {
+        self.config
+            .private_mode
+            .map_or(true, |private_mode| private_mode.check_keys_expiration)
+    }
+
     /// Returns `true` is the tracker is in whitelisted mode.
     pub fn is_behind_reverse_proxy(&self) -> bool {
         self.config.net.on_reverse_proxy
     }
 
+    /// # Panics
+    ///
+    /// Will panic if the announce policy lock is poisoned.
     pub fn get_announce_policy(&self) -> AnnouncePolicy {
-        self.config.announce_policy
+        *self.announce_policy.read().expect("it should get the announce policy")
+    }
+
+    /// # Panics
+    ///
+    /// Will panic if the tracker policy lock is poisoned.
+    pub fn get_tracker_policy(&self) -> TrackerPolicy {
+        self.policy.read().expect("it should get the tracker policy").clone()
+    }
+
+    /// It replaces the announce and tracker policies. The new policies are
+    /// used from the next request or cleanup on.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the policy locks are poisoned.
+    pub fn update_policies(&self, announce_policy: AnnouncePolicy, tracker_policy: TrackerPolicy) {
+        *self.announce_policy.write().expect("it should update the announce policy") = announce_policy;
+        *self.policy.write().expect("it should update the tracker policy") = tracker_policy;
+    }
+
+    /// Returns `true` if the tracker has handled more announce requests per
+    /// second in the last minute than the `overload_announce_rate` in the
+    /// configuration. It's always `false` if the option is `0`.
+    pub fn is_overloaded(&self) -> bool {
+        let threshold = self.config.overload_announce_rate;
+
+        if threshold == 0 {
+            return false;
+        }
+
+        let now: DurationSinceUnixEpoch = CurrentClock::now();
+
+        self.overload.get_or_check(now.as_secs(), || {
+            let window = Duration::from_secs(60);
+
+            let rate = self
+                .stats_repository
+                .requests_per_second(statistics::RequestType::UdpAnnounce, window)
+                + self
+                    .stats_repository
+                    .requests_per_second(statistics::RequestType::HttpAnnounce, window);
+
+            #[allow(clippy::cast_precision_loss)]
+            let overloaded = rate > threshold as f64;
+
+            overloaded
+        })
+    }
+
+    /// The announce policy sent to the clients. The intervals are doubled
+    /// while the tracker [is overloaded](Self::is_overloaded), so the clients
+    /// announce less often.
+    fn announce_policy_for_current_load(&self) -> AnnouncePolicy {
+        let policy = self.get_announce_policy();
+
+        if self.is_overloaded() {
+            AnnouncePolicy::new(policy.interval.saturating_mul(2), policy.interval_min.saturating_mul(2))
+        } else {
+            policy
+        }
     }
 
     pub fn get_maybe_external_ip(&self) -> Option<IpAddr> {
@@ -708,14 +951,15 @@ impl Tracker {
         peer.change_ip(&assign_ip_address_to_peer(remote_client_ip, self.config.net.external_ip));
         tracing::debug!("After: {peer:?}");
 
-        let stats = self.upsert_peer_and_get_stats(info_hash, peer);
+        let (stats, swarm_change) = self.upsert_peer_and_get_change(info_hash, peer);
 
         let peers = self.get_peers_for(info_hash, peer, peers_wanted.limit());
 
         AnnounceData {
             peers,
             stats,
-            policy: self.get_announce_policy(),
+            policy: self.announce_policy_for_current_load(),
+            swarm_change,
         }
     }
 
@@ -790,14 +1034,28 @@ impl Tracker {
     ///
     /// # Context: Tracker
     pub fn upsert_peer_and_get_stats(&self, info_hash: &InfoHash, peer: &peer::Peer) -> SwarmMetadata {
-        let swarm_metadata_before = match self.torrents.get_swarm_metadata(info_hash) {
+        self.upsert_peer_and_get_change(info_hash, peer).0
+    }
+
+    /// Like [`Tracker::upsert_peer_and_get_stats`] but it also returns how the
+    /// swarm changed.
+    fn upsert_peer_and_get_change(&self, info_hash: &InfoHash, peer: &peer::Peer) -> (SwarmMetadata, SwarmChange) {
+        let opt_swarm_metadata_before = self.torrents.get_swarm_metadata(info_hash);
+
+        let swarm_metadata_before = match opt_swarm_metadata_before {
             Some(swarm_metadata) => swarm_metadata,
-            None => SwarmMetadata::zeroed(),
+            None => self.restore_persisted_stats(info_hash),
         };
 
         self.torrents.upsert_peer(info_hash, peer);
 
-        let swarm_metadata_after = match self.torrents.get_swarm_metadata(info_hash) {
+        if peer.event != AnnounceEvent::Stopped {
+            self.peer_index.insert(info_hash, peer);
+        }
+
+        let opt_swarm_metadata_after = self.torrents.get_swarm_metadata(info_hash);
+
+        let swarm_metadata_after = match opt_swarm_metadata_after {
             Some(swarm_metadata) => swarm_metadata,
             None => SwarmMetadata::zeroed(),
         };
@@ -806,14 +1064,50 @@ impl Tracker {
             self.persist_stats(info_hash, &swarm_metadata_after);
         }
 
-        swarm_metadata_after
+        self.activities
+            .record_announce(info_hash, &swarm_metadata_before, &swarm_metadata_after, CurrentClock::now());
+
+        let swarm_change = SwarmChange {
+            torrent_added: opt_swarm_metadata_before.is_none() && opt_swarm_metadata_after.is_some(),
+            completed: swarm_metadata_after.downloaded > swarm_metadata_before.downloaded,
+        };
+
+        (swarm_metadata_after, swarm_change)
+    }
+
+    /// It loads into memory the persisted stats of a torrent that is not in
+    /// memory (if persistency is enabled), and returns its swarm metadata.
+    ///
+    /// The torrents with completed downloads are only missing from memory when
+    /// they have been removed with [`Tracker::remove_torrent`] keeping their
+    /// persisted stats. Otherwise, the first announce would overwrite them.
+    /// Their completed downloads are kept in memory when they are removed, so
+    /// the database is not queried.
+    ///
+    /// # Context: Tracker
+    fn restore_persisted_stats(&self, info_hash: &InfoHash) -> SwarmMetadata {
+        let Some((_, completed)) = self.removed_torrents.remove(info_hash) else {
+            return SwarmMetadata::zeroed();
+        };
+
+        if !self.get_tracker_policy().persistent_torrent_completed_stat {
+            return SwarmMetadata::zeroed();
+        }
+
+        self.torrents
+            .import_persistent(&PersistentTorrents::from([(*info_hash, completed)]));
+
+        SwarmMetadata {
+            downloaded: completed,
+            ..SwarmMetadata::zeroed()
+        }
     }
 
     /// It stores the torrents stats into the database (if persistency is enabled).
     ///
     /// # Context: Tracker
     fn persist_stats(&self, info_hash: &InfoHash, swarm_metadata: &SwarmMetadata) {
-        if self.config.tracker_policy.persistent_torrent_completed_stat {
+        if self.get_tracker_policy().persistent_torrent_completed_stat {
             let completed = swarm_metadata.downloaded;
             let info_hash = *info_hash;
 
@@ -836,16 +1130,147 @@ impl Tracker {
     ///
     /// # Context: Tracker
     pub fn cleanup_torrents(&self) {
-        let current_cutoff = CurrentClock::now_sub(&Duration::from_secs(u64::from(self.config.tracker_policy.max_peer_timeout)))
-            .unwrap_or_default();
+        let policy = self.get_tracker_policy();
+
+        let current_cutoff = CurrentClock::now_sub(&Duration::from_secs(u64::from(policy.max_peer_timeout))).unwrap_or_default();
 
         self.torrents.remove_inactive_peers(current_cutoff);
 
-        if self.config.tracker_policy.remove_peerless_torrents {
-            self.torrents.remove_peerless_torrents(&self.config.tracker_policy);
+        if policy.remove_peerless_torrents {
+            self.torrents.remove_peerless_torrents(&policy);
+        }
+
+        self.activities
+            .update_swarm_metadata(|info_hash| self.torrents.get_swarm_metadata(info_hash));
+
+        self.key_usages
+            .retain_active_peers(|info_hash, peer_id| self.is_peer_active(info_hash, peer_id));
+
+        self.peer_index.retain_active_peers(|info_hash, peer_id| {
+            self.torrents
+                .get(info_hash)
+                .and_then(|entry| entry.get_peer(peer_id))
+                .map(|peer| peer.peer_addr.ip())
+        });
+    }
+
+    /// It removes a torrent and its swarm from memory. If `remove_completed`
+    /// is `true`, it also removes the persisted number of completed downloads,
+    /// otherwise the torrent will be loaded again with its completed downloads
+    /// when the tracker restarts or the torrent is announced again.
+    ///
+    /// It returns `false` if the tracker does not have the torrent in memory.
+    ///
+    /// # Context: Tracker
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to remove the persisted torrent from the database.
+    pub fn remove_torrent(&self, info_hash: &InfoHash, remove_completed: bool) -> Result<bool, databases::error::Error> {
+        if remove_completed {
+            self.database.remove_persistent_torrent(info_hash)?;
+            self.removed_torrents.remove(info_hash);
+        }
+
+        self.activities.remove(info_hash);
+
+        let Some(entry) = self.torrents.remove(info_hash) else {
+            return Ok(false);
+        };
+
+        let completed = entry.get_swarm_metadata().downloaded;
+
+        if !remove_completed && completed > 0 && self.get_tracker_policy().persistent_torrent_completed_stat {
+            self.removed_torrents.insert(*info_hash, completed);
+        }
+
+        Ok(true)
+    }
+
+    /// It removes a peer from a torrent swarm. The peer will be added again if
+    /// it announces again.
+    ///
+    /// It returns `false` if the peer is not in the swarm.
+    ///
+    /// # Context: Tracker
+    #[must_use]
+    pub fn remove_peer(&self, info_hash: &InfoHash, peer_id: &PeerId) -> bool {
+        self.torrents.get(info_hash).is_some_and(|entry| entry.remove_peer(peer_id))
+    }
+
+    /// It removes the peers with the given IP from all the swarms and returns
+    /// how many were removed. Only the swarms where the peer index has the IP
+    /// are updated.
+    ///
+    /// # Context: Tracker
+    #[must_use]
+    pub fn remove_peers_with_ip(&self, ip: &IpAddr) -> u64 {
+        let search = PeerSearch {
+            network: Some(IpNetwork::from(*ip)),
+            peer_id_prefix: None,
+        };
+
+        let info_hashes: BTreeSet<InfoHash> = self
+            .peer_index
+            .candidates(&search)
+            .into_iter()
+            .map(|(info_hash, _)| info_hash)
+            .collect();
+
+        info_hashes
+            .iter()
+            .filter_map(|info_hash| self.torrents.get(info_hash))
+            .map(|entry| entry.remove_peers_with_ip(ip) as u64)
+            .sum()
+    }
+
+    /// Whether the peer is in the torrent swarm.
+    ///
+    /// # Context: Tracker
+    #[must_use]
+    pub fn is_peer_active(&self, info_hash: &InfoHash, peer_id: &PeerId) -> bool {
+        self.torrents
+            .get(info_hash)
+            .is_some_and(|entry| entry.get_peer(peer_id).is_some())
+    }
+
+    /// It searches the peers in all the swarms. It returns the peers that
+    /// match the search with the torrent they are in, sorted by torrent and
+    /// peer ID.
+    ///
+    /// # Context: Tracker
+    #[must_use]
+    pub fn search_peers(&self, search: &PeerSearch, pagination: Option<&Pagination>) -> Vec<PeerMatch> {
+        let matches = self
+            .peer_index
+            .candidates(search)
+            .into_iter()
+            .filter_map(|(info_hash, peer_id)| {
+                let peer = self.torrents.get(&info_hash)?.get_peer(&peer_id)?;
+
+                search.matches(&peer).then_some(PeerMatch { info_hash, peer })
+            });
+
+        match pagination {
+            Some(pagination) => matches
+                .skip(pagination.offset as usize)
+                .take(pagination.limit as usize)
+                .collect(),
+            None => matches.collect(),
         }
     }
 
+    /// It returns the `limit` torrents with the highest score for the given
+    /// [`Ranking`], from the highest.
+    ///
+    /// Only the torrents announced since the tracker started are ranked.
+    ///
+    /// # Context: Tracker
+    #[must_use]
+    pub fn get_top_torrents(&self, ranking: Ranking, limit: usize) -> Vec<TorrentActivity> {
+        self.activities.top(ranking, limit, CurrentClock::now())
+    }
+
     /// It authenticates the peer `key` against the `Tracker` authentication
     /// key list.
     ///
@@ -856,12 +1281,22 @@ impl Tracker {
     /// # Context: Authentication
     pub async fn authenticate(&self, key: &Key) -> Result<(), auth::Error> {
         if self.is_private() {
-            self.verify_auth_key(key).await
+            self.verify_auth_key(key).await?;
+            self.key_usages.record_use(key, CurrentClock::now());
+            Ok(())
         } else {
             Ok(())
         }
     }
 
+    /// It records that a peer announced with an authentication key, so the
+    /// key usage includes the peer while it's active.
+    ///
+    /// # Context: Authentication
+    pub fn record_key_announce(&self, key: &Key, info_hash: &InfoHash, peer_id: &PeerId) {
+        self.key_usages.record_announce(key, *info_hash, *peer_id);
+    }
+
     /// Adds new peer keys to the tracker.
     ///
     /// Keys can be pre-generated or randomly created. They can also be permanent or expire.
@@ -872,6 +1307,7 @@ impl Tracker {
     ///
     /// - The key duration overflows the duration type maximum value.
     /// - The provided pre-generated key is invalid.
+    /// - The provided pre-generated key already exists.
     /// - The key could not been persisted due to database issues.
     pub async fn add_peer_key(&self, add_key_req: AddKeyRequest) -> Result<auth::PeerKey, PeerKeyError> {
         // code-review: all methods related to keys should be moved to a new independent "keys" service.
@@ -890,6 +1326,9 @@ impl Tracker {
                     match key {
                         Ok(key) => match self.add_auth_key(key, Some(valid_until)).await {
                             Ok(auth_key) => Ok(auth_key),
+                            Err(databases::error::Error::UniqueConstraintFailed { .. }) => {
+                                Err(PeerKeyError::DuplicateKey { key: pre_existing_key })
+                            }
                             Err(err) => Err(PeerKeyError::DatabaseError {
                                 source: Located(err).into(),
                             }),
@@ -906,6 +1345,9 @@ impl Tracker {
                     match key {
                         Ok(key) => match self.add_permanent_auth_key(key).await {
                             Ok(auth_key) => Ok(auth_key),
+                            Err(databases::error::Error::UniqueConstraintFailed { .. }) => {
+                                Err(PeerKeyError::DuplicateKey { key: pre_existing_key })
+                            }
                             Err(err) => Err(PeerKeyError::DatabaseError {
                                 source: Located(err).into(),
                             }),
@@ -972,6 +1414,55 @@ impl Tracker {
         Ok(auth_key)
     }
 
+    /// It generates `count` new authentication keys that share the same
+    /// expiration time. Use `None` for permanent keys.
+    ///
+    /// The keys are persisted in a single database transaction.
+    ///
+    /// # Context: Authentication
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if:
+    ///
+    /// - The key duration overflows the duration type maximum value.
+    /// - The keys could not been persisted due to database issues. No key is
+    ///   added in that case.
+    pub async fn generate_auth_keys(
+        &self,
+        count: usize,
+        opt_seconds_valid: Option<u64>,
+    ) -> Result<Vec<auth::PeerKey>, PeerKeyError> {
+        let valid_until = match opt_seconds_valid {
+            Some(seconds_valid) => match CurrentClock::now_add(&Duration::from_secs(seconds_valid)) {
+                Some(valid_until) => Some(valid_until),
+                None => return Err(PeerKeyError::DurationOverflow { seconds_valid }),
+            },
+            None => None,
+        };
+
+        let auth_keys: Vec<auth::PeerKey> = (0..count)
+            .map(|_| PeerKey {
+                valid_until,
+                ..auth::generate_permanent_key()
+            })
+            .collect();
+
+        if let Err(err) = self.database.add_keys_to_keys(&auth_keys) {
+            return Err(PeerKeyError::DatabaseError {
+                source: Located(err).into(),
+            });
+        }
+
+        let mut keys = self.keys.write().await;
+
+        for auth_key in &auth_keys {
+            keys.insert(auth_key.key.clone(), auth_key.clone());
+        }
+
+        Ok(auth_keys)
+    }
+
     /// It adds a pre-generated permanent authentication key.
     ///
     /// Authentication keys are used by HTTP trackers.
@@ -1031,9 +1522,87 @@ impl Tracker {
     pub async fn remove_auth_key(&self, key: &Key) -> Result<(), databases::error::Error> {
         self.database.remove_key_from_keys(key)?;
         self.keys.write().await.remove(key);
+        self.key_usages.remove(key);
         Ok(())
     }
 
+    /// It removes the authentication keys that expired more than
+    /// `expired_keys_grace_period` seconds ago and returns how many were
+    /// removed from the database. Permanent keys are never removed.
+    ///
+    /// It's called periodically by the [`expired_keys_cleanup`](crate::bootstrap::jobs::expired_keys_cleanup)
+    /// job, and on demand from the tracker API.
+    ///
+    /// # Context: Authentication
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to remove the keys from the database.
+    pub async fn remove_expired_auth_keys(&self) -> Result<usize, databases::error::Error> {
+        let grace_period = Duration::from_secs(self.config.expired_keys_grace_period);
+        let expired_before = CurrentClock::now().saturating_sub(grace_period);
+
+        let removed = self.database.remove_keys_expired_before(expired_before.as_secs())?;
+
+        self.keys.write().await.retain(|key, peer_key| {
+            let is_expired = peer_key
+                .valid_until
+                .is_some_and(|valid_until| valid_until.as_secs() < expired_before.as_secs());
+
+            if is_expired {
+                self.key_usages.remove(key);
+            }
+
+            !is_expired
+        });
+
+        Ok(removed)
+    }
+
+    /// It returns the authentication keys that match the filter, sorted by
+    /// key.
+    ///
+    /// # Context: Authentication
+    pub async fn get_auth_keys(&self, filter: &KeyFilter, pagination: Option<&Pagination>) -> Vec<auth::PeerKey> {
+        let now = CurrentClock::now();
+
+        let mut peer_keys: Vec<auth::PeerKey> = self
+            .keys
+            .read()
+            .await
+            .values()
+            .filter(|peer_key| filter.matches(peer_key, now))
+            .cloned()
+            .collect();
+
+        peer_keys.sort_by(|a, b| a.key.value().cmp(b.key.value()));
+
+        match pagination {
+            Some(pagination) => peer_keys
+                .into_iter()
+                .skip(pagination.offset as usize)
+                .take(pagination.limit as usize)
+                .collect(),
+            None => peer_keys,
+        }
+    }
+
+    /// It returns an authentication key.
+    ///
+    /// # Context: Authentication
+    pub async fn get_auth_key(&self, key: &Key) -> Option<auth::PeerKey> {
+        self.keys.read().await.get(key).cloned()
+    }
+
+    /// It returns how the key has been used since the tracker started, if it
+    /// has been used.
+    ///
+    /// # Context: Authentication
+    #[must_use]
+    pub fn get_auth_key_usage(&self, key: &Key) -> Option<KeyUsage> {
+        self.key_usages.get(key)
+    }
+
     /// It verifies an authentication key.
     ///
     /// # Context: Authentication
@@ -1047,16 +1616,13 @@ impl Tracker {
                 location: Location::caller(),
                 key: Box::new(key.clone()),
             }),
-            Some(key) => match self.config.private_mode {
-                Some(private_mode) => {
-                    if private_mode.check_keys_expiration {
-                        return auth::verify_key_expiration(key);
-                    }
-
-                    Ok(())
+            Some(key) => {
+                if self.checks_keys_expiration() {
+                    return auth::verify_key_expiration(key);
                 }
-                None => auth::verify_key_expiration(key),
-            },
+
+                Ok(())
+            }
         }
     }
 
@@ -1152,6 +1718,55 @@ impl Tracker {
         Ok(())
     }
 
+    /// It adds the torrents to the whitelist, persisting them in a single
+    /// database transaction.
+    ///
+    /// It returns, for each torrent, whether it was added to the database.
+    /// The torrents that were already whitelisted in the database return
+    /// `false`.
+    ///
+    /// # Context: Whitelist
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to add the torrents to the whitelist database.
+    /// The whitelist in memory is not changed in that case.
+    pub async fn add_torrents_to_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, databases::error::Error> {
+        let added = self.database.add_info_hashes_to_whitelist(info_hashes)?;
+
+        let mut whitelist = self.whitelist.write().await;
+
+        for info_hash in info_hashes {
+            whitelist.insert(*info_hash);
+        }
+
+        Ok(added)
+    }
+
+    /// It removes the torrents from the whitelist, removing them from the
+    /// database in a single transaction.
+    ///
+    /// It returns, for each torrent, whether it was whitelisted in memory or
+    /// in the database.
+    ///
+    /// # Context: Whitelist
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to remove the torrents from the whitelist database.
+    /// The whitelist in memory is not changed in that case.
+    pub async fn remove_torrents_from_whitelist(&self, info_hashes: &[InfoHash]) -> Result<Vec<bool>, databases::error::Error> {
+        let removed_from_database = self.database.remove_info_hashes_from_whitelist(info_hashes)?;
+
+        let mut whitelist = self.whitelist.write().await;
+
+        Ok(info_hashes
+            .iter()
+            .zip(removed_from_database)
+            .map(|(info_hash, removed_from_database)| whitelist.remove(info_hash) || removed_from_database)
+            .collect())
+    }
+
     /// It removes a torrent from the whitelist in the database.
     ///
     /// # Context: Whitelist
@@ -1205,28 +1820,314 @@ impl Tracker {
         Ok(())
     }
 
-    /// It return the `Tracker` [`statistics::Metrics`].
+    /// It returns the torrents whitelisted in memory or in the database,
+    /// sorted by info-hash, and where each of them is whitelisted.
     ///
-    /// # Context: Statistics
-    pub async fn get_stats(&self) -> tokio::sync::RwLockReadGuard<'_, statistics::Metrics> {
-        self.stats_repository.get_stats().await
+    /// # Context: Whitelist
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to load the whitelist from the database.
+    pub async fn get_whitelist(&self, pagination: Option<&Pagination>) -> Result<Vec<whitelist::Entry>, databases::error::Error> {
+        let entries = self.get_all_whitelist_entries().await?;
+
+        Ok(match pagination {
+            Some(pagination) => entries
+                .into_iter()
+                .skip(pagination.offset as usize)
+                .take(pagination.limit as usize)
+                .collect(),
+            None => entries,
+        })
     }
 
-    /// It allows to send a statistic events which eventually will be used to update [`statistics::Metrics`].
+    /// It returns where a torrent is whitelisted.
     ///
-    /// # Context: Statistics
-    pub async fn send_stats_event(&self, event: statistics::Event) -> Option<Result<(), SendError<statistics::Event>>> {
-        match &self.stats_event_sender {
-            None => None,
-            Some(stats_event_sender) => stats_event_sender.send_event(event).await,
-        }
+    /// # Context: Whitelist
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to check the whitelist in the database.
+    pub async fn get_whitelist_entry(&self, info_hash: &InfoHash) -> Result<whitelist::Entry, databases::error::Error> {
+        Ok(whitelist::Entry {
+            info_hash: *info_hash,
+            in_memory: self.is_info_hash_whitelisted(info_hash).await,
+            in_database: self.database.is_info_hash_whitelisted(*info_hash)?,
+        })
     }
 
-    /// It drops the database tables.
+    /// It returns the torrents that are whitelisted only in memory or only in
+    /// the database.
+    ///
+    /// # Context: Whitelist
     ///
     /// # Errors
     ///
-    /// Will return `Err` if unable to drop tables.
+    /// Will return a `database::Error` if unable to load the whitelist from the database.
+    pub async fn get_whitelist_diff(&self) -> Result<whitelist::Diff, databases::error::Error> {
+        Ok(whitelist::diff(&self.get_all_whitelist_entries().await?))
+    }
+
+    async fn get_all_whitelist_entries(&self) -> Result<Vec<whitelist::Entry>, databases::error::Error> {
+        let in_database = self.database.load_whitelist()?;
+
+        Ok(whitelist::merge(&*self.whitelist.read().await, &in_database))
+    }
+
+    /// It return the `Tracker` [`statistics::Metrics`].
+    ///
+    /// # Context: Statistics
+    #[must_use]
+    pub fn get_stats(&self) -> statistics::Metrics {
+        self.stats_repository.get_stats()
+    }
+
+    /// It registers a tracker server listener in the statistics, so the
+    /// requests it handles are counted without taking a lock. The servers
+    /// call it when they are bound to their socket address.
+    ///
+    /// # Context: Statistics
+    pub fn register_stats_listener(&self, scheme: statistics::Scheme, address: SocketAddr) {
+        self.stats_repository
+            .register_listener(statistics::Listener { scheme, address });
+    }
+
+    /// It builds the breakdown of the active peers by client software for the
+    /// whole tracker and for each torrent, and stores them in the statistics
+    /// repository.
+    ///
+    /// It visits all the peers, so it should not be called for each request.
+    /// It's called periodically by the [`clients_breakdown`](crate::bootstrap::jobs::clients_breakdown) job.
+    ///
+    /// # Context: Statistics
+    pub async fn update_clients_breakdown(&self) {
+        let updated_at = CurrentClock::now();
+
+        let mut breakdown = ClientsBreakdown {
+            updated_at: Some(updated_at),
+            ..Default::default()
+        };
+
+        let mut torrents = HashMap::new();
+
+        for (info_hash, entry) in self.torrents.get_paginated(None) {
+            let peers = entry.get_peers(None);
+
+            if peers.is_empty() {
+                continue;
+            }
+
+            for peer in &peers {
+                breakdown.add(peer);
+            }
+
+            torrents.insert(
+                info_hash,
+                ClientsBreakdown::from_peers(peers.iter().map(Arc::as_ref), updated_at),
+            );
+        }
+
+        self.stats_repository.set_clients_breakdown(breakdown, torrents).await;
+    }
+
+    /// It returns the last breakdown of the active peers by client software
+    /// built by [`Tracker::update_clients_breakdown`].
+    ///
+    /// # Context: Statistics
+    pub async fn get_clients_breakdown(&self) -> ClientsBreakdown {
+        self.stats_repository.get_clients_breakdown().await
+    }
+
+    /// It returns the last breakdown of the active peers by client software
+    /// for a torrent built by [`Tracker::update_clients_breakdown`]. It returns
+    /// `None` if the torrent does not exist.
+    ///
+    /// # Context: Statistics
+    pub async fn get_torrent_clients_breakdown(&self, info_hash: &InfoHash) -> Option<ClientsBreakdown> {
+        self.torrents.get(info_hash)?;
+
+        Some(self.stats_repository.get_torrent_clients_breakdown(info_hash).await)
+    }
+
+    /// It returns the current [`statistics::RequestRates`].
+    ///
+    /// # Context: Statistics
+    #[must_use]
+    pub fn get_request_rates(&self) -> statistics::RequestRates {
+        self.stats_repository.get_request_rates()
+    }
+
+    /// It loads the usage statistics counters persisted by a previous run of
+    /// the tracker.
+    ///
+    /// # Context: Statistics
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to load the counters from the database.
+    pub async fn load_statistics_from_database(&self) -> Result<(), databases::error::Error> {
+        let counters = self.database.load_statistics()?;
+
+        self.stats_repository.restore_counters(&counters).await;
+
+        Ok(())
+    }
+
+    /// It persists the current value of the usage statistics counters and
+    /// adds how much they have increased since the last time to today's
+    /// totals.
+    ///
+    /// It's called periodically by the [`statistics_persistence`](crate::bootstrap::jobs::statistics_persistence)
+    /// job and when the tracker shuts down.
+    ///
+    /// # Context: Statistics
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to save the counters into the database.
+    pub async fn persist_statistics(&self) -> Result<(), databases::error::Error> {
+        let today = CurrentClock::now().as_secs() / statistics::SECONDS_PER_DAY;
+
+        self.stats_repository
+            .persist_counters(|counters, increments| {
+                self.database.save_statistics(counters)?;
+                self.database.increase_daily_statistics(today, increments)
+            })
+            .await
+    }
+
+    /// It returns the daily totals of the usage statistics counters for the
+    /// last `days` days, including today.
+    ///
+    /// # Context: Statistics
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to load the totals from the database.
+    pub fn get_statistics_history(&self, days: u64) -> Result<statistics::DailyCounters, databases::error::Error> {
+        let today = CurrentClock::now().as_secs() / statistics::SECONDS_PER_DAY;
+
+        self.database
+            .load_daily_statistics(today.saturating_sub(days.saturating_sub(1)))
+    }
+
+    /// It appends an entry to the audit log of administrative actions.
+    ///
+    /// # Context: Audit log
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to save the entry into the database.
+    pub fn add_audit_log_entry(&self, entry: &audit::Entry) -> Result<(), databases::error::Error> {
+        self.database.add_audit_log_entry(entry)
+    }
+
+    /// It returns a page of the audit log, from the newest entry, and the
+    /// number of entries in the whole log.
+    ///
+    /// # Context: Audit log
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to load the entries from the database.
+    pub fn get_audit_log(&self, pagination: &Pagination) -> Result<audit::Page, databases::error::Error> {
+        Ok(audit::Page {
+            entries: self.database.load_audit_log(pagination.offset, pagination.limit)?,
+            total: self.database.count_audit_log_entries()?,
+        })
+    }
+
+    /// It removes the audit log entries older than the `retention` period and
+    /// returns how many were removed.
+    ///
+    /// It's called periodically by the [`audit_log_cleanup`](crate::bootstrap::jobs::audit_log_cleanup)
+    /// job.
+    ///
+    /// # Context: Audit log
+    ///
+    /// # Errors
+    ///
+    /// Will return a `database::Error` if unable to remove the entries from the database.
+    pub fn remove_expired_audit_log_entries(&self, retention: Duration) -> Result<usize, databases::error::Error> {
+        let oldest = CurrentClock::now().saturating_sub(retention);
+
+        self.database.remove_audit_log_entries_before(oldest.as_secs())
+    }
+
+    /// It allows to send a statistic events which eventually will be used to update [`statistics::Metrics`].
+    ///
+    /// # Context: Statistics
+    pub async fn send_stats_event(&self, event: statistics::Event) -> Option<Result<(), SendError<statistics::Event>>> {
+        match event {
+            statistics::Event::TcpError { reason } => self.events.publish(events::Event::Error {
+                scheme: statistics::Scheme::Http,
+                reason,
+            }),
+            statistics::Event::UdpError { reason } => self.events.publish(events::Event::Error {
+                scheme: statistics::Scheme::Udp,
+                reason,
+            }),
+            _ => {}
+        }
+
+        match &self.stats_event_sender {
+            None => None,
+            Some(stats_event_sender) => stats_event_sender.send_event(event).await,
+        }
+    }
+
+    /// It publishes the live events for an `announce` request handled by the
+    /// tracker using the `scheme` protocol: the announce itself, and whether
+    /// the torrent was added or the peer completed the download.
+    ///
+    /// # Context: Events
+    pub fn publish_announce_events(
+        &self,
+        scheme: statistics::Scheme,
+        info_hash: &InfoHash,
+        peer: &peer::Peer,
+        announce_data: &AnnounceData,
+    ) {
+        if !self.events.has_subscribers() {
+            return;
+        }
+
+        if announce_data.swarm_change.torrent_added {
+            self.events.publish(events::Event::TorrentAdded {
+                scheme,
+                info_hash: *info_hash,
+            });
+        }
+
+        self.events.publish(events::Event::Announce {
+            scheme,
+            info_hash: *info_hash,
+            peer_id: peer.peer_id,
+            peer_addr: peer.peer_addr,
+            event: peer.event,
+        });
+
+        if announce_data.swarm_change.completed {
+            self.events.publish(events::Event::Completed {
+                scheme,
+                info_hash: *info_hash,
+                peer_id: peer.peer_id,
+            });
+        }
+    }
+
+    /// It subscribes to the live events matching the filter.
+    ///
+    /// # Context: Events
+    pub fn subscribe_to_events(&self, filter: events::Filter) -> events::Subscription {
+        self.events.subscribe(filter)
+    }
+
+    /// It drops the database tables.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if unable to drop tables.
     pub fn drop_database_tables(&self) -> Result<(), databases::error::Error> {
         // todo: this is only used for testing. WE have to pass the database
         // reference directly to the tests instead of via the tracker.
@@ -1828,6 +2729,8 @@ mod tests {
             }
 
             mod handling_the_torrent_whitelist {
+                use bittorrent_primitives::info_hash::InfoHash;
+
                 use crate::core::tests::the_tracker::{sample_info_hash, whitelisted_tracker};
 
                 #[tokio::test]
@@ -1854,7 +2757,43 @@ mod tests {
                     assert!(!tracker.is_info_hash_whitelisted(&info_hash).await);
                 }
 
+                #[tokio::test]
+                async fn it_should_add_torrents_to_the_whitelist_in_bulk_skipping_the_already_whitelisted_ones() {
+                    let tracker = whitelisted_tracker();
+
+                    let whitelisted = sample_info_hash();
+                    let new = InfoHash::from([1u8; 20]);
+
+                    tracker.add_torrent_to_whitelist(&whitelisted).await.unwrap();
+
+                    let added = tracker.add_torrents_to_whitelist(&[whitelisted, new]).await.unwrap();
+
+                    assert_eq!(added, vec![false, true]);
+                    assert!(tracker.is_info_hash_whitelisted(&new).await);
+                }
+
+                #[tokio::test]
+                async fn it_should_remove_torrents_from_the_whitelist_in_bulk() {
+                    let tracker = whitelisted_tracker();
+
+                    let whitelisted = sample_info_hash();
+                    let not_whitelisted = InfoHash::from([1u8; 20]);
+
+                    tracker.add_torrent_to_whitelist(&whitelisted).await.unwrap();
+
+                    let removed = tracker
+                        .remove_torrents_from_whitelist(&[whitelisted, not_whitelisted])
+                        .await
+                        .unwrap();
+
+                    assert_eq!(removed, vec![true, false]);
+                    assert!(!tracker.is_info_hash_whitelisted(&whitelisted).await);
+                    assert!(tracker.get_whitelist_diff().await.unwrap().only_in_database.is_empty());
+                }
+
                 mod persistence {
+                    use bittorrent_primitives::info_hash::InfoHash;
+
                     use crate::core::tests::the_tracker::{sample_info_hash, whitelisted_tracker};
 
                     #[tokio::test]
@@ -1873,6 +2812,39 @@ mod tests {
 
                         assert!(tracker.is_info_hash_whitelisted(&info_hash).await);
                     }
+
+                    #[tokio::test]
+                    async fn it_should_tell_where_a_torrent_is_whitelisted() {
+                        let tracker = whitelisted_tracker();
+
+                        let info_hash = sample_info_hash();
+
+                        tracker.add_torrent_to_whitelist(&info_hash).await.unwrap();
+                        tracker.remove_torrent_from_memory_whitelist(&info_hash).await;
+
+                        let entry = tracker.get_whitelist_entry(&info_hash).await.unwrap();
+
+                        assert!(!entry.in_memory);
+                        assert!(entry.in_database);
+                    }
+
+                    #[tokio::test]
+                    async fn it_should_report_the_torrents_whitelisted_only_in_memory_or_only_in_the_database() {
+                        let tracker = whitelisted_tracker();
+
+                        let only_in_database = sample_info_hash();
+                        let only_in_memory = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".parse::<InfoHash>().unwrap();
+
+                        tracker.add_torrent_to_whitelist(&only_in_database).await.unwrap();
+                        tracker.remove_torrent_from_memory_whitelist(&only_in_database).await;
+                        tracker.add_torrent_to_memory_whitelist(&only_in_memory).await;
+
+                        let diff = tracker.get_whitelist_diff().await.unwrap();
+
+                        assert_eq!(diff.only_in_memory, vec![only_in_memory]);
+                        assert_eq!(diff.only_in_database, vec![only_in_database]);
+                        assert_eq!(tracker.get_whitelist(None).await.unwrap().len(), 2);
+                    }
                 }
             }
 
@@ -1931,6 +2903,7 @@ mod tests {
                 use std::time::Duration;
 
                 use crate::core::auth::{self};
+                use crate::core::error::PeerKeyError;
                 use crate::core::tests::the_tracker::private_tracker;
 
                 #[tokio::test]
@@ -1965,6 +2938,31 @@ mod tests {
                     assert!(tracker.verify_auth_key(&expiring_key.key()).await.is_err());
                 }
 
+                #[tokio::test]
+                async fn it_should_generate_authentication_keys_in_bulk_with_the_same_expiration_time() {
+                    let tracker = private_tracker();
+
+                    let peer_keys = tracker.generate_auth_keys(3, Some(100)).await.unwrap();
+
+                    assert_eq!(peer_keys.len(), 3);
+                    assert!(peer_keys
+                        .iter()
+                        .all(|peer_key| peer_key.valid_until == peer_keys[0].valid_until));
+
+                    for peer_key in peer_keys {
+                        assert!(tracker.verify_auth_key(&peer_key.key()).await.is_ok());
+                    }
+                }
+
+                #[tokio::test]
+                async fn it_should_fail_generating_authentication_keys_in_bulk_when_the_duration_overflows() {
+                    let tracker = private_tracker();
+
+                    let result = tracker.generate_auth_keys(1, Some(u64::MAX)).await;
+
+                    assert!(matches!(result, Err(PeerKeyError::DurationOverflow { .. })));
+                }
+
                 #[tokio::test]
                 async fn it_should_load_authentication_keys_from_the_database() {
                     let tracker = private_tracker();
@@ -1980,6 +2978,43 @@ mod tests {
                     assert!(tracker.verify_auth_key(&expiring_key.key()).await.is_ok());
                 }
 
+                #[tokio::test]
+                async fn it_should_remove_the_keys_that_expired_before_the_grace_period() {
+                    use torrust_tracker_clock::clock;
+                    use torrust_tracker_clock::clock::stopped::Stopped as _;
+                    use torrust_tracker_primitives::DurationSinceUnixEpoch;
+                    use torrust_tracker_test_helpers::configuration;
+
+                    use crate::core::services::tracker_factory;
+
+                    let mut configuration = configuration::ephemeral_private();
+                    configuration.core.expired_keys_grace_period = 100;
+                    let tracker = tracker_factory(&configuration);
+
+                    clock::Stopped::local_set(&DurationSinceUnixEpoch::from_secs(1000));
+
+                    let long_expired_key = auth::generate_permanent_key().key();
+                    let recently_expired_key = auth::generate_permanent_key().key();
+                    let permanent_key = auth::generate_permanent_key().key();
+
+                    tracker
+                        .add_auth_key(long_expired_key.clone(), Some(DurationSinceUnixEpoch::from_secs(800)))
+                        .await
+                        .unwrap();
+                    tracker
+                        .add_auth_key(recently_expired_key.clone(), Some(DurationSinceUnixEpoch::from_secs(950)))
+                        .await
+                        .unwrap();
+                    tracker.add_auth_key(permanent_key.clone(), None).await.unwrap();
+
+                    let removed = tracker.remove_expired_auth_keys().await.unwrap();
+
+                    assert_eq!(removed, 1);
+                    assert!(tracker.get_auth_key(&long_expired_key).await.is_none());
+                    assert!(tracker.get_auth_key(&recently_expired_key).await.is_some());
+                    assert!(tracker.get_auth_key(&permanent_key).await.is_some());
+                }
+
                 mod with_expiring_and {
 
                     mod randomly_generated_keys {
@@ -2217,5 +3252,412 @@ mod tests {
                 assert!(torrent_entry.peers_is_empty());
             }
         }
+
+        mod handling_torrent_removal {
+
+            use aquatic_udp_protocol::AnnounceEvent;
+            use bittorrent_primitives::info_hash::InfoHash;
+            use torrust_tracker_torrent_repository::repository::Repository;
+
+            use crate::core::tests::the_tracker::{
+                peer_ip, sample_info_hash, sample_peer, sample_peer_1, sample_peer_2, tracker_persisting_torrents_in_database,
+            };
+
+            #[tokio::test]
+            async fn it_should_remove_a_torrent_swarm_keeping_the_persisted_completed_downloads() {
+                let tracker = tracker_persisting_torrents_in_database();
+
+                let info_hash = sample_info_hash();
+
+                let mut peer = sample_peer();
+                peer.event = AnnounceEvent::Started;
+                tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+                peer.event = AnnounceEvent::Completed;
+                tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+
+                assert!(tracker.remove_torrent(&info_hash, false).unwrap());
+                assert!(!tracker.remove_torrent(&info_hash, false).unwrap());
+                assert!(tracker.torrents.get(&info_hash).is_none());
+
+                tracker.load_torrents_from_database().unwrap();
+
+                assert_eq!(tracker.torrents.get_swarm_metadata(&info_hash).unwrap().downloaded, 1);
+            }
+
+            #[tokio::test]
+            async fn it_should_keep_the_persisted_completed_downloads_when_a_removed_torrent_is_announced_again() {
+                let tracker = tracker_persisting_torrents_in_database();
+
+                let info_hash = sample_info_hash();
+
+                let mut peer = sample_peer();
+                peer.event = AnnounceEvent::Started;
+                tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+                peer.event = AnnounceEvent::Completed;
+                tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+
+                assert!(tracker.remove_torrent(&info_hash, false).unwrap());
+
+                peer.event = AnnounceEvent::Started;
+                let swarm_metadata = tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+
+                assert_eq!(swarm_metadata.downloaded, 1);
+                assert_eq!(tracker.database.load_persistent_torrents().unwrap().get(&info_hash), Some(&1));
+            }
+
+            #[tokio::test]
+            async fn it_should_remove_a_torrent_and_its_persisted_completed_downloads() {
+                let tracker = tracker_persisting_torrents_in_database();
+
+                let info_hash = sample_info_hash();
+
+                let mut peer = sample_peer();
+                peer.event = AnnounceEvent::Started;
+                tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+                peer.event = AnnounceEvent::Completed;
+                tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+
+                assert!(tracker.remove_torrent(&info_hash, true).unwrap());
+
+                tracker.load_torrents_from_database().unwrap();
+
+                assert!(tracker.torrents.get(&info_hash).is_none());
+            }
+
+            #[tokio::test]
+            async fn it_should_remove_a_peer_from_a_swarm() {
+                let tracker = tracker_persisting_torrents_in_database();
+
+                let info_hash = sample_info_hash();
+
+                tracker.upsert_peer_and_get_stats(&info_hash, &sample_peer_1());
+                tracker.upsert_peer_and_get_stats(&info_hash, &sample_peer_2());
+
+                assert!(tracker.remove_peer(&info_hash, &sample_peer_1().peer_id));
+                assert!(!tracker.remove_peer(&info_hash, &sample_peer_1().peer_id));
+
+                assert_eq!(tracker.get_torrent_peers(&info_hash), vec![sample_peer_2().into()]);
+            }
+
+            #[tokio::test]
+            async fn it_should_remove_the_peers_with_an_ip_from_all_the_swarms() {
+                let tracker = tracker_persisting_torrents_in_database();
+
+                // `sample_peer` and `sample_peer_1` use the `peer_ip`
+                tracker.upsert_peer_and_get_stats(&sample_info_hash(), &sample_peer());
+                tracker.upsert_peer_and_get_stats(&sample_info_hash(), &sample_peer_2());
+                tracker.upsert_peer_and_get_stats(&InfoHash::from([1; 20]), &sample_peer_1());
+
+                assert_eq!(tracker.remove_peers_with_ip(&peer_ip()), 2);
+
+                assert_eq!(tracker.get_torrent_peers(&sample_info_hash()), vec![sample_peer_2().into()]);
+                assert!(tracker.get_torrent_peers(&InfoHash::from([1; 20])).is_empty());
+            }
+        }
+
+        mod handling_torrent_rankings {
+
+            use aquatic_udp_protocol::AnnounceEvent;
+            use bittorrent_primitives::info_hash::InfoHash;
+
+            use crate::core::tests::the_tracker::{public_tracker, sample_info_hash, sample_peer, sample_peer_1};
+            use crate::core::torrent::activity::Ranking;
+
+            #[tokio::test]
+            async fn it_should_rank_the_announced_torrents() {
+                let tracker = public_tracker();
+
+                let popular = sample_info_hash();
+                let other = InfoHash::from([1; 20]);
+
+                tracker.upsert_peer_and_get_stats(&popular, &sample_peer());
+                tracker.upsert_peer_and_get_stats(&popular, &sample_peer_1());
+                tracker.upsert_peer_and_get_stats(&other, &sample_peer());
+
+                let top = tracker.get_top_torrents(Ranking::Seeders, 10);
+
+                assert_eq!(top.len(), 2);
+                assert_eq!(top[0].info_hash, popular);
+                assert_eq!(top[0].seeders, 2);
+                assert_eq!(top[1].info_hash, other);
+            }
+
+            #[tokio::test]
+            async fn it_should_count_the_completed_downloads_in_the_trending_score() {
+                let tracker = public_tracker();
+
+                let info_hash = sample_info_hash();
+                let mut peer = sample_peer();
+
+                peer.event = AnnounceEvent::Started;
+                tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+
+                peer.event = AnnounceEvent::Completed;
+                tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+
+                let top = tracker.get_top_torrents(Ranking::Trending, 1);
+
+                assert_eq!(top[0].completed, 1);
+                assert!((top[0].trending_score - 1.0).abs() < f64::EPSILON);
+            }
+        }
+
+        mod handling_live_events {
+
+            use crate::core::events::{Event, Filter, Kind, Received};
+            use crate::core::statistics::{self, ErrorReason, Scheme};
+            use crate::core::tests::the_tracker::{completed_peer, peer_ip, public_tracker, sample_info_hash, started_peer};
+            use crate::core::PeersWanted;
+
+            #[tokio::test]
+            async fn it_should_report_how_an_announce_changed_the_swarm() {
+                let tracker = public_tracker();
+
+                let mut started_peer = started_peer();
+                let announce_data = tracker.announce(&sample_info_hash(), &mut started_peer, &peer_ip(), &PeersWanted::All);
+
+                assert!(announce_data.swarm_change.torrent_added);
+                assert!(!announce_data.swarm_change.completed);
+
+                let mut completed_peer = completed_peer();
+                let announce_data = tracker.announce(&sample_info_hash(), &mut completed_peer, &peer_ip(), &PeersWanted::All);
+
+                assert!(!announce_data.swarm_change.torrent_added);
+                assert!(announce_data.swarm_change.completed);
+            }
+
+            #[tokio::test]
+            async fn it_should_publish_the_events_for_an_announce_request() {
+                let tracker = public_tracker();
+
+                let mut subscription = tracker.subscribe_to_events(Filter {
+                    kinds: vec![Kind::TorrentAdded, Kind::Completed],
+                    ..Default::default()
+                });
+
+                for mut peer in [started_peer(), completed_peer()] {
+                    let announce_data = tracker.announce(&sample_info_hash(), &mut peer, &peer_ip(), &PeersWanted::All);
+                    tracker.publish_announce_events(Scheme::Udp, &sample_info_hash(), &peer, &announce_data);
+                }
+
+                let Some(Received::Event(torrent_added)) = subscription.next().await else {
+                    panic!("expected an event");
+                };
+                let Some(Received::Event(completed)) = subscription.next().await else {
+                    panic!("expected an event");
+                };
+
+                assert_eq!(
+                    torrent_added.event,
+                    Event::TorrentAdded {
+                        scheme: Scheme::Udp,
+                        info_hash: sample_info_hash()
+                    }
+                );
+                assert_eq!(
+                    completed.event,
+                    Event::Completed {
+                        scheme: Scheme::Udp,
+                        info_hash: sample_info_hash(),
+                        peer_id: completed_peer().peer_id
+                    }
+                );
+            }
+
+            #[tokio::test]
+            async fn it_should_publish_the_error_responses() {
+                let tracker = public_tracker();
+
+                let mut subscription = tracker.subscribe_to_events(Filter::default());
+
+                tracker
+                    .send_stats_event(statistics::Event::TcpError {
+                        reason: ErrorReason::TorrentNotWhitelisted,
+                    })
+                    .await;
+
+                let Some(Received::Event(error)) = subscription.next().await else {
+                    panic!("expected an event");
+                };
+
+                assert_eq!(
+                    error.event,
+                    Event::Error {
+                        scheme: Scheme::Http,
+                        reason: ErrorReason::TorrentNotWhitelisted
+                    }
+                );
+            }
+        }
+
+        mod handling_statistics_persistence {
+
+            use torrust_tracker_test_helpers::configuration;
+
+            use crate::core::services::tracker_factory;
+
+            #[tokio::test]
+            async fn it_should_restore_the_persisted_usage_statistics() {
+                let configuration = configuration::ephemeral();
+
+                let tracker = tracker_factory(&configuration);
+
+                tracker.stats_repository.increase_udp4_announces();
+
+                tracker.persist_statistics().await.unwrap();
+
+                // A new tracker using the same database
+                let tracker = tracker_factory(&configuration);
+
+                tracker.load_statistics_from_database().await.unwrap();
+
+                assert_eq!(tracker.get_stats().udp4_announces_handled, 1);
+            }
+
+            #[tokio::test]
+            async fn it_should_add_the_increments_to_the_daily_totals_when_persisting_the_usage_statistics() {
+                let tracker = tracker_factory(&configuration::ephemeral());
+
+                tracker.stats_repository.increase_udp4_announces();
+                tracker.persist_statistics().await.unwrap();
+
+                tracker.stats_repository.increase_udp4_announces();
+                tracker.persist_statistics().await.unwrap();
+
+                let history = tracker.get_statistics_history(1).unwrap();
+
+                assert_eq!(history.len(), 1);
+                assert_eq!(history.values().next().unwrap()["udp4_announces_handled"], 2);
+            }
+        }
+
+        mod handling_overload {
+
+            use torrust_tracker_configuration::AnnouncePolicy;
+            use torrust_tracker_test_helpers::configuration;
+
+            use crate::core::services::tracker_factory;
+            use crate::core::statistics::RequestType;
+            use crate::core::tests::the_tracker::{peer_ip, sample_info_hash, sample_peer};
+            use crate::core::{Overload, PeersWanted, Tracker};
+
+            fn tracker_overloaded_above(announces_per_second: u64) -> Tracker {
+                let mut configuration = configuration::ephemeral_public();
+                configuration.core.announce_policy = AnnouncePolicy::new(120, 60);
+                configuration.core.overload_announce_rate = announces_per_second;
+                tracker_factory(&configuration)
+            }
+
+            /// It handles the announces, UDP and HTTP, of the last minute.
+            fn handle_announces(tracker: &Tracker, udp: u64, http: u64) {
+                for _ in 0..udp {
+                    tracker.stats_repository.increase_request_windows(RequestType::UdpAnnounce);
+                }
+                for _ in 0..http {
+                    tracker.stats_repository.increase_request_windows(RequestType::HttpAnnounce);
+                }
+            }
+
+            #[test]
+            fn it_should_double_the_announce_intervals_when_the_announce_rate_exceeds_the_threshold() {
+                let tracker = tracker_overloaded_above(1);
+
+                handle_announces(&tracker, 40, 21);
+
+                let announce_data = tracker.announce(&sample_info_hash(), &mut sample_peer(), &peer_ip(), &PeersWanted::All);
+
+                assert!(tracker.is_overloaded());
+                assert_eq!(announce_data.policy, AnnouncePolicy::new(240, 120));
+            }
+
+            #[test]
+            fn it_should_not_change_the_announce_intervals_when_the_announce_rate_does_not_exceed_the_threshold() {
+                let tracker = tracker_overloaded_above(1);
+
+                handle_announces(&tracker, 40, 20);
+
+                let announce_data = tracker.announce(&sample_info_hash(), &mut sample_peer(), &peer_ip(), &PeersWanted::All);
+
+                assert!(!tracker.is_overloaded());
+                assert_eq!(announce_data.policy, AnnouncePolicy::new(120, 60));
+            }
+
+            #[test]
+            fn it_should_check_the_announce_rate_at_most_once_per_second() {
+                let overload = Overload::default();
+
+                assert!(overload.get_or_check(1000, || true));
+                assert!(overload.get_or_check(1000, || false));
+
+                assert!(!overload.get_or_check(1001, || false));
+            }
+
+            #[test]
+            fn it_should_never_be_overloaded_when_the_threshold_is_zero() {
+                let tracker = tracker_overloaded_above(0);
+
+                handle_announces(&tracker, 1000, 1000);
+
+                let announce_data = tracker.announce(&sample_info_hash(), &mut sample_peer(), &peer_ip(), &PeersWanted::All);
+
+                assert!(!tracker.is_overloaded());
+                assert_eq!(announce_data.policy, AnnouncePolicy::new(120, 60));
+            }
+        }
+
+        mod handling_the_audit_log {
+
+            use std::time::Duration;
+
+            use torrust_tracker_clock::clock;
+            use torrust_tracker_clock::clock::stopped::Stopped as _;
+            use torrust_tracker_primitives::pagination::Pagination;
+            use torrust_tracker_primitives::DurationSinceUnixEpoch;
+            use torrust_tracker_test_helpers::configuration;
+
+            use crate::core::audit;
+            use crate::core::services::tracker_factory;
+
+            fn entry_at(secs: u64) -> audit::Entry {
+                audit::Entry {
+                    timestamp: DurationSinceUnixEpoch::from_secs(secs),
+                    label: "admin".to_string(),
+                    action: "POST /api/v1/whitelist/:info_hash".to_string(),
+                    target: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+                    result: 200,
+                }
+            }
+
+            #[test]
+            fn it_should_return_the_audit_log_from_the_newest_entry() {
+                let tracker = tracker_factory(&configuration::ephemeral());
+
+                tracker.add_audit_log_entry(&entry_at(1000)).unwrap();
+                tracker.add_audit_log_entry(&entry_at(2000)).unwrap();
+
+                let page = tracker.get_audit_log(&Pagination::new(0, 1)).unwrap();
+
+                assert_eq!(page.entries, vec![entry_at(2000)]);
+                assert_eq!(page.total, 2);
+            }
+
+            #[test]
+            fn it_should_remove_the_audit_log_entries_older_than_the_retention_period() {
+                let tracker = tracker_factory(&configuration::ephemeral());
+
+                tracker.add_audit_log_entry(&entry_at(1000)).unwrap();
+                tracker.add_audit_log_entry(&entry_at(2000)).unwrap();
+
+                clock::Stopped::local_set(&DurationSinceUnixEpoch::from_secs(2500));
+
+                let removed = tracker.remove_expired_audit_log_entries(Duration::from_secs(1000)).unwrap();
+
+                assert_eq!(removed, 1);
+                assert_eq!(
+                    tracker.get_audit_log(&Pagination::default()).unwrap().entries,
+                    vec![entry_at(2000)]
+                );
+            }
+        }
     }
 }
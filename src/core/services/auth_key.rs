@@ -0,0 +1,143 @@
+//! Core tracker domain services for the authentication keys.
+//!
+//! There are two services:
+//!
+//! - [`get_auth_key_info`]: it returns all the data about one key.
+//! - [`get_auth_keys`]: it returns the keys matching a filter.
+use std::sync::Arc;
+
+use torrust_tracker_primitives::pagination::Pagination;
+use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+use crate::core::auth::{Key, KeyFilter, PeerKey};
+use crate::core::Tracker;
+
+/// It contains all the information the tracker has about an authentication
+/// key.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Info {
+    /// The key and its expiration time.
+    pub peer_key: PeerKey,
+    /// The last time the key was used to authenticate a request since the
+    /// tracker started.
+    pub last_used: Option<DurationSinceUnixEpoch>,
+    /// The number of peers in the swarms that announced with the key.
+    pub active_peers: u64,
+}
+
+/// It returns all the information the tracker has about one key in an [`Info`] struct.
+pub async fn get_auth_key_info(tracker: Arc<Tracker>, key: &Key) -> Option<Info> {
+    let peer_key = tracker.get_auth_key(key).await?;
+
+    let usage = tracker.get_auth_key_usage(key).unwrap_or_default();
+
+    let active_peers = usage
+        .peers
+        .iter()
+        .filter(|(info_hash, peer_id)| tracker.is_peer_active(info_hash, peer_id))
+        .count() as u64;
+
+    Some(Info {
+        peer_key,
+        last_used: usage.last_used,
+        active_peers,
+    })
+}
+
+/// It returns the keys matching the filter, sorted by key.
+pub async fn get_auth_keys(tracker: Arc<Tracker>, filter: &KeyFilter, pagination: Option<&Pagination>) -> Vec<PeerKey> {
+    tracker.get_auth_keys(filter, pagination).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use aquatic_udp_protocol::{AnnounceEvent, NumberOfBytes, PeerId};
+    use bittorrent_primitives::info_hash::InfoHash;
+    use torrust_tracker_configuration::Configuration;
+    use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
+    use torrust_tracker_test_helpers::configuration;
+
+    use crate::core::auth::KeyFilter;
+    use crate::core::services::auth_key::{get_auth_key_info, get_auth_keys};
+    use crate::core::services::tracker_factory;
+    use crate::core::Tracker;
+
+    fn sample_peer() -> peer::Peer {
+        peer::Peer {
+            peer_id: PeerId(*b"-qB00000000000000000"),
+            peer_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080),
+            updated: DurationSinceUnixEpoch::new(1_669_397_478_934, 0),
+            uploaded: NumberOfBytes::new(0),
+            downloaded: NumberOfBytes::new(0),
+            left: NumberOfBytes::new(0),
+            event: AnnounceEvent::Started,
+        }
+    }
+
+    fn private_tracker() -> Arc<Tracker> {
+        let config: Configuration = configuration::ephemeral_private();
+        Arc::new(tracker_factory(&config))
+    }
+
+    #[tokio::test]
+    async fn it_should_return_none_for_an_unknown_key() {
+        let tracker = private_tracker();
+
+        let key = "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ".parse().unwrap();
+
+        assert!(get_auth_key_info(tracker, &key).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn it_should_return_the_key_info_with_the_last_time_it_was_used() {
+        let tracker = private_tracker();
+
+        let peer_key = tracker.generate_auth_key(Some(Duration::from_secs(100))).await.unwrap();
+
+        tracker.authenticate(&peer_key.key).await.unwrap();
+
+        let info = get_auth_key_info(tracker, &peer_key.key).await.unwrap();
+
+        assert_eq!(info.peer_key, peer_key);
+        assert!(info.last_used.is_some());
+        assert_eq!(info.active_peers, 0);
+    }
+
+    #[tokio::test]
+    async fn it_should_count_the_active_peers_that_announced_with_the_key() {
+        let tracker = private_tracker();
+
+        let peer_key = tracker.generate_permanent_auth_key().await.unwrap();
+
+        let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+        let peer = sample_peer();
+
+        tracker.upsert_peer_and_get_stats(&info_hash, &peer);
+        tracker.record_key_announce(&peer_key.key, &info_hash, &peer.peer_id);
+
+        // A peer that is not in the swarm anymore
+        tracker.record_key_announce(&peer_key.key, &info_hash, &PeerId(*b"-qB00000000000000001"));
+
+        let info = get_auth_key_info(tracker, &peer_key.key).await.unwrap();
+
+        assert_eq!(info.active_peers, 1);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_the_keys_sorted() {
+        let tracker = private_tracker();
+
+        let first = tracker.generate_permanent_auth_key().await.unwrap();
+        let second = tracker.generate_permanent_auth_key().await.unwrap();
+
+        let mut expected = vec![first, second];
+        expected.sort_by(|a, b| a.key.value().cmp(b.key.value()));
+
+        assert_eq!(get_auth_keys(tracker, &KeyFilter::default(), None).await, expected);
+    }
+}
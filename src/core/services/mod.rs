@@ -3,9 +3,13 @@
 //! There are two types of service:
 //!
 //! - [Core tracker services](crate::core::services::torrent): related to the tracker main functionalities like getting info about torrents.
+//! - [Authentication key services](crate::core::services::auth_key): related to the keys used by private trackers.
 //! - [Services for statistics](crate::core::services::statistics): related to tracker metrics. Aggregate data about the tracker server.
+//! - [Whitelist services](crate::core::services::whitelist): related to the torrents allowed by listed trackers.
+pub mod auth_key;
 pub mod statistics;
 pub mod torrent;
+pub mod whitelist;
 
 use std::sync::Arc;
 
@@ -5,8 +5,6 @@
 //! - A [`factory`](crate::core::services::statistics::setup::factory) function to build the structs needed to collect the tracker metrics.
 //! - A [`get_metrics`] service to get the [`tracker metrics`](crate::core::statistics::Metrics).
 //!
-//! Tracker metrics are collected using a Publisher-Subscribe pattern.
-//!
 //! The factory function builds two structs:
 //!
 //! - An statistics [`EventSender`](crate::core::statistics::EventSender)
@@ -18,16 +16,22 @@
 //!
 //! The statistics repository is responsible for storing the metrics in memory.
 //! The statistics event sender allows sending events related to metrics.
-//! There is an event listener that is receiving all the events and processing them with an event handler.
-//! Then, the event handler updates the metrics depending on the received event.
+//! It processes each event with an event handler, which updates the metrics
+//! in the repository depending on the received event.
 //!
-//! For example, if you send the event [`Event::Udp4Connect`](crate::core::statistics::Event::Udp4Connect):
+//! For example, if you send a [`Event::Request`](crate::core::statistics::Event::Request) event for a UDP `connect` request:
 //!
 //! ```text
-//! let result = event_sender.send_event(Event::Udp4Connect).await;
+//! let result = event_sender.send_event(Event::Request {
+//!     scheme: Scheme::Udp,
+//!     ip_version: IpVersion::V4,
+//!     kind: RequestKind::Connect,
+//!     listener: server_socket_addr,
+//! }).await;
 //! ```
 //!
-//! Eventually the counter for UDP connections from IPv4 peers will be increased.
+//! The counter for UDP connections from IPv4 peers will be increased,
+//! both the global one and the one for the listener bound to `server_socket_addr`.
 //!
 //! ```rust,no_run
 //! pub struct Metrics {
@@ -42,7 +46,7 @@ use std::sync::Arc;
 
 use torrust_tracker_primitives::torrent_metrics::TorrentsMetrics;
 
-use crate::core::statistics::Metrics;
+use crate::core::statistics::{Metrics, RequestRates};
 use crate::core::Tracker;
 
 /// All the metrics collected by the tracker.
@@ -57,29 +61,19 @@ pub struct TrackerMetrics {
     ///
     /// Metrics about how the tracker is been used (number of udp announce requests, number of http scrape requests, etcetera)
     pub protocol_metrics: Metrics,
+
+    /// Current load. Average number of requests per second in the last
+    /// minute, five minutes and hour.
+    pub request_rates: RequestRates,
 }
 
 /// It returns all the [`TrackerMetrics`]
+#[allow(clippy::unused_async)]
 pub async fn get_metrics(tracker: Arc<Tracker>) -> TrackerMetrics {
-    let torrents_metrics = tracker.get_torrents_metrics();
-    let stats = tracker.get_stats().await;
-
     TrackerMetrics {
-        torrents_metrics,
-        protocol_metrics: Metrics {
-            tcp4_connections_handled: stats.tcp4_connections_handled,
-            tcp4_announces_handled: stats.tcp4_announces_handled,
-            tcp4_scrapes_handled: stats.tcp4_scrapes_handled,
-            tcp6_connections_handled: stats.tcp6_connections_handled,
-            tcp6_announces_handled: stats.tcp6_announces_handled,
-            tcp6_scrapes_handled: stats.tcp6_scrapes_handled,
-            udp4_connections_handled: stats.udp4_connections_handled,
-            udp4_announces_handled: stats.udp4_announces_handled,
-            udp4_scrapes_handled: stats.udp4_scrapes_handled,
-            udp6_connections_handled: stats.udp6_connections_handled,
-            udp6_announces_handled: stats.udp6_announces_handled,
-            udp6_scrapes_handled: stats.udp6_scrapes_handled,
-        },
+        torrents_metrics: tracker.get_torrents_metrics(),
+        protocol_metrics: tracker.get_stats(),
+        request_rates: tracker.get_request_rates(),
     }
 }
 
@@ -110,6 +104,7 @@ mod tests {
             TrackerMetrics {
                 torrents_metrics: TorrentsMetrics::default(),
                 protocol_metrics: core::statistics::Metrics::default(),
+                request_rates: core::statistics::RequestRates::default(),
             }
         );
     }
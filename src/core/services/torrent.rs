@@ -4,9 +4,20 @@
 //!
 //! - [`get_torrent_info`]: it returns all the data about one torrent.
 //! - [`get_torrents`]: it returns data about some torrent in bulk excluding the peer list.
+//!
+//! And [`get_filtered_torrents`] which returns a sorted page of the torrents
+//! matching a [`TorrentFilter`], with the total number of matching torrents.
+//!
+//! To go through all the torrents without loading them at once,
+//! [`get_torrents_after`] returns them in batches, in info-hash order.
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Bound;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use bittorrent_primitives::info_hash::InfoHash;
+use thiserror::Error;
 use torrust_tracker_primitives::pagination::Pagination;
 use torrust_tracker_primitives::peer;
 use torrust_tracker_torrent_repository::entry::EntrySync;
@@ -66,6 +77,7 @@ pub async fn get_torrent_info(tracker: Arc<Tracker>, info_hash: &InfoHash) -> Op
 }
 
 /// It returns all the information the tracker has about multiple torrents in a [`BasicInfo`] struct, excluding the peer list.
+#[allow(clippy::unused_async)]
 pub async fn get_torrents_page(tracker: Arc<Tracker>, pagination: Option<&Pagination>) -> Vec<BasicInfo> {
     let mut basic_infos: Vec<BasicInfo> = vec![];
 
@@ -84,6 +96,7 @@ pub async fn get_torrents_page(tracker: Arc<Tracker>, pagination: Option<&Pagina
 }
 
 /// It returns all the information the tracker has about multiple torrents in a [`BasicInfo`] struct, excluding the peer list.
+#[allow(clippy::unused_async)]
 pub async fn get_torrents(tracker: Arc<Tracker>, info_hashes: &[InfoHash]) -> Vec<BasicInfo> {
     let mut basic_infos: Vec<BasicInfo> = vec![];
 
@@ -101,6 +114,242 @@ pub async fn get_torrents(tracker: Arc<Tracker>, info_hashes: &[InfoHash]) -> Ve
     basic_infos
 }
 
+/// It returns up to `limit` torrents whose info-hash is greater than `after`,
+/// sorted by info-hash. The peer list is only included if `with_peers` is
+/// `true`.
+///
+/// Each batch starts after the last info-hash of the previous one, so going
+/// through all the torrents only keeps one batch in memory and each batch is
+/// found without reading the torrents before it.
+#[allow(clippy::unused_async)]
+pub async fn get_torrents_after(tracker: Arc<Tracker>, after: Option<&InfoHash>, limit: usize, with_peers: bool) -> Vec<Info> {
+    let lower_bound = after.map_or(Bound::Unbounded, Bound::Excluded);
+
+    tracker
+        .torrents
+        .torrents
+        .range((lower_bound, Bound::Unbounded))
+        .take(limit)
+        .map(|entry| {
+            let stats = entry.value().get_swarm_metadata();
+
+            let peers = with_peers.then(|| entry.value().get_peers(None).iter().map(|peer| **peer).collect());
+
+            Info {
+                info_hash: *entry.key(),
+                seeders: u64::from(stats.complete),
+                completed: u64::from(stats.downloaded),
+                leechers: u64::from(stats.incomplete),
+                peers,
+            }
+        })
+        .collect()
+}
+
+/// Criteria to select torrents. Unset criteria match all the torrents.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TorrentFilter {
+    /// Only the torrents with at least this number of seeders.
+    pub min_seeders: Option<u64>,
+    /// Only the torrents with at most this number of seeders.
+    pub max_seeders: Option<u64>,
+    /// Only the torrents with at least this number of leechers.
+    pub min_leechers: Option<u64>,
+    /// Only the torrents with at most this number of leechers.
+    pub max_leechers: Option<u64>,
+    /// Only the torrents with at least this number of completed downloads.
+    pub min_completed: Option<u64>,
+    /// Only the torrents with at most this number of completed downloads.
+    pub max_completed: Option<u64>,
+    /// Only the torrents with (`true`) or without (`false`) peers.
+    pub has_peers: Option<bool>,
+    /// Only the torrents whose info-hash starts with this lowercase hex
+    /// string.
+    pub info_hash_prefix: Option<String>,
+}
+
+impl TorrentFilter {
+    /// Whether the filter does not have any criteria.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether the torrent meets all the criteria.
+    #[must_use]
+    pub fn matches(&self, torrent: &BasicInfo) -> bool {
+        let in_range = |value: u64, min: Option<u64>, max: Option<u64>| {
+            min.map_or(true, |min| value >= min) && max.map_or(true, |max| value <= max)
+        };
+
+        in_range(torrent.seeders, self.min_seeders, self.max_seeders)
+            && in_range(torrent.leechers, self.min_leechers, self.max_leechers)
+            && in_range(torrent.completed, self.min_completed, self.max_completed)
+            && self
+                .has_peers
+                .map_or(true, |has_peers| (torrent.seeders + torrent.leechers > 0) == has_peers)
+            && self
+                .info_hash_prefix
+                .as_ref()
+                .map_or(true, |prefix| torrent.info_hash.to_hex_string().starts_with(prefix.as_str()))
+    }
+}
+
+/// The attribute used to sort the torrents.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum SortField {
+    #[default]
+    InfoHash,
+    Seeders,
+    Leechers,
+    Completed,
+}
+
+impl fmt::Display for SortField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortField::InfoHash => write!(f, "info_hash"),
+            SortField::Seeders => write!(f, "seeders"),
+            SortField::Leechers => write!(f, "leechers"),
+            SortField::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("unknown sort field {0}, expected one of: info_hash, seeders, leechers, completed")]
+pub struct ParseSortFieldError(String);
+
+impl FromStr for SortField {
+    type Err = ParseSortFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info_hash" => Ok(SortField::InfoHash),
+            "seeders" => Ok(SortField::Seeders),
+            "leechers" => Ok(SortField::Leechers),
+            "completed" => Ok(SortField::Completed),
+            _ => Err(ParseSortFieldError(s.to_string())),
+        }
+    }
+}
+
+/// The direction of the sort.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("unknown sort order {0}, expected one of: asc, desc")]
+pub struct ParseSortOrderError(String);
+
+impl FromStr for SortOrder {
+    type Err = ParseSortOrderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(ParseSortOrderError(s.to_string())),
+        }
+    }
+}
+
+/// How to sort the torrents. Torrents with the same value are sorted by
+/// info-hash, so pages are stable.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TorrentSort {
+    pub field: SortField,
+    pub order: SortOrder,
+}
+
+impl TorrentSort {
+    fn compare(self, a: &BasicInfo, b: &BasicInfo) -> Ordering {
+        let ordering = match self.field {
+            SortField::InfoHash => Ordering::Equal,
+            SortField::Seeders => a.seeders.cmp(&b.seeders),
+            SortField::Leechers => a.leechers.cmp(&b.leechers),
+            SortField::Completed => a.completed.cmp(&b.completed),
+        }
+        .then_with(|| a.info_hash.cmp(&b.info_hash));
+
+        match self.order {
+            SortOrder::Asc => ordering,
+            SortOrder::Desc => ordering.reverse(),
+        }
+    }
+}
+
+/// A page of torrents and the total number of torrents matching the filter.
+#[derive(Debug, PartialEq)]
+pub struct TorrentsPage {
+    pub torrents: Vec<BasicInfo>,
+    pub total: u64,
+}
+
+/// It returns a page of the torrents that match the filter, excluding the
+/// peer list.
+///
+/// Without criteria and with the default sort it only reads the requested
+/// page from the repository. Otherwise it has to read all the torrents.
+pub async fn get_filtered_torrents(
+    tracker: Arc<Tracker>,
+    filter: &TorrentFilter,
+    sort: TorrentSort,
+    pagination: &Pagination,
+) -> TorrentsPage {
+    if filter.is_empty() && sort == TorrentSort::default() {
+        return TorrentsPage {
+            torrents: get_torrents_page(tracker.clone(), Some(pagination)).await,
+            total: tracker.torrents.get_metrics().torrents,
+        };
+    }
+
+    let torrents = get_torrents_page(tracker, None).await;
+
+    sorted_page(torrents, filter, sort, Some(pagination))
+}
+
+/// It returns the torrents in the list that match the filter, sorted and
+/// excluding the peer list.
+pub async fn get_filtered_torrents_by_info_hash(
+    tracker: Arc<Tracker>,
+    info_hashes: &[InfoHash],
+    filter: &TorrentFilter,
+    sort: TorrentSort,
+) -> TorrentsPage {
+    let torrents = get_torrents(tracker, info_hashes).await;
+
+    sorted_page(torrents, filter, sort, None)
+}
+
+fn sorted_page(
+    torrents: Vec<BasicInfo>,
+    filter: &TorrentFilter,
+    sort: TorrentSort,
+    pagination: Option<&Pagination>,
+) -> TorrentsPage {
+    let mut torrents: Vec<BasicInfo> = torrents.into_iter().filter(|torrent| filter.matches(torrent)).collect();
+
+    torrents.sort_by(|a, b| sort.compare(a, b));
+
+    let total = torrents.len() as u64;
+
+    let torrents = match pagination {
+        Some(pagination) => torrents
+            .into_iter()
+            .skip(pagination.offset as usize)
+            .take(pagination.limit as usize)
+            .collect(),
+        None => torrents,
+    };
+
+    TorrentsPage { torrents, total }
+}
+
 #[cfg(test)]
 mod tests {
     use std::net::{IpAddr, Ipv4Addr, SocketAddr};
@@ -303,4 +552,222 @@ mod tests {
             );
         }
     }
+
+    mod going_through_all_the_torrents {
+
+        use std::str::FromStr;
+        use std::sync::Arc;
+
+        use bittorrent_primitives::info_hash::InfoHash;
+        use torrust_tracker_test_helpers::configuration;
+
+        use crate::core::services::torrent::tests::sample_peer;
+        use crate::core::services::torrent::{get_torrents_after, Info};
+        use crate::core::services::tracker_factory;
+
+        #[tokio::test]
+        async fn should_return_the_torrents_after_an_info_hash_in_batches() {
+            let tracker = Arc::new(tracker_factory(&configuration::ephemeral()));
+
+            let info_hash_1 = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+            let info_hash_2 = InfoHash::from_str("3e2a6a6e9d4a8bd4a8e2a3d9a5c6e8b1d4c2a1f0").unwrap();
+            let info_hash_3 = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+            for info_hash in [info_hash_3, info_hash_1, info_hash_2] {
+                tracker.upsert_peer_and_get_stats(&info_hash, &sample_peer());
+            }
+
+            let first_batch = get_torrents_after(tracker.clone(), None, 2, false).await;
+
+            assert_eq!(
+                first_batch.iter().map(|info| info.info_hash).collect::<Vec<_>>(),
+                vec![info_hash_1, info_hash_2]
+            );
+
+            let second_batch = get_torrents_after(tracker.clone(), Some(&info_hash_2), 2, false).await;
+
+            assert_eq!(
+                second_batch.iter().map(|info| info.info_hash).collect::<Vec<_>>(),
+                vec![info_hash_3]
+            );
+
+            assert!(get_torrents_after(tracker.clone(), Some(&info_hash_3), 2, false)
+                .await
+                .is_empty());
+        }
+
+        #[tokio::test]
+        async fn should_only_include_the_peers_when_requested() {
+            let tracker = Arc::new(tracker_factory(&configuration::ephemeral()));
+
+            let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+            tracker.upsert_peer_and_get_stats(&info_hash, &sample_peer());
+
+            assert_eq!(
+                get_torrents_after(tracker.clone(), None, 1, true).await,
+                vec![Info {
+                    info_hash,
+                    seeders: 1,
+                    completed: 0,
+                    leechers: 0,
+                    peers: Some(vec![sample_peer()]),
+                }]
+            );
+
+            assert_eq!(get_torrents_after(tracker.clone(), None, 1, false).await[0].peers, None);
+        }
+    }
+
+    mod filtering_and_sorting_torrents {
+
+        use std::str::FromStr;
+        use std::sync::Arc;
+
+        use aquatic_udp_protocol::{AnnounceEvent, NumberOfBytes, PeerId};
+        use bittorrent_primitives::info_hash::InfoHash;
+        use torrust_tracker_primitives::peer;
+        use torrust_tracker_test_helpers::configuration;
+
+        use crate::core::services::torrent::tests::sample_peer;
+        use crate::core::services::torrent::{
+            get_filtered_torrents, get_filtered_torrents_by_info_hash, BasicInfo, Pagination, SortField, SortOrder,
+            TorrentFilter, TorrentSort,
+        };
+        use crate::core::services::tracker_factory;
+        use crate::core::Tracker;
+
+        const SEEDED: &str = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d";
+        const LEECHED: &str = "03840548643af2a7b63a9f5cbca348bc7150ca3a";
+        const BOTH: &str = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4e";
+
+        /// A tracker with a torrent with one seeder, a torrent with one
+        /// leecher and a torrent with one seeder and two leechers.
+        fn tracker_with_torrents() -> Arc<Tracker> {
+            let tracker = Arc::new(tracker_factory(&configuration::ephemeral()));
+
+            let leecher = |id: u8| peer::Peer {
+                peer_id: PeerId([id; 20]),
+                left: NumberOfBytes::new(1),
+                event: AnnounceEvent::Started,
+                ..sample_peer()
+            };
+
+            tracker.upsert_peer_and_get_stats(&InfoHash::from_str(SEEDED).unwrap(), &sample_peer());
+            tracker.upsert_peer_and_get_stats(&InfoHash::from_str(LEECHED).unwrap(), &leecher(1));
+            tracker.upsert_peer_and_get_stats(&InfoHash::from_str(BOTH).unwrap(), &sample_peer());
+            tracker.upsert_peer_and_get_stats(&InfoHash::from_str(BOTH).unwrap(), &leecher(1));
+            tracker.upsert_peer_and_get_stats(&InfoHash::from_str(BOTH).unwrap(), &leecher(2));
+
+            tracker
+        }
+
+        fn info_hashes(torrents: &[BasicInfo]) -> Vec<String> {
+            torrents.iter().map(|torrent| torrent.info_hash.to_hex_string()).collect()
+        }
+
+        #[tokio::test]
+        async fn should_return_the_total_number_of_torrents_without_filter() {
+            let tracker = tracker_with_torrents();
+
+            let page = get_filtered_torrents(
+                tracker,
+                &TorrentFilter::default(),
+                TorrentSort::default(),
+                &Pagination::new(0, 1),
+            )
+            .await;
+
+            assert_eq!(info_hashes(&page.torrents), vec![LEECHED]);
+            assert_eq!(page.total, 3);
+        }
+
+        #[tokio::test]
+        async fn should_filter_by_the_number_of_seeders_and_leechers() {
+            let tracker = tracker_with_torrents();
+
+            let filter = TorrentFilter {
+                min_seeders: Some(1),
+                max_leechers: Some(1),
+                ..Default::default()
+            };
+
+            let page = get_filtered_torrents(tracker, &filter, TorrentSort::default(), &Pagination::default()).await;
+
+            assert_eq!(info_hashes(&page.torrents), vec![SEEDED]);
+            assert_eq!(page.total, 1);
+        }
+
+        #[tokio::test]
+        async fn should_filter_by_info_hash_prefix() {
+            let tracker = tracker_with_torrents();
+
+            let filter = TorrentFilter {
+                info_hash_prefix: Some("9e02".to_string()),
+                ..Default::default()
+            };
+
+            let page = get_filtered_torrents(tracker, &filter, TorrentSort::default(), &Pagination::new(0, 1)).await;
+
+            assert_eq!(info_hashes(&page.torrents), vec![SEEDED]);
+            assert_eq!(page.total, 2);
+        }
+
+        #[tokio::test]
+        async fn should_filter_the_torrents_without_peers() {
+            let tracker = tracker_with_torrents();
+
+            let filter = TorrentFilter {
+                has_peers: Some(false),
+                ..Default::default()
+            };
+
+            let page = get_filtered_torrents(tracker, &filter, TorrentSort::default(), &Pagination::default()).await;
+
+            assert_eq!(page.total, 0);
+        }
+
+        #[tokio::test]
+        async fn should_sort_by_a_field_and_then_by_info_hash() {
+            let tracker = tracker_with_torrents();
+
+            let sort = TorrentSort {
+                field: SortField::Leechers,
+                order: SortOrder::Desc,
+            };
+
+            let page = get_filtered_torrents(tracker, &TorrentFilter::default(), sort, &Pagination::default()).await;
+
+            assert_eq!(info_hashes(&page.torrents), vec![BOTH, LEECHED, SEEDED]);
+        }
+
+        #[tokio::test]
+        async fn should_filter_and_sort_a_list_of_torrents() {
+            let tracker = tracker_with_torrents();
+
+            let sort = TorrentSort {
+                field: SortField::Seeders,
+                order: SortOrder::Asc,
+            };
+
+            let page = get_filtered_torrents_by_info_hash(
+                tracker,
+                &[InfoHash::from_str(BOTH).unwrap(), InfoHash::from_str(LEECHED).unwrap()],
+                &TorrentFilter::default(),
+                sort,
+            )
+            .await;
+
+            assert_eq!(info_hashes(&page.torrents), vec![LEECHED, BOTH]);
+            assert_eq!(page.total, 2);
+        }
+
+        #[test]
+        fn the_sort_field_and_order_should_be_parsed_from_strings() {
+            assert_eq!(SortField::from_str("completed"), Ok(SortField::Completed));
+            assert!(SortField::from_str("unknown").is_err());
+            assert_eq!(SortOrder::from_str("desc"), Ok(SortOrder::Desc));
+            assert!(SortOrder::from_str("down").is_err());
+        }
+    }
 }
@@ -0,0 +1,204 @@
+//! Core tracker domain services for the torrent whitelist.
+//!
+//! There are two services:
+//!
+//! - [`add_torrents_to_whitelist`]: it adds a list of torrents to the whitelist.
+//! - [`remove_torrents_from_whitelist`]: it removes a list of torrents from the whitelist.
+//!
+//! Both take the info-hashes as strings, so that the invalid ones can be
+//! reported individually instead of failing the whole list. The valid ones are
+//! persisted in a single database transaction.
+use std::str::FromStr;
+use std::sync::Arc;
+
+use bittorrent_primitives::info_hash::InfoHash;
+
+use crate::core::databases;
+use crate::core::Tracker;
+
+/// What happened to one of the torrents in a bulk whitelist operation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BulkStatus {
+    /// The torrent was added to the whitelist.
+    Added,
+    /// The torrent was already whitelisted.
+    AlreadyWhitelisted,
+    /// The torrent was removed from the whitelist.
+    Removed,
+    /// The torrent was not whitelisted.
+    NotWhitelisted,
+    /// The item is not a valid info-hash.
+    Invalid,
+}
+
+/// The result of a bulk whitelist operation for one of the items.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BulkResult {
+    /// The item as it was provided.
+    pub info_hash: String,
+    pub status: BulkStatus,
+}
+
+/// It adds the valid info-hashes to the whitelist and returns the result for
+/// each item, in the same order.
+///
+/// # Errors
+///
+/// Will return a `database::Error` if unable to add the torrents to the
+/// whitelist database. No torrent is added in that case.
+pub async fn add_torrents_to_whitelist(
+    tracker: Arc<Tracker>,
+    items: Vec<String>,
+) -> Result<Vec<BulkResult>, databases::error::Error> {
+    let info_hashes = parse_info_hashes(&items);
+
+    let added = tracker.add_torrents_to_whitelist(&valid(&info_hashes)).await?;
+
+    Ok(results(
+        items,
+        &info_hashes,
+        added,
+        BulkStatus::Added,
+        BulkStatus::AlreadyWhitelisted,
+    ))
+}
+
+/// It removes the valid info-hashes from the whitelist and returns the result
+/// for each item, in the same order.
+///
+/// # Errors
+///
+/// Will return a `database::Error` if unable to remove the torrents from the
+/// whitelist database. No torrent is removed in that case.
+pub async fn remove_torrents_from_whitelist(
+    tracker: Arc<Tracker>,
+    items: Vec<String>,
+) -> Result<Vec<BulkResult>, databases::error::Error> {
+    let info_hashes = parse_info_hashes(&items);
+
+    let removed = tracker.remove_torrents_from_whitelist(&valid(&info_hashes)).await?;
+
+    Ok(results(
+        items,
+        &info_hashes,
+        removed,
+        BulkStatus::Removed,
+        BulkStatus::NotWhitelisted,
+    ))
+}
+
+fn parse_info_hashes(items: &[String]) -> Vec<Option<InfoHash>> {
+    items.iter().map(|item| InfoHash::from_str(item).ok()).collect()
+}
+
+fn valid(info_hashes: &[Option<InfoHash>]) -> Vec<InfoHash> {
+    info_hashes.iter().flatten().copied().collect()
+}
+
+/// It merges the results for the valid info-hashes back into the list of
+/// items.
+fn results(
+    items: Vec<String>,
+    info_hashes: &[Option<InfoHash>],
+    changed: Vec<bool>,
+    if_changed: BulkStatus,
+    if_unchanged: BulkStatus,
+) -> Vec<BulkResult> {
+    let mut changed = changed.into_iter();
+
+    items
+        .into_iter()
+        .zip(info_hashes)
+        .map(|(item, info_hash)| {
+            let status = match info_hash {
+                None => BulkStatus::Invalid,
+                Some(_) => {
+                    if changed.next().unwrap_or_default() {
+                        if_changed
+                    } else {
+                        if_unchanged
+                    }
+                }
+            };
+
+            BulkResult { info_hash: item, status }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bittorrent_primitives::info_hash::InfoHash;
+    use torrust_tracker_test_helpers::configuration;
+
+    use crate::core::services::tracker_factory;
+    use crate::core::services::whitelist::{add_torrents_to_whitelist, remove_torrents_from_whitelist, BulkResult, BulkStatus};
+
+    fn result(info_hash: &str, status: BulkStatus) -> BulkResult {
+        BulkResult {
+            info_hash: info_hash.to_string(),
+            status,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_return_the_result_of_adding_each_torrent_to_the_whitelist() {
+        let tracker = Arc::new(tracker_factory(&configuration::ephemeral_listed()));
+
+        let whitelisted = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d";
+        let new = "0b3aea4adc213ce32295be85d3883a63bca25446";
+
+        tracker
+            .add_torrent_to_whitelist(&whitelisted.parse::<InfoHash>().unwrap())
+            .await
+            .unwrap();
+
+        let results = add_torrents_to_whitelist(
+            tracker.clone(),
+            vec![whitelisted.to_string(), "INVALID".to_string(), new.to_string()],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            results,
+            vec![
+                result(whitelisted, BulkStatus::AlreadyWhitelisted),
+                result("INVALID", BulkStatus::Invalid),
+                result(new, BulkStatus::Added),
+            ]
+        );
+        assert!(tracker.is_info_hash_whitelisted(&new.parse::<InfoHash>().unwrap()).await);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_the_result_of_removing_each_torrent_from_the_whitelist() {
+        let tracker = Arc::new(tracker_factory(&configuration::ephemeral_listed()));
+
+        let whitelisted = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d";
+        let not_whitelisted = "0b3aea4adc213ce32295be85d3883a63bca25446";
+
+        tracker
+            .add_torrent_to_whitelist(&whitelisted.parse::<InfoHash>().unwrap())
+            .await
+            .unwrap();
+
+        let results = remove_torrents_from_whitelist(
+            tracker.clone(),
+            vec![not_whitelisted.to_string(), whitelisted.to_string(), "INVALID".to_string()],
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            results,
+            vec![
+                result(not_whitelisted, BulkStatus::NotWhitelisted),
+                result(whitelisted, BulkStatus::Removed),
+                result("INVALID", BulkStatus::Invalid),
+            ]
+        );
+    }
+}
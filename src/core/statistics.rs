@@ -7,49 +7,351 @@
 //! - Number of `scrape` request handled
 //!
 //! These metrics are collected for each connection type: UDP and HTTP and
-//! also for each IP version used by the peers: IPv4 and IPv6.
+//! also for each IP version used by the peers: IPv4 and IPv6. They are also
+//! collected for each tracker [`Listener`](crate::core::statistics::Listener),
+//! so you can tell which socket gets the traffic when there are several UDP or
+//! HTTP trackers.
 //!
-//! > Notice: that UDP tracker have an specific `connection` request. For the HTTP metrics the counter counts one connection for each `announce` or `scrape` request.
+//! Failed and rejected requests are counted by [`ErrorReason`](crate::core::statistics::ErrorReason)
+//! in the [`ErrorCounters`](crate::core::statistics::ErrorCounters).
+//!
+//! Besides the counters, which increase since the tracker started, the
+//! [`statistics::Repo`](crate::core::statistics::Repo) keeps the number of
+//! requests handled in the last hour, one second at a time. It's used to
+//! calculate the current [`RequestRates`](crate::core::statistics::RequestRates)
+//! for the last minute, five minutes and hour.
 //!
-//! The data is collected by using an `event-sender -> event listener` model.
+//! The tracker also keeps a [`LatencyHistogram`](crate::core::statistics::LatencyHistogram)
+//! with the time spent handling each type of request for each protocol.
+//!
+//! The counters can be persisted into the database, so they are not reset when
+//! the tracker restarts. Refer to [`Metrics::counters`](crate::core::statistics::Metrics::counters)
+//! for the list of persisted counters. Besides the current values, the tracker
+//! persists how much each counter increased every day (daily totals).
+//!
+//! > Notice: that UDP tracker have an specific `connection` request. For the HTTP metrics the counter counts one connection for each `announce` or `scrape` request.
 //!
 //! The tracker uses an [`statistics::EventSender`](crate::core::statistics::EventSender) instance to send an event.
-//! The [`statistics::Keeper`](crate::core::statistics::Keeper) listens to new events and uses the [`statistics::Repo`](crate::core::statistics::Repo) to upgrade and store metrics.
+//! The sender created by the [`statistics::Keeper`](crate::core::statistics::Keeper) uses the [`statistics::Repo`](crate::core::statistics::Repo)
+//! to update the metrics as soon as the event is sent.
+//!
+//! The metrics are kept in atomic counters split into shards, one for each
+//! CPU, so requests handled by different threads do not wait for each other.
+//! The shards are merged when the metrics are read. The tracker servers
+//! register their [`Listener`](crate::core::statistics::Listener) when they
+//! start, so the per-listener counters are found without taking a lock.
 //!
 //! See the [`statistics::Event`](crate::core::statistics::Event) enum to check which events are available.
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
-use futures::future::BoxFuture;
+use bittorrent_primitives::info_hash::InfoHash;
+use crossbeam_skiplist::SkipMap;
+use futures::future::{self, BoxFuture};
 use futures::FutureExt;
 #[cfg(test)]
 use mockall::{automock, predicate::str};
 use tokio::sync::mpsc::error::SendError;
-use tokio::sync::{mpsc, RwLock, RwLockReadGuard};
+use tokio::sync::RwLock;
+use torrust_tracker_clock::clock::Time;
+use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+use crate::core::clients::ClientsBreakdown;
+use crate::CurrentClock;
 
-const CHANNEL_BUFFER_SIZE: usize = 65_535;
+/// The maximum number of [`Shard`]s the metrics are split into.
+const MAX_SHARDS: usize = 16;
+
+/// The longest rolling window used to calculate the [`RequestRates`], in seconds.
+const ROLLING_WINDOW_SECS: u64 = 3_600;
+
+/// Number of seconds in a day. Used to number the days for the [`DailyCounters`].
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Value of the persisted counters by counter name.
+pub type PersistentCounters = BTreeMap<String, u64>;
+
+/// Daily totals of the persisted counters by day. Days are numbered from the
+/// Unix Epoch (`1970-01-01` is day `0`).
+pub type DailyCounters = BTreeMap<u64, PersistentCounters>;
 
 /// An statistics event. It is used to collect tracker metrics.
 ///
-/// - `Tcp` prefix means the event was triggered by the HTTP tracker
-/// - `Udp` prefix means the event was triggered by the UDP tracker
-/// - `4` or `6` prefixes means the IP version used by the peer
-/// - Finally the event suffix is the type of request: `announce`, `scrape` or `connection`
+/// The [`Event::Request`] event is sent for every request handled by the
+/// trackers. It contains the tracker protocol ([`Scheme`]), the IP version
+/// used by the peer ([`IpVersion`]), the type of request ([`RequestKind`]) and
+/// the socket address the tracker listener is bound to.
 ///
 /// > NOTE: HTTP trackers do not use `connection` requests.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Event {
-    // code-review: consider one single event for request type with data: Event::Announce { scheme: HTTPorUDP, ip_version: V4orV6 }
-    // Attributes are enums too.
-    Tcp4Announce,
-    Tcp4Scrape,
-    Tcp6Announce,
-    Tcp6Scrape,
-    Udp4Connect,
-    Udp4Announce,
-    Udp4Scrape,
-    Udp6Connect,
-    Udp6Announce,
-    Udp6Scrape,
+    /// A tracker handled a request.
+    Request {
+        scheme: Scheme,
+        ip_version: IpVersion,
+        kind: RequestKind,
+        listener: SocketAddr,
+    },
+    /// The time it took to handle a request.
+    Latency { request: RequestType, latency: Duration },
+    /// The HTTP tracker returned an error response.
+    TcpError { reason: ErrorReason },
+    /// The UDP tracker returned an error response.
+    UdpError { reason: ErrorReason },
+    /// A request to the tracker API was rejected because the access token was
+    /// missing, unknown or expired.
+    ApiAuthFailure,
+    /// A client of the tracker API was locked out after too many invalid
+    /// access tokens.
+    ApiAuthLockout,
+}
+
+/// The protocol of the tracker that handled a request.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Scheme {
+    /// HTTP tracker (TCP), with or without TLS.
+    Http,
+    /// UDP tracker.
+    Udp,
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Scheme::Http => write!(f, "http"),
+            Scheme::Udp => write!(f, "udp"),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("unknown protocol {0}, expected one of: http, udp")]
+pub struct ParseSchemeError(String);
+
+impl FromStr for Scheme {
+    type Err = ParseSchemeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "http" => Ok(Scheme::Http),
+            "udp" => Ok(Scheme::Udp),
+            _ => Err(ParseSchemeError(s.to_string())),
+        }
+    }
+}
+
+/// The IP version used by the peer that sent a request.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IpVersion {
+    V4,
+    V6,
+}
+
+impl From<IpAddr> for IpVersion {
+    fn from(ip: IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(_) => IpVersion::V4,
+            IpAddr::V6(_) => IpVersion::V6,
+        }
+    }
+}
+
+/// The type of request handled by a tracker.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RequestKind {
+    /// UDP `connect` request. HTTP trackers do not have them.
+    Connect,
+    Announce,
+    Scrape,
+}
+
+/// Why the tracker returned an error response.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorReason {
+    /// The request could not be parsed.
+    BadRequest,
+    /// The UDP connection id (cookie) could not be verified.
+    InvalidConnectionId,
+    /// The authentication key is missing, malformed, expired or unknown.
+    AuthenticationFailed,
+    /// The torrent is not whitelisted (only in `listed` or `private_listed`
+    /// modes).
+    TorrentNotWhitelisted,
+    /// Any other error.
+    Internal,
+}
+
+impl ErrorReason {
+    pub const ALL: [ErrorReason; 5] = [
+        ErrorReason::BadRequest,
+        ErrorReason::InvalidConnectionId,
+        ErrorReason::AuthenticationFailed,
+        ErrorReason::TorrentNotWhitelisted,
+        ErrorReason::Internal,
+    ];
+}
+
+impl fmt::Display for ErrorReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorReason::BadRequest => write!(f, "bad_request"),
+            ErrorReason::InvalidConnectionId => write!(f, "invalid_connection_id"),
+            ErrorReason::AuthenticationFailed => write!(f, "authentication_failed"),
+            ErrorReason::TorrentNotWhitelisted => write!(f, "torrent_not_whitelisted"),
+            ErrorReason::Internal => write!(f, "internal"),
+        }
+    }
+}
+
+/// Number of error responses for each [`ErrorReason`].
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ErrorCounters {
+    pub bad_request: u64,
+    pub invalid_connection_id: u64,
+    pub authentication_failed: u64,
+    pub torrent_not_whitelisted: u64,
+    pub internal: u64,
+}
+
+impl ErrorCounters {
+    #[must_use]
+    pub fn get(&self, reason: ErrorReason) -> u64 {
+        match reason {
+            ErrorReason::BadRequest => self.bad_request,
+            ErrorReason::InvalidConnectionId => self.invalid_connection_id,
+            ErrorReason::AuthenticationFailed => self.authentication_failed,
+            ErrorReason::TorrentNotWhitelisted => self.torrent_not_whitelisted,
+            ErrorReason::Internal => self.internal,
+        }
+    }
+
+    pub fn increase(&mut self, reason: ErrorReason) {
+        *self.counter_mut(reason) += 1;
+    }
+
+    fn counter_mut(&mut self, reason: ErrorReason) -> &mut u64 {
+        match reason {
+            ErrorReason::BadRequest => &mut self.bad_request,
+            ErrorReason::InvalidConnectionId => &mut self.invalid_connection_id,
+            ErrorReason::AuthenticationFailed => &mut self.authentication_failed,
+            ErrorReason::TorrentNotWhitelisted => &mut self.torrent_not_whitelisted,
+            ErrorReason::Internal => &mut self.internal,
+        }
+    }
+}
+
+impl Event {
+    /// The type of request handled, for the events counting handled requests.
+    #[must_use]
+    pub fn request_type(&self) -> Option<RequestType> {
+        match self {
+            Event::Request { scheme, kind, .. } => match (scheme, kind) {
+                (Scheme::Http, RequestKind::Connect) => None,
+                (Scheme::Http, RequestKind::Announce) => Some(RequestType::HttpAnnounce),
+                (Scheme::Http, RequestKind::Scrape) => Some(RequestType::HttpScrape),
+                (Scheme::Udp, RequestKind::Connect) => Some(RequestType::UdpConnect),
+                (Scheme::Udp, RequestKind::Announce) => Some(RequestType::UdpAnnounce),
+                (Scheme::Udp, RequestKind::Scrape) => Some(RequestType::UdpScrape),
+            },
+            Event::Latency { .. }
+            | Event::TcpError { .. }
+            | Event::UdpError { .. }
+            | Event::ApiAuthFailure
+            | Event::ApiAuthLockout => None,
+        }
+    }
+}
+
+/// The type of request measured by a [`Event::Latency`](crate::core::statistics::Event::Latency) event.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RequestType {
+    UdpConnect,
+    UdpAnnounce,
+    UdpScrape,
+    HttpAnnounce,
+    HttpScrape,
+}
+
+/// The upper bounds, in microseconds, of the [`LatencyHistogram`] buckets.
+///
+/// There is an extra bucket for the requests slower than the last bound.
+pub const LATENCY_BUCKETS_US: [u64; 12] = [
+    100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000, 1_000_000,
+];
+
+/// A histogram with the time it took to handle requests.
+///
+/// It uses the fixed buckets in [`LATENCY_BUCKETS_US`]. Quantiles are
+/// estimated with the upper bound of the bucket the quantile falls into.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct LatencyHistogram {
+    /// Number of requests in each bucket (not cumulative). The last bucket
+    /// contains the requests slower than the last bound.
+    pub buckets: [u64; LATENCY_BUCKETS_US.len() + 1],
+    /// Total number of requests.
+    pub count: u64,
+    /// Sum of the latencies of all the requests in microseconds.
+    pub sum_us: u64,
+    /// The highest latency observed in microseconds.
+    pub max_us: u64,
+}
+
+impl LatencyHistogram {
+    pub fn observe(&mut self, latency: Duration) {
+        let latency_us = Self::micros(latency);
+
+        self.buckets[Self::bucket(latency_us)] += 1;
+        self.count += 1;
+        self.sum_us = self.sum_us.saturating_add(latency_us);
+        self.max_us = self.max_us.max(latency_us);
+    }
+
+    fn micros(latency: Duration) -> u64 {
+        u64::try_from(latency.as_micros()).unwrap_or(u64::MAX)
+    }
+
+    /// The index of the bucket for a latency in microseconds.
+    fn bucket(latency_us: u64) -> usize {
+        LATENCY_BUCKETS_US
+            .iter()
+            .position(|bound| latency_us <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_US.len())
+    }
+
+    /// It returns the estimated latency in microseconds for the given
+    /// `percentile` (from 0 to 100). It returns `0` if there are no requests.
+    ///
+    /// Requests slower than the last bucket bound are estimated with the
+    /// highest latency observed.
+    #[must_use]
+    pub fn percentile(&self, percentile: u64) -> u64 {
+        if self.count == 0 {
+            return 0;
+        }
+
+        let rank = (self.count.saturating_mul(percentile.min(100)).saturating_add(99) / 100).max(1);
+
+        let mut accumulated = 0;
+
+        for (bucket, requests) in self.buckets.iter().enumerate() {
+            accumulated += requests;
+
+            if accumulated >= rank {
+                return LATENCY_BUCKETS_US
+                    .get(bucket)
+                    .map_or(self.max_us, |bound| (*bound).min(self.max_us));
+            }
+        }
+
+        self.max_us
+    }
 }
 
 /// Metrics collected by the tracker.
@@ -88,12 +390,578 @@ pub struct Metrics {
     pub udp6_announces_handled: u64,
     /// Total number of UDP (UDP tracker) `scrape` requests from IPv6 peers.
     pub udp6_scrapes_handled: u64,
+    /// Total number of TCP (HTTP tracker) error responses by reason.
+    pub tcp_errors: ErrorCounters,
+    /// Total number of UDP (UDP tracker) error responses by reason.
+    pub udp_errors: ErrorCounters,
+    /// Time spent handling UDP (UDP tracker) `connect` requests.
+    pub udp_connect_latency: LatencyHistogram,
+    /// Time spent handling UDP (UDP tracker) `announce` requests.
+    pub udp_announce_latency: LatencyHistogram,
+    /// Time spent handling UDP (UDP tracker) `scrape` requests.
+    pub udp_scrape_latency: LatencyHistogram,
+    /// Time spent handling TCP (HTTP tracker) `announce` requests.
+    pub http_announce_latency: LatencyHistogram,
+    /// Time spent handling TCP (HTTP tracker) `scrape` requests.
+    pub http_scrape_latency: LatencyHistogram,
+    /// Number of requests handled by each tracker listener. Only listeners
+    /// that have handled at least one request are included.
+    pub listeners: BTreeMap<Listener, ListenerMetrics>,
+    /// Number of tracker API requests rejected because the access token was
+    /// missing, unknown or expired.
+    pub api_auth_failures: u64,
+    /// Number of times a tracker API client was locked out after too many
+    /// invalid access tokens.
+    pub api_auth_lockouts: u64,
+}
+
+/// A tracker listener: the protocol and the socket address the tracker is
+/// bound to.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Listener {
+    pub scheme: Scheme,
+    pub address: SocketAddr,
+}
+
+/// Number of requests handled by one tracker listener, for each IP version
+/// used by the peers.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ListenerMetrics {
+    pub ipv4: RequestCounters,
+    pub ipv6: RequestCounters,
+}
+
+impl ListenerMetrics {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Number of requests handled by type.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RequestCounters {
+    pub connections_handled: u64,
+    pub announces_handled: u64,
+    pub scrapes_handled: u64,
+}
+
+impl RequestCounters {
+    fn merge(&mut self, other: &Self) {
+        self.connections_handled += other.connections_handled;
+        self.announces_handled += other.announces_handled;
+        self.scrapes_handled += other.scrapes_handled;
+    }
+}
+
+impl Metrics {
+    /// The counters that are persisted, by name.
+    ///
+    /// The error counters are named `tcp_errors_<reason>` and
+    /// `udp_errors_<reason>`. Latency histograms are not persisted: they
+    /// describe the current performance of the tracker. Neither are the
+    /// per-listener counters, since listeners can change between restarts.
+    #[must_use]
+    pub fn counters(&self) -> PersistentCounters {
+        let mut counters = PersistentCounters::from([
+            ("tcp4_connections_handled".to_string(), self.tcp4_connections_handled),
+            ("tcp4_announces_handled".to_string(), self.tcp4_announces_handled),
+            ("tcp4_scrapes_handled".to_string(), self.tcp4_scrapes_handled),
+            ("tcp6_connections_handled".to_string(), self.tcp6_connections_handled),
+            ("tcp6_announces_handled".to_string(), self.tcp6_announces_handled),
+            ("tcp6_scrapes_handled".to_string(), self.tcp6_scrapes_handled),
+            ("udp4_connections_handled".to_string(), self.udp4_connections_handled),
+            ("udp4_announces_handled".to_string(), self.udp4_announces_handled),
+            ("udp4_scrapes_handled".to_string(), self.udp4_scrapes_handled),
+            ("udp6_connections_handled".to_string(), self.udp6_connections_handled),
+            ("udp6_announces_handled".to_string(), self.udp6_announces_handled),
+            ("udp6_scrapes_handled".to_string(), self.udp6_scrapes_handled),
+        ]);
+
+        for reason in ErrorReason::ALL {
+            counters.insert(format!("tcp_errors_{reason}"), self.tcp_errors.get(reason));
+            counters.insert(format!("udp_errors_{reason}"), self.udp_errors.get(reason));
+        }
+
+        counters
+    }
+
+    /// It sets the counters to their persisted values. Unknown counter names
+    /// are ignored.
+    pub fn restore_counters(&mut self, counters: &PersistentCounters) {
+        for (name, value) in counters {
+            if let Some(counter) = self.counter_mut(name) {
+                *counter = *value;
+            }
+        }
+    }
+
+    fn counter_mut(&mut self, name: &str) -> Option<&mut u64> {
+        let counter = match name {
+            "tcp4_connections_handled" => &mut self.tcp4_connections_handled,
+            "tcp4_announces_handled" => &mut self.tcp4_announces_handled,
+            "tcp4_scrapes_handled" => &mut self.tcp4_scrapes_handled,
+            "tcp6_connections_handled" => &mut self.tcp6_connections_handled,
+            "tcp6_announces_handled" => &mut self.tcp6_announces_handled,
+            "tcp6_scrapes_handled" => &mut self.tcp6_scrapes_handled,
+            "udp4_connections_handled" => &mut self.udp4_connections_handled,
+            "udp4_announces_handled" => &mut self.udp4_announces_handled,
+            "udp4_scrapes_handled" => &mut self.udp4_scrapes_handled,
+            "udp6_connections_handled" => &mut self.udp6_connections_handled,
+            "udp6_announces_handled" => &mut self.udp6_announces_handled,
+            "udp6_scrapes_handled" => &mut self.udp6_scrapes_handled,
+            _ => {
+                let (errors, reason) = if let Some(reason) = name.strip_prefix("tcp_errors_") {
+                    (&mut self.tcp_errors, reason)
+                } else if let Some(reason) = name.strip_prefix("udp_errors_") {
+                    (&mut self.udp_errors, reason)
+                } else {
+                    return None;
+                };
+
+                let reason = ErrorReason::ALL.into_iter().find(|r| r.to_string() == reason)?;
+
+                errors.counter_mut(reason)
+            }
+        };
+
+        Some(counter)
+    }
+}
+
+/// Number of requests handled in each of the last [`ROLLING_WINDOW_SECS`]
+/// seconds. It's a ring buffer with one slot per second.
+///
+/// It can be updated concurrently without locks. When two threads move the
+/// window forward at the same time, a request can be counted in a slot that
+/// is being reset. That's acceptable for the request rates.
+#[derive(Debug)]
+struct RollingCounter {
+    slots: Box<[AtomicU64]>,
+    /// The second (since Unix Epoch) of the most recent slot.
+    last_second: AtomicU64,
+}
+
+impl Default for RollingCounter {
+    fn default() -> Self {
+        let window_size = usize::try_from(ROLLING_WINDOW_SECS).expect("the window size should fit in a usize");
+
+        Self {
+            slots: (0..window_size).map(|_| AtomicU64::new(0)).collect(),
+            last_second: AtomicU64::new(0),
+        }
+    }
+}
+
+impl RollingCounter {
+    fn slot(second: u64) -> usize {
+        usize::try_from(second % ROLLING_WINDOW_SECS).expect("the slot should be lower than the window size")
+    }
+
+    fn increase(&self, now_secs: u64) {
+        let last_second = self.last_second.fetch_max(now_secs, Ordering::AcqRel);
+
+        if now_secs > last_second {
+            // Reset the slots for the seconds without requests
+            if now_secs - last_second >= ROLLING_WINDOW_SECS {
+                self.slots.iter().for_each(|slot| slot.store(0, Ordering::Relaxed));
+            } else {
+                for second in last_second + 1..=now_secs {
+                    self.slots[Self::slot(second)].store(0, Ordering::Relaxed);
+                }
+            }
+        }
+
+        // If the clock goes backwards the request is counted in the most recent second
+        self.slots[Self::slot(last_second.max(now_secs))].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Number of requests handled in the last `window_secs` seconds, including
+    /// the current one.
+    fn count(&self, now_secs: u64, window_secs: u64) -> u64 {
+        let last_second = self.last_second.load(Ordering::Acquire);
+        let first_second = (now_secs + 1).saturating_sub(window_secs.min(ROLLING_WINDOW_SECS));
+        let oldest_kept_second = (last_second + 1).saturating_sub(ROLLING_WINDOW_SECS);
+
+        (first_second.max(oldest_kept_second)..=now_secs.min(last_second))
+            .map(|second| self.slots[Self::slot(second)].load(Ordering::Relaxed))
+            .sum()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn requests_per_second(&self, now_secs: u64, window_secs: u64) -> f64 {
+        self.count(now_secs, window_secs) as f64 / window_secs as f64
+    }
+
+    fn rate(&self, now_secs: u64) -> RequestRate {
+        RequestRate {
+            last_1m: self.requests_per_second(now_secs, 60),
+            last_5m: self.requests_per_second(now_secs, 300),
+            last_1h: self.requests_per_second(now_secs, ROLLING_WINDOW_SECS),
+        }
+    }
+}
+
+/// Average number of requests per second in the last minute, five minutes and
+/// hour.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RequestRate {
+    pub last_1m: f64,
+    pub last_5m: f64,
+    pub last_1h: f64,
+}
+
+impl RequestRate {
+    fn merge(self, other: Self) -> Self {
+        Self {
+            last_1m: self.last_1m + other.last_1m,
+            last_5m: self.last_5m + other.last_5m,
+            last_1h: self.last_1h + other.last_1h,
+        }
+    }
+}
+
+/// The current [`RequestRate`] for each [`RequestType`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct RequestRates {
+    pub udp_connect: RequestRate,
+    pub udp_announce: RequestRate,
+    pub udp_scrape: RequestRate,
+    pub http_announce: RequestRate,
+    pub http_scrape: RequestRate,
+}
+
+impl RequestRates {
+    fn merge(self, other: Self) -> Self {
+        Self {
+            udp_connect: self.udp_connect.merge(other.udp_connect),
+            udp_announce: self.udp_announce.merge(other.udp_announce),
+            udp_scrape: self.udp_scrape.merge(other.udp_scrape),
+            http_announce: self.http_announce.merge(other.http_announce),
+            http_scrape: self.http_scrape.merge(other.http_scrape),
+        }
+    }
+}
+
+/// The rolling request counters for each [`RequestType`].
+#[derive(Debug, Default)]
+struct RequestWindows {
+    udp_connect: RollingCounter,
+    udp_announce: RollingCounter,
+    udp_scrape: RollingCounter,
+    http_announce: RollingCounter,
+    http_scrape: RollingCounter,
+}
+
+impl RequestWindows {
+    fn counter(&self, request: RequestType) -> &RollingCounter {
+        match request {
+            RequestType::UdpConnect => &self.udp_connect,
+            RequestType::UdpAnnounce => &self.udp_announce,
+            RequestType::UdpScrape => &self.udp_scrape,
+            RequestType::HttpAnnounce => &self.http_announce,
+            RequestType::HttpScrape => &self.http_scrape,
+        }
+    }
+
+    fn increase(&self, request: RequestType, now_secs: u64) {
+        self.counter(request).increase(now_secs);
+    }
+
+    fn rates(&self, now_secs: u64) -> RequestRates {
+        RequestRates {
+            udp_connect: self.udp_connect.rate(now_secs),
+            udp_announce: self.udp_announce.rate(now_secs),
+            udp_scrape: self.udp_scrape.rate(now_secs),
+            http_announce: self.http_announce.rate(now_secs),
+            http_scrape: self.http_scrape.rate(now_secs),
+        }
+    }
+}
+
+/// The [`RequestCounters`] of one shard.
+#[derive(Debug, Default)]
+struct AtomicRequestCounters {
+    connections: AtomicU64,
+    announces: AtomicU64,
+    scrapes: AtomicU64,
+}
+
+impl AtomicRequestCounters {
+    fn counter(&self, kind: RequestKind) -> &AtomicU64 {
+        match kind {
+            RequestKind::Connect => &self.connections,
+            RequestKind::Announce => &self.announces,
+            RequestKind::Scrape => &self.scrapes,
+        }
+    }
+
+    fn increase(&self, kind: RequestKind) {
+        self.counter(kind).fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn load(&self) -> RequestCounters {
+        RequestCounters {
+            connections_handled: self.connections.load(Ordering::Relaxed),
+            announces_handled: self.announces.load(Ordering::Relaxed),
+            scrapes_handled: self.scrapes.load(Ordering::Relaxed),
+        }
+    }
+
+    fn store(&self, counters: &RequestCounters) {
+        self.connections.store(counters.connections_handled, Ordering::Relaxed);
+        self.announces.store(counters.announces_handled, Ordering::Relaxed);
+        self.scrapes.store(counters.scrapes_handled, Ordering::Relaxed);
+    }
+}
+
+/// The [`ErrorCounters`] of one shard.
+#[derive(Debug, Default)]
+struct AtomicErrorCounters {
+    counters: [AtomicU64; ErrorReason::ALL.len()],
+}
+
+impl AtomicErrorCounters {
+    fn increase(&self, reason: ErrorReason) {
+        self.counters[reason as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn add_to(&self, errors: &mut ErrorCounters) {
+        for reason in ErrorReason::ALL {
+            *errors.counter_mut(reason) += self.counters[reason as usize].load(Ordering::Relaxed);
+        }
+    }
+
+    fn store(&self, errors: &ErrorCounters) {
+        for reason in ErrorReason::ALL {
+            self.counters[reason as usize].store(errors.get(reason), Ordering::Relaxed);
+        }
+    }
+}
+
+/// The [`LatencyHistogram`] of one shard.
+#[derive(Debug, Default)]
+struct AtomicLatencyHistogram {
+    buckets: [AtomicU64; LATENCY_BUCKETS_US.len() + 1],
+    count: AtomicU64,
+    sum_us: AtomicU64,
+    max_us: AtomicU64,
+}
+
+impl AtomicLatencyHistogram {
+    fn observe(&self, latency: Duration) {
+        let latency_us = LatencyHistogram::micros(latency);
+
+        self.buckets[LatencyHistogram::bucket(latency_us)].fetch_add(1, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.sum_us.fetch_add(latency_us, Ordering::Relaxed);
+        self.max_us.fetch_max(latency_us, Ordering::Relaxed);
+    }
+
+    fn add_to(&self, histogram: &mut LatencyHistogram) {
+        for (total, bucket) in histogram.buckets.iter_mut().zip(self.buckets.iter()) {
+            *total += bucket.load(Ordering::Relaxed);
+        }
+        histogram.count += self.count.load(Ordering::Relaxed);
+        histogram.sum_us = histogram.sum_us.saturating_add(self.sum_us.load(Ordering::Relaxed));
+        histogram.max_us = histogram.max_us.max(self.max_us.load(Ordering::Relaxed));
+    }
+}
+
+/// The part of the metrics updated by one thread.
+///
+/// Each thread is assigned one shard, so threads seldom update the same
+/// counters. The shards are merged when the metrics are read.
+#[derive(Debug, Default)]
+#[repr(align(128))]
+struct Shard {
+    tcp4: AtomicRequestCounters,
+    tcp6: AtomicRequestCounters,
+    udp4: AtomicRequestCounters,
+    udp6: AtomicRequestCounters,
+    tcp_errors: AtomicErrorCounters,
+    udp_errors: AtomicErrorCounters,
+    udp_connect_latency: AtomicLatencyHistogram,
+    udp_announce_latency: AtomicLatencyHistogram,
+    udp_scrape_latency: AtomicLatencyHistogram,
+    http_announce_latency: AtomicLatencyHistogram,
+    http_scrape_latency: AtomicLatencyHistogram,
+    windows: RequestWindows,
+}
+
+impl Shard {
+    fn latency(&self, request: RequestType) -> &AtomicLatencyHistogram {
+        match request {
+            RequestType::UdpConnect => &self.udp_connect_latency,
+            RequestType::UdpAnnounce => &self.udp_announce_latency,
+            RequestType::UdpScrape => &self.udp_scrape_latency,
+            RequestType::HttpAnnounce => &self.http_announce_latency,
+            RequestType::HttpScrape => &self.http_scrape_latency,
+        }
+    }
+
+    fn add_to(&self, metrics: &mut Metrics) {
+        let tcp4 = self.tcp4.load();
+        metrics.tcp4_connections_handled += tcp4.connections_handled;
+        metrics.tcp4_announces_handled += tcp4.announces_handled;
+        metrics.tcp4_scrapes_handled += tcp4.scrapes_handled;
+
+        let tcp6 = self.tcp6.load();
+        metrics.tcp6_connections_handled += tcp6.connections_handled;
+        metrics.tcp6_announces_handled += tcp6.announces_handled;
+        metrics.tcp6_scrapes_handled += tcp6.scrapes_handled;
+
+        let udp4 = self.udp4.load();
+        metrics.udp4_connections_handled += udp4.connections_handled;
+        metrics.udp4_announces_handled += udp4.announces_handled;
+        metrics.udp4_scrapes_handled += udp4.scrapes_handled;
+
+        let udp6 = self.udp6.load();
+        metrics.udp6_connections_handled += udp6.connections_handled;
+        metrics.udp6_announces_handled += udp6.announces_handled;
+        metrics.udp6_scrapes_handled += udp6.scrapes_handled;
+
+        self.tcp_errors.add_to(&mut metrics.tcp_errors);
+        self.udp_errors.add_to(&mut metrics.udp_errors);
+
+        self.udp_connect_latency.add_to(&mut metrics.udp_connect_latency);
+        self.udp_announce_latency.add_to(&mut metrics.udp_announce_latency);
+        self.udp_scrape_latency.add_to(&mut metrics.udp_scrape_latency);
+        self.http_announce_latency.add_to(&mut metrics.http_announce_latency);
+        self.http_scrape_latency.add_to(&mut metrics.http_scrape_latency);
+    }
+
+    /// It sets the persisted counters (see [`Metrics::counters`]) to the
+    /// values in `metrics`.
+    fn store_counters(&self, metrics: &Metrics) {
+        self.tcp4.store(&RequestCounters {
+            connections_handled: metrics.tcp4_connections_handled,
+            announces_handled: metrics.tcp4_announces_handled,
+            scrapes_handled: metrics.tcp4_scrapes_handled,
+        });
+        self.tcp6.store(&RequestCounters {
+            connections_handled: metrics.tcp6_connections_handled,
+            announces_handled: metrics.tcp6_announces_handled,
+            scrapes_handled: metrics.tcp6_scrapes_handled,
+        });
+        self.udp4.store(&RequestCounters {
+            connections_handled: metrics.udp4_connections_handled,
+            announces_handled: metrics.udp4_announces_handled,
+            scrapes_handled: metrics.udp4_scrapes_handled,
+        });
+        self.udp6.store(&RequestCounters {
+            connections_handled: metrics.udp6_connections_handled,
+            announces_handled: metrics.udp6_announces_handled,
+            scrapes_handled: metrics.udp6_scrapes_handled,
+        });
+
+        self.tcp_errors.store(&metrics.tcp_errors);
+        self.udp_errors.store(&metrics.udp_errors);
+    }
+}
+
+/// The [`ListenerMetrics`] of one shard.
+#[derive(Debug, Default)]
+#[repr(align(128))]
+struct AtomicListenerCounters {
+    ipv4: AtomicRequestCounters,
+    ipv6: AtomicRequestCounters,
+}
+
+impl AtomicListenerCounters {
+    fn increase(&self, scheme: Scheme, ip_version: IpVersion, kind: RequestKind) {
+        let counters = match ip_version {
+            IpVersion::V4 => &self.ipv4,
+            IpVersion::V6 => &self.ipv6,
+        };
+
+        counters.increase(kind);
+
+        // Like the global counters, HTTP trackers count one connection for
+        // each request.
+        if scheme == Scheme::Http && kind != RequestKind::Connect {
+            counters.increase(RequestKind::Connect);
+        }
+    }
+
+    fn add_to(&self, metrics: &mut ListenerMetrics) {
+        metrics.ipv4.merge(&self.ipv4.load());
+        metrics.ipv6.merge(&self.ipv6.load());
+    }
+}
+
+/// The counters of one tracker listener, split into the same number of shards
+/// as the [`Shards`].
+#[derive(Debug)]
+struct ListenerCounters {
+    shards: Box<[AtomicListenerCounters]>,
+}
+
+impl ListenerCounters {
+    fn new(shards: usize) -> Self {
+        Self {
+            shards: (0..shards).map(|_| AtomicListenerCounters::default()).collect(),
+        }
+    }
+
+    fn load(&self) -> ListenerMetrics {
+        let mut metrics = ListenerMetrics::default();
+
+        for shard in &self.shards {
+            shard.add_to(&mut metrics);
+        }
+
+        metrics
+    }
 }
 
-/// The service responsible for keeping tracker metrics (listening to statistics events and handle them).
+/// The metrics split into one [`Shard`] for each available CPU, up to
+/// [`MAX_SHARDS`].
+#[derive(Debug)]
+struct Shards {
+    shards: Box<[Shard]>,
+}
+
+/// Used to assign a shard to each thread.
+static NEXT_SHARD: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static SHARD: usize = NEXT_SHARD.fetch_add(1, Ordering::Relaxed);
+}
+
+impl Default for Shards {
+    fn default() -> Self {
+        let shards = thread::available_parallelism().map_or(1, NonZeroUsize::get).min(MAX_SHARDS);
+
+        Self {
+            shards: (0..shards).map(|_| Shard::default()).collect(),
+        }
+    }
+}
+
+impl Shards {
+    /// The index of the shard assigned to the current thread.
+    fn local_index(&self) -> usize {
+        SHARD.with(|shard| *shard) % self.shards.len()
+    }
+
+    /// The shard assigned to the current thread.
+    fn local(&self) -> &Shard {
+        &self.shards[self.local_index()]
+    }
+
+    fn len(&self) -> usize {
+        self.shards.len()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &Shard> {
+        self.shards.iter()
+    }
+}
+
+/// The service responsible for keeping tracker metrics.
 ///
-/// It actively listen to new statistics events. When it receives a new event
-/// it accordingly increases the counters.
+/// The [`EventSender`] it creates records the events directly into the
+/// [`Repo`], so there is no queue where events could pile up.
 pub struct Keeper {
     pub repository: Repo,
 }
@@ -120,68 +988,102 @@ impl Keeper {
     }
 
     pub fn run_event_listener(&mut self) -> Box<dyn EventSender> {
-        let (sender, receiver) = mpsc::channel::<Event>(CHANNEL_BUFFER_SIZE);
-
-        let stats_repository = self.repository.clone();
-
-        tokio::spawn(async move { event_listener(receiver, stats_repository).await });
-
-        Box::new(Sender { sender })
+        Box::new(Sender {
+            repository: self.repository.clone(),
+        })
     }
 }
 
-async fn event_listener(mut receiver: mpsc::Receiver<Event>, stats_repository: Repo) {
-    while let Some(event) = receiver.recv().await {
-        event_handler(event, &stats_repository).await;
+fn event_handler(event: Event, stats_repository: &Repo) {
+    tracing::debug!("stats event: {event:?}");
+
+    if let Some(request) = event.request_type() {
+        stats_repository.increase_request_windows(request);
     }
-}
 
-async fn event_handler(event: Event, stats_repository: &Repo) {
     match event {
-        // TCP4
-        Event::Tcp4Announce => {
-            stats_repository.increase_tcp4_announces().await;
-            stats_repository.increase_tcp4_connections().await;
-        }
-        Event::Tcp4Scrape => {
-            stats_repository.increase_tcp4_scrapes().await;
-            stats_repository.increase_tcp4_connections().await;
+        Event::Request {
+            scheme,
+            ip_version,
+            kind,
+            listener,
+        } => {
+            match (scheme, ip_version, kind) {
+                // TCP4
+                (Scheme::Http, IpVersion::V4, RequestKind::Announce) => {
+                    stats_repository.increase_tcp4_announces();
+                    stats_repository.increase_tcp4_connections();
+                }
+                (Scheme::Http, IpVersion::V4, RequestKind::Scrape) => {
+                    stats_repository.increase_tcp4_scrapes();
+                    stats_repository.increase_tcp4_connections();
+                }
+
+                // TCP6
+                (Scheme::Http, IpVersion::V6, RequestKind::Announce) => {
+                    stats_repository.increase_tcp6_announces();
+                    stats_repository.increase_tcp6_connections();
+                }
+                (Scheme::Http, IpVersion::V6, RequestKind::Scrape) => {
+                    stats_repository.increase_tcp6_scrapes();
+                    stats_repository.increase_tcp6_connections();
+                }
+
+                // HTTP trackers do not handle `connect` requests
+                (Scheme::Http, _, RequestKind::Connect) => {}
+
+                // UDP4
+                (Scheme::Udp, IpVersion::V4, RequestKind::Connect) => {
+                    stats_repository.increase_udp4_connections();
+                }
+                (Scheme::Udp, IpVersion::V4, RequestKind::Announce) => {
+                    stats_repository.increase_udp4_announces();
+                }
+                (Scheme::Udp, IpVersion::V4, RequestKind::Scrape) => {
+                    stats_repository.increase_udp4_scrapes();
+                }
+
+                // UDP6
+                (Scheme::Udp, IpVersion::V6, RequestKind::Connect) => {
+                    stats_repository.increase_udp6_connections();
+                }
+                (Scheme::Udp, IpVersion::V6, RequestKind::Announce) => {
+                    stats_repository.increase_udp6_announces();
+                }
+                (Scheme::Udp, IpVersion::V6, RequestKind::Scrape) => {
+                    stats_repository.increase_udp6_scrapes();
+                }
+            }
+
+            stats_repository.increase_listener_requests(
+                Listener {
+                    scheme,
+                    address: listener,
+                },
+                ip_version,
+                kind,
+            );
         }
 
-        // TCP6
-        Event::Tcp6Announce => {
-            stats_repository.increase_tcp6_announces().await;
-            stats_repository.increase_tcp6_connections().await;
+        // Errors
+        Event::TcpError { reason } => {
+            stats_repository.increase_tcp_errors(reason);
         }
-        Event::Tcp6Scrape => {
-            stats_repository.increase_tcp6_scrapes().await;
-            stats_repository.increase_tcp6_connections().await;
+        Event::UdpError { reason } => {
+            stats_repository.increase_udp_errors(reason);
         }
-
-        // UDP4
-        Event::Udp4Connect => {
-            stats_repository.increase_udp4_connections().await;
+        Event::ApiAuthFailure => {
+            stats_repository.increase_api_auth_failures();
         }
-        Event::Udp4Announce => {
-            stats_repository.increase_udp4_announces().await;
-        }
-        Event::Udp4Scrape => {
-            stats_repository.increase_udp4_scrapes().await;
+        Event::ApiAuthLockout => {
+            stats_repository.increase_api_auth_lockouts();
         }
 
-        // UDP6
-        Event::Udp6Connect => {
-            stats_repository.increase_udp6_connections().await;
-        }
-        Event::Udp6Announce => {
-            stats_repository.increase_udp6_announces().await;
-        }
-        Event::Udp6Scrape => {
-            stats_repository.increase_udp6_scrapes().await;
+        // Latency
+        Event::Latency { request, latency } => {
+            stats_repository.record_latency(request, latency);
         }
     }
-
-    tracing::debug!("stats: {:?}", stats_repository.get_stats().await);
 }
 
 /// A trait to allow sending statistics events
@@ -192,22 +1094,40 @@ pub trait EventSender: Sync + Send {
 
 /// An [`statistics::EventSender`](crate::core::statistics::EventSender) implementation.
 ///
-/// It uses a channel sender to send the statistic events. The channel is created by a
-/// [`statistics::Keeper`](crate::core::statistics::Keeper)
+/// It records the events in the [`Repo`] of the [`statistics::Keeper`](crate::core::statistics::Keeper)
+/// that created it. Recording an event only updates atomic counters, so it
+/// never waits for other requests.
 pub struct Sender {
-    sender: mpsc::Sender<Event>,
+    repository: Repo,
 }
 
 impl EventSender for Sender {
     fn send_event(&self, event: Event) -> BoxFuture<'_, Option<Result<(), SendError<Event>>>> {
-        async move { Some(self.sender.send(event).await) }.boxed()
+        event_handler(event, &self.repository);
+
+        future::ready(Some(Ok(()))).boxed()
     }
 }
 
+/// The last breakdowns of the active peers by client software.
+#[derive(Debug, Default)]
+struct ClientsBreakdowns {
+    tracker: ClientsBreakdown,
+    torrents: HashMap<InfoHash, ClientsBreakdown>,
+}
+
 /// A repository for the tracker metrics.
 #[derive(Clone)]
 pub struct Repo {
-    pub stats: Arc<RwLock<Metrics>>,
+    shards: Arc<Shards>,
+    /// The counters of the registered listeners. The map is lock-free, so
+    /// looking up a listener never waits for other requests.
+    listeners: Arc<SkipMap<Listener, ListenerCounters>>,
+    api_auth_failures: Arc<AtomicU64>,
+    api_auth_lockouts: Arc<AtomicU64>,
+    clients: Arc<RwLock<ClientsBreakdowns>>,
+    /// The counters the last time they were persisted.
+    persisted: Arc<RwLock<PersistentCounters>>,
 }
 
 impl Default for Repo {
@@ -220,98 +1140,260 @@ impl Repo {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            stats: Arc::new(RwLock::new(Metrics::default())),
+            shards: Arc::new(Shards::default()),
+            listeners: Arc::new(SkipMap::new()),
+            api_auth_failures: Arc::new(AtomicU64::new(0)),
+            api_auth_lockouts: Arc::new(AtomicU64::new(0)),
+            clients: Arc::new(RwLock::new(ClientsBreakdowns::default())),
+            persisted: Arc::new(RwLock::new(PersistentCounters::new())),
+        }
+    }
+
+    /// It returns a snapshot of the metrics, merging all the shards.
+    #[must_use]
+    pub fn get_stats(&self) -> Metrics {
+        let mut metrics = Metrics {
+            api_auth_failures: self.api_auth_failures.load(Ordering::Relaxed),
+            api_auth_lockouts: self.api_auth_lockouts.load(Ordering::Relaxed),
+            ..Default::default()
+        };
+
+        for shard in self.shards.iter() {
+            shard.add_to(&mut metrics);
+        }
+
+        for entry in self.listeners.iter() {
+            let listener_metrics = entry.value().load();
+
+            if !listener_metrics.is_empty() {
+                metrics.listeners.insert(*entry.key(), listener_metrics);
+            }
+        }
+
+        metrics
+    }
+
+    /// It returns the current request rates for all the request types.
+    #[must_use]
+    pub fn get_request_rates(&self) -> RequestRates {
+        let now_secs = now_secs();
+
+        self.shards.iter().fold(RequestRates::default(), |rates, shard| {
+            rates.merge(shard.windows.rates(now_secs))
+        })
+    }
+
+    /// It returns the average number of requests per second of one type in
+    /// the last `window` (up to one hour).
+    ///
+    /// It can be used to make decisions depending on the current load.
+    #[must_use]
+    pub fn requests_per_second(&self, request: RequestType, window: Duration) -> f64 {
+        let now_secs = now_secs();
+        let window_secs = window.as_secs().clamp(1, ROLLING_WINDOW_SECS);
+
+        self.shards
+            .iter()
+            .map(|shard| shard.windows.counter(request).requests_per_second(now_secs, window_secs))
+            .sum()
+    }
+
+    pub async fn get_clients_breakdown(&self) -> ClientsBreakdown {
+        self.clients.read().await.tracker.clone()
+    }
+
+    /// It returns the last breakdown built for a torrent. It's empty if the
+    /// torrent had no peers when the breakdowns were built.
+    pub async fn get_torrent_clients_breakdown(&self, info_hash: &InfoHash) -> ClientsBreakdown {
+        let clients = self.clients.read().await;
+
+        clients.torrents.get(info_hash).cloned().unwrap_or_else(|| ClientsBreakdown {
+            clients: BTreeMap::new(),
+            updated_at: clients.tracker.updated_at,
+        })
+    }
+
+    /// It replaces the breakdown for the whole tracker and the breakdowns for
+    /// each torrent.
+    pub async fn set_clients_breakdown(&self, breakdown: ClientsBreakdown, torrents: HashMap<InfoHash, ClientsBreakdown>) {
+        *self.clients.write().await = ClientsBreakdowns {
+            tracker: breakdown,
+            torrents,
+        };
+    }
+
+    /// It sets the counters to the values persisted by a previous run of the
+    /// tracker.
+    ///
+    /// It's meant to be called when the tracker starts. Requests handled
+    /// while the counters are being restored could be lost.
+    pub async fn restore_counters(&self, counters: &PersistentCounters) {
+        let mut persisted_lock = self.persisted.write().await;
+
+        let mut metrics = self.get_stats();
+        metrics.restore_counters(counters);
+
+        // The first shard keeps the restored values and the rest start from zero
+        let zeroed = Metrics::default();
+        for (index, shard) in self.shards.iter().enumerate() {
+            shard.store_counters(if index == 0 { &metrics } else { &zeroed });
         }
+
+        persisted_lock.clone_from(counters);
+        drop(persisted_lock);
+    }
+
+    /// It persists the counters using the `save` function, which receives the
+    /// current value of the counters and how much they have increased since
+    /// they were persisted the last time.
+    ///
+    /// Only the counters that have increased are included in the increments.
+    ///
+    /// # Errors
+    ///
+    /// Will return the error returned by `save`. In that case the increments
+    /// are kept, so they will be persisted the next time.
+    pub async fn persist_counters<E>(
+        &self,
+        save: impl FnOnce(&PersistentCounters, &PersistentCounters) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut persisted_lock = self.persisted.write().await;
+
+        let counters = self.get_stats().counters();
+
+        let increments: PersistentCounters = counters
+            .iter()
+            .map(|(name, value)| {
+                let persisted = persisted_lock.get(name).copied().unwrap_or_default();
+                (name.clone(), value.saturating_sub(persisted))
+            })
+            .filter(|(_, increment)| *increment > 0)
+            .collect();
+
+        save(&counters, &increments)?;
+
+        *persisted_lock = counters;
+        drop(persisted_lock);
+
+        Ok(())
+    }
+
+    /// It registers a tracker listener, so its counters exist before it
+    /// handles the first request. It's meant to be called when the tracker
+    /// server starts. Registering a listener twice keeps its counters.
+    pub fn register_listener(&self, listener: Listener) {
+        self.listeners
+            .get_or_insert_with(listener, || ListenerCounters::new(self.shards.len()));
+    }
+
+    /// It counts a tracker API request rejected by the authentication.
+    pub fn increase_api_auth_failures(&self) {
+        self.api_auth_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// It counts a tracker API client locked out by the authentication.
+    pub fn increase_api_auth_lockouts(&self) {
+        self.api_auth_lockouts.fetch_add(1, Ordering::Relaxed);
     }
 
-    pub async fn get_stats(&self) -> RwLockReadGuard<'_, Metrics> {
-        self.stats.read().await
+    pub fn increase_request_windows(&self, request: RequestType) {
+        self.shards.local().windows.increase(request, now_secs());
     }
 
-    pub async fn increase_tcp4_announces(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.tcp4_announces_handled += 1;
-        drop(stats_lock);
+    pub fn increase_tcp4_announces(&self) {
+        self.shards.local().tcp4.increase(RequestKind::Announce);
     }
 
-    pub async fn increase_tcp4_connections(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.tcp4_connections_handled += 1;
-        drop(stats_lock);
+    pub fn increase_tcp4_connections(&self) {
+        self.shards.local().tcp4.increase(RequestKind::Connect);
     }
 
-    pub async fn increase_tcp4_scrapes(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.tcp4_scrapes_handled += 1;
-        drop(stats_lock);
+    pub fn increase_tcp4_scrapes(&self) {
+        self.shards.local().tcp4.increase(RequestKind::Scrape);
     }
 
-    pub async fn increase_tcp6_announces(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.tcp6_announces_handled += 1;
-        drop(stats_lock);
+    pub fn increase_tcp6_announces(&self) {
+        self.shards.local().tcp6.increase(RequestKind::Announce);
     }
 
-    pub async fn increase_tcp6_connections(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.tcp6_connections_handled += 1;
-        drop(stats_lock);
+    pub fn increase_tcp6_connections(&self) {
+        self.shards.local().tcp6.increase(RequestKind::Connect);
     }
 
-    pub async fn increase_tcp6_scrapes(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.tcp6_scrapes_handled += 1;
-        drop(stats_lock);
+    pub fn increase_tcp6_scrapes(&self) {
+        self.shards.local().tcp6.increase(RequestKind::Scrape);
     }
 
-    pub async fn increase_udp4_connections(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.udp4_connections_handled += 1;
-        drop(stats_lock);
+    pub fn increase_udp4_connections(&self) {
+        self.shards.local().udp4.increase(RequestKind::Connect);
     }
 
-    pub async fn increase_udp4_announces(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.udp4_announces_handled += 1;
-        drop(stats_lock);
+    pub fn increase_udp4_announces(&self) {
+        self.shards.local().udp4.increase(RequestKind::Announce);
     }
 
-    pub async fn increase_udp4_scrapes(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.udp4_scrapes_handled += 1;
-        drop(stats_lock);
+    pub fn increase_udp4_scrapes(&self) {
+        self.shards.local().udp4.increase(RequestKind::Scrape);
     }
 
-    pub async fn increase_udp6_connections(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.udp6_connections_handled += 1;
-        drop(stats_lock);
+    pub fn increase_udp6_connections(&self) {
+        self.shards.local().udp6.increase(RequestKind::Connect);
     }
 
-    pub async fn increase_udp6_announces(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.udp6_announces_handled += 1;
-        drop(stats_lock);
+    pub fn increase_udp6_announces(&self) {
+        self.shards.local().udp6.increase(RequestKind::Announce);
     }
 
-    pub async fn increase_udp6_scrapes(&self) {
-        let mut stats_lock = self.stats.write().await;
-        stats_lock.udp6_scrapes_handled += 1;
-        drop(stats_lock);
+    pub fn increase_udp6_scrapes(&self) {
+        self.shards.local().udp6.increase(RequestKind::Scrape);
     }
+
+    pub fn increase_listener_requests(&self, listener: Listener, ip_version: IpVersion, kind: RequestKind) {
+        // Listeners are registered when the servers start, so the entry is
+        // only inserted here for the events of listeners that were not.
+        let entry = match self.listeners.get(&listener) {
+            Some(entry) => entry,
+            None => self
+                .listeners
+                .get_or_insert_with(listener, || ListenerCounters::new(self.shards.len())),
+        };
+
+        entry.value().shards[self.shards.local_index()].increase(listener.scheme, ip_version, kind);
+    }
+
+    pub fn increase_tcp_errors(&self, reason: ErrorReason) {
+        self.shards.local().tcp_errors.increase(reason);
+    }
+
+    pub fn increase_udp_errors(&self, reason: ErrorReason) {
+        self.shards.local().udp_errors.increase(reason);
+    }
+
+    pub fn record_latency(&self, request: RequestType, latency: Duration) {
+        self.shards.local().latency(request).observe(latency);
+    }
+}
+
+fn now_secs() -> u64 {
+    let now: DurationSinceUnixEpoch = CurrentClock::now();
+    now.as_secs()
 }
 
 #[cfg(test)]
 mod tests {
 
     mod stats_tracker {
-        use crate::core::statistics::{Event, Keeper, Metrics};
+        use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+        use std::thread;
+        use std::time::Duration;
 
-        #[tokio::test]
-        async fn should_contain_the_tracker_statistics() {
+        use crate::core::statistics::{Event, IpVersion, Keeper, Listener, Metrics, RequestKind, RequestType, Scheme};
+
+        #[test]
+        fn should_contain_the_tracker_statistics() {
             let stats_tracker = Keeper::new();
 
-            let stats = stats_tracker.repository.get_stats().await;
+            let stats = stats_tracker.repository.get_stats();
 
             assert_eq!(stats.tcp4_announces_handled, Metrics::default().tcp4_announces_handled);
         }
@@ -322,167 +1404,646 @@ mod tests {
 
             let event_sender = stats_tracker.run_event_listener();
 
-            let result = event_sender.send_event(Event::Udp4Connect).await;
+            let result = event_sender
+                .send_event(Event::Request {
+                    scheme: Scheme::Udp,
+                    ip_version: IpVersion::V4,
+                    kind: RequestKind::Connect,
+                    listener: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 6969),
+                })
+                .await;
 
             assert!(result.is_some());
         }
+
+        #[test]
+        fn should_merge_the_counters_updated_from_different_threads() {
+            let stats_tracker = Keeper::new();
+
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    let repository = stats_tracker.repository.clone();
+                    thread::spawn(move || {
+                        for _ in 0..100 {
+                            repository.increase_udp4_announces();
+                            repository.record_latency(RequestType::UdpAnnounce, Duration::from_micros(200));
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            let stats = stats_tracker.repository.get_stats();
+
+            assert_eq!(stats.udp4_announces_handled, 400);
+            assert_eq!(stats.udp_announce_latency.count, 400);
+        }
+
+        fn sample_listener() -> Listener {
+            Listener {
+                scheme: Scheme::Udp,
+                address: SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 6969),
+            }
+        }
+
+        #[test]
+        fn should_not_include_the_registered_listeners_that_have_not_handled_any_request() {
+            let stats_tracker = Keeper::new();
+
+            stats_tracker.repository.register_listener(sample_listener());
+
+            assert!(stats_tracker.repository.get_stats().listeners.is_empty());
+        }
+
+        #[test]
+        fn should_merge_the_listener_counters_updated_from_different_threads() {
+            let stats_tracker = Keeper::new();
+
+            stats_tracker.repository.register_listener(sample_listener());
+
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    let repository = stats_tracker.repository.clone();
+                    thread::spawn(move || {
+                        for _ in 0..100 {
+                            repository.increase_listener_requests(sample_listener(), IpVersion::V4, RequestKind::Announce);
+                        }
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+
+            let stats = stats_tracker.repository.get_stats();
+
+            assert_eq!(stats.listeners[&sample_listener()].ipv4.announces_handled, 400);
+        }
+
+        #[test]
+        fn should_count_the_api_authentication_failures() {
+            let stats_tracker = Keeper::new();
+
+            stats_tracker.repository.increase_api_auth_failures();
+
+            assert_eq!(stats_tracker.repository.get_stats().api_auth_failures, 1);
+        }
+
+        #[test]
+        fn should_count_the_api_authentication_lockouts() {
+            let stats_tracker = Keeper::new();
+
+            stats_tracker.repository.increase_api_auth_lockouts();
+
+            assert_eq!(stats_tracker.repository.get_stats().api_auth_lockouts, 1);
+        }
     }
 
     mod event_handler {
-        use crate::core::statistics::{event_handler, Event, Repo};
+        use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+        use std::time::Duration;
 
-        #[tokio::test]
-        async fn should_increase_the_tcp4_announces_counter_when_it_receives_a_tcp4_announce_event() {
+        use crate::core::statistics::{
+            event_handler, ErrorReason, Event, IpVersion, Listener, ListenerMetrics, Repo, RequestCounters, RequestKind,
+            RequestType, Scheme,
+        };
+
+        fn sample_listener() -> SocketAddr {
+            SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 6969)
+        }
+
+        fn request_event(scheme: Scheme, ip_version: IpVersion, kind: RequestKind) -> Event {
+            Event::Request {
+                scheme,
+                ip_version,
+                kind,
+                listener: sample_listener(),
+            }
+        }
+
+        #[test]
+        fn should_increase_the_tcp4_announces_counter_when_it_receives_a_tcp4_announce_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Tcp4Announce, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V4, RequestKind::Announce),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.tcp4_announces_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_tcp4_connections_counter_when_it_receives_a_tcp4_announce_event() {
+        #[test]
+        fn should_increase_the_tcp4_connections_counter_when_it_receives_a_tcp4_announce_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Tcp4Announce, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V4, RequestKind::Announce),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.tcp4_connections_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_tcp4_scrapes_counter_when_it_receives_a_tcp4_scrape_event() {
+        #[test]
+        fn should_increase_the_tcp4_scrapes_counter_when_it_receives_a_tcp4_scrape_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Tcp4Scrape, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V4, RequestKind::Scrape),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.tcp4_scrapes_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_tcp4_connections_counter_when_it_receives_a_tcp4_scrape_event() {
+        #[test]
+        fn should_increase_the_tcp4_connections_counter_when_it_receives_a_tcp4_scrape_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Tcp4Scrape, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V4, RequestKind::Scrape),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.tcp4_connections_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_tcp6_announces_counter_when_it_receives_a_tcp6_announce_event() {
+        #[test]
+        fn should_increase_the_tcp6_announces_counter_when_it_receives_a_tcp6_announce_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Tcp6Announce, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V6, RequestKind::Announce),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.tcp6_announces_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_tcp6_connections_counter_when_it_receives_a_tcp6_announce_event() {
+        #[test]
+        fn should_increase_the_tcp6_connections_counter_when_it_receives_a_tcp6_announce_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Tcp6Announce, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V6, RequestKind::Announce),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.tcp6_connections_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_tcp6_scrapes_counter_when_it_receives_a_tcp6_scrape_event() {
+        #[test]
+        fn should_increase_the_tcp6_scrapes_counter_when_it_receives_a_tcp6_scrape_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Tcp6Scrape, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V6, RequestKind::Scrape),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.tcp6_scrapes_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_tcp6_connections_counter_when_it_receives_a_tcp6_scrape_event() {
+        #[test]
+        fn should_increase_the_tcp6_connections_counter_when_it_receives_a_tcp6_scrape_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Tcp6Scrape, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V6, RequestKind::Scrape),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.tcp6_connections_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_udp4_connections_counter_when_it_receives_a_udp4_connect_event() {
+        #[test]
+        fn should_increase_the_udp4_connections_counter_when_it_receives_a_udp4_connect_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Udp4Connect, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Udp, IpVersion::V4, RequestKind::Connect),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.udp4_connections_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_udp4_announces_counter_when_it_receives_a_udp4_announce_event() {
+        #[test]
+        fn should_increase_the_udp4_announces_counter_when_it_receives_a_udp4_announce_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Udp4Announce, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Udp, IpVersion::V4, RequestKind::Announce),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.udp4_announces_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_udp4_scrapes_counter_when_it_receives_a_udp4_scrape_event() {
+        #[test]
+        fn should_increase_the_udp4_scrapes_counter_when_it_receives_a_udp4_scrape_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Udp4Scrape, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Udp, IpVersion::V4, RequestKind::Scrape),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.udp4_scrapes_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_udp6_connections_counter_when_it_receives_a_udp6_connect_event() {
+        #[test]
+        fn should_increase_the_udp6_connections_counter_when_it_receives_a_udp6_connect_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Udp6Connect, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Udp, IpVersion::V6, RequestKind::Connect),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.udp6_connections_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_udp6_announces_counter_when_it_receives_a_udp6_announce_event() {
+        #[test]
+        fn should_increase_the_udp6_announces_counter_when_it_receives_a_udp6_announce_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Udp6Announce, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Udp, IpVersion::V6, RequestKind::Announce),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.udp6_announces_handled, 1);
         }
 
-        #[tokio::test]
-        async fn should_increase_the_udp6_scrapes_counter_when_it_receives_a_udp6_scrape_event() {
+        #[test]
+        fn should_increase_the_udp6_scrapes_counter_when_it_receives_a_udp6_scrape_event() {
             let stats_repository = Repo::new();
 
-            event_handler(Event::Udp6Scrape, &stats_repository).await;
+            event_handler(
+                request_event(Scheme::Udp, IpVersion::V6, RequestKind::Scrape),
+                &stats_repository,
+            );
 
-            let stats = stats_repository.get_stats().await;
+            let stats = stats_repository.get_stats();
 
             assert_eq!(stats.udp6_scrapes_handled, 1);
         }
+
+        #[test]
+        fn should_increase_the_listener_counters_when_it_receives_a_request_event() {
+            let stats_repository = Repo::new();
+
+            event_handler(
+                request_event(Scheme::Udp, IpVersion::V6, RequestKind::Scrape),
+                &stats_repository,
+            );
+            event_handler(
+                request_event(Scheme::Http, IpVersion::V4, RequestKind::Announce),
+                &stats_repository,
+            );
+
+            let stats = stats_repository.get_stats();
+
+            assert_eq!(
+                stats.listeners[&Listener {
+                    scheme: Scheme::Udp,
+                    address: sample_listener(),
+                }],
+                ListenerMetrics {
+                    ipv6: RequestCounters {
+                        scrapes_handled: 1,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                }
+            );
+
+            // HTTP trackers count one connection for each request
+            assert_eq!(
+                stats.listeners[&Listener {
+                    scheme: Scheme::Http,
+                    address: sample_listener(),
+                }],
+                ListenerMetrics {
+                    ipv4: RequestCounters {
+                        connections_handled: 1,
+                        announces_handled: 1,
+                        scrapes_handled: 0,
+                    },
+                    ..Default::default()
+                }
+            );
+        }
+
+        #[test]
+        fn should_increase_the_tcp_errors_counter_for_the_reason_when_it_receives_a_tcp_error_event() {
+            let stats_repository = Repo::new();
+
+            event_handler(
+                Event::TcpError {
+                    reason: ErrorReason::TorrentNotWhitelisted,
+                },
+                &stats_repository,
+            );
+
+            let stats = stats_repository.get_stats();
+
+            assert_eq!(stats.tcp_errors.torrent_not_whitelisted, 1);
+            assert_eq!(stats.udp_errors.torrent_not_whitelisted, 0);
+        }
+
+        #[test]
+        fn should_increase_the_api_authentication_failures_counter_when_it_receives_an_api_auth_failure_event() {
+            let stats_repository = Repo::new();
+
+            event_handler(Event::ApiAuthFailure, &stats_repository);
+
+            assert_eq!(stats_repository.get_stats().api_auth_failures, 1);
+        }
+
+        #[test]
+        fn should_increase_the_api_authentication_lockouts_counter_when_it_receives_an_api_auth_lockout_event() {
+            let stats_repository = Repo::new();
+
+            event_handler(Event::ApiAuthLockout, &stats_repository);
+
+            assert_eq!(stats_repository.get_stats().api_auth_lockouts, 1);
+        }
+
+        #[test]
+        fn should_increase_the_udp_errors_counter_for_the_reason_when_it_receives_a_udp_error_event() {
+            let stats_repository = Repo::new();
+
+            event_handler(
+                Event::UdpError {
+                    reason: ErrorReason::InvalidConnectionId,
+                },
+                &stats_repository,
+            );
+
+            let stats = stats_repository.get_stats();
+
+            assert_eq!(stats.udp_errors.invalid_connection_id, 1);
+            assert_eq!(stats.tcp_errors.invalid_connection_id, 0);
+        }
+
+        #[test]
+        fn should_record_the_request_latency_when_it_receives_a_latency_event() {
+            let stats_repository = Repo::new();
+
+            event_handler(
+                Event::Latency {
+                    request: RequestType::HttpScrape,
+                    latency: Duration::from_micros(300),
+                },
+                &stats_repository,
+            );
+
+            let stats = stats_repository.get_stats();
+
+            assert_eq!(stats.http_scrape_latency.count, 1);
+            assert_eq!(stats.http_scrape_latency.sum_us, 300);
+            assert_eq!(stats.http_announce_latency.count, 0);
+        }
+    }
+
+    mod rolling_counter {
+        use crate::core::statistics::{RollingCounter, ROLLING_WINDOW_SECS};
+
+        #[test]
+        fn it_should_count_the_requests_in_the_window() {
+            let counter = RollingCounter::default();
+
+            counter.increase(1_000);
+            counter.increase(1_000);
+            counter.increase(1_030);
+            counter.increase(1_100);
+
+            assert_eq!(counter.count(1_100, 1), 1);
+            assert_eq!(counter.count(1_100, 60), 1);
+            assert_eq!(counter.count(1_100, 71), 2);
+            assert_eq!(counter.count(1_100, 300), 4);
+        }
+
+        #[test]
+        fn it_should_forget_the_requests_older_than_the_longest_window() {
+            let counter = RollingCounter::default();
+
+            counter.increase(1_000);
+            counter.increase(1_000 + ROLLING_WINDOW_SECS);
+
+            assert_eq!(counter.count(1_000 + ROLLING_WINDOW_SECS, ROLLING_WINDOW_SECS), 1);
+        }
+
+        #[test]
+        fn it_should_not_count_requests_from_a_reused_slot_after_a_long_period_without_requests() {
+            let counter = RollingCounter::default();
+
+            counter.increase(1_000);
+            counter.increase(1_000 + 2 * ROLLING_WINDOW_SECS + 1);
+
+            assert_eq!(counter.count(1_000 + 2 * ROLLING_WINDOW_SECS + 1, ROLLING_WINDOW_SECS), 1);
+        }
+
+        #[test]
+        fn it_should_not_count_any_request_when_nothing_has_been_handled_in_the_window() {
+            let counter = RollingCounter::default();
+
+            counter.increase(1_000);
+
+            assert_eq!(counter.count(2_000, 60), 0);
+        }
+
+        #[test]
+        fn it_should_calculate_the_average_requests_per_second() {
+            let counter = RollingCounter::default();
+
+            for _ in 0..120 {
+                counter.increase(1_000);
+            }
+
+            let rate = counter.rate(1_000);
+
+            assert!((rate.last_1m - 2.0).abs() < f64::EPSILON);
+            assert!((rate.last_5m - 0.4).abs() < f64::EPSILON);
+            assert!((rate.last_1h - 120.0 / 3_600.0).abs() < f64::EPSILON);
+        }
+    }
+
+    mod latency_histogram {
+        use std::time::Duration;
+
+        use crate::core::statistics::{LatencyHistogram, LATENCY_BUCKETS_US};
+
+        #[test]
+        fn it_should_put_each_latency_in_the_first_bucket_whose_bound_is_not_lower() {
+            let mut histogram = LatencyHistogram::default();
+
+            histogram.observe(Duration::from_micros(100));
+            histogram.observe(Duration::from_micros(101));
+            histogram.observe(Duration::from_secs(2));
+
+            assert_eq!(histogram.buckets[0], 1);
+            assert_eq!(histogram.buckets[1], 1);
+            assert_eq!(histogram.buckets[LATENCY_BUCKETS_US.len()], 1);
+            assert_eq!(histogram.count, 3);
+            assert_eq!(histogram.sum_us, 2_000_201);
+            assert_eq!(histogram.max_us, 2_000_000);
+        }
+
+        #[test]
+        fn it_should_return_zero_for_any_percentile_when_there_are_no_requests() {
+            assert_eq!(LatencyHistogram::default().percentile(99), 0);
+        }
+
+        #[test]
+        fn it_should_estimate_percentiles_with_the_bucket_upper_bound() {
+            let mut histogram = LatencyHistogram::default();
+
+            for _ in 0..99 {
+                histogram.observe(Duration::from_micros(50));
+            }
+            histogram.observe(Duration::from_millis(20));
+
+            assert_eq!(histogram.percentile(50), 100);
+            assert_eq!(histogram.percentile(99), 100);
+            assert_eq!(histogram.percentile(100), 20_000);
+        }
+
+        #[test]
+        fn it_should_use_the_highest_latency_for_requests_slower_than_the_last_bound() {
+            let mut histogram = LatencyHistogram::default();
+
+            histogram.observe(Duration::from_secs(3));
+
+            assert_eq!(histogram.percentile(99), 3_000_000);
+        }
+    }
+
+    mod persistent_counters {
+        use crate::core::statistics::{ErrorCounters, Metrics, PersistentCounters, Repo};
+
+        #[test]
+        fn it_should_restore_the_persisted_counters() {
+            let metrics = Metrics {
+                udp4_announces_handled: 1,
+                tcp6_scrapes_handled: 2,
+                udp_errors: ErrorCounters {
+                    invalid_connection_id: 3,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            let mut restored = Metrics::default();
+            restored.restore_counters(&metrics.counters());
+
+            assert_eq!(restored, metrics);
+        }
+
+        #[test]
+        fn it_should_ignore_unknown_counters_when_restoring_them() {
+            let mut metrics = Metrics::default();
+
+            metrics.restore_counters(&PersistentCounters::from([
+                ("unknown".to_string(), 1),
+                ("tcp_errors_unknown".to_string(), 1),
+            ]));
+
+            assert_eq!(metrics, Metrics::default());
+        }
+
+        #[tokio::test]
+        async fn it_should_persist_only_the_increments_since_the_last_time() {
+            let repo = Repo::new();
+
+            repo.restore_counters(&PersistentCounters::from([("udp4_connections_handled".to_string(), 10)]))
+                .await;
+
+            repo.increase_udp4_connections();
+
+            let mut saved = PersistentCounters::new();
+            let mut increments = PersistentCounters::new();
+
+            repo.persist_counters::<()>(|c, i| {
+                saved.clone_from(c);
+                increments.clone_from(i);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+            assert_eq!(saved["udp4_connections_handled"], 11);
+            assert_eq!(
+                increments,
+                PersistentCounters::from([("udp4_connections_handled".to_string(), 1)])
+            );
+
+            repo.persist_counters::<()>(|_, i| {
+                increments.clone_from(i);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+            assert!(increments.is_empty());
+        }
+
+        #[tokio::test]
+        async fn it_should_keep_the_increments_when_they_can_not_be_persisted() {
+            let repo = Repo::new();
+
+            repo.increase_tcp4_announces();
+
+            let result = repo.persist_counters(|_, _| Err(())).await;
+
+            assert!(result.is_err());
+
+            let mut increments = PersistentCounters::new();
+
+            repo.persist_counters::<()>(|_, i| {
+                increments.clone_from(i);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+            assert_eq!(
+                increments,
+                PersistentCounters::from([("tcp4_announces_handled".to_string(), 1)])
+            );
+        }
     }
 }
@@ -0,0 +1,381 @@
+//! Per-torrent activity counters used to rank the torrents.
+//!
+//! The tracker keeps a few counters for each torrent announced since it
+//! started, so it can return the most active torrents without visiting all the
+//! swarms:
+//!
+//! - The number of seeders, leechers and completed downloads. They are updated
+//!   on every `announce` request and when the inactive peers are removed.
+//! - The `announce` request rate.
+//! - The trending score: the completed downloads with time decay. Each
+//!   completed download adds one point and the points lose half of their value
+//!   every [`TRENDING_HALF_LIFE`].
+//!
+//! Both the request rate and the trending score are kept as exponentially
+//! decayed counters, so they only need two numbers per torrent.
+use std::cmp::Ordering;
+use std::f64::consts::LN_2;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use bittorrent_primitives::info_hash::InfoHash;
+use dashmap::DashMap;
+use thiserror::Error;
+use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
+use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+/// How long it takes for an `announce` request to count half in the
+/// [`TorrentActivity::announce_rate`].
+pub const ANNOUNCE_RATE_HALF_LIFE: Duration = Duration::from_secs(300);
+
+/// How long it takes for a completed download to count half in the
+/// [`TorrentActivity::trending_score`].
+pub const TRENDING_HALF_LIFE: Duration = Duration::from_secs(6 * 3_600);
+
+/// The criteria used to rank the torrents.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Ranking {
+    /// Number of active peers with a full copy of the torrent.
+    Seeders,
+    /// Number of active peers downloading the torrent.
+    Leechers,
+    /// Number of peers that have ever completed the download.
+    Completed,
+    /// Current number of `announce` requests per second.
+    AnnounceRate,
+    /// Recently completed downloads. See [`TorrentActivity::trending_score`].
+    Trending,
+}
+
+impl Ranking {
+    pub const ALL: [Ranking; 5] = [
+        Ranking::Seeders,
+        Ranking::Leechers,
+        Ranking::Completed,
+        Ranking::AnnounceRate,
+        Ranking::Trending,
+    ];
+}
+
+impl fmt::Display for Ranking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Ranking::Seeders => write!(f, "seeders"),
+            Ranking::Leechers => write!(f, "leechers"),
+            Ranking::Completed => write!(f, "completed"),
+            Ranking::AnnounceRate => write!(f, "announce_rate"),
+            Ranking::Trending => write!(f, "trending"),
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("unknown ranking {0}, expected one of: seeders, leechers, completed, announce_rate, trending")]
+pub struct ParseRankingError(String);
+
+impl FromStr for Ranking {
+    type Err = ParseRankingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ranking::ALL
+            .into_iter()
+            .find(|ranking| ranking.to_string() == s)
+            .ok_or_else(|| ParseRankingError(s.to_string()))
+    }
+}
+
+/// A counter whose value halves every `half_life`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+struct DecayedCounter {
+    value: f64,
+    /// When the value was last updated, in seconds since Unix Epoch.
+    updated_at: f64,
+}
+
+impl DecayedCounter {
+    fn value_at(&self, now: DurationSinceUnixEpoch, half_life: Duration) -> f64 {
+        let elapsed = (now.as_secs_f64() - self.updated_at).max(0.0);
+
+        self.value * (-elapsed * LN_2 / half_life.as_secs_f64()).exp()
+    }
+
+    fn increase(&mut self, now: DurationSinceUnixEpoch, half_life: Duration) {
+        self.value = self.value_at(now, half_life) + 1.0;
+        self.updated_at = self.updated_at.max(now.as_secs_f64());
+    }
+}
+
+/// The activity counters of one torrent.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Activity {
+    /// The swarm metadata after the last update.
+    pub swarm_metadata: SwarmMetadata,
+    announces: DecayedCounter,
+    completions: DecayedCounter,
+}
+
+impl Activity {
+    /// The current number of `announce` requests per second.
+    ///
+    /// A torrent receiving a steady number of requests per second gets that
+    /// rate. When the requests stop, the rate halves every
+    /// [`ANNOUNCE_RATE_HALF_LIFE`].
+    #[must_use]
+    pub fn announce_rate(&self, now: DurationSinceUnixEpoch) -> f64 {
+        self.announces.value_at(now, ANNOUNCE_RATE_HALF_LIFE) * LN_2 / ANNOUNCE_RATE_HALF_LIFE.as_secs_f64()
+    }
+
+    /// The completed downloads, where each download loses half of its value
+    /// every [`TRENDING_HALF_LIFE`].
+    #[must_use]
+    pub fn trending_score(&self, now: DurationSinceUnixEpoch) -> f64 {
+        self.completions.value_at(now, TRENDING_HALF_LIFE)
+    }
+
+    fn score(&self, ranking: Ranking, now: DurationSinceUnixEpoch) -> f64 {
+        match ranking {
+            Ranking::Seeders => f64::from(self.swarm_metadata.complete),
+            Ranking::Leechers => f64::from(self.swarm_metadata.incomplete),
+            Ranking::Completed => f64::from(self.swarm_metadata.downloaded),
+            Ranking::AnnounceRate => self.announce_rate(now),
+            Ranking::Trending => self.trending_score(now),
+        }
+    }
+}
+
+/// The activity of a torrent at a given time.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TorrentActivity {
+    pub info_hash: InfoHash,
+    pub seeders: u64,
+    pub completed: u64,
+    pub leechers: u64,
+    /// See [`Activity::announce_rate`].
+    pub announce_rate: f64,
+    /// See [`Activity::trending_score`].
+    pub trending_score: f64,
+}
+
+impl TorrentActivity {
+    fn new(info_hash: InfoHash, activity: &Activity, now: DurationSinceUnixEpoch) -> Self {
+        Self {
+            info_hash,
+            seeders: u64::from(activity.swarm_metadata.complete),
+            completed: u64::from(activity.swarm_metadata.downloaded),
+            leechers: u64::from(activity.swarm_metadata.incomplete),
+            announce_rate: activity.announce_rate(now),
+            trending_score: activity.trending_score(now),
+        }
+    }
+}
+
+/// The activity counters for all the torrents.
+#[derive(Debug, Default)]
+pub struct Activities {
+    torrents: DashMap<InfoHash, Activity>,
+}
+
+impl Activities {
+    /// It records an `announce` request. `before` and `after` are the swarm
+    /// metadata before and after adding the peer to the swarm.
+    pub fn record_announce(
+        &self,
+        info_hash: &InfoHash,
+        before: &SwarmMetadata,
+        after: &SwarmMetadata,
+        now: DurationSinceUnixEpoch,
+    ) {
+        let mut activity = self.torrents.entry(*info_hash).or_default();
+
+        activity.swarm_metadata = *after;
+        activity.announces.increase(now, ANNOUNCE_RATE_HALF_LIFE);
+
+        if after.downloaded > before.downloaded {
+            activity.completions.increase(now, TRENDING_HALF_LIFE);
+        }
+    }
+
+    /// It updates the swarm metadata of all the torrents with the function
+    /// `swarm_metadata`. Torrents for which it returns `None` are removed.
+    pub fn update_swarm_metadata(&self, swarm_metadata: impl Fn(&InfoHash) -> Option<SwarmMetadata>) {
+        self.torrents.retain(|info_hash, activity| match swarm_metadata(info_hash) {
+            Some(swarm_metadata) => {
+                activity.swarm_metadata = swarm_metadata;
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// It removes the activity counters of a torrent.
+    pub fn remove(&self, info_hash: &InfoHash) {
+        self.torrents.remove(info_hash);
+    }
+
+    #[must_use]
+    pub fn get(&self, info_hash: &InfoHash) -> Option<Activity> {
+        self.torrents.get(info_hash).map(|activity| *activity)
+    }
+
+    /// It returns the `limit` torrents with the highest score for the
+    /// `ranking`, from the highest. Ties are sorted by info-hash.
+    #[must_use]
+    pub fn top(&self, ranking: Ranking, limit: usize, now: DurationSinceUnixEpoch) -> Vec<TorrentActivity> {
+        let mut scores: Vec<(f64, InfoHash)> = self
+            .torrents
+            .iter()
+            .map(|entry| (entry.value().score(ranking, now), *entry.key()))
+            .collect();
+
+        let by_score = |a: &(f64, InfoHash), b: &(f64, InfoHash)| -> Ordering { b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)) };
+
+        if limit < scores.len() {
+            scores.select_nth_unstable_by(limit, by_score);
+            scores.truncate(limit);
+        }
+
+        scores.sort_unstable_by(by_score);
+
+        scores
+            .into_iter()
+            .filter_map(|(_, info_hash)| {
+                self.get(&info_hash)
+                    .map(|activity| TorrentActivity::new(info_hash, &activity, now))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use bittorrent_primitives::info_hash::InfoHash;
+    use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
+    use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+    use super::{Activities, Ranking, ANNOUNCE_RATE_HALF_LIFE};
+
+    fn info_hash(n: u8) -> InfoHash {
+        InfoHash::from([n; 20])
+    }
+
+    fn swarm_metadata(complete: u32, downloaded: u32, incomplete: u32) -> SwarmMetadata {
+        SwarmMetadata {
+            complete,
+            downloaded,
+            incomplete,
+        }
+    }
+
+    fn at(secs: u64) -> DurationSinceUnixEpoch {
+        DurationSinceUnixEpoch::from_secs(secs)
+    }
+
+    #[test]
+    fn it_should_parse_the_rankings() {
+        for ranking in Ranking::ALL {
+            assert_eq!(Ranking::from_str(&ranking.to_string()), Ok(ranking));
+        }
+
+        assert!(Ranking::from_str("peers").is_err());
+    }
+
+    #[test]
+    fn it_should_rank_the_torrents_by_the_swarm_metadata() {
+        let activities = Activities::default();
+
+        activities.record_announce(&info_hash(1), &SwarmMetadata::zeroed(), &swarm_metadata(1, 0, 5), at(0));
+        activities.record_announce(&info_hash(2), &SwarmMetadata::zeroed(), &swarm_metadata(3, 0, 1), at(0));
+        activities.record_announce(&info_hash(3), &SwarmMetadata::zeroed(), &swarm_metadata(2, 0, 0), at(0));
+
+        let top = activities.top(Ranking::Seeders, 2, at(0));
+
+        assert_eq!(
+            top.iter().map(|torrent| torrent.info_hash).collect::<Vec<_>>(),
+            vec![info_hash(2), info_hash(3)]
+        );
+
+        let top = activities.top(Ranking::Leechers, 10, at(0));
+
+        assert_eq!(
+            top.iter().map(|torrent| torrent.info_hash).collect::<Vec<_>>(),
+            vec![info_hash(1), info_hash(2), info_hash(3)]
+        );
+    }
+
+    #[test]
+    fn it_should_sort_the_torrents_with_the_same_score_by_info_hash() {
+        let activities = Activities::default();
+
+        activities.record_announce(&info_hash(2), &SwarmMetadata::zeroed(), &swarm_metadata(1, 0, 0), at(0));
+        activities.record_announce(&info_hash(1), &SwarmMetadata::zeroed(), &swarm_metadata(1, 0, 0), at(0));
+
+        let top = activities.top(Ranking::Seeders, 10, at(0));
+
+        assert_eq!(top[0].info_hash, info_hash(1));
+    }
+
+    #[test]
+    fn the_announce_rate_should_halve_every_half_life_without_requests() {
+        let activities = Activities::default();
+
+        activities.record_announce(&info_hash(1), &SwarmMetadata::zeroed(), &SwarmMetadata::zeroed(), at(1_000));
+
+        let rate = activities.get(&info_hash(1)).unwrap().announce_rate(at(1_000));
+        let later = at(1_000) + ANNOUNCE_RATE_HALF_LIFE;
+
+        assert!((activities.get(&info_hash(1)).unwrap().announce_rate(later) - rate / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn the_announce_rate_should_approach_the_steady_request_rate() {
+        let activities = Activities::default();
+
+        // One request every 10 seconds for two hours
+        for second in (0..7_200).step_by(10) {
+            activities.record_announce(&info_hash(1), &SwarmMetadata::zeroed(), &SwarmMetadata::zeroed(), at(second));
+        }
+
+        let rate = activities.get(&info_hash(1)).unwrap().announce_rate(at(7_190));
+
+        assert!((rate - 0.1).abs() < 0.01);
+    }
+
+    #[test]
+    fn the_trending_score_should_favour_the_recently_completed_downloads() {
+        let activities = Activities::default();
+
+        // Two downloads completed a day ago
+        activities.record_announce(&info_hash(1), &swarm_metadata(0, 0, 1), &swarm_metadata(1, 1, 0), at(0));
+        activities.record_announce(&info_hash(1), &swarm_metadata(1, 1, 1), &swarm_metadata(2, 2, 0), at(0));
+
+        // One download completed now
+        let now = at(0) + Duration::from_secs(86_400);
+        activities.record_announce(&info_hash(2), &swarm_metadata(0, 0, 1), &swarm_metadata(1, 1, 0), now);
+
+        let top = activities.top(Ranking::Trending, 10, now);
+
+        assert_eq!(top[0].info_hash, info_hash(2));
+        assert!((top[0].trending_score - 1.0).abs() < 1e-9);
+        // A day is four half-lives
+        assert!((top[1].trending_score - 2.0 / 16.0).abs() < 1e-9);
+        assert_eq!(top[1].completed, 2);
+    }
+
+    #[test]
+    fn it_should_remove_the_torrents_without_swarm_metadata_when_updating_it() {
+        let activities = Activities::default();
+
+        activities.record_announce(&info_hash(1), &SwarmMetadata::zeroed(), &swarm_metadata(1, 0, 0), at(0));
+        activities.record_announce(&info_hash(2), &SwarmMetadata::zeroed(), &swarm_metadata(1, 0, 0), at(0));
+
+        activities
+            .update_swarm_metadata(|info_hash_to_update| (*info_hash_to_update == info_hash(1)).then(|| swarm_metadata(0, 0, 0)));
+
+        assert_eq!(activities.get(&info_hash(1)).unwrap().swarm_metadata, swarm_metadata(0, 0, 0));
+        assert!(activities.get(&info_hash(2)).is_none());
+    }
+}
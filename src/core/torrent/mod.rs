@@ -25,6 +25,15 @@
 //! - The number of peers that have NOT completed downloading the torrent and are still active, that means they are actively participating in the network.
 //!   Peer that don not have a full copy of the torrent data are called "leechers".
 //!
+//! Besides the swarms, the tracker keeps some [`activity`] counters for each
+//! torrent, which are used to rank the torrents.
+//!
+pub mod activity;
+pub mod peer_index;
+
 use torrust_tracker_torrent_repository::TorrentsSkipMapMutexStd;
 
 pub type Torrents = TorrentsSkipMapMutexStd; // Currently Used
+
+/// The name of the [`Torrents`] repository implementation.
+pub const TORRENTS_REPOSITORY: &str = "skip_map_mutex_std";
@@ -0,0 +1,519 @@
+//! A secondary index to search the peers in all the swarms by IP address or
+//! peer ID.
+//!
+//! The swarms are stored by info-hash, so finding the torrents a peer is in
+//! would require going through all of them. The [`PeerIndex`] keeps the
+//! torrents each IP address and each peer ID has announced, sorted, so that an
+//! IP network ([`IpNetwork`]) or a peer ID prefix ([`PeerIdPrefix`]) is a range
+//! in the index.
+//!
+//! The index is updated when the peers announce, but not when they are removed
+//! from the swarms. It can contain peers that are not in the swarms anymore,
+//! or with an old IP address, so the candidates it returns have to be checked
+//! against the swarms. Those entries are removed from the index when the
+//! inactive peers are removed from the swarms.
+//!
+//! The index is split into shards by info-hash, so the announces for different
+//! torrents seldom wait for each other, and the cleanup only locks one shard
+//! at a time.
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+
+use aquatic_udp_protocol::PeerId;
+use bittorrent_primitives::info_hash::InfoHash;
+use thiserror::Error;
+use torrust_tracker_primitives::peer;
+
+/// A range of IP addresses in CIDR notation. For example: `192.168.1.0/24`.
+///
+/// An address without the prefix length is a network with only that address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpNetwork {
+    first: IpAddr,
+    last: IpAddr,
+}
+
+impl IpNetwork {
+    /// The first address in the network.
+    #[must_use]
+    pub fn first(&self) -> IpAddr {
+        self.first
+    }
+
+    /// The last address in the network.
+    #[must_use]
+    pub fn last(&self) -> IpAddr {
+        self.last
+    }
+
+    #[must_use]
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        self.first <= *ip && *ip <= self.last
+    }
+}
+
+impl From<IpAddr> for IpNetwork {
+    fn from(ip: IpAddr) -> Self {
+        Self { first: ip, last: ip }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseIpNetworkError {
+    #[error("invalid IP address {0}")]
+    InvalidAddress(String),
+
+    #[error("invalid prefix length {0}, expected at most 32 for IPv4 and 128 for IPv6")]
+    InvalidPrefixLength(String),
+}
+
+impl FromStr for IpNetwork {
+    type Err = ParseIpNetworkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_length) = match s.split_once('/') {
+            Some((address, prefix_length)) => (address, Some(prefix_length)),
+            None => (s, None),
+        };
+
+        let address: IpAddr = address
+            .parse()
+            .map_err(|_| ParseIpNetworkError::InvalidAddress(address.to_string()))?;
+
+        let max_prefix_length = if address.is_ipv4() { 32 } else { 128 };
+
+        let prefix_length = match prefix_length {
+            Some(prefix_length) => prefix_length
+                .parse::<u32>()
+                .ok()
+                .filter(|prefix_length| *prefix_length <= max_prefix_length)
+                .ok_or_else(|| ParseIpNetworkError::InvalidPrefixLength(prefix_length.to_string()))?,
+            None => max_prefix_length,
+        };
+
+        let (first, last) = match address {
+            IpAddr::V4(address) => {
+                let mask = u32::MAX.checked_shl(32 - prefix_length).unwrap_or(0);
+                let first = u32::from(address) & mask;
+                (IpAddr::V4(Ipv4Addr::from(first)), IpAddr::V4(Ipv4Addr::from(first | !mask)))
+            }
+            IpAddr::V6(address) => {
+                let mask = u128::MAX.checked_shl(128 - prefix_length).unwrap_or(0);
+                let first = u128::from(address) & mask;
+                (IpAddr::V6(Ipv6Addr::from(first)), IpAddr::V6(Ipv6Addr::from(first | !mask)))
+            }
+        };
+
+        Ok(Self { first, last })
+    }
+}
+
+/// The first bytes of a peer ID. Up to the 20 bytes of a full peer ID.
+///
+/// Most clients start the peer ID with their name and version. For example:
+/// `-qB4410-` for qBittorrent 4.4.1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerIdPrefix(Vec<u8>);
+
+impl PeerIdPrefix {
+    /// It returns `None` if the prefix is longer than a peer ID.
+    #[must_use]
+    pub fn new(bytes: &[u8]) -> Option<Self> {
+        (bytes.len() <= 20).then(|| Self(bytes.to_vec()))
+    }
+
+    /// The first peer ID with the prefix.
+    #[must_use]
+    pub fn first(&self) -> PeerId {
+        self.padded_with(u8::MIN)
+    }
+
+    /// The last peer ID with the prefix.
+    #[must_use]
+    pub fn last(&self) -> PeerId {
+        self.padded_with(u8::MAX)
+    }
+
+    #[must_use]
+    pub fn matches(&self, peer_id: &PeerId) -> bool {
+        peer_id.0.starts_with(&self.0)
+    }
+
+    fn padded_with(&self, byte: u8) -> PeerId {
+        let mut bytes = [byte; 20];
+        bytes[..self.0.len()].copy_from_slice(&self.0);
+        PeerId(bytes)
+    }
+}
+
+/// The criteria to search peers in all the swarms. The peers have to meet all
+/// of them. A search without criteria matches all the peers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeerSearch {
+    /// The network the peer IP address belongs to.
+    pub network: Option<IpNetwork>,
+    /// The prefix of the peer ID. A full peer ID only matches that peer ID.
+    pub peer_id_prefix: Option<PeerIdPrefix>,
+}
+
+impl PeerSearch {
+    #[must_use]
+    pub fn matches(&self, peer: &peer::Peer) -> bool {
+        self.network.map_or(true, |network| network.contains(&peer.peer_addr.ip()))
+            && self
+                .peer_id_prefix
+                .as_ref()
+                .map_or(true, |prefix| prefix.matches(&peer.peer_id))
+    }
+}
+
+/// A peer found in a swarm.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeerMatch {
+    pub info_hash: InfoHash,
+    pub peer: Arc<peer::Peer>,
+}
+
+/// The number of [`Shard`]s the index is split into.
+const SHARDS: usize = 64;
+
+/// The torrents announced by each IP address and each peer ID.
+#[derive(Debug)]
+pub struct PeerIndex {
+    shards: Box<[RwLock<Shard>]>,
+}
+
+/// The peers of the torrents whose info-hash falls in the shard. Both maps are
+/// behind the same lock, so they always contain the same peers.
+#[derive(Debug, Default)]
+struct Shard {
+    by_ip: BTreeMap<IpAddr, HashSet<(InfoHash, PeerId)>>,
+    by_peer_id: BTreeMap<PeerId, HashSet<InfoHash>>,
+}
+
+impl Default for PeerIndex {
+    fn default() -> Self {
+        Self {
+            shards: (0..SHARDS).map(|_| RwLock::default()).collect(),
+        }
+    }
+}
+
+impl PeerIndex {
+    /// It adds a peer in a swarm to the index.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the index lock is poisoned.
+    pub fn insert(&self, info_hash: &InfoHash, peer: &peer::Peer) {
+        let ip = peer.peer_addr.ip();
+        let key = (*info_hash, peer.peer_id);
+        let shard = self.shard(info_hash);
+
+        // Most announces come from peers already indexed.
+        if shard
+            .read()
+            .expect("it should read the peer index")
+            .by_ip
+            .get(&ip)
+            .is_some_and(|keys| keys.contains(&key))
+        {
+            return;
+        }
+
+        let mut shard = shard.write().expect("it should update the peer index");
+
+        shard.by_ip.entry(ip).or_default().insert(key);
+        shard.by_peer_id.entry(peer.peer_id).or_default().insert(*info_hash);
+    }
+
+    /// It returns the torrents and peer IDs in the index that may match the
+    /// search, sorted.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the index lock is poisoned.
+    #[must_use]
+    pub fn candidates(&self, search: &PeerSearch) -> BTreeSet<(InfoHash, PeerId)> {
+        let matches_peer_id = |peer_id: &PeerId| search.peer_id_prefix.as_ref().map_or(true, |prefix| prefix.matches(peer_id));
+
+        let mut candidates = BTreeSet::new();
+
+        for shard in &self.shards {
+            let shard = shard.read().expect("it should read the peer index");
+
+            if let Some(network) = search.network {
+                candidates.extend(
+                    shard
+                        .by_ip
+                        .range(network.first()..=network.last())
+                        .flat_map(|(_, keys)| keys.iter().copied())
+                        .filter(|(_, peer_id)| matches_peer_id(peer_id)),
+                );
+
+                continue;
+            }
+
+            let entries: Box<dyn Iterator<Item = (&PeerId, &HashSet<InfoHash>)>> = match &search.peer_id_prefix {
+                Some(prefix) => Box::new(shard.by_peer_id.range(prefix.first()..=prefix.last())),
+                None => Box::new(shard.by_peer_id.iter()),
+            };
+
+            candidates.extend(
+                entries.flat_map(|(peer_id, info_hashes)| info_hashes.iter().map(move |info_hash| (*info_hash, *peer_id))),
+            );
+        }
+
+        candidates
+    }
+
+    /// It removes the peers that are not in the swarms anymore, or whose IP
+    /// address has changed. `peer_ip` returns the IP address of the peer in
+    /// the swarm, if the peer is still in it.
+    ///
+    /// The swarms have to be updated before the index when the peers announce.
+    /// Otherwise, a peer that announces while its shard is being cleaned could
+    /// be removed from the index while it's in the swarm.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the index lock is poisoned.
+    pub fn retain_active_peers(&self, peer_ip: impl Fn(&InfoHash, &PeerId) -> Option<IpAddr>) {
+        for shard in &self.shards {
+            let mut shard = shard.write().expect("it should update the peer index");
+
+            let mut removed = vec![];
+
+            shard.by_ip.retain(|ip, keys| {
+                keys.retain(|(info_hash, peer_id)| {
+                    let current_ip = peer_ip(info_hash, peer_id);
+
+                    if current_ip.is_none() {
+                        removed.push((*info_hash, *peer_id));
+                    }
+
+                    current_ip == Some(*ip)
+                });
+                !keys.is_empty()
+            });
+
+            for (info_hash, peer_id) in removed {
+                if let Some(info_hashes) = shard.by_peer_id.get_mut(&peer_id) {
+                    info_hashes.remove(&info_hash);
+
+                    if info_hashes.is_empty() {
+                        shard.by_peer_id.remove(&peer_id);
+                    }
+                }
+            }
+        }
+    }
+
+    fn shard(&self, info_hash: &InfoHash) -> &RwLock<Shard> {
+        &self.shards[usize::from(info_hash.0[0]) % self.shards.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::str::FromStr;
+
+    use aquatic_udp_protocol::PeerId;
+    use bittorrent_primitives::info_hash::InfoHash;
+    use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+    use torrust_tracker_primitives::peer::Peer;
+
+    use super::{IpNetwork, ParseIpNetworkError, PeerIdPrefix, PeerIndex, PeerSearch};
+
+    fn peer(peer_id: &[u8; 20], ip: IpAddr) -> Peer {
+        PeerBuilder::default()
+            .with_peer_id(&PeerId(*peer_id))
+            .with_peer_addr(&SocketAddr::new(ip, 8080))
+            .build()
+    }
+
+    fn info_hash(hex: &str) -> InfoHash {
+        InfoHash::from_str(hex).unwrap()
+    }
+
+    #[test]
+    fn an_ip_network_should_be_parsed_from_the_cidr_notation() {
+        let network = IpNetwork::from_str("126.0.1.77/16").unwrap();
+
+        assert_eq!(network.first(), IpAddr::V4(Ipv4Addr::new(126, 0, 0, 0)));
+        assert_eq!(network.last(), IpAddr::V4(Ipv4Addr::new(126, 0, 255, 255)));
+
+        let network = IpNetwork::from_str("2001:db8::/32").unwrap();
+
+        assert_eq!(network.first(), IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)));
+        assert_eq!(
+            network.last(),
+            IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff, 0xffff))
+        );
+
+        let network = IpNetwork::from_str("0.0.0.0/0").unwrap();
+
+        assert!(network.contains(&IpAddr::V4(Ipv4Addr::new(255, 255, 255, 255))));
+        assert!(!network.contains(&IpAddr::V6(Ipv6Addr::LOCALHOST)));
+    }
+
+    #[test]
+    fn an_ip_address_should_be_a_network_with_only_that_address() {
+        let network = IpNetwork::from_str("126.0.0.1").unwrap();
+
+        assert_eq!(network.first(), IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)));
+        assert_eq!(network.last(), IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)));
+    }
+
+    #[test]
+    fn an_ip_network_should_not_be_parsed_from_an_invalid_address_or_prefix_length() {
+        assert_eq!(
+            IpNetwork::from_str("126.0.0/8"),
+            Err(ParseIpNetworkError::InvalidAddress("126.0.0".to_string()))
+        );
+        assert_eq!(
+            IpNetwork::from_str("126.0.0.0/33"),
+            Err(ParseIpNetworkError::InvalidPrefixLength("33".to_string()))
+        );
+        assert_eq!(
+            IpNetwork::from_str("::/x"),
+            Err(ParseIpNetworkError::InvalidPrefixLength("x".to_string()))
+        );
+    }
+
+    #[test]
+    fn a_peer_id_prefix_should_match_the_peer_ids_starting_with_it() {
+        let prefix = PeerIdPrefix::new(b"-qB").unwrap();
+
+        assert!(prefix.matches(&PeerId(*b"-qB00000000000000001")));
+        assert!(!prefix.matches(&PeerId(*b"-TR00000000000000001")));
+        assert!(PeerIdPrefix::new(&[0u8; 21]).is_none());
+    }
+
+    #[test]
+    fn the_index_should_return_the_peers_in_an_ip_network() {
+        let index = PeerIndex::default();
+
+        let info_hash_1 = info_hash("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d");
+        let info_hash_2 = info_hash("0b3aea4adc213ce32295be85d3883a63bca25446");
+
+        let peer_1 = peer(b"-qB00000000000000001", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)));
+        let peer_2 = peer(b"-TR00000000000000002", IpAddr::V4(Ipv4Addr::new(126, 0, 1, 2)));
+        let peer_3 = peer(b"-qB00000000000000003", IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
+
+        index.insert(&info_hash_1, &peer_1);
+        index.insert(&info_hash_2, &peer_1);
+        index.insert(&info_hash_1, &peer_2);
+        index.insert(&info_hash_1, &peer_3);
+
+        let search = PeerSearch {
+            network: Some(IpNetwork::from_str("126.0.0.0/16").unwrap()),
+            peer_id_prefix: None,
+        };
+
+        let candidates = index.candidates(&search);
+
+        assert_eq!(candidates.len(), 3);
+        assert!(candidates.contains(&(info_hash_1, peer_1.peer_id)));
+        assert!(candidates.contains(&(info_hash_2, peer_1.peer_id)));
+        assert!(candidates.contains(&(info_hash_1, peer_2.peer_id)));
+    }
+
+    #[test]
+    fn the_index_should_return_the_peers_with_a_peer_id_prefix() {
+        let index = PeerIndex::default();
+
+        let info_hash = info_hash("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d");
+
+        let peer_1 = peer(b"-qB00000000000000001", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)));
+        let peer_2 = peer(b"-TR00000000000000002", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 2)));
+
+        index.insert(&info_hash, &peer_1);
+        index.insert(&info_hash, &peer_2);
+
+        let search = PeerSearch {
+            network: Some(IpNetwork::from_str("126.0.0.0/24").unwrap()),
+            peer_id_prefix: Some(PeerIdPrefix::new(b"-qB").unwrap()),
+        };
+
+        assert_eq!(
+            index.candidates(&search).into_iter().collect::<Vec<_>>(),
+            vec![(info_hash, peer_1.peer_id)]
+        );
+
+        let search = PeerSearch {
+            network: None,
+            peer_id_prefix: Some(PeerIdPrefix::new(b"-TR").unwrap()),
+        };
+
+        assert_eq!(
+            index.candidates(&search).into_iter().collect::<Vec<_>>(),
+            vec![(info_hash, peer_2.peer_id)]
+        );
+    }
+
+    #[test]
+    fn the_index_should_forget_the_peers_that_are_not_in_the_swarms_anymore() {
+        let index = PeerIndex::default();
+
+        let info_hash = info_hash("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d");
+
+        let active_peer = peer(b"-qB00000000000000001", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)));
+        let removed_peer = peer(b"-qB00000000000000002", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 2)));
+        let moved_peer = peer(b"-qB00000000000000003", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 3)));
+
+        index.insert(&info_hash, &active_peer);
+        index.insert(&info_hash, &removed_peer);
+        index.insert(&info_hash, &moved_peer);
+
+        let moved_peer = peer(b"-qB00000000000000003", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 4)));
+
+        index.insert(&info_hash, &moved_peer);
+
+        index.retain_active_peers(|_, peer_id| {
+            [active_peer, moved_peer]
+                .iter()
+                .find(|peer| peer.peer_id == *peer_id)
+                .map(|peer| peer.peer_addr.ip())
+        });
+
+        assert_eq!(
+            index.candidates(&PeerSearch::default()).into_iter().collect::<Vec<_>>(),
+            vec![(info_hash, active_peer.peer_id), (info_hash, moved_peer.peer_id)]
+        );
+
+        let search = PeerSearch {
+            network: Some(IpNetwork::from_str("126.0.0.3").unwrap()),
+            peer_id_prefix: None,
+        };
+
+        assert!(index.candidates(&search).is_empty());
+    }
+
+    #[test]
+    fn the_index_should_return_the_peers_of_all_the_torrents_sorted() {
+        let index = PeerIndex::default();
+
+        let info_hash_1 = info_hash("0b3aea4adc213ce32295be85d3883a63bca25446");
+        let info_hash_2 = info_hash("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d");
+
+        let peer_1 = peer(b"-qB00000000000000001", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)));
+        let peer_2 = peer(b"-TR00000000000000002", IpAddr::V4(Ipv4Addr::new(126, 0, 0, 2)));
+
+        index.insert(&info_hash_2, &peer_1);
+        index.insert(&info_hash_1, &peer_2);
+        index.insert(&info_hash_1, &peer_1);
+
+        assert_eq!(
+            index.candidates(&PeerSearch::default()).into_iter().collect::<Vec<_>>(),
+            vec![
+                (info_hash_1, peer_2.peer_id),
+                (info_hash_1, peer_1.peer_id),
+                (info_hash_2, peer_1.peer_id)
+            ]
+        );
+    }
+}
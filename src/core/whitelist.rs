@@ -0,0 +1,137 @@
+//! Whitelisted torrents in memory and in the database.
+//!
+//! The tracker checks the whitelist in memory, but it also persists it in the
+//! database. Both lists should contain the same torrents, but they can drift
+//! apart, for example, when the database is changed directly or when the
+//! tracker fails to update one of them. This module compares both lists so
+//! that the drift can be detected and fixed by
+//! [reloading the whitelist](crate::core::Tracker::load_whitelist_from_database).
+use std::collections::{BTreeMap, HashSet};
+use std::hash::BuildHasher;
+
+use bittorrent_primitives::info_hash::InfoHash;
+
+/// A whitelisted torrent and where it is whitelisted.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Entry {
+    pub info_hash: InfoHash,
+    /// The torrent is in the whitelist the tracker uses to allow requests.
+    pub in_memory: bool,
+    /// The torrent is in the persisted whitelist.
+    pub in_database: bool,
+}
+
+impl Entry {
+    /// Returns `true` if the torrent is whitelisted in memory and in the
+    /// database, or in neither of them.
+    #[must_use]
+    pub fn is_synced(&self) -> bool {
+        self.in_memory == self.in_database
+    }
+}
+
+/// The torrents that are only whitelisted in memory or only in the database.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Diff {
+    pub only_in_memory: Vec<InfoHash>,
+    pub only_in_database: Vec<InfoHash>,
+}
+
+/// It merges the whitelist in memory and the whitelist in the database into a
+/// list of entries sorted by info-hash.
+#[must_use]
+pub fn merge<S: BuildHasher>(in_memory: &HashSet<InfoHash, S>, in_database: &[InfoHash]) -> Vec<Entry> {
+    let mut entries: BTreeMap<InfoHash, Entry> = BTreeMap::new();
+
+    for info_hash in in_memory {
+        entries.insert(
+            *info_hash,
+            Entry {
+                info_hash: *info_hash,
+                in_memory: true,
+                in_database: false,
+            },
+        );
+    }
+
+    for info_hash in in_database {
+        entries
+            .entry(*info_hash)
+            .or_insert(Entry {
+                info_hash: *info_hash,
+                in_memory: false,
+                in_database: false,
+            })
+            .in_database = true;
+    }
+
+    entries.into_values().collect()
+}
+
+/// It returns the entries that are not whitelisted in both places.
+#[must_use]
+pub fn diff(entries: &[Entry]) -> Diff {
+    let mut diff = Diff::default();
+
+    for entry in entries.iter().filter(|entry| !entry.is_synced()) {
+        if entry.in_memory {
+            diff.only_in_memory.push(entry.info_hash);
+        } else {
+            diff.only_in_database.push(entry.info_hash);
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use bittorrent_primitives::info_hash::InfoHash;
+
+    use super::{diff, merge, Diff, Entry};
+
+    fn info_hash(byte: u8) -> InfoHash {
+        InfoHash::from([byte; 20])
+    }
+
+    fn entry(byte: u8, in_memory: bool, in_database: bool) -> Entry {
+        Entry {
+            info_hash: info_hash(byte),
+            in_memory,
+            in_database,
+        }
+    }
+
+    #[test]
+    fn it_should_merge_both_whitelists_sorted_by_info_hash() {
+        let in_memory = HashSet::from([info_hash(3), info_hash(1)]);
+        let in_database = vec![info_hash(2), info_hash(1)];
+
+        assert_eq!(
+            merge(&in_memory, &in_database),
+            vec![entry(1, true, true), entry(2, false, true), entry(3, true, false)]
+        );
+    }
+
+    #[test]
+    fn it_should_list_the_entries_whitelisted_only_in_one_place() {
+        let entries = vec![entry(1, true, true), entry(2, false, true), entry(3, true, false)];
+
+        assert_eq!(
+            diff(&entries),
+            Diff {
+                only_in_memory: vec![info_hash(3)],
+                only_in_database: vec![info_hash(2)],
+            }
+        );
+    }
+
+    #[test]
+    fn the_diff_should_be_empty_when_both_whitelists_are_synced() {
+        let in_memory = HashSet::from([info_hash(1)]);
+
+        assert_eq!(diff(&merge(&in_memory, &[info_hash(1)])), Diff::default());
+    }
+}
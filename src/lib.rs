@@ -171,9 +171,14 @@
 //! threshold = "info"
 //!
 //! [core]
+//! clients_breakdown_interval = 60
+//! expired_keys_cleanup_interval = 3600
+//! expired_keys_grace_period = 0
 //! inactive_peer_cleanup_interval = 600
 //! listed = false
+//! overload_announce_rate = 0
 //! private = false
+//! statistics_persistence_interval = 300
 //! tracker_usage_statistics = true
 //!
 //! [core.announce_policy]
@@ -253,7 +258,9 @@
 //! LABEL = "YOUR_TOKEN"
 //! ```
 //!
-//! All tokens give full access the the API. Once you have defined you token you can make request adding the token as a `GET` parameter. For example:
+//! Plain tokens give full access the the API. Tokens can also be restricted to a role and have an expiration time, see
+//! the [`auth`](crate::servers::apis::v1::middlewares::auth) middleware. Once you have defined you token you can make
+//! request adding the token as a `GET` parameter or an `Authorization: Bearer` header. For example:
 //!
 //! <http://127.0.0.1:1212/api/v1/stats?token=MyAccessToken>
 //!
@@ -1,19 +1,78 @@
+use std::sync::Arc;
+
+use torrust_tracker::bootstrap::reload::ConfigReloader;
 use torrust_tracker::{app, bootstrap};
 
 #[tokio::main]
 async fn main() {
     let (config, tracker) = bootstrap::app::setup();
 
-    let jobs = app::start(&config, tracker).await;
+    let reloader = Arc::new(ConfigReloader::new(&config, tracker.clone()));
+
+    let jobs = app::start(&config, tracker, reloader.clone()).await;
+
+    let mut hangup = Hangup::new();
 
     // handle the signals
-    tokio::select! {
-        _ = tokio::signal::ctrl_c() => {
-            tracing::info!("Torrust shutting down ...");
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {
+                tracing::info!("Torrust shutting down ...");
+
+                // Await for all jobs to shutdown
+                futures::future::join_all(jobs).await;
+                tracing::info!("Torrust successfully shutdown.");
+
+                break;
+            }
+            () = hangup.recv() => {
+                tracing::info!("Reloading the configuration ...");
 
-            // Await for all jobs to shutdown
-            futures::future::join_all(jobs).await;
-            tracing::info!("Torrust successfully shutdown.");
+                // Loading the configuration reads the configuration file.
+                let reloader = reloader.clone();
+
+                match tokio::task::spawn_blocking(move || reloader.reload()).await {
+                    Ok(Ok(_)) => {}
+                    Ok(Err(err)) => tracing::error!("Unable to reload the configuration: {err}"),
+                    Err(err) => tracing::error!("Unable to reload the configuration: {err}"),
+                }
+            }
         }
     }
 }
+
+/// The `SIGHUP` signal, used to reload the configuration.
+///
+/// The signal handler is installed once, so the signals received while the
+/// configuration is being reloaded are not lost.
+#[cfg(unix)]
+struct Hangup(tokio::signal::unix::Signal);
+
+#[cfg(unix)]
+impl Hangup {
+    fn new() -> Self {
+        Self(
+            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())
+                .expect("Failed to install the SIGHUP signal handler"),
+        )
+    }
+
+    async fn recv(&mut self) {
+        self.0.recv().await;
+    }
+}
+
+/// There is no `SIGHUP` signal on other platforms.
+#[cfg(not(unix))]
+struct Hangup;
+
+#[cfg(not(unix))]
+impl Hangup {
+    fn new() -> Self {
+        Self
+    }
+
+    async fn recv(&mut self) {
+        std::future::pending::<()>().await;
+    }
+}
@@ -3,7 +3,7 @@
 //! > **NOTICE**: This API should not be exposed directly to the internet, it is
 //! > intended for internal use only.
 //!
-//! Endpoints for the latest API: [v1].
+//! Endpoints for the latest API: [v2]. The [v1] API is still available.
 //!
 //! All endpoints require an authorization token which must be set in the
 //! configuration before running the tracker. The default configuration uses
@@ -85,19 +85,28 @@
 //!
 //! # Authentication
 //!
-//! The API supports authentication using a GET parameter token.
+//! The API supports authentication using a GET parameter token:
 //!
 //! <http://0.0.0.0:1212/api/v1/stats?token=MyAccessToken>
 //!
+//! Or an `Authorization: Bearer` header:
+//!
+//! ```bash
+//! $ curl -s -H "Authorization: Bearer MyAccessToken" "http://0.0.0.0:1212/api/v1/stats"
+//! ```
+//!
 //! You can set as many tokens as you want in the configuration file:
 //!
 //! ```toml
 //! [http_api.access_tokens]
 //! admin = "MyAccessToken"
+//! stats = { token = "MyStatsToken", role = "stats_reader", valid_until = 1767225600 }
 //! ```
 //!
-//! The token label is used to identify the token. All tokens have full access
-//! to the API.
+//! The token label is used to identify the token. Plain tokens have full
+//! access to the API. Tokens with a role (`stats_reader`, `torrent_viewer`,
+//! `key_manager` or `admin`) can only use the endpoints allowed for the role,
+//! and they stop working after the optional `valid_until` unix time.
 //!
 //! Refer to [`torrust-tracker-configuration`](torrust_tracker_configuration)
 //! for more information about the API configuration and to the
@@ -137,14 +146,17 @@
 //!
 //! The API is versioned and each version has its own module.
 //! The API server runs all the API versions on the same server using
-//! the same port. Currently there is only one API version: [v1]
-//! but a version [`v2`](https://github.com/torrust/torrust-tracker/issues/144)
-//! is planned.
+//! the same port. There are two API versions: [v1] and [v2]. Both versions
+//! use the same authentication tokens and resources, but [v2] uses resource
+//! routes and [problem details](crate::servers::apis::v2::responses::Problem)
+//! errors.
 //!
 //! # Endpoints
 //!
-//! Refer to the [v1] module for the list of available
-//! API endpoints.
+//! Refer to the [v1] and [v2] modules for the list of available
+//! API endpoints. The [v2] endpoints are also described in an
+//! [`OpenAPI` document](crate::servers::apis::v2::openapi) served at
+//! `/api/v2/openapi.json`.
 //!
 //! # Documentation
 //!
@@ -156,9 +168,13 @@
 pub mod routes;
 pub mod server;
 pub mod v1;
+pub mod v2;
 
+use aquatic_udp_protocol::PeerId;
 use serde::{Deserialize, Serialize};
 
+use crate::core::torrent::peer_index::PeerIdPrefix;
+
 pub const API_LOG_TARGET: &str = "API";
 
 /// The info hash URL path parameter.
@@ -173,9 +189,108 @@ pub const API_LOG_TARGET: &str = "API";
 #[derive(Deserialize)]
 pub struct InfoHashParam(pub String);
 
+/// The peer ID URL path parameter.
+///
+/// It's the peer ID in the hex format the API uses to show the peers. The `0x`
+/// prefix is optional.
+///
+/// For example: `http://localhost:1212/api/v1/torrent/{info_hash}/peer/0x2d7142343431302d2a64465a3844484944704579`.
+#[derive(Deserialize)]
+pub struct PeerIdParam(pub String);
+
+impl PeerIdParam {
+    /// It returns `None` if the param is not a 20-byte hex string.
+    #[must_use]
+    pub fn peer_id(&self) -> Option<PeerId> {
+        let hex = self.0.strip_prefix("0x").unwrap_or(&self.0);
+
+        if hex.len() != 40 || !hex.is_ascii() {
+            return None;
+        }
+
+        let mut bytes = [0u8; 20];
+
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+        }
+
+        Some(PeerId(bytes))
+    }
+
+    /// The first bytes of a peer ID, in the same hex format. It returns `None`
+    /// if the param is not a hex string with an even number of characters, up
+    /// to 40.
+    #[must_use]
+    pub fn peer_id_prefix(&self) -> Option<PeerIdPrefix> {
+        let hex = self.0.strip_prefix("0x").unwrap_or(&self.0);
+
+        if hex.len() % 2 != 0 || !hex.is_ascii() {
+            return None;
+        }
+
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+
+        PeerIdPrefix::new(&bytes)
+    }
+}
+
 /// The version of the HTTP Api.
 #[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Version {
     /// The `v1` version of the HTTP Api.
     V1,
 }
+
+#[cfg(test)]
+mod tests {
+    use aquatic_udp_protocol::PeerId;
+
+    use super::PeerIdParam;
+    use crate::core::torrent::peer_index::PeerIdPrefix;
+
+    #[test]
+    fn it_should_parse_a_peer_id_param_in_hex_format_with_or_without_prefix() {
+        let peer_id = Some(PeerId(*b"-qB00000000000000000"));
+
+        assert_eq!(
+            PeerIdParam("0x2d71423030303030303030303030303030303030".to_string()).peer_id(),
+            peer_id
+        );
+        assert_eq!(
+            PeerIdParam("2d71423030303030303030303030303030303030".to_string()).peer_id(),
+            peer_id
+        );
+    }
+
+    #[test]
+    fn it_should_reject_peer_id_params_that_are_not_20_bytes_in_hex_format() {
+        for invalid in [
+            "",
+            "0x2d71",
+            "0x2d7142303030303030303030303030303030303z",
+            "-qB00000000000000000",
+        ] {
+            assert_eq!(PeerIdParam(invalid.to_string()).peer_id(), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn it_should_parse_a_peer_id_prefix_param_in_hex_format() {
+        assert_eq!(
+            PeerIdParam("0x2d7142".to_string()).peer_id_prefix(),
+            PeerIdPrefix::new(b"-qB")
+        );
+        assert_eq!(PeerIdParam("2d7142".to_string()).peer_id_prefix(), PeerIdPrefix::new(b"-qB"));
+        assert_eq!(
+            PeerIdParam("2d71423030303030303030303030303030303030".to_string()).peer_id_prefix(),
+            PeerIdPrefix::new(b"-qB00000000000000000")
+        );
+
+        for invalid in ["0x2d714", "2d7z", "2d714230303030303030303030303030303030303030", "-qB"] {
+            assert_eq!(PeerIdParam(invalid.to_string()).peer_id_prefix(), None, "{invalid}");
+        }
+    }
+}
@@ -1,10 +1,14 @@
 //! API routes.
 //!
 //! It loads all the API routes for all API versions and adds the authentication
-//! middleware to them.
+//! and [audit](crate::servers::apis::v1::middlewares::audit) middlewares to them.
 //!
 //! All the API routes have the `/api` prefix and the version number as the
-//! first path segment. For example: `/api/v1/torrents`.
+//! first path segment. For example: `/api/v1/torrents`. Each version has its
+//! own authentication middleware, so the errors have the format of the version.
+//!
+//! The only exception is the Prometheus metrics endpoint which is served at
+//! `/metrics`, the default path used by Prometheus scrapers.
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -14,7 +18,7 @@ use axum::response::Response;
 use axum::routing::get;
 use axum::{middleware, BoxError, Router};
 use hyper::{Request, StatusCode};
-use torrust_tracker_configuration::{AccessTokens, DEFAULT_TIMEOUT};
+use torrust_tracker_configuration::{HttpApi, DEFAULT_TIMEOUT};
 use tower::timeout::TimeoutLayer;
 use tower::ServiceBuilder;
 use tower_http::compression::CompressionLayer;
@@ -23,27 +27,64 @@ use tower_http::request_id::{MakeRequestUuid, SetRequestIdLayer};
 use tower_http::trace::{DefaultMakeSpan, TraceLayer};
 use tracing::{instrument, Level, Span};
 
-use super::v1;
 use super::v1::context::health_check::handlers::health_check_handler;
+use super::v1::context::metrics;
+use super::v1::middlewares::audit::audit;
 use super::v1::middlewares::auth::State;
+use super::v1::middlewares::throttling::AuthThrottle;
+use super::v2::openapi::openapi_handler;
+use super::{v1, v2};
+use crate::bootstrap::reload::ConfigReloader;
 use crate::core::Tracker;
 use crate::servers::apis::API_LOG_TARGET;
+use crate::servers::registar::ServiceRegistry;
 
 /// Add all API routes to the router.
 #[allow(clippy::needless_pass_by_value)]
-#[instrument(skip(tracker, access_tokens))]
-pub fn router(tracker: Arc<Tracker>, access_tokens: Arc<AccessTokens>) -> Router {
+#[instrument(skip(tracker, config, reloader, registry))]
+pub fn router(tracker: Arc<Tracker>, config: Arc<HttpApi>, reloader: Arc<ConfigReloader>, registry: ServiceRegistry) -> Router {
     let router = Router::new();
 
     let api_url_prefix = "/api";
 
-    let router = v1::routes::add(api_url_prefix, router, tracker.clone());
+    let router = v1::routes::add(api_url_prefix, router, tracker.clone(), reloader.clone(), registry.clone());
+
+    // Routes added before the authentication layer require a token.
+    let router = if config.metrics_require_token {
+        metrics::routes::add(router, tracker.clone())
+    } else {
+        router
+    };
+
+    let state = State {
+        access_tokens: reloader.api_access_tokens(),
+        tracker: tracker.clone(),
+        throttle: Arc::new(AuthThrottle::new(config.auth_throttling)),
+        trusted_proxies: config.trusted_proxies.clone().into(),
+    };
+
+    // The audit layer is added before the authentication layer, so it runs
+    // after the request is authenticated.
+    let router = router
+        .layer(middleware::from_fn_with_state(tracker.clone(), audit))
+        .layer(middleware::from_fn_with_state(state.clone(), v1::middlewares::auth::auth));
+
+    let v2_router = v2::routes::add(api_url_prefix, Router::new(), tracker.clone(), reloader, registry)
+        .layer(middleware::from_fn_with_state(tracker.clone(), audit))
+        .layer(middleware::from_fn_with_state(state, v2::middlewares::auth::auth));
+
+    let router = router
+        .merge(v2_router)
+        .route(&format!("{api_url_prefix}/v2/openapi.json"), get(openapi_handler))
+        .route(&format!("{api_url_prefix}/health_check"), get(health_check_handler));
 
-    let state = State { access_tokens };
+    let router = if config.metrics_require_token {
+        router
+    } else {
+        metrics::routes::add(router, tracker)
+    };
 
     router
-        .layer(middleware::from_fn_with_state(state, v1::middlewares::auth::auth))
-        .route(&format!("{api_url_prefix}/health_check"), get(health_check_handler))
         .layer(CompressionLayer::new())
         .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
         .layer(PropagateHeaderLayer::new(HeaderName::from_static("x-request-id")))
@@ -33,16 +33,17 @@ use derive_more::Constructor;
 use futures::future::BoxFuture;
 use thiserror::Error;
 use tokio::sync::oneshot::{Receiver, Sender};
-use torrust_tracker_configuration::AccessTokens;
+use torrust_tracker_configuration::HttpApi;
 use tracing::{instrument, Level};
 
 use super::routes::router;
 use crate::bootstrap::jobs::Started;
+use crate::bootstrap::reload::ConfigReloader;
 use crate::core::Tracker;
 use crate::servers::apis::API_LOG_TARGET;
 use crate::servers::custom_axum_server::{self, TimeoutAcceptor};
 use crate::servers::logging::STARTED_ON;
-use crate::servers::registar::{ServiceHealthCheckJob, ServiceRegistration, ServiceRegistrationForm};
+use crate::servers::registar::{Service, ServiceHealthCheckJob, ServiceRegistration, ServiceRegistrationForm, ServiceRegistry};
 use crate::servers::signals::{graceful_shutdown, Halted};
 
 /// Errors that can occur when starting or stopping the API server.
@@ -122,12 +123,14 @@ impl ApiServer<Stopped> {
     /// # Panics
     ///
     /// It would panic if the bound socket address cannot be sent back to this starter.
-    #[instrument(skip(self, tracker, form, access_tokens), err, ret(Display, level = Level::INFO))]
+    #[instrument(skip(self, tracker, form, config, reloader, registry), err, ret(Display, level = Level::INFO))]
     pub async fn start(
         self,
         tracker: Arc<Tracker>,
         form: ServiceRegistrationForm,
-        access_tokens: Arc<AccessTokens>,
+        config: Arc<HttpApi>,
+        reloader: Arc<ConfigReloader>,
+        registry: ServiceRegistry,
     ) -> Result<ApiServer<Running>, Error> {
         let (tx_start, rx_start) = tokio::sync::oneshot::channel::<Started>();
         let (tx_halt, rx_halt) = tokio::sync::oneshot::channel::<Halted>();
@@ -137,7 +140,7 @@ impl ApiServer<Stopped> {
         let task = tokio::spawn(async move {
             tracing::debug!(target: API_LOG_TARGET, "Starting with launcher in spawned task ...");
 
-            let _task = launcher.start(tracker, access_tokens, tx_start, rx_halt).await;
+            let _task = launcher.start(tracker, config, reloader, registry, tx_start, rx_halt).await;
 
             tracing::debug!(target: API_LOG_TARGET, "Started with launcher in spawned task");
 
@@ -146,7 +149,7 @@ impl ApiServer<Stopped> {
 
         let api_server = match rx_start.await {
             Ok(started) => {
-                form.send(ServiceRegistration::new(started.address, check_fn))
+                form.send(ServiceRegistration::new(started.address, Service::HttpApi, check_fn))
                     .expect("it should be able to send service registration");
 
                 ApiServer {
@@ -235,15 +238,17 @@ impl Launcher {
     ///
     /// Will panic if unable to bind to the socket, or unable to get the address of the bound socket.
     /// Will also panic if unable to send message regarding the bound socket address.
-    #[instrument(skip(self, tracker, access_tokens, tx_start, rx_halt))]
+    #[instrument(skip(self, tracker, config, reloader, registry, tx_start, rx_halt))]
     pub fn start(
         &self,
         tracker: Arc<Tracker>,
-        access_tokens: Arc<AccessTokens>,
+        config: Arc<HttpApi>,
+        reloader: Arc<ConfigReloader>,
+        registry: ServiceRegistry,
         tx_start: Sender<Started>,
         rx_halt: Receiver<Halted>,
     ) -> BoxFuture<'static, ()> {
-        let router = router(tracker, access_tokens);
+        let router = router(tracker, config, reloader, registry);
         let socket = std::net::TcpListener::bind(self.bind_to).expect("Could not bind tcp_listener to address.");
         let address = socket.local_addr().expect("Could not get local_addr from tcp_listener.");
 
@@ -297,30 +302,31 @@ mod tests {
 
     use crate::bootstrap::app::initialize_with_configuration;
     use crate::bootstrap::jobs::make_rust_tls;
+    use crate::bootstrap::reload::ConfigReloader;
     use crate::servers::apis::server::{ApiServer, Launcher};
     use crate::servers::registar::Registar;
 
     #[tokio::test]
     async fn it_should_be_able_to_start_and_stop() {
         let cfg = Arc::new(ephemeral_public());
-        let config = &cfg.http_api.clone().unwrap();
+        let config = Arc::new(cfg.http_api.clone().unwrap());
 
         let tracker = initialize_with_configuration(&cfg);
 
+        let reloader = Arc::new(ConfigReloader::new(&cfg, tracker.clone()));
+
         let bind_to = config.bind_address;
 
         let tls = make_rust_tls(&config.tsl_config)
             .await
             .map(|tls| tls.expect("tls config failed"));
 
-        let access_tokens = Arc::new(config.access_tokens.clone());
-
         let stopped = ApiServer::new(Launcher::new(bind_to, tls));
 
         let register = &Registar::default();
 
         let started = stopped
-            .start(tracker, register.give_form(), access_tokens)
+            .start(tracker, register.give_form(), config, reloader, register.entries())
             .await
             .expect("it should start the server");
         let stopped = started.stop().await.expect("it should stop the server");
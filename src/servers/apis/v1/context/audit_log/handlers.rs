@@ -0,0 +1,49 @@
+//! API handlers for the [`audit_log`](crate::servers::apis::v1::context::audit_log)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::Response;
+use axum_extra::extract::Query;
+use serde::Deserialize;
+use torrust_tracker_primitives::pagination::Pagination;
+use utoipa::IntoParams;
+
+use super::responses::{audit_log_response, failed_to_load_audit_log_response};
+use crate::core::Tracker;
+
+/// Query parameters for the audit log.
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueryParams {
+    /// The number of entries to skip. Starts at 0.
+    pub offset: Option<u32>,
+    /// The maximum number of entries to return per page.
+    pub limit: Option<u32>,
+}
+
+impl QueryParams {
+    #[must_use]
+    pub fn pagination(&self) -> Pagination {
+        Pagination::new_with_options(self.offset, self.limit)
+    }
+}
+
+/// It handles the request to get the audit log.
+///
+/// It returns:
+///
+/// - `200` response with a json array of [`AuditLogEntry`](crate::servers::apis::v1::context::audit_log::resources::AuditLogEntry)
+///   resources, from the newest one. The `X-Total-Count` header contains the
+///   number of entries in the whole log.
+/// - `500` with serialized error in debug format if the audit log couldn't be
+///   loaded from the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::audit_log#get-the-audit-log)
+/// for more information about this endpoint.
+pub async fn get_audit_log_handler(State(tracker): State<Arc<Tracker>>, Query(params): Query<QueryParams>) -> Response {
+    match tracker.get_audit_log(&params.pagination()) {
+        Ok(page) => audit_log_response(page),
+        Err(e) => failed_to_load_audit_log_response(e),
+    }
+}
@@ -0,0 +1,66 @@
+//! Audit log API context.
+//!
+//! The tracker records every API request that changes the tracker state, for
+//! example, adding a torrent to the whitelist, deleting a key or reloading the
+//! configuration. Read-only requests are not recorded, except the `GET`
+//! requests to reload the keys and the whitelist.
+//!
+//! The log is stored in the database. The entries older than the retention
+//! period are removed periodically. The retention is in days, and `0` keeps
+//! the entries forever:
+//!
+//! ```toml
+//! [http_api]
+//! audit_log_retention = 90
+//! ```
+//!
+//! Only the tokens with the `admin` role can read the audit log. Refer to the
+//! [authentication middleware](crate::servers::apis::v1::middlewares::auth)
+//! for more information about roles.
+//!
+//! # Endpoints
+//!
+//! - [Get the audit log](#get-the-audit-log)
+//!
+//! # Get the audit log
+//!
+//! `GET /audit_log`
+//!
+//! It lists the audit log entries, from the newest one. The `x-total-count`
+//! header contains the number of entries in the whole log.
+//!
+//! **Query parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `offset` | positive integer | The number of entries to skip | No | `0`
+//! `limit` | positive integer | Page size. The number of results per page | No | `10`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/audit_log?token=MyAccessToken&offset=0&limit=10"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! [
+//!     {
+//!         "timestamp": 1729326371,
+//!         "label": "admin",
+//!         "action": "POST /api/v1/whitelist/:info_hash",
+//!         "target": "5452869be36f9f3350ccee6b4544e7e76caaadab",
+//!         "result": 200
+//!     }
+//! ]
+//! ```
+//!
+//! **Resource**
+//!
+//! Refer to the API [`AuditLogEntry`](crate::servers::apis::v1::context::audit_log::resources::AuditLogEntry)
+//! resource for more information about the response attributes.
+pub mod handlers;
+pub mod resources;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,62 @@
+//! API resources for the [`audit_log`](crate::servers::apis::v1::context::audit_log)
+//! API context.
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::audit;
+
+/// An administrative action done with the tracker API.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct AuditLogEntry {
+    /// When the action was done, in seconds since Unix Epoch.
+    pub timestamp: u64,
+    /// The label of the access token used to authenticate the request.
+    pub label: String,
+    /// The request method and route. For example: `POST /api/v1/whitelist/:info_hash`.
+    pub action: String,
+    /// The path params of the request, separated by `/`. Empty for actions
+    /// without a target.
+    pub target: String,
+    /// The status code of the response.
+    pub result: u16,
+}
+
+impl From<audit::Entry> for AuditLogEntry {
+    fn from(entry: audit::Entry) -> Self {
+        Self {
+            timestamp: entry.timestamp.as_secs(),
+            label: entry.label,
+            action: entry.action,
+            target: entry.target,
+            result: entry.result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+    use super::AuditLogEntry;
+    use crate::core::audit;
+
+    #[test]
+    fn it_should_be_converted_from_the_domain_entry() {
+        assert_eq!(
+            AuditLogEntry::from(audit::Entry {
+                timestamp: DurationSinceUnixEpoch::new(1_729_326_371, 500),
+                label: "admin".to_string(),
+                action: "DELETE /api/v1/keys/:key".to_string(),
+                target: "xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6".to_string(),
+                result: 200,
+            }),
+            AuditLogEntry {
+                timestamp: 1_729_326_371,
+                label: "admin".to_string(),
+                action: "DELETE /api/v1/keys/:key".to_string(),
+                target: "xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6".to_string(),
+                result: 200,
+            }
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! API responses for the [`audit_log`](crate::servers::apis::v1::context::audit_log)
+//! API context.
+use std::error::Error;
+
+use axum::response::{IntoResponse, Json, Response};
+
+use super::resources::AuditLogEntry;
+use crate::core::audit;
+use crate::servers::apis::v1::context::torrent::responses::TOTAL_COUNT_HEADER;
+use crate::servers::apis::v1::responses::unhandled_rejection_response;
+
+/// `200` response that contains an array of [`AuditLogEntry`] resources as
+/// json, with the number of entries in the whole log in the
+/// [`TOTAL_COUNT_HEADER`] header.
+#[must_use]
+pub fn audit_log_response(page: audit::Page) -> Response {
+    let entries: Vec<AuditLogEntry> = page.entries.into_iter().map(AuditLogEntry::from).collect();
+
+    ([(TOTAL_COUNT_HEADER, page.total.to_string())], Json(entries)).into_response()
+}
+
+/// `500` error response when the audit log cannot be loaded from the database.
+#[must_use]
+pub fn failed_to_load_audit_log_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to load audit log: {e}"))
+}
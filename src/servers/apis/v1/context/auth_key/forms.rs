@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DefaultOnNull};
+use utoipa::ToSchema;
 
 /// This type contains the info needed to add a new tracker key.
 ///
@@ -7,7 +8,7 @@ use serde_with::{serde_as, DefaultOnNull};
 /// You can also set an expiration date or leave it empty (`None`) if you want
 /// to create permanent key that does not expire.
 #[serde_as]
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
 pub struct AddKeyForm {
     /// The pre-generated key. Use `None` (null in json) to generate a random key.
     #[serde_as(deserialize_as = "DefaultOnNull")]
@@ -20,3 +21,31 @@ pub struct AddKeyForm {
     #[serde(rename = "seconds_valid")]
     pub opt_seconds_valid: Option<u64>,
 }
+
+/// Maximum number of keys that can be generated in a single request.
+pub const MAX_BULK_KEYS: usize = 10_000;
+
+/// This type contains the info needed to generate a batch of tracker keys.
+///
+/// All the keys in the batch share the same expiration date. Leave
+/// `seconds_valid` empty (`None`) to generate permanent keys.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, ToSchema)]
+pub struct GenerateKeysForm {
+    /// The number of keys to generate, from 1 to 10000.
+    pub count: usize,
+
+    /// How long the keys will be valid in seconds. Use `None` (null in json)
+    /// for permanent keys.
+    #[serde_as(deserialize_as = "DefaultOnNull")]
+    #[serde(default, rename = "seconds_valid")]
+    pub opt_seconds_valid: Option<u64>,
+}
+
+impl GenerateKeysForm {
+    /// It returns `true` if the number of keys to generate is allowed.
+    #[must_use]
+    pub fn has_valid_count(&self) -> bool {
+        (1..=MAX_BULK_KEYS).contains(&self.count)
+    }
+}
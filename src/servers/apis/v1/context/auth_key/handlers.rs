@@ -4,15 +4,21 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use axum::extract::{self, Path, State};
-use axum::response::Response;
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::Query;
 use serde::Deserialize;
+use torrust_tracker_primitives::pagination::Pagination;
+use utoipa::IntoParams;
 
-use super::forms::AddKeyForm;
+use super::forms::{AddKeyForm, GenerateKeysForm, MAX_BULK_KEYS};
 use super::responses::{
-    auth_key_response, failed_to_delete_key_response, failed_to_generate_key_response, failed_to_reload_keys_response,
-    invalid_auth_key_duration_response, invalid_auth_key_response,
+    auth_key_detail_response, auth_key_list_response, auth_key_not_known_response, auth_key_response,
+    failed_to_delete_key_response, failed_to_generate_key_response, failed_to_generate_keys_response,
+    failed_to_purge_keys_response, failed_to_reload_keys_response, generated_auth_keys_response,
+    invalid_auth_key_duration_response, invalid_auth_key_response, invalid_key_count_response, purged_keys_response,
 };
-use crate::core::auth::Key;
+use crate::core::auth::{Key, KeyFilter};
+use crate::core::services::auth_key::{get_auth_key_info, get_auth_keys};
 use crate::core::{AddKeyRequest, Tracker};
 use crate::servers::apis::v1::context::auth_key::resources::AuthKey;
 use crate::servers::apis::v1::responses::{invalid_auth_key_param_response, ok_response};
@@ -47,11 +53,48 @@ pub async fn add_auth_key_handler(
                 invalid_auth_key_duration_response(seconds_valid)
             }
             crate::core::error::PeerKeyError::InvalidKey { key, source } => invalid_auth_key_response(&key, source),
+            err @ crate::core::error::PeerKeyError::DuplicateKey { .. } => failed_to_generate_key_response(err),
             crate::core::error::PeerKeyError::DatabaseError { source } => failed_to_generate_key_response(source),
         },
     }
 }
 
+/// It handles the request to generate a batch of authentication keys.
+///
+/// It returns these types of responses:
+///
+/// - `200` with a json array of [`AuthKey`] resources. If the keys were
+///    generated successfully.
+/// - `400` with an error if the number of keys or the duration are not valid.
+/// - `500` with serialized error in debug format. If the keys couldn't be
+///    generated.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::auth_key#generate-a-batch-of-authentication-keys)
+/// for more information about this endpoint.
+pub async fn generate_auth_keys_handler(
+    State(tracker): State<Arc<Tracker>>,
+    extract::Json(generate_keys_form): extract::Json<GenerateKeysForm>,
+) -> Response {
+    if !generate_keys_form.has_valid_count() {
+        return invalid_key_count_response(generate_keys_form.count, MAX_BULK_KEYS);
+    }
+
+    match tracker
+        .generate_auth_keys(generate_keys_form.count, generate_keys_form.opt_seconds_valid)
+        .await
+    {
+        Ok(peer_keys) => generated_auth_keys_response(peer_keys).into_response(),
+        Err(err) => match err {
+            crate::core::error::PeerKeyError::DurationOverflow { seconds_valid } => {
+                invalid_auth_key_duration_response(seconds_valid)
+            }
+            crate::core::error::PeerKeyError::InvalidKey { key, source } => invalid_auth_key_response(&key, source),
+            err @ crate::core::error::PeerKeyError::DuplicateKey { .. } => failed_to_generate_keys_response(err),
+            crate::core::error::PeerKeyError::DatabaseError { source } => failed_to_generate_keys_response(source),
+        },
+    }
+}
+
 /// It handles the request to generate a new authentication key.
 ///
 /// It returns two types of responses:
@@ -89,7 +132,7 @@ pub async fn generate_auth_key_handler(State(tracker): State<Arc<Tracker>>, Path
 ///
 /// > **NOTICE**: this may change in the future, in the [API v2](https://github.com/torrust/torrust-tracker/issues/144).
 #[derive(Deserialize)]
-pub struct KeyParam(String);
+pub struct KeyParam(pub String);
 
 /// It handles the request to delete an authentication key.
 ///
@@ -133,3 +176,89 @@ pub async fn reload_keys_handler(State(tracker): State<Arc<Tracker>>) -> Respons
         Err(e) => failed_to_reload_keys_response(e),
     }
 }
+
+/// It handles the request to remove the expired authentication keys from
+/// memory and from the database.
+///
+/// It returns two types of responses:
+///
+/// - `200` with a json [`PurgedKeys`](crate::servers::apis::v1::context::auth_key::resources::PurgedKeys)
+///    resource. If the expired keys were successfully removed.
+/// - `500` with serialized error in debug format. If they couldn't be
+///    removed.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::auth_key#purge-expired-authentication-keys)
+/// for more information about this endpoint.
+pub async fn purge_expired_keys_handler(State(tracker): State<Arc<Tracker>>) -> Response {
+    match tracker.remove_expired_auth_keys().await {
+        Ok(removed_keys) => purged_keys_response(removed_keys as u64).into_response(),
+        Err(e) => failed_to_purge_keys_response(e),
+    }
+}
+
+/// Query parameters for the list of authentication keys. The filters are
+/// combined: a key must meet all of them.
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListQueryParams {
+    /// The offset of the first page to return. Starts at 0.
+    pub offset: Option<u32>,
+    /// The maximum number of items to return per page.
+    pub limit: Option<u32>,
+    /// Only the keys that have expired (`true`) or have not expired (`false`).
+    pub expired: Option<bool>,
+    /// Only the permanent keys (`true`) or the expiring keys (`false`).
+    pub permanent: Option<bool>,
+    /// Only the keys that expire before this timestamp (seconds since the Unix
+    /// epoch).
+    pub expiring_before: Option<u64>,
+}
+
+impl ListQueryParams {
+    #[must_use]
+    pub fn filter(&self) -> KeyFilter {
+        KeyFilter {
+            expired: self.expired,
+            permanent: self.permanent,
+            expiring_before: self.expiring_before.map(Duration::from_secs),
+        }
+    }
+
+    #[must_use]
+    pub fn pagination(&self) -> Pagination {
+        Pagination::new_with_options(self.offset, self.limit)
+    }
+}
+
+/// It handles the request to get a list of authentication keys.
+///
+/// It returns a `200` response with a json array of [`AuthKey`] resources,
+/// sorted by key.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::auth_key#list-authentication-keys)
+/// for more information about this endpoint.
+pub async fn get_auth_keys_handler(State(tracker): State<Arc<Tracker>>, Query(params): Query<ListQueryParams>) -> Response {
+    auth_key_list_response(get_auth_keys(tracker, &params.filter(), Some(&params.pagination())).await).into_response()
+}
+
+/// It handles the request to get all the information about an
+/// authentication key.
+///
+/// It returns:
+///
+/// - `200` response with a json [`AuthKeyDetail`](crate::servers::apis::v1::context::auth_key::resources::AuthKeyDetail).
+/// - `400` response if the key is not valid.
+/// - `200` response with the json string `"key not known"` if the tracker
+///   does not have the key.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::auth_key#get-an-authentication-key)
+/// for more information about this endpoint.
+pub async fn get_auth_key_handler(State(tracker): State<Arc<Tracker>>, Path(seconds_valid_or_key): Path<KeyParam>) -> Response {
+    match Key::from_str(&seconds_valid_or_key.0) {
+        Err(_) => invalid_auth_key_param_response(&seconds_valid_or_key.0),
+        Ok(key) => match get_auth_key_info(tracker, &key).await {
+            Some(info) => auth_key_detail_response(info).into_response(),
+            None => auth_key_not_known_response(),
+        },
+    }
+}
@@ -17,8 +17,12 @@
 //! # Endpoints
 //!
 //! - [Generate a new authentication key](#generate-a-new-authentication-key)
+//! - [Generate a batch of authentication keys](#generate-a-batch-of-authentication-keys)
+//! - [List authentication keys](#list-authentication-keys)
+//! - [Get an authentication key](#get-an-authentication-key)
 //! - [Delete an authentication key](#delete-an-authentication-key)
 //! - [Reload authentication keys](#reload-authentication-keys)
+//! - [Purge expired authentication keys](#purge-expired-authentication-keys)
 //!
 //! # Generate a new authentication key
 //!
@@ -67,6 +71,132 @@
 //! Refer to the API [`AuthKey`](crate::servers::apis::v1::context::auth_key::resources::AuthKey)
 //! resource for more information about the response attributes.
 //!
+//! # Generate a batch of authentication keys
+//!
+//! `POST /keys/bulk`
+//!
+//! It generates up to 10,000 random keys in one request. All the keys share the
+//! same expiration date and they are stored in a single database transaction,
+//! so either all of them are stored or none.
+//!
+//! **POST parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `count` | positive integer | The number of keys to generate, from 1 to 10000. | Yes | `100`
+//! `seconds_valid` | positive integer or `null` | The key duration in seconds. `null` for permanent keys. | No | `7200`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X POST http://localhost:1212/api/v1/keys/bulk?token=MyAccessToken \
+//!   -H "Content-Type: application/json" \
+//!   -d '{
+//!     "count": 2,
+//!     "seconds_valid": 7200
+//!   }'
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! [
+//!     {
+//!         "key": "xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6",
+//!         "valid_until": 1680009900,
+//!         "expiry_time": "2023-03-28 13:25:00.058085050 UTC"
+//!     },
+//!     {
+//!         "key": "Yb5Ms8N5ysCmMZ8cvXfVpTrDj2dmPZJ6",
+//!         "valid_until": 1680009900,
+//!         "expiry_time": "2023-03-28 13:25:00.058085050 UTC"
+//!     }
+//! ]
+//! ```
+//!
+//! # List authentication keys
+//!
+//! `GET /keys`
+//!
+//! It returns the authentication keys sorted by key.
+//!
+//! **Query parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `offset` | positive integer | The page offset, starting at 0. | No | `0`
+//! `limit` | positive integer | Page size. The number of results per page. Defaults to 4000. | No | `10`
+//! `expired` | boolean | Only the expired (`true`) or the not expired (`false`) keys. | No | `true`
+//! `permanent` | boolean | Only the permanent (`true`) or the expiring (`false`) keys. | No | `false`
+//! `expiring_before` | positive integer | Only the keys that expire before this timestamp (seconds since the Unix epoch). | No | `1680009900`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/keys?permanent=false&expired=false&token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! [
+//!     {
+//!         "key": "xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6",
+//!         "valid_until": 1680009900,
+//!         "expiry_time": "2023-03-28 13:25:00.058085050 UTC"
+//!     }
+//! ]
+//! ```
+//!
+//! **Resource**
+//!
+//! Refer to the API [`AuthKey`](crate::servers::apis::v1::context::auth_key::resources::AuthKey)
+//! resource for more information about the response attributes.
+//!
+//! # Get an authentication key
+//!
+//! `GET /key/:key`
+//!
+//! It returns all the information the tracker has about an authentication key.
+//!
+//! **Path parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `key` | 32-char string | The `key`. | Yes | `xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/key/xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!     "key": "xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6",
+//!     "valid_until": 1680009900,
+//!     "expiry_time": "2023-03-28 13:25:00.058085050 UTC",
+//!     "expired": false,
+//!     "last_used_time": "2023-03-28 12:10:21.512345678 UTC",
+//!     "active_peers": 1
+//! }
+//! ```
+//!
+//! The usage of the key (`last_used_time` and `active_peers`) is not
+//! persisted, so it only includes the requests since the tracker started.
+//! `active_peers` is the number of peers in the swarms that announced with the
+//! key.
+//!
+//! If the tracker does not have the key, the response is the json string
+//! `"key not known"`.
+//!
+//! **Resource**
+//!
+//! Refer to the API [`AuthKeyDetail`](crate::servers::apis::v1::context::auth_key::resources::AuthKeyDetail)
+//! resource for more information about the response attributes.
+//!
 //! # Delete an authentication key
 //!
 //! `DELETE /key/:key`
@@ -126,6 +256,34 @@
 //!     "status": "ok"
 //! }
 //! ```
+//!
+//! # Purge expired authentication keys
+//!
+//! `POST /keys/purge`
+//!
+//! It removes the keys that expired more than `core.expired_keys_grace_period`
+//! seconds ago, from memory and from the database. Permanent keys are never
+//! removed. The tracker also does it periodically when it's private and it
+//! checks the keys expiration.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X POST "http://127.0.0.1:1212/api/v1/keys/purge?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!     "removed_keys": 3
+//! }
+//! ```
+//!
+//! **Resource**
+//!
+//! Refer to the API [`PurgedKeys`](crate::servers::apis::v1::context::auth_key::resources::PurgedKeys)
+//! resource for more information about the response attributes.
 pub mod forms;
 pub mod handlers;
 pub mod resources;
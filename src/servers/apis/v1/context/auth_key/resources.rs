@@ -1,12 +1,16 @@
 //! API resources for the [`auth_key`](crate::servers::apis::v1::context::auth_key) API context.
 
 use serde::{Deserialize, Serialize};
-use torrust_tracker_clock::conv::convert_from_iso_8601_to_timestamp;
+use torrust_tracker_clock::clock::Time;
+use torrust_tracker_clock::conv::{convert_from_iso_8601_to_timestamp, convert_from_timestamp_to_datetime_utc};
+use utoipa::ToSchema;
 
 use crate::core::auth::{self, Key};
+use crate::core::services::auth_key::Info;
+use crate::CurrentClock;
 
 /// A resource that represents an authentication key.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
 pub struct AuthKey {
     /// The authentication key.
     pub key: String,
@@ -46,6 +50,48 @@ impl From<auth::PeerKey> for AuthKey {
     }
 }
 
+/// A resource with the number of expired authentication keys removed by a
+/// purge.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct PurgedKeys {
+    /// The number of expired keys removed from the database.
+    pub removed_keys: u64,
+}
+
+/// A resource with all the information the tracker has about an
+/// authentication key.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct AuthKeyDetail {
+    /// The authentication key.
+    pub key: String,
+    /// The timestamp when the key will expire.
+    pub valid_until: Option<u64>,
+    /// The ISO 8601 timestamp when the key will expire.
+    pub expiry_time: Option<String>,
+    /// Whether the key has expired.
+    pub expired: bool,
+    /// The ISO 8601 timestamp of the last time the key was used to
+    /// authenticate a request since the tracker started.
+    pub last_used_time: Option<String>,
+    /// The number of peers in the swarms that announced with the key.
+    pub active_peers: u64,
+}
+
+impl From<Info> for AuthKeyDetail {
+    fn from(info: Info) -> Self {
+        Self {
+            key: info.peer_key.key.to_string(),
+            valid_until: info.peer_key.valid_until.map(|valid_until| valid_until.as_secs()),
+            expiry_time: info.peer_key.expiry_time().map(|expiry_time| expiry_time.to_string()),
+            expired: info.peer_key.is_expired_at(CurrentClock::now()),
+            last_used_time: info
+                .last_used
+                .map(|last_used| convert_from_timestamp_to_datetime_utc(last_used).to_string()),
+            active_peers: info.active_peers,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -53,8 +99,9 @@ mod tests {
     use torrust_tracker_clock::clock::stopped::Stopped as _;
     use torrust_tracker_clock::clock::{self, Time};
 
-    use super::AuthKey;
+    use super::{AuthKey, AuthKeyDetail};
     use crate::core::auth::{self, Key};
+    use crate::core::services::auth_key::Info;
     use crate::CurrentClock;
 
     struct TestTime {
@@ -127,4 +174,30 @@ mod tests {
             "{\"key\":\"IaWDneuFNZi8IB4MPA3qW1CD0M30EZSM\",\"valid_until\":60,\"expiry_time\":\"1970-01-01T00:01:00.000Z\"}" // cspell:disable-line
         );
     }
+
+    #[test]
+    fn auth_key_detail_resource_should_be_converted_from_the_key_info() {
+        clock::Stopped::local_set_to_unix_epoch();
+
+        let info = Info {
+            peer_key: auth::PeerKey {
+                key: "IaWDneuFNZi8IB4MPA3qW1CD0M30EZSM".parse::<Key>().unwrap(), // cspell:disable-line
+                valid_until: Some(Duration::new(one_hour_after_unix_epoch().timestamp, 0)),
+            },
+            last_used: Some(Duration::from_secs(30)),
+            active_peers: 2,
+        };
+
+        assert_eq!(
+            AuthKeyDetail::from(info),
+            AuthKeyDetail {
+                key: "IaWDneuFNZi8IB4MPA3qW1CD0M30EZSM".to_string(), // cspell:disable-line
+                valid_until: Some(one_hour_after_unix_epoch().timestamp),
+                expiry_time: Some(one_hour_after_unix_epoch().iso_8601_v2),
+                expired: false,
+                last_used_time: Some("1970-01-01 00:00:30 UTC".to_string()),
+                active_peers: 2,
+            }
+        );
+    }
 }
@@ -2,9 +2,12 @@
 use std::error::Error;
 
 use axum::http::{header, StatusCode};
-use axum::response::{IntoResponse, Response};
+use axum::response::{IntoResponse, Json, Response};
+use serde_json::json;
 
-use crate::servers::apis::v1::context::auth_key::resources::AuthKey;
+use crate::core::auth::PeerKey;
+use crate::core::services::auth_key::Info;
+use crate::servers::apis::v1::context::auth_key::resources::{AuthKey, AuthKeyDetail, PurgedKeys};
 use crate::servers::apis::v1::responses::{bad_request_response, unhandled_rejection_response};
 
 /// `200` response that contains the `AuthKey` resource as json.
@@ -22,6 +25,33 @@ pub fn auth_key_response(auth_key: &AuthKey) -> Response {
         .into_response()
 }
 
+/// `200` response that contains an array of [`AuthKey`] resources as json.
+pub fn auth_key_list_response(peer_keys: Vec<PeerKey>) -> Json<Vec<AuthKey>> {
+    Json(peer_keys.into_iter().map(AuthKey::from).collect())
+}
+
+/// `200` response that contains an array with the generated [`AuthKey`]
+/// resources as json.
+pub fn generated_auth_keys_response(peer_keys: Vec<PeerKey>) -> Json<Vec<AuthKey>> {
+    auth_key_list_response(peer_keys)
+}
+
+/// `200` response that contains an [`AuthKeyDetail`] resource as json.
+pub fn auth_key_detail_response(info: Info) -> Json<AuthKeyDetail> {
+    Json(AuthKeyDetail::from(info))
+}
+
+/// `200` response that contains the [`PurgedKeys`] resource as json.
+pub fn purged_keys_response(removed_keys: u64) -> Json<PurgedKeys> {
+    Json(PurgedKeys { removed_keys })
+}
+
+/// `200` response with a json string returned when a key is not found.
+#[must_use]
+pub fn auth_key_not_known_response() -> Response {
+    Json(json!("key not known")).into_response()
+}
+
 // Error responses
 
 /// `500` error response when a new authentication key cannot be generated.
@@ -30,6 +60,13 @@ pub fn failed_to_generate_key_response<E: Error>(e: E) -> Response {
     unhandled_rejection_response(format!("failed to generate key: {e}"))
 }
 
+/// `500` error response when a batch of authentication keys cannot be
+/// generated.
+#[must_use]
+pub fn failed_to_generate_keys_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to generate keys: {e}"))
+}
+
 /// `500` error response when the provide key cannot be added.
 #[must_use]
 pub fn failed_to_add_key_response<E: Error>(e: E) -> Response {
@@ -49,6 +86,13 @@ pub fn failed_to_reload_keys_response<E: Error>(e: E) -> Response {
     unhandled_rejection_response(format!("failed to reload keys: {e}"))
 }
 
+/// `500` error response when the expired authentication keys cannot be
+/// removed.
+#[must_use]
+pub fn failed_to_purge_keys_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to purge keys: {e}"))
+}
+
 #[must_use]
 pub fn invalid_auth_key_response<E: Error>(auth_key: &str, e: E) -> Response {
     bad_request_response(&format!("Invalid URL: invalid auth key: string \"{auth_key}\", {e}"))
@@ -58,3 +102,10 @@ pub fn invalid_auth_key_response<E: Error>(auth_key: &str, e: E) -> Response {
 pub fn invalid_auth_key_duration_response(duration: u64) -> Response {
     bad_request_response(&format!("Invalid URL: invalid auth key duration: \"{duration}\""))
 }
+
+#[must_use]
+pub fn invalid_key_count_response(count: usize, max: usize) -> Response {
+    bad_request_response(&format!(
+        "Invalid body: invalid key count: \"{count}\", expected from 1 to {max}"
+    ))
+}
@@ -2,8 +2,12 @@
 //! API context.
 //!
 //! - `POST /key/:seconds_valid`
+//! - `GET /key/:key`
 //! - `DELETE /key/:key`
+//! - `GET /keys`
 //! - `GET /keys/reload`
+//! - `POST /keys/purge`
+//! - `POST /keys/bulk`
 //!
 //! Refer to the [API endpoint documentation](crate::servers::apis::v1::context::auth_key).
 use std::sync::Arc;
@@ -11,7 +15,10 @@ use std::sync::Arc;
 use axum::routing::{get, post};
 use axum::Router;
 
-use super::handlers::{add_auth_key_handler, delete_auth_key_handler, generate_auth_key_handler, reload_keys_handler};
+use super::handlers::{
+    add_auth_key_handler, delete_auth_key_handler, generate_auth_key_handler, generate_auth_keys_handler, get_auth_key_handler,
+    get_auth_keys_handler, purge_expired_keys_handler, reload_keys_handler,
+};
 use crate::core::Tracker;
 
 /// It adds the routes to the router for the [`auth_key`](crate::servers::apis::v1::context::auth_key) API context.
@@ -29,6 +36,8 @@ pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
             // Use POST /keys
             &format!("{prefix}/key/:seconds_valid_or_key"),
             post(generate_auth_key_handler)
+                .with_state(tracker.clone())
+                .get(get_auth_key_handler)
                 .with_state(tracker.clone())
                 .delete(delete_auth_key_handler)
                 .with_state(tracker.clone()),
@@ -38,5 +47,19 @@ pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
             &format!("{prefix}/keys/reload"),
             get(reload_keys_handler).with_state(tracker.clone()),
         )
-        .route(&format!("{prefix}/keys"), post(add_auth_key_handler).with_state(tracker))
+        .route(
+            &format!("{prefix}/keys/purge"),
+            post(purge_expired_keys_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/keys/bulk"),
+            post(generate_auth_keys_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/keys"),
+            post(add_auth_key_handler)
+                .with_state(tracker.clone())
+                .get(get_auth_keys_handler)
+                .with_state(tracker),
+        )
 }
@@ -0,0 +1,50 @@
+//! API handlers for the [`clients`](crate::servers::apis::v1::context::clients)
+//! API context.
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Json, Response};
+use bittorrent_primitives::info_hash::InfoHash;
+
+use super::resources::ClientsBreakdown;
+use super::responses::clients_breakdown_response;
+use crate::core::Tracker;
+use crate::servers::apis::v1::context::torrent::responses::torrent_not_known_response;
+use crate::servers::apis::v1::responses::invalid_info_hash_param_response;
+use crate::servers::apis::InfoHashParam;
+
+/// It handles the request to get the breakdown of active peers by client
+/// software for the whole tracker.
+///
+/// It returns a `200` response with a json [`ClientsBreakdown`].
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::clients#get-clients-breakdown)
+/// for more information about this endpoint.
+pub async fn get_clients_breakdown_handler(State(tracker): State<Arc<Tracker>>) -> Json<ClientsBreakdown> {
+    clients_breakdown_response(&tracker.get_clients_breakdown().await)
+}
+
+/// It handles the request to get the breakdown of active peers by client
+/// software for one torrent.
+///
+/// It returns:
+///
+/// - `200` response with a json [`ClientsBreakdown`].
+/// - `200` response with the `"torrent not known"` json string if the torrent
+///   is not known.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::clients#get-clients-breakdown-for-a-torrent)
+/// for more information about this endpoint.
+pub async fn get_torrent_clients_breakdown_handler(
+    State(tracker): State<Arc<Tracker>>,
+    Path(info_hash): Path<InfoHashParam>,
+) -> Response {
+    match InfoHash::from_str(&info_hash.0) {
+        Err(_) => invalid_info_hash_param_response(&info_hash.0),
+        Ok(info_hash) => match tracker.get_torrent_clients_breakdown(&info_hash).await {
+            Some(breakdown) => clients_breakdown_response(&breakdown).into_response(),
+            None => torrent_not_known_response(),
+        },
+    }
+}
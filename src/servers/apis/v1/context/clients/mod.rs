@@ -0,0 +1,111 @@
+//! Client software statistics API context.
+//!
+//! The tracker decodes the client software and version from the peer id of
+//! each active peer. This context provides the number of peers by client
+//! software and version, for the whole tracker or for one torrent.
+//!
+//! The breakdowns are updated periodically, every `clients_breakdown_interval`
+//! seconds, so they may not include the latest peers. The `updated_at`
+//! attribute contains the time when they were built.
+//!
+//! ```toml
+//! [core]
+//! clients_breakdown_interval = 60
+//! ```
+//!
+//! Peers whose client software or version can not be decoded are counted as
+//! `unknown`.
+//!
+//! # Endpoints
+//!
+//! - [Get clients breakdown](#get-clients-breakdown)
+//! - [Get clients breakdown for a torrent](#get-clients-breakdown-for-a-torrent)
+//!
+//! # Get clients breakdown
+//!
+//! `GET /clients`
+//!
+//! Returns the number of active peers by client software for all the torrents.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/clients?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!   "peers": 3,
+//!   "updated_at": 1669397478,
+//!   "clients": [
+//!     {
+//!       "name": "qBittorrent",
+//!       "peers": 2,
+//!       "seeders": 1,
+//!       "leechers": 1,
+//!       "versions": [
+//!         {
+//!           "version": "4.5.0",
+//!           "peers": 2
+//!         }
+//!       ]
+//!     },
+//!     {
+//!       "name": "unknown",
+//!       "peers": 1,
+//!       "seeders": 0,
+//!       "leechers": 1,
+//!       "versions": [
+//!         {
+//!           "version": "unknown",
+//!           "peers": 1
+//!         }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `updated_at` is `null` until the breakdown is built for the first time.
+//!
+//! **Resource**
+//!
+//! Refer to the API [`ClientsBreakdown`](crate::servers::apis::v1::context::clients::resources::ClientsBreakdown)
+//! resource for more information about the response attributes.
+//!
+//! # Get clients breakdown for a torrent
+//!
+//! `GET /clients/:info_hash`
+//!
+//! Returns the number of active peers by client software for one torrent.
+//!
+//! **Path parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `info_hash` | 40-char string | The Info Hash v1 | Yes | `5452869be36f9f3350ccee6b4544e7e76caaadab`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/clients/5452869be36f9f3350ccee6b4544e7e76caaadab?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! The same resource returned by the [Get clients breakdown](#get-clients-breakdown)
+//! endpoint.
+//!
+//! **Not Found response** `200`
+//!
+//! This response is returned when the tracker does not have the torrent.
+//!
+//! ```json
+//! "torrent not known"
+//! ```
+pub mod handlers;
+pub mod resources;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,150 @@
+//! API resources for the [`clients`](crate::servers::apis::v1::context::clients)
+//! API context.
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::clients;
+
+/// Number of active peers by client software.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct ClientsBreakdown {
+    /// Total number of peers.
+    pub peers: u64,
+    /// When the breakdown was built, in seconds since Unix Epoch. `None` if
+    /// it has not been built yet.
+    pub updated_at: Option<u64>,
+    /// Clients sorted by number of peers, from the most used.
+    pub clients: Vec<Client>,
+}
+
+/// Number of active peers using one client software.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Client {
+    /// Client software name.
+    pub name: String,
+    /// Total number of peers.
+    pub peers: u64,
+    /// Number of peers that have completed the download.
+    pub seeders: u64,
+    /// Number of peers that are still downloading.
+    pub leechers: u64,
+    /// Versions sorted by number of peers, from the most used.
+    pub versions: Vec<Version>,
+}
+
+/// Number of active peers using one version of a client software.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Version {
+    pub version: String,
+    pub peers: u64,
+}
+
+impl From<&clients::ClientsBreakdown> for ClientsBreakdown {
+    fn from(breakdown: &clients::ClientsBreakdown) -> Self {
+        let mut clients: Vec<Client> = breakdown
+            .clients
+            .iter()
+            .map(|(name, client)| {
+                let mut versions: Vec<Version> = client
+                    .versions
+                    .iter()
+                    .map(|(version, peers)| Version {
+                        version: version.clone(),
+                        peers: *peers,
+                    })
+                    .collect();
+
+                // The sort is stable, so ties keep the alphabetical order
+                versions.sort_by_key(|version| Reverse(version.peers));
+
+                Client {
+                    name: name.clone(),
+                    peers: client.peers(),
+                    seeders: client.seeders,
+                    leechers: client.leechers,
+                    versions,
+                }
+            })
+            .collect();
+
+        clients.sort_by_key(|client| Reverse(client.peers));
+
+        Self {
+            peers: breakdown.peers(),
+            updated_at: breakdown.updated_at.map(|updated_at| updated_at.as_secs()),
+            clients,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+    use super::{Client, ClientsBreakdown, Version};
+    use crate::core::clients;
+
+    #[test]
+    fn it_should_be_converted_from_the_domain_breakdown_sorting_the_clients_by_number_of_peers() {
+        let breakdown = clients::ClientsBreakdown {
+            clients: BTreeMap::from([
+                (
+                    "Deluge".to_string(),
+                    clients::Client {
+                        seeders: 1,
+                        leechers: 0,
+                        versions: BTreeMap::from([("2.1.1".to_string(), 1)]),
+                    },
+                ),
+                (
+                    "qBittorrent".to_string(),
+                    clients::Client {
+                        seeders: 1,
+                        leechers: 2,
+                        versions: BTreeMap::from([("4.5.0".to_string(), 1), ("4.6.3".to_string(), 2)]),
+                    },
+                ),
+            ]),
+            updated_at: Some(DurationSinceUnixEpoch::from_secs(1_669_397_478)),
+        };
+
+        assert_eq!(
+            ClientsBreakdown::from(&breakdown),
+            ClientsBreakdown {
+                peers: 4,
+                updated_at: Some(1_669_397_478),
+                clients: vec![
+                    Client {
+                        name: "qBittorrent".to_string(),
+                        peers: 3,
+                        seeders: 1,
+                        leechers: 2,
+                        versions: vec![
+                            Version {
+                                version: "4.6.3".to_string(),
+                                peers: 2
+                            },
+                            Version {
+                                version: "4.5.0".to_string(),
+                                peers: 1
+                            }
+                        ]
+                    },
+                    Client {
+                        name: "Deluge".to_string(),
+                        peers: 1,
+                        seeders: 1,
+                        leechers: 0,
+                        versions: vec![Version {
+                            version: "2.1.1".to_string(),
+                            peers: 1
+                        }]
+                    }
+                ]
+            }
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! API responses for the [`clients`](crate::servers::apis::v1::context::clients)
+//! API context.
+use axum::response::Json;
+
+use super::resources::ClientsBreakdown;
+use crate::core::clients;
+
+/// `200` response that contains the [`ClientsBreakdown`] resource as json.
+pub fn clients_breakdown_response(breakdown: &clients::ClientsBreakdown) -> Json<ClientsBreakdown> {
+    Json(ClientsBreakdown::from(breakdown))
+}
@@ -0,0 +1,26 @@
+//! API routes for the [`clients`](crate::servers::apis::v1::context::clients) API context.
+//!
+//! - `GET /clients`
+//! - `GET /clients/:info_hash`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v1::context::clients).
+use std::sync::Arc;
+
+use axum::routing::get;
+use axum::Router;
+
+use super::handlers::{get_clients_breakdown_handler, get_torrent_clients_breakdown_handler};
+use crate::core::Tracker;
+
+/// It adds the routes to the router for the [`clients`](crate::servers::apis::v1::context::clients) API context.
+pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    router
+        .route(
+            &format!("{prefix}/clients"),
+            get(get_clients_breakdown_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/clients/:info_hash"),
+            get(get_torrent_clients_breakdown_handler).with_state(tracker),
+        )
+}
@@ -0,0 +1,28 @@
+//! API handlers for the [`config`](crate::servers::apis::v1::context::config)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::{IntoResponse, Response};
+
+use super::responses::{config_reload_response, failed_to_reload_config_response, restart_required_response};
+use crate::bootstrap::reload::{ConfigReloader, Error};
+
+/// It handles the request to reload the configuration.
+///
+/// It returns:
+///
+/// - `200` response with a json [`ConfigReload`](crate::servers::apis::v1::context::config::resources::ConfigReload).
+/// - `409` response if some of the changed options require a restart.
+/// - `500` response if the configuration cannot be loaded or it's not valid.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::config#reload-the-configuration)
+/// for more information about this endpoint.
+#[allow(clippy::unused_async)]
+pub async fn reload_config_handler(State(reloader): State<Arc<ConfigReloader>>) -> Response {
+    match reloader.reload() {
+        Ok(changed_options) => config_reload_response(changed_options).into_response(),
+        Err(Error::RestartRequired { options }) => restart_required_response(&options),
+        Err(e) => failed_to_reload_config_response(e),
+    }
+}
@@ -0,0 +1,64 @@
+//! Configuration API context.
+//!
+//! This API context is responsible for reloading the tracker configuration
+//! without restarting the tracker, so the swarms are not lost.
+//!
+//! Only these options can be changed while the tracker is running:
+//!
+//! - `core.announce_policy`
+//! - `core.tracker_policy`
+//! - `http_api.access_tokens`
+//! - `logging.threshold` (unless logging was disabled at startup)
+//!
+//! The configuration is loaded again from the same sources used when the
+//! tracker started. If any other option has changed, for example, the bind
+//! address of a service, nothing is applied. The tracker also reloads the
+//! configuration when it receives a `SIGHUP` signal.
+//!
+//! Refer to the [`reload`](crate::bootstrap::reload) module for more
+//! information.
+//!
+//! # Endpoints
+//!
+//! - [Reload the configuration](#reload-the-configuration)
+//!
+//! # Reload the configuration
+//!
+//! `POST /config/reload`
+//!
+//! It applies the changes in the configuration and returns the options that
+//! have changed.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X POST "http://127.0.0.1:1212/api/v1/config/reload?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!     "changed_options": [
+//!         "core.announce_policy.interval",
+//!         "logging.threshold"
+//!     ]
+//! }
+//! ```
+//!
+//! **Example response** `409`
+//!
+//! When some of the changed options can't be applied without restarting the
+//! tracker:
+//!
+//! ```text
+//! Unable to reload the configuration, these options require a restart: http_api.bind_address
+//! ```
+//!
+//! **Example response** `500`
+//!
+//! When the configuration can't be loaded or it's not valid.
+pub mod handlers;
+pub mod resources;
+pub mod responses;
+pub mod routes;
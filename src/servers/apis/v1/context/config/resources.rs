@@ -0,0 +1,11 @@
+//! API resources for the [`config`](crate::servers::apis::v1::context::config) API context.
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A resource with the result of reloading the configuration.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct ConfigReload {
+    /// The options that have changed, as dotted paths. For example:
+    /// `core.announce_policy.interval`. It's empty if nothing has changed.
+    pub changed_options: Vec<String>,
+}
@@ -0,0 +1,36 @@
+//! API responses for the [`config`](crate::servers::apis::v1::context::config)
+//! API context.
+use std::error::Error;
+
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Json, Response};
+
+use super::resources::ConfigReload;
+use crate::servers::apis::v1::responses::unhandled_rejection_response;
+
+/// `200` response that contains a [`ConfigReload`] resource as json.
+pub fn config_reload_response(changed_options: Vec<String>) -> Json<ConfigReload> {
+    Json(ConfigReload { changed_options })
+}
+
+/// `409` error response when some of the changed options can't be applied
+/// without restarting the tracker.
+#[must_use]
+pub fn restart_required_response(options: &[String]) -> Response {
+    (
+        StatusCode::CONFLICT,
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        format!(
+            "Unable to reload the configuration, these options require a restart: {}",
+            options.join(", ")
+        ),
+    )
+        .into_response()
+}
+
+/// `500` error response when the configuration cannot be loaded or it's not
+/// valid.
+#[must_use]
+pub fn failed_to_reload_config_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to reload configuration: {e}"))
+}
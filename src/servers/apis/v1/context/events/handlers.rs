@@ -0,0 +1,102 @@
+//! API handlers for the [`events`](crate::servers::apis::v1::context::events)
+//! API context.
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::Query;
+use bittorrent_primitives::info_hash::InfoHash;
+use serde::Deserialize;
+use thiserror::Error;
+use utoipa::IntoParams;
+
+use super::responses::{event_stream_response, invalid_protocol_param_response, invalid_type_param_response};
+use crate::core::events::{Filter, Kind, ParseKindError};
+use crate::core::statistics::{ParseSchemeError, Scheme};
+use crate::core::Tracker;
+use crate::servers::apis::v1::responses::invalid_info_hash_param_response;
+
+/// A container for the URL query parameters of the events endpoint.
+///
+/// All the parameters can be repeated. For example, the announces and
+/// completed downloads of one torrent in the UDP tracker:
+///
+/// <http://127.0.0.1:1212/api/v1/events?token=MyAccessToken&info_hash=9c38422213e30bff212b30c360d26f9a02136422&type=announce&type=completed&protocol=udp>
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct QueryParams {
+    /// Only the events for these torrents.
+    #[serde(default, rename = "info_hash")]
+    pub info_hashes: Vec<String>,
+    /// Only these types of events: `announce`, `completed`, `torrent_added`
+    /// or `error`.
+    #[serde(default, rename = "type")]
+    pub types: Vec<String>,
+    /// Only the events from the trackers using these protocols: `http` or
+    /// `udp`.
+    #[serde(default, rename = "protocol")]
+    pub protocols: Vec<String>,
+}
+
+impl QueryParams {
+    /// It returns the filter for the subscription.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if an infohash, an event type or a protocol is
+    /// not valid.
+    pub fn filter(&self) -> Result<Filter, QueryParamError> {
+        Ok(Filter {
+            info_hashes: self
+                .info_hashes
+                .iter()
+                .map(|info_hash| {
+                    InfoHash::from_str(info_hash).map_err(|_| QueryParamError::InvalidInfoHash {
+                        info_hash: info_hash.clone(),
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            kinds: self
+                .types
+                .iter()
+                .map(|kind| Kind::from_str(kind).map_err(|source| QueryParamError::InvalidType { source }))
+                .collect::<Result<_, _>>()?,
+            schemes: self
+                .protocols
+                .iter()
+                .map(|scheme| Scheme::from_str(scheme).map_err(|source| QueryParamError::InvalidProtocol { source }))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum QueryParamError {
+    #[error("invalid infohash {info_hash}")]
+    InvalidInfoHash { info_hash: String },
+
+    #[error("invalid event type: {source}")]
+    InvalidType { source: ParseKindError },
+
+    #[error("invalid protocol: {source}")]
+    InvalidProtocol { source: ParseSchemeError },
+}
+
+/// It handles the request to watch the live tracker events.
+///
+/// It returns:
+///
+/// - `200` response with a `text/event-stream` of [`Event`](crate::servers::apis::v1::context::events::resources::Event) resources.
+/// - `400` response if a filter is not valid.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::events#watch-the-events)
+/// for more information about this endpoint.
+pub async fn get_events_handler(State(tracker): State<Arc<Tracker>>, params: Query<QueryParams>) -> Response {
+    match params.0.filter() {
+        Ok(filter) => event_stream_response(tracker.subscribe_to_events(filter)).into_response(),
+        Err(QueryParamError::InvalidInfoHash { info_hash }) => invalid_info_hash_param_response(&info_hash),
+        Err(QueryParamError::InvalidType { source }) => invalid_type_param_response(&source.to_string()),
+        Err(QueryParamError::InvalidProtocol { source }) => invalid_protocol_param_response(&source.to_string()),
+    }
+}
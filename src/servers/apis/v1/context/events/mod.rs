@@ -0,0 +1,79 @@
+//! Live events API context.
+//!
+//! This API context is responsible for streaming the live tracker events:
+//! announces, completed downloads, new torrents and error responses. For
+//! example, to watch the tracker activity in a dashboard without polling.
+//!
+//! The endpoints in this context require the
+//! [`ReadTorrents`](crate::servers::apis::v1::middlewares::auth::Permission::ReadTorrents)
+//! permission.
+//!
+//! # Endpoints
+//!
+//! - [Watch the events](#watch-the-events)
+//!
+//! # Watch the events
+//!
+//! `GET /events`
+//!
+//! It returns a stream of [Server-Sent Events](https://html.spec.whatwg.org/multipage/server-sent-events.html).
+//! The connection stays open and the tracker sends each event as soon as it
+//! happens, until the client closes the connection. Only the events after the
+//! request are sent; there is no history.
+//!
+//! Each event uses the event type as the SSE event name and a json
+//! [`Event`](crate::servers::apis::v1::context::events::resources::Event)
+//! resource as data.
+//!
+//! The tracker never waits for a slow client. Each client has a buffer of
+//! [`BUFFER_CAPACITY`](crate::core::events::BUFFER_CAPACITY) events. When it
+//! is full, the oldest events are skipped and the client receives a `lagged`
+//! event with the number of skipped events instead.
+//!
+//! **Query parameters**
+//!
+//! All the parameters are optional and can be repeated to accept several
+//! values.
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `info_hash` | 40-char string | Only the events for this torrent. Errors are excluded | No | `info_hash=9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d`
+//! `type` | `announce`, `completed`, `torrent_added` or `error` | Only this type of events | No | `type=completed`
+//! `protocol` | `http` or `udp` | Only the events from the trackers using this protocol | No | `protocol=udp`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -N "http://127.0.0.1:1212/api/v1/events?token=MyAccessToken&type=announce&type=completed"
+//! ```
+//!
+//! The token can also be sent in the `Authorization: Bearer` header, but the
+//! browser `EventSource` API only allows the `token` query parameter.
+//!
+//! **Example response** `200`
+//!
+//! ```text
+//! event: announce
+//! data: {"type":"announce","time_milliseconds":1669397478934,"protocol":"udp","info_hash":"9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d","peer_id":{"id":"0x2d7142343431302d2a64465a3844484944704579","client":"qBittorrent"},"peer_addr":"126.0.0.1:8080","event":"Completed"}
+//!
+//! event: completed
+//! data: {"type":"completed","time_milliseconds":1669397478934,"protocol":"udp","info_hash":"9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d","peer_id":{"id":"0x2d7142343431302d2a64465a3844484944704579","client":"qBittorrent"}}
+//!
+//! event: lagged
+//! data: {"skipped":12}
+//! ```
+//!
+//! **Resources**
+//!
+//! Refer to the API [`Event`](crate::servers::apis::v1::context::events::resources::Event)
+//! and [`Lagged`](crate::servers::apis::v1::context::events::resources::Lagged)
+//! resources for more information about the event attributes.
+//!
+//! **Error responses**
+//!
+//! It returns a `400` response if an infohash, an event type or a protocol is
+//! not valid.
+pub mod handlers;
+pub mod resources;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,145 @@
+//! API resources for the [`events`](crate::servers::apis::v1::context::events)
+//! API context.
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::events::{self, TimedEvent};
+use crate::servers::apis::v1::context::torrent::resources::peer::Id;
+
+/// A live tracker event.
+///
+/// The attributes depend on the event `type`:
+///
+/// - `announce`: `info_hash`, `peer_id`, `peer_addr` and `event`.
+/// - `completed`: `info_hash` and `peer_id`.
+/// - `torrent_added`: `info_hash`.
+/// - `error`: `reason`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct Event {
+    /// The event type: `announce`, `completed`, `torrent_added` or `error`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// When the event happened, in milliseconds since Unix Epoch.
+    pub time_milliseconds: u128,
+    /// The protocol of the tracker: `http` or `udp`.
+    pub protocol: String,
+    /// The torrent infohash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info_hash: Option<String>,
+    /// The ID of the peer announcing or completing the download.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_id: Option<Id>,
+    /// The peer's socket address. For example: `192.168.1.88:17548`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peer_addr: Option<String>,
+    /// The announce event: `Started`, `Stopped`, `Completed` or `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    /// Why the tracker returned an error response. For example: `bad_request`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl From<TimedEvent> for Event {
+    fn from(timed_event: TimedEvent) -> Self {
+        let event = timed_event.event;
+
+        let mut resource = Event {
+            kind: event.kind().to_string(),
+            time_milliseconds: timed_event.time.as_millis(),
+            protocol: event.scheme().to_string(),
+            info_hash: event.info_hash().map(|info_hash| info_hash.to_hex_string()),
+            peer_id: None,
+            peer_addr: None,
+            event: None,
+            reason: None,
+        };
+
+        match event {
+            events::Event::Announce {
+                peer_id,
+                peer_addr,
+                event,
+                ..
+            } => {
+                resource.peer_id = Some(Id::from(peer_id));
+                resource.peer_addr = Some(peer_addr.to_string());
+                resource.event = Some(format!("{event:?}"));
+            }
+            events::Event::Completed { peer_id, .. } => resource.peer_id = Some(Id::from(peer_id)),
+            events::Event::TorrentAdded { .. } => {}
+            events::Event::Error { reason, .. } => resource.reason = Some(reason.to_string()),
+        }
+
+        resource
+    }
+}
+
+/// It's sent instead of the events the subscriber missed because it did not
+/// read them fast enough.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct Lagged {
+    /// The number of events skipped.
+    pub skipped: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    use aquatic_udp_protocol::{AnnounceEvent, PeerId};
+    use bittorrent_primitives::info_hash::InfoHash;
+    use torrust_tracker_primitives::DurationSinceUnixEpoch;
+
+    use super::Event;
+    use crate::core::events::{self, TimedEvent};
+    use crate::core::statistics::{ErrorReason, Scheme};
+    use crate::servers::apis::v1::context::torrent::resources::peer::Id;
+
+    #[test]
+    fn it_should_be_converted_from_an_announce_event() {
+        let timed_event = TimedEvent {
+            time: DurationSinceUnixEpoch::from_millis(1_669_397_478_934),
+            event: events::Event::Announce {
+                scheme: Scheme::Udp,
+                info_hash: InfoHash::from([0x9c; 20]),
+                peer_id: PeerId(*b"-qB4410-*dFZ8DHIDpEy"),
+                peer_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080),
+                event: AnnounceEvent::Started,
+            },
+        };
+
+        assert_eq!(
+            Event::from(timed_event),
+            Event {
+                kind: "announce".to_string(),
+                time_milliseconds: 1_669_397_478_934,
+                protocol: "udp".to_string(),
+                info_hash: Some("9c9c9c9c9c9c9c9c9c9c9c9c9c9c9c9c9c9c9c9c".to_string()),
+                peer_id: Some(Id {
+                    id: Some("0x2d7142343431302d2a64465a3844484944704579".to_string()),
+                    client: Some("qBittorrent".to_string()),
+                }),
+                peer_addr: Some("126.0.0.1:8080".to_string()),
+                event: Some("Started".to_string()),
+                reason: None,
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_only_serialize_the_attributes_of_the_event_type() {
+        let timed_event = TimedEvent {
+            time: DurationSinceUnixEpoch::from_millis(1_000),
+            event: events::Event::Error {
+                scheme: Scheme::Http,
+                reason: ErrorReason::BadRequest,
+            },
+        };
+
+        assert_eq!(
+            serde_json::to_string(&Event::from(timed_event)).unwrap(),
+            r#"{"type":"error","time_milliseconds":1000,"protocol":"http","reason":"bad_request"}"#
+        );
+    }
+}
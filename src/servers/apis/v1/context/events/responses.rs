@@ -0,0 +1,57 @@
+//! API responses for the [`events`](crate::servers::apis::v1::context::events)
+//! API context.
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::response::sse::{Event as SseEvent, KeepAlive, Sse};
+use axum::response::Response;
+use futures::stream::{self, Stream};
+
+use super::resources::{Event, Lagged};
+use crate::core::events::{Received, Subscription};
+use crate::servers::apis::v1::responses::bad_request_response;
+
+/// How often a comment is sent to keep the connection open when there are no
+/// events.
+pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// `200` response with a stream of Server-Sent Events.
+///
+/// Each tracker event is sent as an [`Event`] json resource, using the event
+/// type as the SSE event name. When the client does not read the events fast
+/// enough, the skipped events are replaced with a `lagged` event containing
+/// a [`Lagged`] json resource.
+///
+/// The subscription ends when the client closes the connection.
+///
+/// # Panics
+///
+/// Will panic if a resource can not be serialized to json, which can not
+/// happen.
+pub fn event_stream_response(subscription: Subscription) -> Sse<impl Stream<Item = Result<SseEvent, Infallible>>> {
+    let events = stream::unfold(subscription, |mut subscription| async move {
+        let sse_event = match subscription.next().await? {
+            Received::Event(timed_event) => {
+                let event = Event::from(timed_event);
+                SseEvent::default().event(event.kind.clone()).json_data(event)
+            }
+            Received::Lagged(skipped) => SseEvent::default().event("lagged").json_data(Lagged { skipped }),
+        };
+
+        Some((Ok(sse_event.expect("the event should be serializable to json")), subscription))
+    });
+
+    Sse::new(events).keep_alive(KeepAlive::new().interval(KEEP_ALIVE_INTERVAL))
+}
+
+/// `400` error response when an event type is unknown.
+#[must_use]
+pub fn invalid_type_param_response(error: &str) -> Response {
+    bad_request_response(&format!("Invalid type param: {error}"))
+}
+
+/// `400` error response when a protocol is unknown.
+#[must_use]
+pub fn invalid_protocol_param_response(error: &str) -> Response {
+    bad_request_response(&format!("Invalid protocol param: {error}"))
+}
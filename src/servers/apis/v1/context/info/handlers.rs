@@ -0,0 +1,26 @@
+//! API handlers for the [`info`](crate::servers::apis::v1::context::info)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::Json;
+
+use super::resources::TrackerInfo;
+use super::responses::tracker_info_response;
+use crate::bootstrap::reload::ConfigReloader;
+use crate::core::Tracker;
+use crate::servers::registar::ServiceRegistry;
+
+/// It handles the request to get the tracker info.
+///
+/// It returns a `200` response with a json [`TrackerInfo`].
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::info#get-tracker-info)
+/// for more information about this endpoint.
+pub async fn get_tracker_info_handler(
+    State((tracker, reloader, registry)): State<(Arc<Tracker>, Arc<ConfigReloader>, ServiceRegistry)>,
+) -> Json<TrackerInfo> {
+    let registrations = registry.lock().await.values().cloned().collect::<Vec<_>>();
+
+    tracker_info_response(&tracker, reloader.current(), &registrations)
+}
@@ -0,0 +1,68 @@
+//! Tracker info API context.
+//!
+//! This API context is responsible for showing which tracker is deployed: the
+//! version, how long it has been running, the services it's listening on and
+//! the effective configuration.
+//!
+//! # Endpoints
+//!
+//! - [Get tracker info](#get-tracker-info)
+//!
+//! # Get tracker info
+//!
+//! `GET /info`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/info?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!     "version": "3.0.0-develop",
+//!     "git_commit": "15acef9d1c5e4c1f5c2d6b1e0c7a4ad9e3b2f871",
+//!     "started_at": 1729326371,
+//!     "uptime": 3600,
+//!     "listeners": [
+//!         {
+//!             "service": "http_api",
+//!             "binding": "127.0.0.1:1212"
+//!         },
+//!         {
+//!             "service": "http_tracker",
+//!             "binding": "0.0.0.0:7070"
+//!         },
+//!         {
+//!             "service": "udp_tracker",
+//!             "binding": "0.0.0.0:6969"
+//!         }
+//!     ],
+//!     "torrent_repository": "skip_map_mutex_std",
+//!     "database_driver": "sqlite3",
+//!     "config": {
+//!         "metadata": {
+//!             "app": "torrust-tracker",
+//!             "purpose": "configuration",
+//!             "schema_version": "2.0.0"
+//!         },
+//!         ...
+//!     }
+//! }
+//! ```
+//!
+//! The times are in seconds. The `started_at` time is since Unix Epoch.
+//!
+//! The `config` is the configuration the tracker is running with, including
+//! the changes applied with a [reload](crate::servers::apis::v1::context::config).
+//! The secrets, like the API access tokens and the database password, are
+//! masked.
+//!
+//! The `git_commit` is `unknown` when the tracker was not built from a git
+//! repository.
+pub mod handlers;
+pub mod resources;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,80 @@
+//! API resources for the [`info`](crate::servers::apis::v1::context::info)
+//! API context.
+use serde::{Deserialize, Serialize};
+use torrust_tracker_configuration::v2_0_0::database::Driver;
+use torrust_tracker_configuration::Configuration;
+use utoipa::ToSchema;
+
+use crate::core::torrent::TORRENTS_REPOSITORY;
+use crate::core::Tracker;
+use crate::servers::registar::ServiceRegistration;
+
+/// The tracker version, set by `Cargo`.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The git commit the tracker was built from, set by the build script.
+pub const GIT_COMMIT: &str = env!("TORRUST_TRACKER_GIT_COMMIT");
+
+/// Which tracker is deployed and how it's running.
+#[derive(Serialize, Deserialize, Debug, PartialEq, ToSchema)]
+pub struct TrackerInfo {
+    /// The tracker version.
+    pub version: String,
+    /// The git commit the tracker was built from, or `unknown`.
+    pub git_commit: String,
+    /// When the tracker was started, in seconds since Unix Epoch.
+    pub started_at: u64,
+    /// How long the tracker has been running, in seconds.
+    pub uptime: u64,
+    /// The running services, sorted by service and address.
+    pub listeners: Vec<Listener>,
+    /// The implementation of the in-memory torrents repository.
+    pub torrent_repository: String,
+    /// The database driver.
+    #[schema(value_type = String, example = "sqlite3")]
+    pub database_driver: Driver,
+    /// The effective configuration with the secrets masked.
+    #[schema(value_type = Object)]
+    pub config: serde_json::Value,
+}
+
+/// A service the tracker is listening on.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+pub struct Listener {
+    /// The kind of service: `udp_tracker`, `http_tracker` or `http_api`.
+    pub service: String,
+    /// The address the service is bound to.
+    pub binding: String,
+}
+
+impl TrackerInfo {
+    /// # Panics
+    ///
+    /// Will panic if the configuration can't be converted to JSON.
+    #[must_use]
+    pub fn new(tracker: &Tracker, configuration: Configuration, registrations: &[ServiceRegistration]) -> Self {
+        let mut listeners: Vec<Listener> = registrations.iter().map(Listener::from).collect();
+
+        listeners.sort();
+
+        Self {
+            version: VERSION.to_string(),
+            git_commit: GIT_COMMIT.to_string(),
+            started_at: tracker.started_at().as_secs(),
+            uptime: tracker.uptime().as_secs(),
+            listeners,
+            torrent_repository: TORRENTS_REPOSITORY.to_string(),
+            database_driver: configuration.core.database.driver.clone(),
+            config: serde_json::from_str(&configuration.mask_secrets().to_json()).expect("Could not decode JSON value"),
+        }
+    }
+}
+
+impl From<&ServiceRegistration> for Listener {
+    fn from(registration: &ServiceRegistration) -> Self {
+        Self {
+            service: registration.service().to_string(),
+            binding: registration.binding().to_string(),
+        }
+    }
+}
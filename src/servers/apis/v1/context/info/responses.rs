@@ -0,0 +1,17 @@
+//! API responses for the [`info`](crate::servers::apis::v1::context::info)
+//! API context.
+use axum::response::Json;
+use torrust_tracker_configuration::Configuration;
+
+use super::resources::TrackerInfo;
+use crate::core::Tracker;
+use crate::servers::registar::ServiceRegistration;
+
+/// `200` response that contains the [`TrackerInfo`] resource as json.
+pub fn tracker_info_response(
+    tracker: &Tracker,
+    configuration: Configuration,
+    registrations: &[ServiceRegistration],
+) -> Json<TrackerInfo> {
+    Json(TrackerInfo::new(tracker, configuration, registrations))
+}
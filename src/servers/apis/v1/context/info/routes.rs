@@ -0,0 +1,28 @@
+//! API routes for the [`info`](crate::servers::apis::v1::context::info) API context.
+//!
+//! - `GET /info`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v1::context::info).
+use std::sync::Arc;
+
+use axum::routing::get;
+use axum::Router;
+
+use super::handlers::get_tracker_info_handler;
+use crate::bootstrap::reload::ConfigReloader;
+use crate::core::Tracker;
+use crate::servers::registar::ServiceRegistry;
+
+/// It adds the routes to the router for the [`info`](crate::servers::apis::v1::context::info) API context.
+pub fn add(
+    prefix: &str,
+    router: Router,
+    tracker: Arc<Tracker>,
+    reloader: Arc<ConfigReloader>,
+    registry: ServiceRegistry,
+) -> Router {
+    router.route(
+        &format!("{prefix}/info"),
+        get(get_tracker_info_handler).with_state((tracker, reloader, registry)),
+    )
+}
@@ -0,0 +1,22 @@
+//! API handlers for the [`metrics`](crate::servers::apis::v1::context::metrics)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::Response;
+
+use super::resources::Exposition;
+use super::responses::metrics_response;
+use crate::core::services::statistics::get_metrics;
+use crate::core::Tracker;
+
+/// It handles the request to get the tracker metrics in Prometheus format.
+///
+/// It returns a `200` response with the metrics in the Prometheus text-based
+/// exposition format.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::metrics#get-tracker-metrics)
+/// for more information about this endpoint.
+pub async fn get_metrics_handler(State(tracker): State<Arc<Tracker>>) -> Response {
+    metrics_response(&Exposition::from(get_metrics(tracker.clone()).await))
+}
@@ -0,0 +1,101 @@
+//! Prometheus metrics API context.
+//!
+//! It exposes the same data as the [`stats`](crate::servers::apis::v1::context::stats)
+//! context but using the [Prometheus text-based exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/),
+//! so that Prometheus can scrape the tracker directly.
+//!
+//! Instead of one field for each combination of protocol, IP version and
+//! request type, the protocol metrics use labels:
+//!
+//! Label | Values
+//! ---|---
+//! `protocol` | `tcp` (HTTP tracker), `udp` (UDP tracker)
+//! `ip_version` | `ipv4`, `ipv6`
+//! `request` | `connect` (only UDP), `announce`, `scrape`
+//! `reason` | `bad_request`, `invalid_connection_id`, `authentication_failed`, `torrent_not_whitelisted`, `internal`
+//!
+//! The time spent handling each type of request is exposed as the
+//! `torrust_tracker_request_duration_microseconds` histogram. It does not use
+//! the `ip_version` label.
+//!
+//! # Endpoints
+//!
+//! - [Get tracker metrics](#get-tracker-metrics)
+//!
+//! # Get tracker metrics
+//!
+//! `GET /metrics`
+//!
+//! > **NOTICE**: the endpoint is not under the `/api/v1` prefix. It uses the
+//! > default path for Prometheus scrapers.
+//!
+//! By default, the endpoint requires an access token like any other API
+//! endpoint. You can disable the authentication for this endpoint with the
+//! `metrics_require_token` option in the [HTTP API configuration](torrust_tracker_configuration::HttpApi):
+//!
+//! ```toml
+//! [http_api]
+//! metrics_require_token = false
+//! ```
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/metrics?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```text
+//! # HELP torrust_tracker_torrents Number of torrents.
+//! # TYPE torrust_tracker_torrents gauge
+//! torrust_tracker_torrents 1
+//! # HELP torrust_tracker_seeders Number of seeders for all torrents.
+//! # TYPE torrust_tracker_seeders gauge
+//! torrust_tracker_seeders 1
+//! # HELP torrust_tracker_completed Number of peers that have ever completed downloading for all torrents.
+//! # TYPE torrust_tracker_completed gauge
+//! torrust_tracker_completed 0
+//! # HELP torrust_tracker_leechers Number of leechers for all torrents.
+//! # TYPE torrust_tracker_leechers gauge
+//! torrust_tracker_leechers 0
+//! # HELP torrust_tracker_connections_handled_total Number of connections handled.
+//! # TYPE torrust_tracker_connections_handled_total counter
+//! torrust_tracker_connections_handled_total{protocol="tcp",ip_version="ipv4"} 0
+//! ...
+//! # HELP torrust_tracker_requests_handled_total Number of requests handled.
+//! # TYPE torrust_tracker_requests_handled_total counter
+//! torrust_tracker_requests_handled_total{protocol="tcp",ip_version="ipv4",request="announce"} 0
+//! ...
+//! # HELP torrust_tracker_errors_total Number of error responses.
+//! # TYPE torrust_tracker_errors_total counter
+//! torrust_tracker_errors_total{protocol="tcp",reason="bad_request"} 0
+//! ...
+//! # HELP torrust_tracker_listener_requests_handled_total Number of requests handled by each tracker listener.
+//! # TYPE torrust_tracker_listener_requests_handled_total counter
+//! torrust_tracker_listener_requests_handled_total{scheme="udp",address="0.0.0.0:6969",ip_version="ipv4",request="connect"} 1
+//! ...
+//! # HELP torrust_tracker_api_authentication_failures_total Number of tracker API requests rejected because the access token was missing, unknown or expired.
+//! # TYPE torrust_tracker_api_authentication_failures_total counter
+//! torrust_tracker_api_authentication_failures_total 0
+//! # HELP torrust_tracker_api_authentication_lockouts_total Number of times a tracker API client was locked out after too many invalid access tokens.
+//! # TYPE torrust_tracker_api_authentication_lockouts_total counter
+//! torrust_tracker_api_authentication_lockouts_total 0
+//! # HELP torrust_tracker_request_duration_microseconds Time spent handling requests in microseconds.
+//! # TYPE torrust_tracker_request_duration_microseconds histogram
+//! torrust_tracker_request_duration_microseconds_bucket{protocol="udp",request="connect",le="100"} 0
+//! ...
+//! torrust_tracker_request_duration_microseconds_bucket{protocol="udp",request="connect",le="+Inf"} 0
+//! torrust_tracker_request_duration_microseconds_sum{protocol="udp",request="connect"} 0
+//! torrust_tracker_request_duration_microseconds_count{protocol="udp",request="connect"} 0
+//! ...
+//! ```
+//!
+//! **Resource**
+//!
+//! Refer to the API [`Exposition`](crate::servers::apis::v1::context::metrics::resources::Exposition)
+//! resource for more information about the response.
+pub mod handlers;
+pub mod resources;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,471 @@
+//! API resources for the [`metrics`](crate::servers::apis::v1::context::metrics)
+//! API context.
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::core::services::statistics::TrackerMetrics;
+use crate::core::statistics::{
+    ErrorCounters, ErrorReason, LatencyHistogram, Listener, ListenerMetrics, Scheme, LATENCY_BUCKETS_US,
+};
+
+/// The type of a Prometheus metric family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricType {
+    /// A value that only goes up (or is reset when the tracker restarts).
+    Counter,
+    /// A value that can go up and down.
+    Gauge,
+    /// Observations counted in configurable buckets.
+    Histogram,
+}
+
+impl fmt::Display for MetricType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricType::Counter => write!(f, "counter"),
+            MetricType::Gauge => write!(f, "gauge"),
+            MetricType::Histogram => write!(f, "histogram"),
+        }
+    }
+}
+
+/// One sample of a metric family with its labels.
+#[derive(Debug, PartialEq)]
+pub struct Sample {
+    /// Appended to the metric family name. Histograms use it for the
+    /// `_bucket`, `_sum` and `_count` series.
+    pub suffix: &'static str,
+    pub labels: Vec<(&'static str, String)>,
+    pub value: u64,
+}
+
+impl Sample {
+    #[must_use]
+    pub fn new(labels: &[(&'static str, &str)], value: u64) -> Self {
+        Self {
+            suffix: "",
+            labels: labels.iter().map(|(name, value)| (*name, (*value).to_string())).collect(),
+            value,
+        }
+    }
+
+    #[must_use]
+    fn with_suffix(mut self, suffix: &'static str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// It returns one sample for each error reason.
+    fn from_errors(protocol: &str, errors: &ErrorCounters) -> Vec<Self> {
+        ErrorReason::ALL
+            .iter()
+            .map(|reason| {
+                Sample::new(
+                    &[("protocol", protocol), ("reason", &reason.to_string())],
+                    errors.get(*reason),
+                )
+            })
+            .collect()
+    }
+
+    /// It returns one sample for each listener, peer IP version and request
+    /// type. HTTP listeners do not handle `connect` requests.
+    fn from_listeners(listeners: &BTreeMap<Listener, ListenerMetrics>) -> Vec<Self> {
+        let mut samples = vec![];
+
+        for (listener, metrics) in listeners {
+            let scheme = listener.scheme.to_string();
+            let address = listener.address.to_string();
+
+            for (ip_version, counters) in [("ipv4", &metrics.ipv4), ("ipv6", &metrics.ipv6)] {
+                let requests = [
+                    ("connect", counters.connections_handled),
+                    ("announce", counters.announces_handled),
+                    ("scrape", counters.scrapes_handled),
+                ];
+
+                for (request, value) in requests {
+                    if listener.scheme == Scheme::Http && request == "connect" {
+                        continue;
+                    }
+
+                    let labels = [
+                        ("scheme", scheme.as_str()),
+                        ("address", address.as_str()),
+                        ("ip_version", ip_version),
+                        ("request", request),
+                    ];
+
+                    samples.push(Sample::new(&labels, value));
+                }
+            }
+        }
+
+        samples
+    }
+
+    /// It returns the `_bucket`, `_sum` and `_count` samples for a latency
+    /// histogram.
+    fn from_histogram(labels: &[(&'static str, &str)], histogram: &LatencyHistogram) -> Vec<Self> {
+        let mut samples = Vec::with_capacity(LATENCY_BUCKETS_US.len() + 3);
+        let mut accumulated = 0;
+
+        for (bound, requests) in LATENCY_BUCKETS_US.iter().zip(histogram.buckets.iter()) {
+            accumulated += requests;
+
+            let mut sample = Sample::new(labels, accumulated).with_suffix("_bucket");
+            sample.labels.push(("le", bound.to_string()));
+            samples.push(sample);
+        }
+
+        let mut sample = Sample::new(labels, histogram.count).with_suffix("_bucket");
+        sample.labels.push(("le", "+Inf".to_string()));
+        samples.push(sample);
+
+        samples.push(Sample::new(labels, histogram.sum_us).with_suffix("_sum"));
+        samples.push(Sample::new(labels, histogram.count).with_suffix("_count"));
+
+        samples
+    }
+}
+
+/// A Prometheus metric family. All the samples share the same name, help and
+/// type.
+#[derive(Debug, PartialEq)]
+pub struct MetricFamily {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub metric_type: MetricType,
+    pub samples: Vec<Sample>,
+}
+
+impl MetricFamily {
+    #[must_use]
+    pub fn gauge(name: &'static str, help: &'static str, value: u64) -> Self {
+        Self {
+            name,
+            help,
+            metric_type: MetricType::Gauge,
+            samples: vec![Sample::new(&[], value)],
+        }
+    }
+
+    #[must_use]
+    pub fn counter(name: &'static str, help: &'static str, samples: Vec<Sample>) -> Self {
+        Self {
+            name,
+            help,
+            metric_type: MetricType::Counter,
+            samples,
+        }
+    }
+
+    #[must_use]
+    pub fn histogram(
+        name: &'static str,
+        help: &'static str,
+        histograms: &[(&[(&'static str, &str)], &LatencyHistogram)],
+    ) -> Self {
+        Self {
+            name,
+            help,
+            metric_type: MetricType::Histogram,
+            samples: histograms
+                .iter()
+                .flat_map(|(labels, histogram)| Sample::from_histogram(labels, histogram))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for MetricFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# HELP {} {}", self.name, self.help)?;
+        writeln!(f, "# TYPE {} {}", self.name, self.metric_type)?;
+
+        for sample in &self.samples {
+            write!(f, "{}{}", self.name, sample.suffix)?;
+
+            if !sample.labels.is_empty() {
+                let labels = sample
+                    .labels
+                    .iter()
+                    .map(|(name, value)| format!("{name}=\"{}\"", escape_label_value(value)))
+                    .collect::<Vec<String>>()
+                    .join(",");
+
+                write!(f, "{{{labels}}}")?;
+            }
+
+            writeln!(f, " {}", sample.value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Label values can contain any Unicode character but the backslash, the
+/// double-quote and the line feed must be escaped.
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', r"\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// All the tracker metrics in the Prometheus text-based exposition format.
+#[derive(Debug, PartialEq)]
+pub struct Exposition {
+    pub metric_families: Vec<MetricFamily>,
+}
+
+impl fmt::Display for Exposition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for metric_family in &self.metric_families {
+            write!(f, "{metric_family}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<TrackerMetrics> for Exposition {
+    #[allow(clippy::too_many_lines)]
+    fn from(metrics: TrackerMetrics) -> Self {
+        let torrents = metrics.torrents_metrics;
+        let protocol = metrics.protocol_metrics;
+
+        Self {
+            metric_families: vec![
+                MetricFamily::gauge("torrust_tracker_torrents", "Number of torrents.", torrents.torrents),
+                MetricFamily::gauge(
+                    "torrust_tracker_seeders",
+                    "Number of seeders for all torrents.",
+                    torrents.complete,
+                ),
+                MetricFamily::gauge(
+                    "torrust_tracker_completed",
+                    "Number of peers that have ever completed downloading for all torrents.",
+                    torrents.downloaded,
+                ),
+                MetricFamily::gauge(
+                    "torrust_tracker_leechers",
+                    "Number of leechers for all torrents.",
+                    torrents.incomplete,
+                ),
+                MetricFamily::counter(
+                    "torrust_tracker_connections_handled_total",
+                    "Number of connections handled.",
+                    vec![
+                        Sample::new(
+                            &[("protocol", "tcp"), ("ip_version", "ipv4")],
+                            protocol.tcp4_connections_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "tcp"), ("ip_version", "ipv6")],
+                            protocol.tcp6_connections_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "udp"), ("ip_version", "ipv4")],
+                            protocol.udp4_connections_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "udp"), ("ip_version", "ipv6")],
+                            protocol.udp6_connections_handled,
+                        ),
+                    ],
+                ),
+                MetricFamily::counter(
+                    "torrust_tracker_requests_handled_total",
+                    "Number of requests handled.",
+                    vec![
+                        Sample::new(
+                            &[("protocol", "tcp"), ("ip_version", "ipv4"), ("request", "announce")],
+                            protocol.tcp4_announces_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "tcp"), ("ip_version", "ipv4"), ("request", "scrape")],
+                            protocol.tcp4_scrapes_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "tcp"), ("ip_version", "ipv6"), ("request", "announce")],
+                            protocol.tcp6_announces_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "tcp"), ("ip_version", "ipv6"), ("request", "scrape")],
+                            protocol.tcp6_scrapes_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "udp"), ("ip_version", "ipv4"), ("request", "announce")],
+                            protocol.udp4_announces_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "udp"), ("ip_version", "ipv4"), ("request", "scrape")],
+                            protocol.udp4_scrapes_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "udp"), ("ip_version", "ipv6"), ("request", "announce")],
+                            protocol.udp6_announces_handled,
+                        ),
+                        Sample::new(
+                            &[("protocol", "udp"), ("ip_version", "ipv6"), ("request", "scrape")],
+                            protocol.udp6_scrapes_handled,
+                        ),
+                    ],
+                ),
+                MetricFamily::counter(
+                    "torrust_tracker_errors_total",
+                    "Number of error responses.",
+                    [("tcp", &protocol.tcp_errors), ("udp", &protocol.udp_errors)]
+                        .into_iter()
+                        .flat_map(|(label, errors)| Sample::from_errors(label, errors))
+                        .collect(),
+                ),
+                MetricFamily::counter(
+                    "torrust_tracker_listener_requests_handled_total",
+                    "Number of requests handled by each tracker listener.",
+                    Sample::from_listeners(&protocol.listeners),
+                ),
+                MetricFamily::counter(
+                    "torrust_tracker_api_authentication_failures_total",
+                    "Number of tracker API requests rejected because the access token was missing, unknown or expired.",
+                    vec![Sample::new(&[], protocol.api_auth_failures)],
+                ),
+                MetricFamily::counter(
+                    "torrust_tracker_api_authentication_lockouts_total",
+                    "Number of times a tracker API client was locked out after too many invalid access tokens.",
+                    vec![Sample::new(&[], protocol.api_auth_lockouts)],
+                ),
+                MetricFamily::histogram(
+                    "torrust_tracker_request_duration_microseconds",
+                    "Time spent handling requests in microseconds.",
+                    &[
+                        (&[("protocol", "udp"), ("request", "connect")], &protocol.udp_connect_latency),
+                        (
+                            &[("protocol", "udp"), ("request", "announce")],
+                            &protocol.udp_announce_latency,
+                        ),
+                        (&[("protocol", "udp"), ("request", "scrape")], &protocol.udp_scrape_latency),
+                        (
+                            &[("protocol", "tcp"), ("request", "announce")],
+                            &protocol.http_announce_latency,
+                        ),
+                        (&[("protocol", "tcp"), ("request", "scrape")], &protocol.http_scrape_latency),
+                    ],
+                ),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    use torrust_tracker_primitives::torrent_metrics::TorrentsMetrics;
+
+    use super::{Exposition, MetricFamily, Sample};
+    use crate::core::services::statistics::TrackerMetrics;
+    use crate::core::statistics::{
+        ErrorCounters, LatencyHistogram, Listener, ListenerMetrics, Metrics, RequestCounters, RequestRates, Scheme,
+    };
+
+    #[test]
+    fn a_metric_family_should_be_rendered_in_prometheus_text_format() {
+        let metric_family = MetricFamily::counter(
+            "requests_total",
+            "Number of requests.",
+            vec![
+                Sample::new(&[("protocol", "udp"), ("request", "announce")], 1),
+                Sample::new(&[("protocol", "tcp"), ("request", "announce")], 2),
+            ],
+        );
+
+        assert_eq!(
+            metric_family.to_string(),
+            "# HELP requests_total Number of requests.\n\
+             # TYPE requests_total counter\n\
+             requests_total{protocol=\"udp\",request=\"announce\"} 1\n\
+             requests_total{protocol=\"tcp\",request=\"announce\"} 2\n"
+        );
+    }
+
+    #[test]
+    fn a_histogram_should_be_rendered_with_cumulative_buckets_sum_and_count() {
+        let mut histogram = LatencyHistogram::default();
+        histogram.observe(Duration::from_micros(50));
+        histogram.observe(Duration::from_micros(200));
+        histogram.observe(Duration::from_secs(2));
+
+        let metric_family =
+            MetricFamily::histogram("duration", "Duration.", &[(&[("request", "scrape")], &histogram)]).to_string();
+
+        assert!(metric_family.contains("# TYPE duration histogram\n"));
+        assert!(metric_family.contains("duration_bucket{request=\"scrape\",le=\"100\"} 1\n"));
+        assert!(metric_family.contains("duration_bucket{request=\"scrape\",le=\"250\"} 2\n"));
+        assert!(metric_family.contains("duration_bucket{request=\"scrape\",le=\"1000000\"} 2\n"));
+        assert!(metric_family.contains("duration_bucket{request=\"scrape\",le=\"+Inf\"} 3\n"));
+        assert!(metric_family.contains("duration_sum{request=\"scrape\"} 2000250\n"));
+        assert!(metric_family.contains("duration_count{request=\"scrape\"} 3\n"));
+    }
+
+    #[test]
+    fn label_values_should_be_escaped() {
+        let metric_family = MetricFamily::counter("total", "Total.", vec![Sample::new(&[("label", "a\"b\\c\nd")], 1)]);
+
+        assert!(metric_family.to_string().contains("total{label=\"a\\\"b\\\\c\\nd\"} 1\n"));
+    }
+
+    #[test]
+    fn the_exposition_should_be_converted_from_the_tracker_metrics() {
+        let exposition = Exposition::from(TrackerMetrics {
+            torrents_metrics: TorrentsMetrics {
+                complete: 1,
+                downloaded: 2,
+                incomplete: 3,
+                torrents: 4,
+            },
+            protocol_metrics: Metrics {
+                tcp4_connections_handled: 5,
+                udp6_scrapes_handled: 16,
+                udp_errors: ErrorCounters {
+                    authentication_failed: 17,
+                    ..Default::default()
+                },
+                listeners: BTreeMap::from([(
+                    Listener {
+                        scheme: Scheme::Http,
+                        address: "0.0.0.0:7070".parse().unwrap(),
+                    },
+                    ListenerMetrics {
+                        ipv6: RequestCounters {
+                            announces_handled: 18,
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                )]),
+                api_auth_failures: 20,
+                api_auth_lockouts: 21,
+                ..Default::default()
+            },
+            request_rates: RequestRates::default(),
+        })
+        .to_string();
+
+        assert!(exposition.contains("torrust_tracker_torrents 4\n"));
+        assert!(exposition.contains("torrust_tracker_seeders 1\n"));
+        assert!(exposition.contains("torrust_tracker_completed 2\n"));
+        assert!(exposition.contains("torrust_tracker_leechers 3\n"));
+        assert!(exposition.contains("torrust_tracker_connections_handled_total{protocol=\"tcp\",ip_version=\"ipv4\"} 5\n"));
+        assert!(exposition
+            .contains("torrust_tracker_requests_handled_total{protocol=\"udp\",ip_version=\"ipv6\",request=\"scrape\"} 16\n"));
+        assert!(exposition.contains("torrust_tracker_errors_total{protocol=\"udp\",reason=\"authentication_failed\"} 17\n"));
+        assert!(exposition.contains("torrust_tracker_errors_total{protocol=\"tcp\",reason=\"authentication_failed\"} 0\n"));
+        assert!(exposition.contains(
+            "torrust_tracker_listener_requests_handled_total{scheme=\"http\",address=\"0.0.0.0:7070\",ip_version=\"ipv6\",\
+             request=\"announce\"} 18\n"
+        ));
+        assert!(!exposition.contains("scheme=\"http\",address=\"0.0.0.0:7070\",ip_version=\"ipv6\",request=\"connect\""));
+        assert!(exposition.contains("torrust_tracker_api_authentication_failures_total 20\n"));
+        assert!(exposition.contains("torrust_tracker_api_authentication_lockouts_total 21\n"));
+    }
+}
@@ -0,0 +1,21 @@
+//! API responses for the [`metrics`](crate::servers::apis::v1::context::metrics)
+//! API context.
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use super::resources::Exposition;
+
+/// The content type for the Prometheus text-based exposition format.
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// `200` response that contains the [`Exposition`] resource in Prometheus
+/// text format.
+#[must_use]
+pub fn metrics_response(exposition: &Exposition) -> Response {
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        exposition.to_string(),
+    )
+        .into_response()
+}
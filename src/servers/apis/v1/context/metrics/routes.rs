@@ -0,0 +1,20 @@
+//! API routes for the [`metrics`](crate::servers::apis::v1::context::metrics) API context.
+//!
+//! - `GET /metrics`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v1::context::metrics).
+use std::sync::Arc;
+
+use axum::routing::get;
+use axum::Router;
+
+use super::handlers::get_metrics_handler;
+use crate::core::Tracker;
+
+/// It adds the routes to the router for the [`metrics`](crate::servers::apis::v1::context::metrics) API context.
+///
+/// The route does not use the API prefix. Prometheus scrapers use `/metrics`
+/// by default.
+pub fn add(router: Router, tracker: Arc<Tracker>) -> Router {
+    router.route("/metrics", get(get_metrics_handler).with_state(tracker))
+}
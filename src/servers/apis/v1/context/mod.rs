@@ -2,8 +2,15 @@
 //!
 //! Each context is a module that contains the API endpoints related to a
 //! specific resource group.
+pub mod audit_log;
 pub mod auth_key;
+pub mod clients;
+pub mod config;
+pub mod events;
 pub mod health_check;
+pub mod info;
+pub mod metrics;
+pub mod peer;
 pub mod stats;
 pub mod torrent;
 pub mod whitelist;
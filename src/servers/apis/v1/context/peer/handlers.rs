@@ -0,0 +1,127 @@
+//! API handlers for the [`peer`](crate::servers::apis::v1::context::peer)
+//! API context.
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::Query;
+use serde::Deserialize;
+use thiserror::Error;
+use torrust_tracker_primitives::pagination::Pagination;
+use utoipa::IntoParams;
+
+use super::responses::{
+    invalid_ip_network_param_response, invalid_ip_param_response, invalid_peer_id_prefix_param_response,
+    missing_search_params_response, peer_matches_response, removed_peers_response,
+};
+use crate::core::torrent::peer_index::{IpNetwork, PeerSearch};
+use crate::core::Tracker;
+use crate::servers::apis::PeerIdParam;
+
+/// A container for the URL query parameters of the peer search.
+///
+/// At least one of `ip` and `peer_id` is required. For example, the peers in
+/// a network whose client is qBittorrent (`-qB`):
+///
+/// <http://127.0.0.1:1212/api/v1/peers?token=MyAccessToken&ip=192.168.0.0/16&peer_id=2d7142>
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQueryParams {
+    /// An IP address or a network in CIDR notation.
+    pub ip: Option<String>,
+    /// A peer ID or the first bytes of it, in hex format.
+    pub peer_id: Option<String>,
+    /// The offset of the first page to return. Starts at 0.
+    pub offset: Option<u32>,
+    /// The maximum number of items to return per page.
+    pub limit: Option<u32>,
+}
+
+impl SearchQueryParams {
+    /// It returns the criteria to search the peers.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if there is no criteria or any of them is not
+    /// valid.
+    pub fn search(&self) -> Result<PeerSearch, SearchParamError> {
+        let ip = self.ip.as_deref().filter(|ip| !ip.is_empty());
+        let peer_id = self.peer_id.as_deref().filter(|peer_id| !peer_id.is_empty());
+
+        if ip.is_none() && peer_id.is_none() {
+            return Err(SearchParamError::MissingCriteria);
+        }
+
+        let network = ip
+            .map(|ip| {
+                ip.parse::<IpNetwork>()
+                    .map_err(|_| SearchParamError::InvalidIp { ip: ip.to_string() })
+            })
+            .transpose()?;
+
+        let peer_id_prefix = peer_id
+            .map(|peer_id| {
+                PeerIdParam(peer_id.to_string())
+                    .peer_id_prefix()
+                    .ok_or_else(|| SearchParamError::InvalidPeerId {
+                        peer_id: peer_id.to_string(),
+                    })
+            })
+            .transpose()?;
+
+        Ok(PeerSearch { network, peer_id_prefix })
+    }
+
+    #[must_use]
+    pub fn pagination(&self) -> Pagination {
+        Pagination::new_with_options(self.offset, self.limit)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum SearchParamError {
+    #[error("missing search criteria")]
+    MissingCriteria,
+
+    #[error("invalid ip {ip}")]
+    InvalidIp { ip: String },
+
+    #[error("invalid peer id {peer_id}")]
+    InvalidPeerId { peer_id: String },
+}
+
+/// It handles the request to search the peers in all the swarms.
+///
+/// It returns:
+///
+/// - `200` response with a json array of [`PeerMatch`](crate::servers::apis::v1::context::peer::resources::PeerMatch) resources.
+/// - `400` response if the search params are missing or not valid.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::peer#search-peers)
+/// for more information about this endpoint.
+pub async fn search_peers_handler(State(tracker): State<Arc<Tracker>>, params: Query<SearchQueryParams>) -> Response {
+    match params.0.search() {
+        Ok(search) => peer_matches_response(&tracker.search_peers(&search, Some(&params.0.pagination()))).into_response(),
+        Err(SearchParamError::MissingCriteria) => missing_search_params_response(),
+        Err(SearchParamError::InvalidIp { ip }) => invalid_ip_network_param_response(&ip),
+        Err(SearchParamError::InvalidPeerId { peer_id }) => invalid_peer_id_prefix_param_response(&peer_id),
+    }
+}
+
+/// It handles the request to remove all the peers with an IP from all the
+/// swarms.
+///
+/// It returns:
+///
+/// - `200` response with a json [`RemovedPeers`](crate::servers::apis::v1::context::peer::resources::RemovedPeers) resource.
+/// - `400` response if the IP is not valid.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::peer#remove-the-peers-with-an-ip)
+/// for more information about this endpoint.
+pub async fn remove_peers_with_ip_handler(State(tracker): State<Arc<Tracker>>, Path(ip): Path<String>) -> Response {
+    match ip.parse::<IpAddr>() {
+        Ok(ip) => removed_peers_response(tracker.remove_peers_with_ip(&ip)).into_response(),
+        Err(_) => invalid_ip_param_response(&ip),
+    }
+}
@@ -0,0 +1,115 @@
+//! Peers API context.
+//!
+//! This API context is responsible for handling the requests related to the
+//! peers in all the torrent swarms. To remove a single peer from one swarm,
+//! use the [torrent context](crate::servers::apis::v1::context::torrent#remove-a-peer).
+//!
+//! The search requires the
+//! [`ReadTorrents`](crate::servers::apis::v1::middlewares::auth::Permission::ReadTorrents)
+//! permission. Removing peers requires the
+//! [`ManageSwarms`](crate::servers::apis::v1::middlewares::auth::Permission::ManageSwarms)
+//! permission. Only admin tokens have it.
+//!
+//! # Endpoints
+//!
+//! - [Search peers](#search-peers)
+//! - [Remove the peers with an IP](#remove-the-peers-with-an-ip)
+//!
+//! # Search peers
+//!
+//! `GET /peers`
+//!
+//! It returns the peers in all the swarms with an IP address or a peer ID,
+//! together with the torrent they are in. For example, to find the torrents
+//! an IP address from an abuse report is in. A peer in several torrents is
+//! returned once for each torrent.
+//!
+//! The peers are sorted by torrent and peer ID. The tracker keeps an index of
+//! the IP addresses and peer IDs, so the search does not go through all the
+//! swarms.
+//!
+//! **Query parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `ip` | IP address or network | The IP address of the peers, or their network in CIDR notation | No | `192.168.1.0/24`
+//! `peer_id` | hex string | The peer ID, or the first bytes of it, in hex format. The `0x` prefix is optional | No | `2d7142`
+//! `offset` | positive integer | The number of peers to skip | No | `0`
+//! `limit` | positive integer | The maximum number of peers to return. Defaults to 4000 | No | `100`
+//!
+//! At least one of `ip` and `peer_id` is required. If both are given, the
+//! peers have to match both.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/peers?token=MyAccessToken&ip=192.168.1.88"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! [
+//!   {
+//!     "info_hash": "5452869be36f9f3350ccee6b4544e7e76caaadab",
+//!     "peer": {
+//!       "peer_id": {
+//!         "id": "0x2d7142343431302d2a64465a3844484944704579",
+//!         "client": "qBittorrent"
+//!       },
+//!       "peer_addr": "192.168.1.88:17548",
+//!       "updated": 1669397478934,
+//!       "updated_milliseconds_ago": 1669397478934,
+//!       "uploaded": 0,
+//!       "downloaded": 0,
+//!       "left": 0,
+//!       "event": "Completed"
+//!     }
+//!   }
+//! ]
+//! ```
+//!
+//! The `updated` attribute of the peer is the last time it announced.
+//!
+//! **Resource**
+//!
+//! Refer to the API [`PeerMatch`](crate::servers::apis::v1::context::peer::resources::PeerMatch)
+//! resource for more information about the attributes for a single item in the
+//! response.
+//!
+//! # Remove the peers with an IP
+//!
+//! `DELETE /peers/:ip`
+//!
+//! It removes all the peers with the given IP address from all the swarms.
+//! For example, to remove an abusive client. The peers will be added again if
+//! they announce again.
+//!
+//! **Path parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `ip` | IPv4 or IPv6 address | The IP address of the peers | Yes | `192.168.1.88`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X DELETE "http://127.0.0.1:1212/api/v1/peers/192.168.1.88?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!   "removed_peers": 3
+//! }
+//! ```
+//!
+//! **Resource**
+//!
+//! Refer to the API [`RemovedPeers`](crate::servers::apis::v1::context::peer::resources::RemovedPeers)
+//! resource for more information about the response attributes.
+pub mod handlers;
+pub mod resources;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,31 @@
+//! API resources for the [`peer`](crate::servers::apis::v1::context::peer) API context.
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::torrent::peer_index;
+use crate::servers::apis::v1::context::torrent::resources::peer::Peer;
+
+/// A resource with the number of peers removed from the swarms.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct RemovedPeers {
+    /// The number of peers removed from all the swarms.
+    pub removed_peers: u64,
+}
+
+/// A peer found in a swarm by the peer search.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct PeerMatch {
+    /// The info-hash of the torrent the peer is in.
+    pub info_hash: String,
+    /// The peer. Its `updated` attribute is the last time it announced.
+    pub peer: Peer,
+}
+
+impl From<&peer_index::PeerMatch> for PeerMatch {
+    fn from(peer_match: &peer_index::PeerMatch) -> Self {
+        Self {
+            info_hash: peer_match.info_hash.to_string(),
+            peer: Peer::from(*peer_match.peer),
+        }
+    }
+}
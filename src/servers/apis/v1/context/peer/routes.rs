@@ -0,0 +1,27 @@
+//! API routes for the [`peer`](crate::servers::apis::v1::context::peer) API context.
+//!
+//! - `GET /peers`
+//! - `DELETE /peers/:ip`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v1::context::peer).
+use std::sync::Arc;
+
+use axum::routing::{delete, get};
+use axum::Router;
+
+use super::handlers::{remove_peers_with_ip_handler, search_peers_handler};
+use crate::core::Tracker;
+
+/// It adds the routes to the router for the [`peer`](crate::servers::apis::v1::context::peer) API context.
+pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    router.route(
+        &format!("{prefix}/peers/:ip"),
+        delete(remove_peers_with_ip_handler).with_state(tracker),
+    )
+}
+
+/// It adds the route to search the peers. It requires a different permission
+/// than the route to remove them.
+pub fn add_search(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    router.route(&format!("{prefix}/peers"), get(search_peers_handler).with_state(tracker))
+}
@@ -3,13 +3,24 @@
 use std::sync::Arc;
 
 use axum::extract::State;
-use axum::response::Json;
+use axum::response::{IntoResponse, Json, Response};
+use axum_extra::extract::Query;
+use serde::Deserialize;
+use utoipa::IntoParams;
 
 use super::resources::Stats;
-use super::responses::stats_response;
+use super::responses::{
+    failed_to_load_stats_history_response, invalid_days_param_response, stats_history_response, stats_response,
+};
 use crate::core::services::statistics::get_metrics;
 use crate::core::Tracker;
 
+/// Number of days returned by the statistics history endpoint by default.
+pub const DEFAULT_HISTORY_DAYS: u64 = 30;
+
+/// Maximum number of days returned by the statistics history endpoint.
+pub const MAX_HISTORY_DAYS: u64 = 366;
+
 /// It handles the request to get the tracker statistics.
 ///
 /// It returns a `200` response with a json [`Stats`]
@@ -19,3 +30,36 @@ use crate::core::Tracker;
 pub async fn get_stats_handler(State(tracker): State<Arc<Tracker>>) -> Json<Stats> {
     stats_response(get_metrics(tracker.clone()).await)
 }
+
+/// A container for the URL query parameters of the statistics history
+/// endpoint.
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HistoryQueryParams {
+    /// Number of days to return, including today.
+    pub days: Option<u64>,
+}
+
+/// It handles the request to get the daily totals of the persisted usage
+/// statistics.
+///
+/// It returns:
+///
+/// - `200` response with a json array of [`DailyStats`](crate::servers::apis::v1::context::stats::resources::DailyStats).
+/// - `400` response if the number of days is out of range.
+/// - `500` response if the history could not be loaded from the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::stats#get-tracker-statistics-history)
+/// for more information about this endpoint.
+pub async fn get_stats_history_handler(State(tracker): State<Arc<Tracker>>, params: Query<HistoryQueryParams>) -> Response {
+    let days = params.0.days.unwrap_or(DEFAULT_HISTORY_DAYS);
+
+    if !(1..=MAX_HISTORY_DAYS).contains(&days) {
+        return invalid_days_param_response(days, MAX_HISTORY_DAYS);
+    }
+
+    match tracker.get_statistics_history(days) {
+        Ok(daily_counters) => stats_history_response(&daily_counters).into_response(),
+        Err(e) => failed_to_load_stats_history_response(e),
+    }
+}
@@ -6,6 +6,7 @@
 //! # Endpoints
 //!
 //! - [Get tracker statistics](#get-tracker-statistics)
+//! - [Get tracker statistics history](#get-tracker-statistics-history)
 //!
 //! # Get tracker statistics
 //!
@@ -38,14 +39,159 @@
 //!     "udp4_scrapes_handled": 0,
 //!     "udp6_connections_handled": 0,
 //!     "udp6_announces_handled": 0,
-//!     "udp6_scrapes_handled": 0
+//!     "udp6_scrapes_handled": 0,
+//!     "tcp_errors": {
+//!       "bad_request": 0,
+//!       "invalid_connection_id": 0,
+//!       "authentication_failed": 0,
+//!       "torrent_not_whitelisted": 0,
+//!       "internal": 0
+//!     },
+//!     "udp_errors": {
+//!       "bad_request": 0,
+//!       "invalid_connection_id": 0,
+//!       "authentication_failed": 0,
+//!       "torrent_not_whitelisted": 0,
+//!       "internal": 0
+//!     },
+//!     "udp_connect_latency": {
+//!       "count": 0,
+//!       "avg_us": 0,
+//!       "p50_us": 0,
+//!       "p90_us": 0,
+//!       "p99_us": 0,
+//!       "max_us": 0
+//!     },
+//!     "udp_announce_latency": {
+//!       "count": 0,
+//!       "avg_us": 0,
+//!       "p50_us": 0,
+//!       "p90_us": 0,
+//!       "p99_us": 0,
+//!       "max_us": 0
+//!     },
+//!     "udp_scrape_latency": {
+//!       "count": 0,
+//!       "avg_us": 0,
+//!       "p50_us": 0,
+//!       "p90_us": 0,
+//!       "p99_us": 0,
+//!       "max_us": 0
+//!     },
+//!     "http_announce_latency": {
+//!       "count": 0,
+//!       "avg_us": 0,
+//!       "p50_us": 0,
+//!       "p90_us": 0,
+//!       "p99_us": 0,
+//!       "max_us": 0
+//!     },
+//!     "http_scrape_latency": {
+//!       "count": 0,
+//!       "avg_us": 0,
+//!       "p50_us": 0,
+//!       "p90_us": 0,
+//!       "p99_us": 0,
+//!       "max_us": 0
+//!     },
+//!     "request_rates": {
+//!       "udp_connect": { "last_1m": 0.0, "last_5m": 0.0, "last_1h": 0.0 },
+//!       "udp_announce": { "last_1m": 0.0, "last_5m": 0.0, "last_1h": 0.0 },
+//!       "udp_scrape": { "last_1m": 0.0, "last_5m": 0.0, "last_1h": 0.0 },
+//!       "http_announce": { "last_1m": 0.0, "last_5m": 0.0, "last_1h": 0.0 },
+//!       "http_scrape": { "last_1m": 0.0, "last_5m": 0.0, "last_1h": 0.0 }
+//!     },
+//!     "listeners": [
+//!       {
+//!         "scheme": "udp",
+//!         "address": "0.0.0.0:6969",
+//!         "ipv4": { "connections_handled": 1, "announces_handled": 1, "scrapes_handled": 0 },
+//!         "ipv6": { "connections_handled": 0, "announces_handled": 0, "scrapes_handled": 0 }
+//!       }
+//!     ],
+//!     "api_auth_failures": 0,
+//!     "api_auth_lockouts": 0
 //!   }
 //! ```
 //!
+//! The `*_errors` attributes contain the number of error responses by reason.
+//!
+//! The `*_latency` attributes contain the time it took to handle each type of
+//! request, in microseconds. Percentiles are estimated from a histogram, so
+//! they are rounded up to the histogram bucket bounds.
+//!
+//! The `request_rates` attribute contains the current load: the average number
+//! of requests per second in the last minute, five minutes and hour.
+//!
+//! The `listeners` attribute contains the number of requests handled by each
+//! HTTP and UDP tracker listener, identified by its bound socket address.
+//!
+//! The `api_auth_failures` attribute contains the number of tracker API
+//! requests rejected because the access token was missing, unknown or expired.
+//! The `api_auth_lockouts` attribute contains the number of times a client
+//! was locked out after too many invalid access tokens. See the
+//! [authentication middleware](crate::servers::apis::v1::middlewares::auth).
+//!
 //! **Resource**
 //!
 //! Refer to the API [`Stats`](crate::servers::apis::v1::context::stats::resources::Stats)
 //! resource for more information about the response attributes.
+//!
+//! The counters are persisted into the database every
+//! `statistics_persistence_interval` seconds and when the tracker shuts down,
+//! so they are not reset when the tracker restarts. Latency histograms,
+//! request rates and per-listener counters are not persisted.
+//!
+//! # Get tracker statistics history
+//!
+//! `GET /stats/history`
+//!
+//! Returns how much the persisted counters increased each day (UTC), from the
+//! oldest to the newest day. Days without requests are omitted, and so are
+//! the counters that did not increase.
+//!
+//! **Query parameters**
+//!
+//! Name | Type | Description | Required | Default | Example
+//! ---|---|---|---|---|---
+//! `days` | positive integer | Number of days, including today. Up to 366 | No | `30` | `7`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/stats/history?token=MyAccessToken&days=7"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! [
+//!   {
+//!     "date": "2024-01-01",
+//!     "counters": {
+//!       "tcp4_announces_handled": 120,
+//!       "tcp4_connections_handled": 150,
+//!       "tcp4_scrapes_handled": 30,
+//!       "tcp_errors_bad_request": 2
+//!     }
+//!   },
+//!   {
+//!     "date": "2024-01-02",
+//!     "counters": {
+//!       "udp4_announces_handled": 80,
+//!       "udp4_connections_handled": 80
+//!     }
+//!   }
+//! ]
+//! ```
+//!
+//! The history is only updated when the counters are persisted, so the
+//! requests handled since the last time are not included yet.
+//!
+//! **Resource**
+//!
+//! Refer to the API [`DailyStats`](crate::servers::apis::v1::context::stats::resources::DailyStats)
+//! resource for more information about the response attributes.
 pub mod handlers;
 pub mod resources;
 pub mod responses;
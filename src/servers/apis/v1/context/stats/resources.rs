@@ -1,11 +1,20 @@
 //! API resources for the [`stats`](crate::servers::apis::v1::context::stats)
 //! API context.
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
+use torrust_tracker_clock::conv::convert_from_timestamp_to_datetime_utc;
+use torrust_tracker_primitives::DurationSinceUnixEpoch;
+use utoipa::ToSchema;
 
 use crate::core::services::statistics::TrackerMetrics;
+use crate::core::statistics::{
+    self, DailyCounters, ErrorCounters, LatencyHistogram, Listener, ListenerMetrics, PersistentCounters, RequestCounters,
+    SECONDS_PER_DAY,
+};
 
 /// It contains all the statistics generated by the tracker.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, ToSchema)]
 pub struct Stats {
     // Torrent metrics
     /// Total number of torrents.
@@ -44,6 +53,194 @@ pub struct Stats {
     pub udp6_announces_handled: u64,
     /// Total number of UDP (UDP tracker) `scrape` requests from IPv6 peers.
     pub udp6_scrapes_handled: u64,
+
+    // Error metrics
+    /// Total number of TCP (HTTP tracker) error responses by reason.
+    pub tcp_errors: Errors,
+    /// Total number of UDP (UDP tracker) error responses by reason.
+    pub udp_errors: Errors,
+
+    // Latency metrics
+    /// Time spent handling UDP (UDP tracker) `connect` requests.
+    pub udp_connect_latency: Latency,
+    /// Time spent handling UDP (UDP tracker) `announce` requests.
+    pub udp_announce_latency: Latency,
+    /// Time spent handling UDP (UDP tracker) `scrape` requests.
+    pub udp_scrape_latency: Latency,
+    /// Time spent handling TCP (HTTP tracker) `announce` requests.
+    pub http_announce_latency: Latency,
+    /// Time spent handling TCP (HTTP tracker) `scrape` requests.
+    pub http_scrape_latency: Latency,
+
+    // Load metrics
+    /// Current number of requests per second.
+    pub request_rates: RequestRates,
+
+    // Listener metrics
+    /// Number of requests handled by each tracker listener. Only listeners
+    /// that have handled at least one request are included.
+    pub listeners: Vec<ListenerStats>,
+
+    // API metrics
+    /// Number of tracker API requests rejected because the access token was
+    /// missing, unknown or expired.
+    pub api_auth_failures: u64,
+    /// Number of times a tracker API client was locked out after too many
+    /// invalid access tokens.
+    pub api_auth_lockouts: u64,
+}
+
+/// Number of requests handled by one tracker listener.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct ListenerStats {
+    /// The tracker protocol: `http` or `udp`.
+    pub scheme: String,
+    /// The socket address the listener is bound to.
+    pub address: String,
+    /// Requests from IPv4 peers.
+    pub ipv4: RequestStats,
+    /// Requests from IPv6 peers.
+    pub ipv6: RequestStats,
+}
+
+impl From<(&Listener, &ListenerMetrics)> for ListenerStats {
+    fn from((listener, metrics): (&Listener, &ListenerMetrics)) -> Self {
+        Self {
+            scheme: listener.scheme.to_string(),
+            address: listener.address.to_string(),
+            ipv4: RequestStats::from(&metrics.ipv4),
+            ipv6: RequestStats::from(&metrics.ipv6),
+        }
+    }
+}
+
+/// Number of requests by type.
+///
+/// Since the HTTP tracker does not require a handshake, `connections_handled`
+/// increases for every HTTP request.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Default, ToSchema)]
+pub struct RequestStats {
+    pub connections_handled: u64,
+    pub announces_handled: u64,
+    pub scrapes_handled: u64,
+}
+
+impl From<&RequestCounters> for RequestStats {
+    fn from(counters: &RequestCounters) -> Self {
+        Self {
+            connections_handled: counters.connections_handled,
+            announces_handled: counters.announces_handled,
+            scrapes_handled: counters.scrapes_handled,
+        }
+    }
+}
+
+/// Average number of requests per second for each type of request.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default, ToSchema)]
+pub struct RequestRates {
+    /// UDP (UDP tracker) `connect` requests.
+    pub udp_connect: RequestRate,
+    /// UDP (UDP tracker) `announce` requests.
+    pub udp_announce: RequestRate,
+    /// UDP (UDP tracker) `scrape` requests.
+    pub udp_scrape: RequestRate,
+    /// TCP (HTTP tracker) `announce` requests.
+    pub http_announce: RequestRate,
+    /// TCP (HTTP tracker) `scrape` requests.
+    pub http_scrape: RequestRate,
+}
+
+impl From<statistics::RequestRates> for RequestRates {
+    fn from(rates: statistics::RequestRates) -> Self {
+        Self {
+            udp_connect: rates.udp_connect.into(),
+            udp_announce: rates.udp_announce.into(),
+            udp_scrape: rates.udp_scrape.into(),
+            http_announce: rates.http_announce.into(),
+            http_scrape: rates.http_scrape.into(),
+        }
+    }
+}
+
+/// Average number of requests per second in rolling windows.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Default, ToSchema)]
+pub struct RequestRate {
+    /// In the last minute.
+    pub last_1m: f64,
+    /// In the last five minutes.
+    pub last_5m: f64,
+    /// In the last hour.
+    pub last_1h: f64,
+}
+
+impl From<statistics::RequestRate> for RequestRate {
+    fn from(rate: statistics::RequestRate) -> Self {
+        Self {
+            last_1m: rate.last_1m,
+            last_5m: rate.last_5m,
+            last_1h: rate.last_1h,
+        }
+    }
+}
+
+/// Number of error responses by reason.
+///
+/// Refer to [`ErrorReason`](crate::core::statistics::ErrorReason) for the
+/// meaning of each reason.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Default, ToSchema)]
+pub struct Errors {
+    pub bad_request: u64,
+    pub invalid_connection_id: u64,
+    pub authentication_failed: u64,
+    pub torrent_not_whitelisted: u64,
+    pub internal: u64,
+}
+
+impl From<&ErrorCounters> for Errors {
+    fn from(counters: &ErrorCounters) -> Self {
+        Self {
+            bad_request: counters.bad_request,
+            invalid_connection_id: counters.invalid_connection_id,
+            authentication_failed: counters.authentication_failed,
+            torrent_not_whitelisted: counters.torrent_not_whitelisted,
+            internal: counters.internal,
+        }
+    }
+}
+
+/// A summary of the time spent handling one type of request. All the times
+/// are in microseconds.
+///
+/// Percentiles are estimations. Refer to
+/// [`LatencyHistogram`](crate::core::statistics::LatencyHistogram) for more
+/// information.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Default, ToSchema)]
+pub struct Latency {
+    /// Number of requests measured.
+    pub count: u64,
+    /// Average latency.
+    pub avg_us: u64,
+    /// 50th percentile (median) latency.
+    pub p50_us: u64,
+    /// 90th percentile latency.
+    pub p90_us: u64,
+    /// 99th percentile latency.
+    pub p99_us: u64,
+    /// Highest latency.
+    pub max_us: u64,
+}
+
+impl From<&LatencyHistogram> for Latency {
+    fn from(histogram: &LatencyHistogram) -> Self {
+        Self {
+            count: histogram.count,
+            avg_us: histogram.sum_us.checked_div(histogram.count).unwrap_or_default(),
+            p50_us: histogram.percentile(50),
+            p90_us: histogram.percentile(90),
+            p99_us: histogram.percentile(99),
+            max_us: histogram.max_us,
+        }
+    }
 }
 
 impl From<TrackerMetrics> for Stats {
@@ -65,19 +262,66 @@ impl From<TrackerMetrics> for Stats {
             udp6_connections_handled: metrics.protocol_metrics.udp6_connections_handled,
             udp6_announces_handled: metrics.protocol_metrics.udp6_announces_handled,
             udp6_scrapes_handled: metrics.protocol_metrics.udp6_scrapes_handled,
+            tcp_errors: Errors::from(&metrics.protocol_metrics.tcp_errors),
+            udp_errors: Errors::from(&metrics.protocol_metrics.udp_errors),
+            udp_connect_latency: Latency::from(&metrics.protocol_metrics.udp_connect_latency),
+            udp_announce_latency: Latency::from(&metrics.protocol_metrics.udp_announce_latency),
+            udp_scrape_latency: Latency::from(&metrics.protocol_metrics.udp_scrape_latency),
+            http_announce_latency: Latency::from(&metrics.protocol_metrics.http_announce_latency),
+            http_scrape_latency: Latency::from(&metrics.protocol_metrics.http_scrape_latency),
+            request_rates: metrics.request_rates.into(),
+            listeners: metrics.protocol_metrics.listeners.iter().map(ListenerStats::from).collect(),
+            api_auth_failures: metrics.protocol_metrics.api_auth_failures,
+            api_auth_lockouts: metrics.protocol_metrics.api_auth_lockouts,
+        }
+    }
+}
+
+/// How much the usage statistics counters increased in one day.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct DailyStats {
+    /// The day, in `YYYY-MM-DD` format (UTC).
+    pub date: String,
+    /// Increment by counter name. Counters that did not increase are omitted.
+    pub counters: BTreeMap<String, u64>,
+}
+
+impl DailyStats {
+    /// It converts the daily totals into a list sorted by date.
+    #[must_use]
+    pub fn from_daily_counters(daily_counters: &DailyCounters) -> Vec<Self> {
+        daily_counters
+            .iter()
+            .map(|(day, counters)| Self::new(*day, counters))
+            .collect()
+    }
+
+    fn new(day: u64, counters: &PersistentCounters) -> Self {
+        let date = convert_from_timestamp_to_datetime_utc(DurationSinceUnixEpoch::from_secs(day * SECONDS_PER_DAY)).date_naive();
+
+        Self {
+            date: date.to_string(),
+            counters: counters.clone(),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
     use torrust_tracker_primitives::torrent_metrics::TorrentsMetrics;
 
-    use super::Stats;
+    use super::{DailyStats, Errors, Latency, ListenerStats, RequestRate, RequestRates, RequestStats, Stats};
     use crate::core::services::statistics::TrackerMetrics;
-    use crate::core::statistics::Metrics;
+    use crate::core::statistics::{
+        self, DailyCounters, ErrorCounters, LatencyHistogram, Listener, ListenerMetrics, Metrics, PersistentCounters,
+        RequestCounters, Scheme,
+    };
 
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn stats_resource_should_be_converted_from_tracker_metrics() {
         assert_eq!(
             Stats::from(TrackerMetrics {
@@ -99,7 +343,40 @@ mod tests {
                     udp4_scrapes_handled: 13,
                     udp6_connections_handled: 14,
                     udp6_announces_handled: 15,
-                    udp6_scrapes_handled: 16
+                    udp6_scrapes_handled: 16,
+                    tcp_errors: ErrorCounters {
+                        bad_request: 17,
+                        ..Default::default()
+                    },
+                    udp_errors: ErrorCounters {
+                        invalid_connection_id: 18,
+                        ..Default::default()
+                    },
+                    listeners: BTreeMap::from([(
+                        Listener {
+                            scheme: Scheme::Udp,
+                            address: "0.0.0.0:6969".parse().unwrap(),
+                        },
+                        ListenerMetrics {
+                            ipv4: RequestCounters {
+                                connections_handled: 22,
+                                announces_handled: 23,
+                                scrapes_handled: 24,
+                            },
+                            ..Default::default()
+                        },
+                    )]),
+                    api_auth_failures: 26,
+                    api_auth_lockouts: 27,
+                    ..Default::default()
+                },
+                request_rates: statistics::RequestRates {
+                    udp_announce: statistics::RequestRate {
+                        last_1m: 19.0,
+                        last_5m: 20.0,
+                        last_1h: 21.0,
+                    },
+                    ..Default::default()
                 }
             }),
             Stats {
@@ -118,8 +395,82 @@ mod tests {
                 udp4_scrapes_handled: 13,
                 udp6_connections_handled: 14,
                 udp6_announces_handled: 15,
-                udp6_scrapes_handled: 16
+                udp6_scrapes_handled: 16,
+                tcp_errors: Errors {
+                    bad_request: 17,
+                    ..Default::default()
+                },
+                udp_errors: Errors {
+                    invalid_connection_id: 18,
+                    ..Default::default()
+                },
+                udp_connect_latency: Latency::default(),
+                udp_announce_latency: Latency::default(),
+                udp_scrape_latency: Latency::default(),
+                http_announce_latency: Latency::default(),
+                http_scrape_latency: Latency::default(),
+                request_rates: RequestRates {
+                    udp_announce: RequestRate {
+                        last_1m: 19.0,
+                        last_5m: 20.0,
+                        last_1h: 21.0,
+                    },
+                    ..Default::default()
+                },
+                listeners: vec![ListenerStats {
+                    scheme: "udp".to_string(),
+                    address: "0.0.0.0:6969".to_string(),
+                    ipv4: RequestStats {
+                        connections_handled: 22,
+                        announces_handled: 23,
+                        scrapes_handled: 24,
+                    },
+                    ipv6: RequestStats::default(),
+                }],
+                api_auth_failures: 26,
+                api_auth_lockouts: 27,
+            }
+        );
+    }
+
+    #[test]
+    fn latency_resource_should_be_converted_from_a_latency_histogram() {
+        let mut histogram = LatencyHistogram::default();
+
+        histogram.observe(Duration::from_micros(200));
+        histogram.observe(Duration::from_micros(400));
+
+        assert_eq!(
+            Latency::from(&histogram),
+            Latency {
+                count: 2,
+                avg_us: 300,
+                p50_us: 250,
+                p90_us: 400,
+                p99_us: 400,
+                max_us: 400,
             }
         );
     }
+
+    #[test]
+    fn daily_stats_resources_should_be_converted_from_the_daily_counters_sorted_by_date() {
+        let counters = PersistentCounters::from([("udp4_announces_handled".to_string(), 1)]);
+
+        let daily_counters = DailyCounters::from([(19_724, counters.clone()), (0, counters.clone())]);
+
+        assert_eq!(
+            DailyStats::from_daily_counters(&daily_counters),
+            vec![
+                DailyStats {
+                    date: "1970-01-01".to_string(),
+                    counters: counters.clone(),
+                },
+                DailyStats {
+                    date: "2024-01-02".to_string(),
+                    counters,
+                },
+            ]
+        );
+    }
 }
@@ -1,11 +1,34 @@
 //! API responses for the [`stats`](crate::servers::apis::v1::context::stats)
 //! API context.
-use axum::response::Json;
+use std::error::Error;
 
-use super::resources::Stats;
+use axum::response::{Json, Response};
+
+use super::resources::{DailyStats, Stats};
 use crate::core::services::statistics::TrackerMetrics;
+use crate::core::statistics::DailyCounters;
+use crate::servers::apis::v1::responses::{bad_request_response, unhandled_rejection_response};
 
 /// `200` response that contains the [`Stats`] resource as json.
 pub fn stats_response(tracker_metrics: TrackerMetrics) -> Json<Stats> {
     Json(Stats::from(tracker_metrics))
 }
+
+/// `200` response that contains an array of [`DailyStats`] resources as json.
+pub fn stats_history_response(daily_counters: &DailyCounters) -> Json<Vec<DailyStats>> {
+    Json(DailyStats::from_daily_counters(daily_counters))
+}
+
+/// `400` error response when the number of days is out of range.
+#[must_use]
+pub fn invalid_days_param_response(days: u64, max_days: u64) -> Response {
+    bad_request_response(&format!(
+        "Invalid days param {days}, expected a number between 1 and {max_days}"
+    ))
+}
+
+/// `500` error response when the statistics history cannot be loaded from the database.
+#[must_use]
+pub fn failed_to_load_stats_history_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to load statistics history: {e}"))
+}
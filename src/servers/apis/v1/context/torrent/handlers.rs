@@ -11,12 +11,23 @@ use bittorrent_primitives::info_hash::InfoHash;
 use serde::{de, Deserialize, Deserializer};
 use thiserror::Error;
 use torrust_tracker_primitives::pagination::Pagination;
+use utoipa::IntoParams;
 
-use super::responses::{torrent_info_response, torrent_list_response, torrent_not_known_response};
-use crate::core::services::torrent::{get_torrent_info, get_torrents, get_torrents_page};
+use super::resources::export::{ExportFormat, ParseExportFormatError};
+use super::responses::{
+    failed_to_remove_torrent_response, invalid_format_param_response, invalid_info_hash_prefix_param_response,
+    invalid_limit_param_response, invalid_order_param_response, invalid_ranking_param_response, invalid_sort_param_response,
+    peer_not_known_response, top_torrents_response, torrent_info_response, torrent_not_known_response, torrent_page_response,
+    torrents_export_response,
+};
+use crate::core::services::torrent::{
+    get_filtered_torrents, get_filtered_torrents_by_info_hash, get_torrent_info, ParseSortFieldError, ParseSortOrderError,
+    SortField, SortOrder, TorrentFilter, TorrentSort,
+};
+use crate::core::torrent::activity::Ranking;
 use crate::core::Tracker;
-use crate::servers::apis::v1::responses::invalid_info_hash_param_response;
-use crate::servers::apis::InfoHashParam;
+use crate::servers::apis::v1::responses::{invalid_info_hash_param_response, invalid_peer_id_param_response, ok_response};
+use crate::servers::apis::{InfoHashParam, PeerIdParam};
 
 /// It handles the request to get the torrent data.
 ///
@@ -41,6 +52,9 @@ pub async fn get_torrent_handler(State(tracker): State<Arc<Tracker>>, Path(info_
 ///
 /// Pagination: `offset` and `limit`.
 /// Array of infohashes: `info_hash`.
+/// Filters: `min_seeders`, `max_seeders`, `min_leechers`, `max_leechers`,
+/// `min_completed`, `max_completed`, `has_peers` and `info_hash_prefix`.
+/// Sorting: `sort` and `order`.
 ///
 /// You can either get all torrents with pagination or get a list of torrents
 /// providing a list of infohashes. For example:
@@ -55,8 +69,14 @@ pub async fn get_torrent_handler(State(tracker): State<Arc<Tracker>>, Path(info_
 /// <http://127.0.0.1:1212/api/v1/torrents?token=MyAccessToken&info_hash=9c38422213e30bff212b30c360d26f9a02136422&info_hash=2b66980093bc11806fab50cb3cb41835b95a0362>
 ///
 ///
+/// The torrents with peers and at least ten seeders, from the most seeded:
+///
+/// <http://127.0.0.1:1212/api/v1/torrents?token=MyAccessToken&has_peers=true&min_seeders=10&sort=seeders&order=desc>
+///
+///
 /// NOTICE: Pagination is ignored if array of infohashes is provided.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct QueryParams {
     /// The offset of the first page to return. Starts at 0.
     #[serde(default, deserialize_with = "empty_string_as_none")]
@@ -67,43 +87,307 @@ pub struct QueryParams {
     /// A list of infohashes to retrieve.
     #[serde(default, rename = "info_hash")]
     pub info_hashes: Vec<String>,
+    /// Only the torrents with at least this number of seeders.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub min_seeders: Option<u64>,
+    /// Only the torrents with at most this number of seeders.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub max_seeders: Option<u64>,
+    /// Only the torrents with at least this number of leechers.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub min_leechers: Option<u64>,
+    /// Only the torrents with at most this number of leechers.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub max_leechers: Option<u64>,
+    /// Only the torrents with at least this number of completed downloads.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub min_completed: Option<u64>,
+    /// Only the torrents with at most this number of completed downloads.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub max_completed: Option<u64>,
+    /// Only the torrents with (`true`) or without (`false`) peers.
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub has_peers: Option<bool>,
+    /// Only the torrents whose info-hash starts with this hex string.
+    pub info_hash_prefix: Option<String>,
+    /// The [`SortField`]: `info_hash`, `seeders`, `leechers` or `completed`.
+    /// Defaults to `info_hash`.
+    pub sort: Option<String>,
+    /// The [`SortOrder`]: `asc` or `desc`. Defaults to `asc`.
+    pub order: Option<String>,
+}
+
+impl QueryParams {
+    /// It returns the filter to select the torrents.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the info-hash prefix is not a hex string of up
+    /// to 40 characters.
+    pub fn filter(&self) -> Result<TorrentFilter, QueryParamError> {
+        let info_hash_prefix = match self.info_hash_prefix.as_deref() {
+            None | Some("") => None,
+            Some(prefix) if prefix.len() <= 40 && prefix.chars().all(|c| c.is_ascii_hexdigit()) => {
+                Some(prefix.to_ascii_lowercase())
+            }
+            Some(prefix) => {
+                return Err(QueryParamError::InvalidInfoHashPrefix {
+                    prefix: prefix.to_string(),
+                })
+            }
+        };
+
+        Ok(TorrentFilter {
+            min_seeders: self.min_seeders,
+            max_seeders: self.max_seeders,
+            min_leechers: self.min_leechers,
+            max_leechers: self.max_leechers,
+            min_completed: self.min_completed,
+            max_completed: self.max_completed,
+            has_peers: self.has_peers,
+            info_hash_prefix,
+        })
+    }
+
+    /// It returns how to sort the torrents.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the sort field or the sort order are unknown.
+    pub fn sort(&self) -> Result<TorrentSort, QueryParamError> {
+        let field = match self.sort.as_deref() {
+            None | Some("") => SortField::default(),
+            Some(field) => SortField::from_str(field).map_err(|source| QueryParamError::InvalidSort { source })?,
+        };
+
+        let order = match self.order.as_deref() {
+            None | Some("") => SortOrder::default(),
+            Some(order) => SortOrder::from_str(order).map_err(|source| QueryParamError::InvalidOrder { source })?,
+        };
+
+        Ok(TorrentSort { field, order })
+    }
 }
 
 /// It handles the request to get a list of torrents.
 ///
 /// It returns a `200` response with a json array with [`crate::servers::apis::v1::context::torrent::resources::torrent::ListItem`] resources.
+/// The `X-Total-Count` header contains the number of torrents matching the
+/// filters, without pagination.
 ///
 /// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::torrent#list-torrents)
 /// for more information about this endpoint.
-pub async fn get_torrents_handler(State(tracker): State<Arc<Tracker>>, pagination: Query<QueryParams>) -> Response {
-    tracing::debug!("pagination: {:?}", pagination);
-
-    if pagination.0.info_hashes.is_empty() {
-        torrent_list_response(
-            &get_torrents_page(
-                tracker.clone(),
-                Some(&Pagination::new_with_options(pagination.0.offset, pagination.0.limit)),
-            )
-            .await,
-        )
-        .into_response()
+pub async fn get_torrents_handler(State(tracker): State<Arc<Tracker>>, params: Query<QueryParams>) -> Response {
+    tracing::debug!("params: {:?}", params);
+
+    let (filter, sort) = match (params.0.filter(), params.0.sort()) {
+        (Ok(filter), Ok(sort)) => (filter, sort),
+        (Err(err), _) | (_, Err(err)) => return query_param_error_response(err),
+    };
+
+    if params.0.info_hashes.is_empty() {
+        let pagination = Pagination::new_with_options(params.0.offset, params.0.limit);
+
+        torrent_page_response(&get_filtered_torrents(tracker.clone(), &filter, sort, &pagination).await)
     } else {
-        match parse_info_hashes(pagination.0.info_hashes) {
-            Ok(info_hashes) => torrent_list_response(&get_torrents(tracker.clone(), &info_hashes).await).into_response(),
-            Err(err) => match err {
-                QueryParamError::InvalidInfoHash { info_hash } => invalid_info_hash_param_response(&info_hash),
-            },
+        match parse_info_hashes(params.0.info_hashes) {
+            Ok(info_hashes) => {
+                torrent_page_response(&get_filtered_torrents_by_info_hash(tracker.clone(), &info_hashes, &filter, sort).await)
+            }
+            Err(err) => query_param_error_response(err),
+        }
+    }
+}
+
+fn query_param_error_response(err: QueryParamError) -> Response {
+    match err {
+        QueryParamError::InvalidInfoHash { info_hash } => invalid_info_hash_param_response(&info_hash),
+        QueryParamError::InvalidInfoHashPrefix { prefix } => invalid_info_hash_prefix_param_response(&prefix),
+        QueryParamError::InvalidSort { source } => invalid_sort_param_response(&source.to_string()),
+        QueryParamError::InvalidOrder { source } => invalid_order_param_response(&source.to_string()),
+    }
+}
+
+/// Number of torrents returned by the top torrents endpoint by default.
+pub const DEFAULT_TOP_TORRENTS_LIMIT: usize = 10;
+
+/// Maximum number of torrents returned by the top torrents endpoint.
+pub const MAX_TOP_TORRENTS_LIMIT: usize = 100;
+
+/// A container for the URL query parameters of the top torrents endpoint.
+///
+/// For example, the five torrents with the most completed downloads:
+///
+/// <http://127.0.0.1:1212/api/v1/torrents/top?token=MyAccessToken&by=completed&limit=5>
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TopQueryParams {
+    /// The [`Ranking`] used to sort the torrents. Defaults to `seeders`.
+    pub by: Option<String>,
+    /// The maximum number of torrents to return.
+    pub limit: Option<usize>,
+}
+
+/// It handles the request to get the torrents with the highest score for a
+/// given ranking.
+///
+/// It returns:
+///
+/// - `200` response with a json array of [`RankedTorrent`](crate::servers::apis::v1::context::torrent::resources::torrent::RankedTorrent) resources.
+/// - `400` response if the ranking is unknown or the limit is out of range.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::torrent#top-torrents)
+/// for more information about this endpoint.
+pub async fn get_top_torrents_handler(State(tracker): State<Arc<Tracker>>, params: Query<TopQueryParams>) -> Response {
+    let ranking = match params.0.by.as_deref().map(Ranking::from_str).transpose() {
+        Ok(ranking) => ranking.unwrap_or(Ranking::Seeders),
+        Err(err) => return invalid_ranking_param_response(&err.to_string()),
+    };
+
+    let limit = params.0.limit.unwrap_or(DEFAULT_TOP_TORRENTS_LIMIT);
+
+    if !(1..=MAX_TOP_TORRENTS_LIMIT).contains(&limit) {
+        return invalid_limit_param_response(limit, MAX_TOP_TORRENTS_LIMIT);
+    }
+
+    top_torrents_response(&tracker.get_top_torrents(ranking, limit)).into_response()
+}
+
+/// A container for the URL query parameters of the torrents export.
+///
+/// For example, all the torrents with their peers in CSV:
+///
+/// <http://127.0.0.1:1212/api/v1/torrents/export?token=MyAccessToken&format=csv&peers=true>
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportQueryParams {
+    /// The [`ExportFormat`]: `ndjson` or `csv`. Defaults to `ndjson`.
+    pub format: Option<String>,
+    /// Whether to include the peers of each torrent. Defaults to `false`.
+    #[serde(default)]
+    pub peers: bool,
+}
+
+impl ExportQueryParams {
+    /// It returns the format of the export.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the format is unknown.
+    pub fn format(&self) -> Result<ExportFormat, ParseExportFormatError> {
+        match self.format.as_deref() {
+            None | Some("") => Ok(ExportFormat::default()),
+            Some(format) => ExportFormat::from_str(format),
         }
     }
 }
 
+/// It handles the request to export all the torrents.
+///
+/// It returns:
+///
+/// - `200` response with a stream of [`Torrent`](crate::servers::apis::v1::context::torrent::resources::torrent::Torrent)
+///   resources, as NDJSON or CSV.
+/// - `400` response if the format is unknown.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::torrent#export-torrents)
+/// for more information about this endpoint.
+pub async fn export_torrents_handler(State(tracker): State<Arc<Tracker>>, params: Query<ExportQueryParams>) -> Response {
+    match params.0.format() {
+        Ok(format) => torrents_export_response(tracker, format, params.0.peers),
+        Err(err) => invalid_format_param_response(&err.to_string()),
+    }
+}
+
+/// A container for the URL query parameters of the endpoint to remove a
+/// torrent.
+///
+/// For example, to also remove the persisted number of completed downloads:
+///
+/// <http://127.0.0.1:1212/api/v1/torrent/9c38422213e30bff212b30c360d26f9a02136422?token=MyAccessToken&remove_completed=true>
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RemoveQueryParams {
+    /// Whether to remove the persisted number of completed downloads too.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub remove_completed: bool,
+}
+
+/// It handles the request to remove a torrent and its swarm.
+///
+/// It returns:
+///
+/// - `200` response with a json [`ActionStatus::Ok`](crate::servers::apis::v1::responses::ActionStatus::Ok).
+/// - `200` response with the `torrent not known` json string if the tracker does not have the torrent.
+/// - `500` with serialized error in debug format if the torrent cannot be removed from the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::torrent#remove-a-torrent)
+/// for more information about this endpoint.
+pub async fn remove_torrent_handler(
+    State(tracker): State<Arc<Tracker>>,
+    Path(info_hash): Path<InfoHashParam>,
+    params: Query<RemoveQueryParams>,
+) -> Response {
+    match InfoHash::from_str(&info_hash.0) {
+        Err(_) => invalid_info_hash_param_response(&info_hash.0),
+        Ok(info_hash) => match tracker.remove_torrent(&info_hash, params.0.remove_completed) {
+            Ok(true) => ok_response(),
+            Ok(false) => torrent_not_known_response(),
+            Err(e) => failed_to_remove_torrent_response(e),
+        },
+    }
+}
+
+/// It handles the request to remove a peer from a torrent swarm.
+///
+/// It returns:
+///
+/// - `200` response with a json [`ActionStatus::Ok`](crate::servers::apis::v1::responses::ActionStatus::Ok).
+/// - `200` response with the `peer not known` json string if the peer is not in the swarm.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::torrent#remove-a-peer)
+/// for more information about this endpoint.
+pub async fn remove_peer_handler(
+    State(tracker): State<Arc<Tracker>>,
+    Path((info_hash, peer_id)): Path<(InfoHashParam, PeerIdParam)>,
+) -> Response {
+    let Ok(info_hash) = InfoHash::from_str(&info_hash.0) else {
+        return invalid_info_hash_param_response(&info_hash.0);
+    };
+
+    let Some(peer_id) = peer_id.peer_id() else {
+        return invalid_peer_id_param_response(&peer_id.0);
+    };
+
+    if tracker.remove_peer(&info_hash, &peer_id) {
+        ok_response()
+    } else {
+        peer_not_known_response()
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum QueryParamError {
     #[error("invalid infohash {info_hash}")]
     InvalidInfoHash { info_hash: String },
+
+    #[error("invalid infohash prefix {prefix}")]
+    InvalidInfoHashPrefix { prefix: String },
+
+    #[error("invalid sort field: {source}")]
+    InvalidSort { source: ParseSortFieldError },
+
+    #[error("invalid sort order: {source}")]
+    InvalidOrder { source: ParseSortOrderError },
 }
 
-fn parse_info_hashes(info_hashes_str: Vec<String>) -> Result<Vec<InfoHash>, QueryParamError> {
+/// It parses the list of infohashes in the query params.
+///
+/// # Errors
+///
+/// Will return an error with the first infohash that can not be parsed.
+pub fn parse_info_hashes(info_hashes_str: Vec<String>) -> Result<Vec<InfoHash>, QueryParamError> {
     let mut info_hashes: Vec<InfoHash> = Vec::new();
 
     for info_hash_str in info_hashes_str {
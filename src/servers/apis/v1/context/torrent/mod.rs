@@ -7,6 +7,14 @@
 //!
 //! - [Get a torrent](#get-a-torrent)
 //! - [List torrents](#list-torrents)
+//! - [Top torrents](#top-torrents)
+//! - [Export torrents](#export-torrents)
+//! - [Remove a torrent](#remove-a-torrent)
+//! - [Remove a peer](#remove-a-peer)
+//!
+//! The endpoints to remove torrents and peers require the
+//! [`ManageSwarms`](crate::servers::apis::v1::middlewares::auth::Permission::ManageSwarms)
+//! permission. Only admin tokens have it.
 //!
 //! # Get a torrent
 //!
@@ -73,21 +81,34 @@
 //!
 //! **Query parameters**
 //!
-//! The endpoint supports pagination.
+//! The endpoint supports pagination, filters and sorting.
 //!
 //! Name | Type | Description | Required | Example
 //! ---|---|---|---|---
 //! `offset` | positive integer | The page number, starting at 0 | No | `1`
 //! `limit` | positive integer | Page size. The number of results per page | No | `10`
+//! `info_hash` | string | Only these torrents. It can be repeated. Pagination is ignored | No | `5452869be36f9f3350ccee6b4544e7e76caaadab`
+//! `min_seeders`, `max_seeders` | positive integer | Only the torrents within this range of seeders | No | `1`
+//! `min_leechers`, `max_leechers` | positive integer | Only the torrents within this range of leechers | No | `1`
+//! `min_completed`, `max_completed` | positive integer | Only the torrents within this range of completed downloads | No | `100`
+//! `has_peers` | boolean | Only the torrents with (`true`) or without (`false`) peers | No | `true`
+//! `info_hash_prefix` | hex string | Only the torrents whose info-hash starts with this prefix | No | `5452`
+//! `sort` | string | Sort by `info_hash` (default), `seeders`, `leechers` or `completed` | No | `seeders`
+//! `order` | string | Sort order: `asc` (default) or `desc`. Ties are sorted by info-hash | No | `desc`
+//!
+//! The response contains the `X-Total-Count` header with the number of
+//! torrents matching the filters, without pagination.
 //!
 //! **Example request**
 //!
 //! ```bash
-//! curl "http://127.0.0.1:1212/api/v1/torrents?token=MyAccessToken&offset=1&limit=1"
+//! curl -i "http://127.0.0.1:1212/api/v1/torrents?token=MyAccessToken&min_seeders=1&sort=seeders&order=desc&offset=1&limit=1"
 //! ```
 //!
 //! **Example response** `200`
 //!
+//! With the header `X-Total-Count: 2`.
+//!
 //! ```json
 //! [
 //!     {
@@ -107,6 +128,176 @@
 //! response.
 //!
 //! > **NOTICE**: this endpoint does not include the `peers` list.
+//!
+//! # Top torrents
+//!
+//! `GET /torrents/top`
+//!
+//! Returns the torrents with the highest score for a given ranking, from the
+//! highest.
+//!
+//! **Query parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `by` | string | The ranking: `seeders`, `leechers`, `completed`, `announce_rate` or `trending`. Defaults to `seeders` | No | `trending`
+//! `limit` | positive integer | Number of torrents to return, between 1 and 100. Defaults to 10 | No | `5`
+//!
+//! The `announce_rate` is the number of announces per second over the last
+//! few minutes. The `trending` score is the number of completed downloads in
+//! the last hours, where each completion counts half as much every six hours.
+//! Both are kept in memory, so they are reset when the tracker restarts.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/torrents/top?token=MyAccessToken&by=trending&limit=1"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! [
+//!     {
+//!       "info_hash": "5452869be36f9f3350ccee6b4544e7e76caaadab",
+//!       "seeders": 12,
+//!       "completed": 40,
+//!       "leechers": 3,
+//!       "announce_rate": 0.21,
+//!       "trending_score": 7.4
+//!     }
+//! ]
+//! ```
+//!
+//! **Resource**
+//!
+//! Refer to the API [`RankedTorrent`](crate::servers::apis::v1::context::torrent::resources::torrent::RankedTorrent)
+//! resource for more information about the attributes for a single item in the
+//! response.
+//!
+//! # Export torrents
+//!
+//! `GET /torrents/export`
+//!
+//! Returns all the torrents, optionally with their peers, as newline
+//! delimited json (NDJSON) or CSV. Unlike the [torrent list](#list-torrents),
+//! the response is not paginated: it is written while the tracker goes
+//! through the torrents, so the memory used does not depend on the number of
+//! torrents.
+//!
+//! **Query parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `format` | string | The format: `ndjson` or `csv`. Defaults to `ndjson` | No | `csv`
+//! `peers` | boolean | Whether to include the peers of each torrent. Defaults to `false` | No | `true`
+//!
+//! The torrents are sorted by info-hash. Torrents added or removed while the
+//! export is running may or may not be included.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/torrents/export?token=MyAccessToken&format=csv"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```text
+//! info_hash,seeders,completed,leechers
+//! 5452869be36f9f3350ccee6b4544e7e76caaadab,1,0,0
+//! 9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d,12,40,3
+//! ```
+//!
+//! With `peers=true`, the CSV has a row for each peer, with the
+//! `peer_id,client,peer_addr,updated,uploaded,downloaded,left,event` columns
+//! after the torrent ones. A torrent without peers has one row with empty peer
+//! columns. In NDJSON, each line is a
+//! [`Torrent`](crate::servers::apis::v1::context::torrent::resources::torrent::Torrent)
+//! resource, with the `peers` attribute only when they are included.
+//!
+//! # Remove a torrent
+//!
+//! `DELETE /torrent/:info_hash`
+//!
+//! It removes a torrent and its swarm from memory. For example, to remove a
+//! torrent with an abusive swarm. The torrent will be added again if a peer
+//! announces it again.
+//!
+//! By default, the persisted number of completed downloads is kept, so the
+//! torrent is loaded again with its completed downloads when the tracker
+//! restarts.
+//!
+//! **Path parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `info_hash` | 40-char string | The Info Hash v1 | Yes | `5452869be36f9f3350ccee6b4544e7e76caaadab`
+//!
+//! **Query parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `remove_completed` | boolean | Also remove the persisted number of completed downloads. Defaults to `false` | No | `true`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X DELETE "http://127.0.0.1:1212/api/v1/torrent/5452869be36f9f3350ccee6b4544e7e76caaadab?token=MyAccessToken&remove_completed=true"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!   "status": "ok"
+//! }
+//! ```
+//!
+//! **Not Found response** `200`
+//!
+//! This response is returned when the tracker does not have the torrent.
+//!
+//! ```json
+//! "torrent not known"
+//! ```
+//!
+//! # Remove a peer
+//!
+//! `DELETE /torrent/:info_hash/peer/:peer_id`
+//!
+//! It removes a peer from a torrent swarm. The peer will be added again if it
+//! announces again. To remove all the peers with a given IP from all the
+//! swarms, use the [peer context](crate::servers::apis::v1::context::peer).
+//!
+//! **Path parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `info_hash` | 40-char string | The Info Hash v1 | Yes | `5452869be36f9f3350ccee6b4544e7e76caaadab`
+//! `peer_id` | 40-char hex string | The peer ID, with or without the `0x` prefix, as shown in the [`Torrent`](crate::servers::apis::v1::context::torrent::resources::torrent::Torrent) resource | Yes | `0x2d7142343431302d2a64465a3844484944704579`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X DELETE "http://127.0.0.1:1212/api/v1/torrent/5452869be36f9f3350ccee6b4544e7e76caaadab/peer/0x2d7142343431302d2a64465a3844484944704579?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!   "status": "ok"
+//! }
+//! ```
+//!
+//! **Not Found response** `200`
+//!
+//! This response is returned when the peer is not in the torrent swarm.
+//!
+//! ```json
+//! "peer not known"
+//! ```
 pub mod handlers;
 pub mod resources;
 pub mod responses;
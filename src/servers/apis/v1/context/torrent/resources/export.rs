@@ -0,0 +1,218 @@
+//! The rows of the torrents export.
+//!
+//! Each torrent is written as a [`Torrent`] resource, in one of the
+//! [`ExportFormat`]s:
+//!
+//! - `ndjson`: one json [`Torrent`] per line.
+//! - `csv`: one row per torrent, or one row per peer when the peers are
+//!   included. A torrent without peers still has one row, with empty peer
+//!   columns.
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::peer::Peer;
+use super::torrent::Torrent;
+
+/// The CSV columns of a torrent.
+pub const CSV_TORRENT_COLUMNS: &str = "info_hash,seeders,completed,leechers";
+
+/// The CSV columns of a torrent and one of its peers.
+pub const CSV_PEER_COLUMNS: &str =
+    "info_hash,seeders,completed,leechers,peer_id,client,peer_addr,updated,uploaded,downloaded,left,event";
+
+/// The format of the torrents export.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ExportFormat {
+    /// Newline delimited json.
+    #[default]
+    Ndjson,
+    /// Comma-separated values, with a header row.
+    Csv,
+}
+
+impl ExportFormat {
+    #[must_use]
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Ndjson => "application/x-ndjson",
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    /// The first line of the export, if the format has one.
+    #[must_use]
+    pub fn header(self, with_peers: bool) -> Option<String> {
+        match self {
+            ExportFormat::Ndjson => None,
+            ExportFormat::Csv if with_peers => Some(format!("{CSV_PEER_COLUMNS}\n")),
+            ExportFormat::Csv => Some(format!("{CSV_TORRENT_COLUMNS}\n")),
+        }
+    }
+
+    /// It appends the lines of a torrent to the output.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the torrent can not be serialized to json, which can not
+    /// happen.
+    pub fn write_torrent(self, torrent: &Torrent, output: &mut String) {
+        match self {
+            ExportFormat::Ndjson => {
+                output.push_str(&serde_json::to_string(torrent).expect("the torrent should be serializable to json"));
+                output.push('\n');
+            }
+            ExportFormat::Csv => write_csv_rows(torrent, output),
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("unknown export format {0}, expected one of: ndjson, csv")]
+pub struct ParseExportFormatError(String);
+
+impl FromStr for ExportFormat {
+    type Err = ParseExportFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ndjson" => Ok(ExportFormat::Ndjson),
+            "csv" => Ok(ExportFormat::Csv),
+            _ => Err(ParseExportFormatError(s.to_string())),
+        }
+    }
+}
+
+fn write_csv_rows(torrent: &Torrent, output: &mut String) {
+    let torrent_columns = format!(
+        "{},{},{},{}",
+        torrent.info_hash, torrent.seeders, torrent.completed, torrent.leechers
+    );
+
+    match &torrent.peers {
+        None => {
+            output.push_str(&torrent_columns);
+            output.push('\n');
+        }
+        Some(peers) if peers.is_empty() => {
+            output.push_str(&torrent_columns);
+            output.push_str(",,,,,,,,\n");
+        }
+        Some(peers) => {
+            for peer in peers {
+                output.push_str(&torrent_columns);
+                output.push(',');
+                output.push_str(&csv_peer_columns(peer));
+                output.push('\n');
+            }
+        }
+    }
+}
+
+fn csv_peer_columns(peer: &Peer) -> String {
+    format!(
+        "{},{},{},{},{},{},{},{}",
+        peer.peer_id.id.as_deref().unwrap_or_default(),
+        csv_field(peer.peer_id.client.as_deref().unwrap_or_default()),
+        peer.peer_addr,
+        peer.updated_milliseconds_ago,
+        peer.uploaded,
+        peer.downloaded,
+        peer.left,
+        peer.event
+    )
+}
+
+/// It quotes the field if it contains a separator, a quote or a new line.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    use aquatic_udp_protocol::{AnnounceEvent, NumberOfBytes, PeerId};
+    use torrust_tracker_primitives::{peer, DurationSinceUnixEpoch};
+
+    use super::{csv_field, ExportFormat, CSV_PEER_COLUMNS};
+    use crate::servers::apis::v1::context::torrent::resources::peer::Peer;
+    use crate::servers::apis::v1::context::torrent::resources::torrent::Torrent;
+
+    fn sample_torrent(peers: Option<Vec<Peer>>) -> Torrent {
+        Torrent {
+            info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+            seeders: 1,
+            completed: 2,
+            leechers: 3,
+            peers,
+        }
+    }
+
+    fn sample_peer() -> Peer {
+        Peer::from(peer::Peer {
+            peer_id: PeerId(*b"-qB00000000000000000"),
+            peer_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080),
+            updated: DurationSinceUnixEpoch::new(1_669_397_478_934, 0),
+            uploaded: NumberOfBytes::new(0),
+            downloaded: NumberOfBytes::new(0),
+            left: NumberOfBytes::new(0),
+            event: AnnounceEvent::Started,
+        })
+    }
+
+    fn write(format: ExportFormat, torrent: &Torrent) -> String {
+        let mut output = String::new();
+        format.write_torrent(torrent, &mut output);
+        output
+    }
+
+    #[test]
+    fn a_torrent_should_be_exported_as_a_json_line() {
+        assert_eq!(
+            write(ExportFormat::Ndjson, &sample_torrent(None)),
+            "{\"info_hash\":\"9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d\",\"seeders\":1,\"completed\":2,\"leechers\":3}\n"
+        );
+    }
+
+    #[test]
+    fn a_torrent_should_be_exported_as_a_csv_row() {
+        assert_eq!(
+            write(ExportFormat::Csv, &sample_torrent(None)),
+            "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d,1,2,3\n"
+        );
+    }
+
+    #[test]
+    fn a_torrent_with_peers_should_be_exported_as_a_csv_row_per_peer() {
+        let output = write(ExportFormat::Csv, &sample_torrent(Some(vec![sample_peer(), sample_peer()])));
+
+        let rows: Vec<&str> = output.lines().collect();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d,1,2,3,0x2d71423030303030303030303030303030303030,,126.0.0.1:8080,1669397478934000,0,0,0,Started"
+        );
+        assert_eq!(rows[0].split(',').count(), CSV_PEER_COLUMNS.split(',').count());
+    }
+
+    #[test]
+    fn a_torrent_without_peers_should_be_exported_as_a_csv_row_with_empty_peer_columns() {
+        let output = write(ExportFormat::Csv, &sample_torrent(Some(vec![])));
+
+        assert_eq!(output, "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d,1,2,3,,,,,,,,\n");
+        assert_eq!(output.trim_end().split(',').count(), CSV_PEER_COLUMNS.split(',').count());
+    }
+
+    #[test]
+    fn csv_fields_with_separators_or_quotes_should_be_quoted() {
+        assert_eq!(csv_field("qBittorrent"), "qBittorrent");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("a\"b"), "\"a\"\"b\"");
+    }
+}
@@ -1,4 +1,5 @@
 //! API resources for the [`torrent`](crate::servers::apis::v1::context::torrent)
 //! API context.
+pub mod export;
 pub mod peer;
 pub mod torrent;
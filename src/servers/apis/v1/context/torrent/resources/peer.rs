@@ -3,9 +3,10 @@ use aquatic_udp_protocol::PeerId;
 use derive_more::From;
 use serde::{Deserialize, Serialize};
 use torrust_tracker_primitives::peer;
+use utoipa::ToSchema;
 
 /// `Peer` API resource.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
 pub struct Peer {
     /// The peer's ID. See [`Id`].
     pub peer_id: Id,
@@ -28,7 +29,7 @@ pub struct Peer {
 }
 
 /// Peer `Id` API resource.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
 pub struct Id {
     /// The peer's ID in hex format. For example: `0x2d7142343431302d2a64465a3844484944704579`.
     pub id: Option<String>,
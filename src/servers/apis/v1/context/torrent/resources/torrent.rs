@@ -4,12 +4,15 @@
 //! - `ListItem` is a list item resource on a torrent list. `ListItem` does
 //!   include a `peers` field but it is always `None` in the struct and `null` in
 //!   the JSON response.
+//! - `RankedTorrent` is an item on the top torrents list.
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::core::services::torrent::{BasicInfo, Info};
+use crate::core::torrent::activity::TorrentActivity;
 
 /// `Torrent` API resource.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
 pub struct Torrent {
     /// The torrent's info hash v1.
     pub info_hash: String,
@@ -30,7 +33,7 @@ pub struct Torrent {
 /// `ListItem` API resource. A list item on a torrent list.
 /// `ListItem` does include a `peers` field but it is always `None` in the
 ///  struct and `null` in the JSON response.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
 pub struct ListItem {
     /// The torrent's info hash v1.
     pub info_hash: String,
@@ -45,6 +48,26 @@ pub struct ListItem {
     pub leechers: u64,
 }
 
+/// `RankedTorrent` API resource. An item on the top torrents list.
+#[derive(Serialize, Deserialize, Debug, PartialEq, ToSchema)]
+pub struct RankedTorrent {
+    /// The torrent's info hash v1.
+    pub info_hash: String,
+    /// The torrent's seeders counter. Active peers with a full copy of the
+    /// torrent.
+    pub seeders: u64,
+    /// The torrent's completed counter. Peers that have ever completed the
+    /// download.
+    pub completed: u64,
+    /// The torrent's leechers counter. Active peers that are downloading the
+    /// torrent.
+    pub leechers: u64,
+    /// Recent announces per second.
+    pub announce_rate: f64,
+    /// Recent completions, weighted by how recent they are.
+    pub trending_score: f64,
+}
+
 impl ListItem {
     #[must_use]
     pub fn new_vec(basic_info_vec: &[BasicInfo]) -> Vec<Self> {
@@ -92,6 +115,19 @@ impl From<BasicInfo> for ListItem {
     }
 }
 
+impl From<&TorrentActivity> for RankedTorrent {
+    fn from(activity: &TorrentActivity) -> Self {
+        Self {
+            info_hash: activity.info_hash.to_string(),
+            seeders: activity.seeders,
+            completed: activity.completed,
+            leechers: activity.leechers,
+            announce_rate: activity.announce_rate,
+            trending_score: activity.trending_score,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::net::{IpAddr, Ipv4Addr, SocketAddr};
@@ -103,8 +139,9 @@ mod tests {
 
     use super::Torrent;
     use crate::core::services::torrent::{BasicInfo, Info};
+    use crate::core::torrent::activity::TorrentActivity;
     use crate::servers::apis::v1::context::torrent::resources::peer::Peer;
-    use crate::servers::apis::v1::context::torrent::resources::torrent::ListItem;
+    use crate::servers::apis::v1::context::torrent::resources::torrent::{ListItem, RankedTorrent};
 
     fn sample_peer() -> peer::Peer {
         peer::Peer {
@@ -155,4 +192,26 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn ranked_torrent_resource_should_be_converted_from_the_torrent_activity() {
+        assert_eq!(
+            RankedTorrent::from(&TorrentActivity {
+                info_hash: InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap(),
+                seeders: 1,
+                completed: 2,
+                leechers: 3,
+                announce_rate: 0.5,
+                trending_score: 1.5,
+            }),
+            RankedTorrent {
+                info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+                seeders: 1,
+                completed: 2,
+                leechers: 3,
+                announce_rate: 0.5,
+                trending_score: 1.5,
+            }
+        );
+    }
 }
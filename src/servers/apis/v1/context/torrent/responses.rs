@@ -1,10 +1,22 @@
 //! API responses for the [`torrent`](crate::servers::apis::v1::context::torrent)
 //! API context.
+use std::convert::Infallible;
+use std::error::Error;
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::header;
 use axum::response::{IntoResponse, Json, Response};
+use bittorrent_primitives::info_hash::InfoHash;
+use futures::stream::{self, StreamExt};
 use serde_json::json;
 
-use super::resources::torrent::{ListItem, Torrent};
-use crate::core::services::torrent::{BasicInfo, Info};
+use super::resources::export::ExportFormat;
+use super::resources::torrent::{ListItem, RankedTorrent, Torrent};
+use crate::core::services::torrent::{get_torrents_after, BasicInfo, Info, TorrentsPage};
+use crate::core::torrent::activity::TorrentActivity;
+use crate::core::Tracker;
+use crate::servers::apis::v1::responses::{bad_request_response, unhandled_rejection_response};
 
 /// `200` response that contains an array of
 /// [`ListItem`]
@@ -13,6 +25,22 @@ pub fn torrent_list_response(basic_infos: &[BasicInfo]) -> Json<Vec<ListItem>> {
     Json(ListItem::new_vec(basic_infos))
 }
 
+/// Header with the total number of items matching the request, without
+/// pagination.
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+/// `200` response that contains an array of [`ListItem`] resources as json,
+/// with the total number of matching torrents in the
+/// [`TOTAL_COUNT_HEADER`] header.
+#[must_use]
+pub fn torrent_page_response(page: &TorrentsPage) -> Response {
+    (
+        [(TOTAL_COUNT_HEADER, page.total.to_string())],
+        torrent_list_response(&page.torrents),
+    )
+        .into_response()
+}
+
 /// `200` response that contains a
 /// [`Torrent`]
 /// resources as json.
@@ -20,8 +48,116 @@ pub fn torrent_info_response(info: Info) -> Json<Torrent> {
     Json(Torrent::from(info))
 }
 
+/// Number of torrents read from the repository for each chunk of the
+/// export.
+pub const EXPORT_BATCH_SIZE: usize = 1000;
+
+/// `200` response with a stream of all the torrents in the given
+/// [`ExportFormat`].
+///
+/// The torrents are read from the repository in batches of
+/// [`EXPORT_BATCH_SIZE`] while the body is written, and each batch is sent as
+/// one chunk. Only one batch is kept in memory, no matter how many torrents
+/// the tracker has. Torrents added or removed during the export may or may
+/// not be included.
+#[must_use]
+pub fn torrents_export_response(tracker: Arc<Tracker>, format: ExportFormat, with_peers: bool) -> Response {
+    let header = stream::iter(format.header(with_peers).map(Ok::<_, Infallible>));
+
+    // The state is the last info-hash of the previous batch, or `None` when
+    // there are no more torrents.
+    let batches = stream::unfold(Some(None), move |after: Option<Option<InfoHash>>| {
+        let tracker = tracker.clone();
+
+        async move {
+            let after = after?;
+
+            let torrents = get_torrents_after(tracker, after.as_ref(), EXPORT_BATCH_SIZE, with_peers).await;
+
+            let last = torrents.last()?.info_hash;
+
+            let next = (torrents.len() == EXPORT_BATCH_SIZE).then_some(Some(last));
+
+            let mut chunk = String::new();
+
+            for info in torrents {
+                format.write_torrent(&Torrent::from(info), &mut chunk);
+            }
+
+            Some((Ok(chunk), next))
+        }
+    });
+
+    (
+        [(header::CONTENT_TYPE, format.content_type())],
+        Body::from_stream(header.chain(batches)),
+    )
+        .into_response()
+}
+
 /// `500` error response in plain text returned when a torrent is not found.
 #[must_use]
 pub fn torrent_not_known_response() -> Response {
     Json(json!("torrent not known")).into_response()
 }
+
+/// `200` error response in json returned when a peer is not in the torrent
+/// swarm.
+#[must_use]
+pub fn peer_not_known_response() -> Response {
+    Json(json!("peer not known")).into_response()
+}
+
+/// `500` error response when a torrent cannot be removed from the database.
+#[must_use]
+pub fn failed_to_remove_torrent_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to remove torrent: {e}"))
+}
+
+/// `200` response that contains an array of
+/// [`RankedTorrent`]
+/// resources as json.
+pub fn top_torrents_response(activities: &[TorrentActivity]) -> Json<Vec<RankedTorrent>> {
+    Json(activities.iter().map(RankedTorrent::from).collect())
+}
+
+/// `400` error response when the ranking for the top torrents is unknown.
+#[must_use]
+pub fn invalid_ranking_param_response(error: &str) -> Response {
+    bad_request_response(&format!("Invalid by param: {error}"))
+}
+
+/// `400` error response when the number of top torrents requested is out of
+/// range.
+#[must_use]
+pub fn invalid_limit_param_response(limit: usize, max_limit: usize) -> Response {
+    bad_request_response(&format!(
+        "Invalid limit param {limit}, expected a number between 1 and {max_limit}"
+    ))
+}
+
+/// `400` error response when the info-hash prefix is not a hex string.
+#[must_use]
+pub fn invalid_info_hash_prefix_param_response(prefix: &str) -> Response {
+    bad_request_response(&format!(
+        "Invalid info_hash_prefix param: string \"{prefix}\", expected a hex string of up to 40 characters"
+    ))
+}
+
+/// `400` error response when the sort field is unknown.
+#[must_use]
+pub fn invalid_sort_param_response(error: &str) -> Response {
+    bad_request_response(&format!("Invalid sort param: {error}"))
+}
+
+/// `400` error response when the sort order is unknown.
+#[must_use]
+pub fn invalid_order_param_response(error: &str) -> Response {
+    bad_request_response(&format!("Invalid order param: {error}"))
+}
+
+/// `400` error response when the export format is unknown.
+#[must_use]
+pub fn invalid_format_param_response(error: &str) -> Response {
+    bad_request_response(&format!("Invalid format param: {error}"))
+}
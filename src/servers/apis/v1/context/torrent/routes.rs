@@ -2,14 +2,21 @@
 //!
 //! - `GET /torrent/:info_hash`
 //! - `GET /torrents`
+//! - `GET /torrents/top`
+//! - `GET /torrents/export`
+//! - `DELETE /torrent/:info_hash`
+//! - `DELETE /torrent/:info_hash/peer/:peer_id`
 //!
 //! Refer to the [API endpoint documentation](crate::servers::apis::v1::context::torrent).
 use std::sync::Arc;
 
-use axum::routing::get;
+use axum::routing::{delete, get};
 use axum::Router;
 
-use super::handlers::{get_torrent_handler, get_torrents_handler};
+use super::handlers::{
+    export_torrents_handler, get_top_torrents_handler, get_torrent_handler, get_torrents_handler, remove_peer_handler,
+    remove_torrent_handler,
+};
 use crate::core::Tracker;
 
 /// It adds the routes to the router for the [`torrent`](crate::servers::apis::v1::context::torrent) API context.
@@ -20,5 +27,30 @@ pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
             &format!("{prefix}/torrent/:info_hash"),
             get(get_torrent_handler).with_state(tracker.clone()),
         )
-        .route(&format!("{prefix}/torrents"), get(get_torrents_handler).with_state(tracker))
+        .route(
+            &format!("{prefix}/torrents"),
+            get(get_torrents_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/torrents/top"),
+            get(get_top_torrents_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/torrents/export"),
+            get(export_torrents_handler).with_state(tracker),
+        )
+}
+
+/// It adds the routes to remove torrents and peers from the swarms. They
+/// require a different permission than the routes to read the torrents.
+pub fn add_management(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    router
+        .route(
+            &format!("{prefix}/torrent/:info_hash"),
+            delete(remove_torrent_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/torrent/:info_hash/peer/:peer_id"),
+            delete(remove_peer_handler).with_state(tracker),
+        )
 }
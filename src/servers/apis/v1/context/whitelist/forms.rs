@@ -0,0 +1,168 @@
+//! Forms for the [`whitelist`](crate::servers::apis::v1::context::whitelist) API context.
+use thiserror::Error;
+
+/// Maximum number of info-hashes in a bulk whitelist request.
+pub const MAX_BULK_INFO_HASHES: usize = 10_000;
+
+/// The list of info-hashes in the body of the bulk whitelist requests.
+///
+/// The body can be:
+///
+/// - A json array of strings, with the `application/json` content type.
+/// - A newline-delimited list, with the `application/x-ndjson` or
+///   `text/plain` content types, or without content type. Each line is an
+///   info-hash, optionally as a json string. Empty lines are ignored.
+///
+/// The info-hashes are not validated here, so that the invalid ones can be
+/// reported individually.
+#[derive(Debug, PartialEq, Eq)]
+pub struct InfoHashList(pub Vec<String>);
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum InfoHashListError {
+    #[error("unsupported content type \"{content_type}\", expected application/json, application/x-ndjson or text/plain")]
+    UnsupportedContentType { content_type: String },
+
+    #[error("invalid json array of info-hashes: {message}")]
+    InvalidJson { message: String },
+
+    #[error("invalid json string in line {line}: {message}")]
+    InvalidLine { line: usize, message: String },
+
+    #[error("the list of info-hashes is empty")]
+    Empty,
+
+    #[error("too many info-hashes: {len}, the maximum is {max}")]
+    TooMany { len: usize, max: usize },
+}
+
+impl InfoHashList {
+    /// It parses the body of a bulk whitelist request.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the content type is not supported, the body
+    /// can't be parsed, or the list is empty or longer than
+    /// [`MAX_BULK_INFO_HASHES`].
+    pub fn parse(content_type: Option<&str>, body: &str) -> Result<Self, InfoHashListError> {
+        let media_type =
+            content_type.map(|content_type| content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase());
+
+        let info_hashes = match media_type.as_deref() {
+            Some("application/json") => {
+                serde_json::from_str::<Vec<String>>(body).map_err(|err| InfoHashListError::InvalidJson {
+                    message: err.to_string(),
+                })?
+            }
+            None | Some("application/x-ndjson" | "text/plain") => parse_lines(body)?,
+            Some(_) => {
+                return Err(InfoHashListError::UnsupportedContentType {
+                    content_type: content_type.unwrap_or_default().to_string(),
+                })
+            }
+        };
+
+        if info_hashes.is_empty() {
+            return Err(InfoHashListError::Empty);
+        }
+
+        if info_hashes.len() > MAX_BULK_INFO_HASHES {
+            return Err(InfoHashListError::TooMany {
+                len: info_hashes.len(),
+                max: MAX_BULK_INFO_HASHES,
+            });
+        }
+
+        Ok(Self(info_hashes))
+    }
+}
+
+fn parse_lines(body: &str) -> Result<Vec<String>, InfoHashListError> {
+    let mut info_hashes = vec![];
+
+    for (index, line) in body.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with('"') {
+            let info_hash = serde_json::from_str::<String>(line).map_err(|err| InfoHashListError::InvalidLine {
+                line: index + 1,
+                message: err.to_string(),
+            })?;
+            info_hashes.push(info_hash);
+        } else {
+            info_hashes.push(line.to_string());
+        }
+    }
+
+    Ok(info_hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InfoHashList, InfoHashListError, MAX_BULK_INFO_HASHES};
+
+    fn list(info_hashes: &[&str]) -> InfoHashList {
+        InfoHashList(info_hashes.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn it_should_parse_a_json_array() {
+        assert_eq!(
+            InfoHashList::parse(Some("application/json; charset=utf-8"), r#"["a", "b"]"#),
+            Ok(list(&["a", "b"]))
+        );
+    }
+
+    #[test]
+    fn it_should_parse_a_newline_delimited_list_of_plain_or_json_strings() {
+        for content_type in [None, Some("application/x-ndjson"), Some("text/plain")] {
+            assert_eq!(
+                InfoHashList::parse(content_type, "a\r\n\n\"b\"\n"),
+                Ok(list(&["a", "b"])),
+                "{content_type:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_fail_when_the_content_type_is_not_supported() {
+        assert!(matches!(
+            InfoHashList::parse(Some("application/xml"), "a"),
+            Err(InfoHashListError::UnsupportedContentType { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_when_a_line_is_not_a_valid_json_string() {
+        assert!(matches!(
+            InfoHashList::parse(None, "a\n\"b"),
+            Err(InfoHashListError::InvalidLine { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_when_the_list_is_empty() {
+        assert_eq!(InfoHashList::parse(None, "\n"), Err(InfoHashListError::Empty));
+        assert_eq!(
+            InfoHashList::parse(Some("application/json"), "[]"),
+            Err(InfoHashListError::Empty)
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_the_list_is_too_long() {
+        let body = "a\n".repeat(MAX_BULK_INFO_HASHES + 1);
+
+        assert_eq!(
+            InfoHashList::parse(None, &body),
+            Err(InfoHashListError::TooMany {
+                len: MAX_BULK_INFO_HASHES + 1,
+                max: MAX_BULK_INFO_HASHES
+            })
+        );
+    }
+}
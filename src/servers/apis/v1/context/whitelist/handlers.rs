@@ -4,12 +4,22 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use axum::extract::{Path, State};
-use axum::response::Response;
+use axum::http::{header, HeaderMap};
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::Query;
 use bittorrent_primitives::info_hash::InfoHash;
+use serde::Deserialize;
+use torrust_tracker_primitives::pagination::Pagination;
+use utoipa::IntoParams;
 
+use super::forms::InfoHashList;
 use super::responses::{
-    failed_to_reload_whitelist_response, failed_to_remove_torrent_from_whitelist_response, failed_to_whitelist_torrent_response,
+    bulk_whitelist_response, failed_to_load_whitelist_response, failed_to_reload_whitelist_response,
+    failed_to_remove_torrent_from_whitelist_response, failed_to_remove_torrents_from_whitelist_response,
+    failed_to_whitelist_torrent_response, failed_to_whitelist_torrents_response, invalid_info_hash_list_response,
+    torrent_not_whitelisted_response, whitelist_diff_response, whitelist_entry_response, whitelist_response,
 };
+use crate::core::services::whitelist::{add_torrents_to_whitelist, remove_torrents_from_whitelist};
 use crate::core::Tracker;
 use crate::servers::apis::v1::responses::{invalid_info_hash_param_response, ok_response};
 use crate::servers::apis::InfoHashParam;
@@ -75,3 +85,133 @@ pub async fn reload_whitelist_handler(State(tracker): State<Arc<Tracker>>) -> Re
         Err(e) => failed_to_reload_whitelist_response(e),
     }
 }
+
+/// Query parameters for the list of whitelisted torrents.
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListQueryParams {
+    /// The offset of the first page to return. Starts at 0.
+    pub offset: Option<u32>,
+    /// The maximum number of items to return per page.
+    pub limit: Option<u32>,
+}
+
+impl ListQueryParams {
+    #[must_use]
+    pub fn pagination(&self) -> Pagination {
+        Pagination::new_with_options(self.offset, self.limit)
+    }
+}
+
+/// It handles the request to get the list of whitelisted torrents.
+///
+/// It returns:
+///
+/// - `200` response with a json array of [`WhitelistEntry`](crate::servers::apis::v1::context::whitelist::resources::WhitelistEntry)
+///   resources, sorted by info-hash.
+/// - `500` with serialized error in debug format if the whitelist couldn't be
+///   loaded from the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::whitelist#list-the-whitelisted-torrents)
+/// for more information about this endpoint.
+pub async fn get_whitelist_handler(State(tracker): State<Arc<Tracker>>, Query(params): Query<ListQueryParams>) -> Response {
+    match tracker.get_whitelist(Some(&params.pagination())).await {
+        Ok(entries) => whitelist_response(entries).into_response(),
+        Err(e) => failed_to_load_whitelist_response(e),
+    }
+}
+
+/// It handles the request to get a whitelisted torrent.
+///
+/// It returns:
+///
+/// - `200` response with a json [`WhitelistEntry`](crate::servers::apis::v1::context::whitelist::resources::WhitelistEntry).
+/// - `200` response with the json string `"torrent not whitelisted"` if the
+///   torrent is neither whitelisted in memory nor in the database.
+/// - `500` with serialized error in debug format if the whitelist couldn't be
+///   checked in the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::whitelist#get-a-whitelisted-torrent)
+/// for more information about this endpoint.
+pub async fn get_whitelist_entry_handler(State(tracker): State<Arc<Tracker>>, Path(info_hash): Path<InfoHashParam>) -> Response {
+    match InfoHash::from_str(&info_hash.0) {
+        Err(_) => invalid_info_hash_param_response(&info_hash.0),
+        Ok(info_hash) => match tracker.get_whitelist_entry(&info_hash).await {
+            Ok(entry) if entry.in_memory || entry.in_database => whitelist_entry_response(entry).into_response(),
+            Ok(_) => torrent_not_whitelisted_response(),
+            Err(e) => failed_to_load_whitelist_response(e),
+        },
+    }
+}
+
+/// It handles the request to compare the whitelist in memory with the
+/// whitelist in the database.
+///
+/// It returns:
+///
+/// - `200` response with a json [`WhitelistDiff`](crate::servers::apis::v1::context::whitelist::resources::WhitelistDiff).
+/// - `500` with serialized error in debug format if the whitelist couldn't be
+///   loaded from the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::whitelist#compare-the-whitelist-in-memory-and-in-the-database)
+/// for more information about this endpoint.
+pub async fn get_whitelist_diff_handler(State(tracker): State<Arc<Tracker>>) -> Response {
+    match tracker.get_whitelist_diff().await {
+        Ok(diff) => whitelist_diff_response(diff).into_response(),
+        Err(e) => failed_to_load_whitelist_response(e),
+    }
+}
+
+/// It handles the request to add a list of torrents to the whitelist.
+///
+/// It returns:
+///
+/// - `200` response with a json array of [`BulkWhitelistItem`](crate::servers::apis::v1::context::whitelist::resources::BulkWhitelistItem) resources.
+/// - `400` response if the body is not a valid list of info-hashes.
+/// - `500` with serialized error in debug format if the torrents couldn't be whitelisted.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::whitelist#add-a-list-of-torrents-to-the-whitelist)
+/// for more information about this endpoint.
+pub async fn add_torrents_to_whitelist_handler(
+    State(tracker): State<Arc<Tracker>>,
+    headers: HeaderMap,
+    body: String,
+) -> Response {
+    match InfoHashList::parse(content_type(&headers), &body) {
+        Err(e) => invalid_info_hash_list_response(&e),
+        Ok(list) => match add_torrents_to_whitelist(tracker, list.0).await {
+            Ok(results) => bulk_whitelist_response(results).into_response(),
+            Err(e) => failed_to_whitelist_torrents_response(e),
+        },
+    }
+}
+
+/// It handles the request to remove a list of torrents from the whitelist.
+///
+/// It returns:
+///
+/// - `200` response with a json array of [`BulkWhitelistItem`](crate::servers::apis::v1::context::whitelist::resources::BulkWhitelistItem) resources.
+/// - `400` response if the body is not a valid list of info-hashes.
+/// - `500` with serialized error in debug format if the torrents couldn't be removed from the whitelist.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v1::context::whitelist#remove-a-list-of-torrents-from-the-whitelist)
+/// for more information about this endpoint.
+pub async fn remove_torrents_from_whitelist_handler(
+    State(tracker): State<Arc<Tracker>>,
+    headers: HeaderMap,
+    body: String,
+) -> Response {
+    match InfoHashList::parse(content_type(&headers), &body) {
+        Err(e) => invalid_info_hash_list_response(&e),
+        Ok(list) => match remove_torrents_from_whitelist(tracker, list.0).await {
+            Ok(results) => bulk_whitelist_response(results).into_response(),
+            Err(e) => failed_to_remove_torrents_from_whitelist_response(e),
+        },
+    }
+}
+
+/// It returns the `Content-Type` header of the request, if it's valid.
+#[must_use]
+pub fn content_type(headers: &HeaderMap) -> Option<&str> {
+    headers.get(header::CONTENT_TYPE).and_then(|value| value.to_str().ok())
+}
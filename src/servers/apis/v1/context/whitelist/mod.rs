@@ -18,12 +18,114 @@
 //! > **NOTICE**: if the tracker is not running in `listed` or `private_listed`
 //! > modes the requests to the whitelist API will be ignored.
 //!
+//! The whitelist is kept in memory and persisted in the database. Both lists
+//! should be the same, but they can drift apart, for example, if the database
+//! is changed directly. The read endpoints show where each torrent is
+//! whitelisted, and the [reload](#reload-the-whitelist) endpoint replaces the
+//! whitelist in memory with the one in the database.
+//!
 //! # Endpoints
 //!
+//! - [List the whitelisted torrents](#list-the-whitelisted-torrents)
+//! - [Get a whitelisted torrent](#get-a-whitelisted-torrent)
+//! - [Compare the whitelist in memory and in the database](#compare-the-whitelist-in-memory-and-in-the-database)
 //! - [Add a torrent to the whitelist](#add-a-torrent-to-the-whitelist)
 //! - [Remove a torrent from the whitelist](#remove-a-torrent-from-the-whitelist)
+//! - [Add a list of torrents to the whitelist](#add-a-list-of-torrents-to-the-whitelist)
+//! - [Remove a list of torrents from the whitelist](#remove-a-list-of-torrents-from-the-whitelist)
 //! - [Reload the whitelist](#reload-the-whitelist)
 //!
+//! # List the whitelisted torrents
+//!
+//! `GET /whitelist`
+//!
+//! It lists the torrents whitelisted in memory or in the database, sorted by
+//! info-hash.
+//!
+//! **Query parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `offset` | positive integer | The page number, starting at 0 | No | `1`
+//! `limit` | positive integer | Page size. The number of results per page | No | `10`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/whitelist?token=MyAccessToken&offset=0&limit=10"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! [
+//!     {
+//!         "info_hash": "5452869be36f9f3350ccee6b4544e7e76caaadab",
+//!         "in_memory": true,
+//!         "in_database": true
+//!     }
+//! ]
+//! ```
+//!
+//! # Get a whitelisted torrent
+//!
+//! `GET /whitelist/:info_hash`
+//!
+//! It shows whether a torrent is whitelisted in memory and in the database.
+//!
+//! **Path parameters**
+//!
+//! Name | Type | Description | Required | Example
+//! ---|---|---|---|---
+//! `info_hash` | 40-char string | The Info Hash v1 | Yes | `5452869be36f9f3350ccee6b4544e7e76caaadab`
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/whitelist/5452869be36f9f3350ccee6b4544e7e76caaadab?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!     "info_hash": "5452869be36f9f3350ccee6b4544e7e76caaadab",
+//!     "in_memory": false,
+//!     "in_database": true
+//! }
+//! ```
+//!
+//! **Not Found response** `200`
+//!
+//! This response is returned when the torrent is neither whitelisted in memory
+//! nor in the database.
+//!
+//! ```text
+//! "torrent not whitelisted"
+//! ```
+//!
+//! # Compare the whitelist in memory and in the database
+//!
+//! `GET /whitelist/diff`
+//!
+//! It lists the torrents that are only whitelisted in memory or only in the
+//! database. Both lists are empty when the whitelist is in sync.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v1/whitelist/diff?token=MyAccessToken"
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! {
+//!     "only_in_memory": [],
+//!     "only_in_database": ["5452869be36f9f3350ccee6b4544e7e76caaadab"]
+//! }
+//! ```
+//!
 //! # Add a torrent to the whitelist
 //!
 //! `POST /whitelist/:info_hash`
@@ -76,6 +178,82 @@
 //! }
 //! ```
 //!
+//! # Add a list of torrents to the whitelist
+//!
+//! `POST /whitelist/bulk`
+//!
+//! It adds up to 10,000 torrents to the whitelist in one request. All the
+//! valid info-hashes are stored in a single database transaction, so either
+//! all of them are stored or none.
+//!
+//! **Request body**
+//!
+//! The body can be a json array of info-hashes, with the `application/json`
+//! content type, or a newline-delimited list, with the `application/x-ndjson`
+//! or `text/plain` content types. Each line of the list is an info-hash,
+//! optionally as a json string.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X POST "http://127.0.0.1:1212/api/v1/whitelist/bulk?token=MyAccessToken" \
+//!      --header "Content-Type: application/x-ndjson" \
+//!      --data-binary @info-hashes.txt
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! The response contains the result for each item, in the same order as the
+//! request. The status is `added`, `already_whitelisted` or `invalid` if the
+//! item is not a valid info-hash.
+//!
+//! ```json
+//! [
+//!     {
+//!         "info_hash": "5452869be36f9f3350ccee6b4544e7e76caaadab",
+//!         "status": "added"
+//!     },
+//!     {
+//!         "info_hash": "INVALID",
+//!         "status": "invalid"
+//!     }
+//! ]
+//! ```
+//!
+//! **Resource**
+//!
+//! Refer to the API [`BulkWhitelistItem`](crate::servers::apis::v1::context::whitelist::resources::BulkWhitelistItem)
+//! resource for more information about the attributes for a single item in the
+//! response.
+//!
+//! # Remove a list of torrents from the whitelist
+//!
+//! `DELETE /whitelist/bulk`
+//!
+//! It removes up to 10,000 torrents from the whitelist in one request, in a
+//! single database transaction. The request body is the same as in the
+//! [add](#add-a-list-of-torrents-to-the-whitelist) endpoint. The status of
+//! each item in the response is `removed`, `not_whitelisted` or `invalid`.
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X DELETE "http://127.0.0.1:1212/api/v1/whitelist/bulk?token=MyAccessToken" \
+//!      --header "Content-Type: application/json" \
+//!      --data '["5452869be36f9f3350ccee6b4544e7e76caaadab"]'
+//! ```
+//!
+//! **Example response** `200`
+//!
+//! ```json
+//! [
+//!     {
+//!         "info_hash": "5452869be36f9f3350ccee6b4544e7e76caaadab",
+//!         "status": "removed"
+//!     }
+//! ]
+//! ```
+//!
 //! # Reload the whitelist
 //!
 //! It reloads the whitelist from the database.
@@ -93,6 +271,8 @@
 //!     "status": "ok"
 //! }
 //! ```
+pub mod forms;
 pub mod handlers;
+pub mod resources;
 pub mod responses;
 pub mod routes;
@@ -0,0 +1,159 @@
+//! API resources for the [`whitelist`](crate::servers::apis::v1::context::whitelist) API context.
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::core::services::whitelist::{BulkResult, BulkStatus};
+use crate::core::whitelist;
+
+/// A resource that represents a whitelisted torrent.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct WhitelistEntry {
+    /// The torrent's info hash v1.
+    pub info_hash: String,
+    /// Whether the torrent is in the whitelist the tracker uses to allow
+    /// requests.
+    pub in_memory: bool,
+    /// Whether the torrent is in the whitelist persisted in the database.
+    pub in_database: bool,
+}
+
+impl From<whitelist::Entry> for WhitelistEntry {
+    fn from(entry: whitelist::Entry) -> Self {
+        Self {
+            info_hash: entry.info_hash.to_string(),
+            in_memory: entry.in_memory,
+            in_database: entry.in_database,
+        }
+    }
+}
+
+/// A resource with the torrents that are not whitelisted both in memory and
+/// in the database.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct WhitelistDiff {
+    /// The info hashes of the torrents that are only whitelisted in memory.
+    pub only_in_memory: Vec<String>,
+    /// The info hashes of the torrents that are only whitelisted in the
+    /// database.
+    pub only_in_database: Vec<String>,
+}
+
+impl From<whitelist::Diff> for WhitelistDiff {
+    fn from(diff: whitelist::Diff) -> Self {
+        Self {
+            only_in_memory: diff.only_in_memory.iter().map(ToString::to_string).collect(),
+            only_in_database: diff.only_in_database.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// What happened to one of the torrents in a bulk whitelist request.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkWhitelistStatus {
+    /// The torrent was added to the whitelist.
+    Added,
+    /// The torrent was already whitelisted.
+    AlreadyWhitelisted,
+    /// The torrent was removed from the whitelist.
+    Removed,
+    /// The torrent was not whitelisted.
+    NotWhitelisted,
+    /// The item is not a valid info-hash.
+    Invalid,
+}
+
+impl From<BulkStatus> for BulkWhitelistStatus {
+    fn from(status: BulkStatus) -> Self {
+        match status {
+            BulkStatus::Added => Self::Added,
+            BulkStatus::AlreadyWhitelisted => Self::AlreadyWhitelisted,
+            BulkStatus::Removed => Self::Removed,
+            BulkStatus::NotWhitelisted => Self::NotWhitelisted,
+            BulkStatus::Invalid => Self::Invalid,
+        }
+    }
+}
+
+/// A resource with the result for one of the items in a bulk whitelist
+/// request.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct BulkWhitelistItem {
+    /// The info hash as it was provided in the request.
+    pub info_hash: String,
+    pub status: BulkWhitelistStatus,
+}
+
+impl From<BulkResult> for BulkWhitelistItem {
+    fn from(result: BulkResult) -> Self {
+        Self {
+            info_hash: result.info_hash,
+            status: result.status.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bittorrent_primitives::info_hash::InfoHash;
+
+    use super::{BulkWhitelistItem, BulkWhitelistStatus, WhitelistDiff, WhitelistEntry};
+    use crate::core::services::whitelist::{BulkResult, BulkStatus};
+    use crate::core::whitelist;
+
+    fn sample_info_hash() -> InfoHash {
+        "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".parse::<InfoHash>().unwrap()
+    }
+
+    #[test]
+    fn it_should_be_convertible_from_a_whitelist_entry() {
+        assert_eq!(
+            WhitelistEntry::from(whitelist::Entry {
+                info_hash: sample_info_hash(),
+                in_memory: true,
+                in_database: false,
+            }),
+            WhitelistEntry {
+                info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+                in_memory: true,
+                in_database: false,
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_be_convertible_from_a_whitelist_diff() {
+        assert_eq!(
+            WhitelistDiff::from(whitelist::Diff {
+                only_in_memory: vec![],
+                only_in_database: vec![sample_info_hash()],
+            }),
+            WhitelistDiff {
+                only_in_memory: vec![],
+                only_in_database: vec!["9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_be_convertible_from_a_bulk_whitelist_result() {
+        assert_eq!(
+            BulkWhitelistItem::from(BulkResult {
+                info_hash: "INVALID".to_string(),
+                status: BulkStatus::Invalid,
+            }),
+            BulkWhitelistItem {
+                info_hash: "INVALID".to_string(),
+                status: BulkWhitelistStatus::Invalid,
+            }
+        );
+    }
+
+    #[test]
+    fn the_bulk_whitelist_status_should_be_serialized_in_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&BulkWhitelistStatus::AlreadyWhitelisted).unwrap(),
+            "\"already_whitelisted\""
+        );
+    }
+}
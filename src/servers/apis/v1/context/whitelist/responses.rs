@@ -2,9 +2,42 @@
 //! API context.
 use std::error::Error;
 
-use axum::response::Response;
+use axum::response::{IntoResponse, Json, Response};
+use serde_json::json;
 
-use crate::servers::apis::v1::responses::unhandled_rejection_response;
+use super::forms::InfoHashListError;
+use super::resources::{BulkWhitelistItem, WhitelistDiff, WhitelistEntry};
+use crate::core::services::whitelist::BulkResult;
+use crate::core::whitelist;
+use crate::servers::apis::v1::responses::{bad_request_response, unhandled_rejection_response};
+
+/// `200` response that contains an array of [`WhitelistEntry`] resources as json.
+pub fn whitelist_response(entries: Vec<whitelist::Entry>) -> Json<Vec<WhitelistEntry>> {
+    Json(entries.into_iter().map(WhitelistEntry::from).collect())
+}
+
+/// `200` response that contains a [`WhitelistEntry`] resource as json.
+pub fn whitelist_entry_response(entry: whitelist::Entry) -> Json<WhitelistEntry> {
+    Json(WhitelistEntry::from(entry))
+}
+
+/// `200` response that contains a [`WhitelistDiff`] resource as json.
+pub fn whitelist_diff_response(diff: whitelist::Diff) -> Json<WhitelistDiff> {
+    Json(WhitelistDiff::from(diff))
+}
+
+/// `200` response that contains an array of [`BulkWhitelistItem`] resources
+/// as json, in the same order as the request items.
+pub fn bulk_whitelist_response(results: Vec<BulkResult>) -> Json<Vec<BulkWhitelistItem>> {
+    Json(results.into_iter().map(BulkWhitelistItem::from).collect())
+}
+
+/// `200` response with a json string returned when a torrent is neither
+/// whitelisted in memory nor in the database.
+#[must_use]
+pub fn torrent_not_whitelisted_response() -> Response {
+    Json(json!("torrent not whitelisted")).into_response()
+}
 
 /// `500` error response when a torrent cannot be removed from the whitelist.
 #[must_use]
@@ -23,3 +56,30 @@ pub fn failed_to_whitelist_torrent_response<E: Error>(e: E) -> Response {
 pub fn failed_to_reload_whitelist_response<E: Error>(e: E) -> Response {
     unhandled_rejection_response(format!("failed to reload whitelist: {e}"))
 }
+
+/// `500` error response when the whitelist cannot be loaded from the database.
+#[must_use]
+pub fn failed_to_load_whitelist_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to load whitelist: {e}"))
+}
+
+/// `500` error response when the torrents of a bulk request cannot be added to
+/// the whitelist.
+#[must_use]
+pub fn failed_to_whitelist_torrents_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to whitelist torrents: {e}"))
+}
+
+/// `500` error response when the torrents of a bulk request cannot be removed
+/// from the whitelist.
+#[must_use]
+pub fn failed_to_remove_torrents_from_whitelist_response<E: Error>(e: E) -> Response {
+    unhandled_rejection_response(format!("failed to remove torrents from whitelist: {e}"))
+}
+
+/// `400` error response when the body of a bulk request is not a valid list of
+/// info-hashes.
+#[must_use]
+pub fn invalid_info_hash_list_response(e: &InfoHashListError) -> Response {
+    bad_request_response(&format!("Invalid body: {e}"))
+}
@@ -1,8 +1,13 @@
 //! API routes for the [`whitelist`](crate::servers::apis::v1::context::whitelist) API context.
 //!
+//! - `GET /whitelist`
+//! - `GET /whitelist/:info_hash`
 //! - `POST /whitelist/:info_hash`
 //! - `DELETE /whitelist/:info_hash`
 //! - `GET /whitelist/reload`
+//! - `GET /whitelist/diff`
+//! - `POST /whitelist/bulk`
+//! - `DELETE /whitelist/bulk`
 //!
 //! Refer to the [API endpoint documentation](crate::servers::apis::v1::context::torrent).
 use std::sync::Arc;
@@ -10,7 +15,11 @@ use std::sync::Arc;
 use axum::routing::{delete, get, post};
 use axum::Router;
 
-use super::handlers::{add_torrent_to_whitelist_handler, reload_whitelist_handler, remove_torrent_from_whitelist_handler};
+use super::handlers::{
+    add_torrent_to_whitelist_handler, add_torrents_to_whitelist_handler, get_whitelist_diff_handler, get_whitelist_entry_handler,
+    get_whitelist_handler, reload_whitelist_handler, remove_torrent_from_whitelist_handler,
+    remove_torrents_from_whitelist_handler,
+};
 use crate::core::Tracker;
 
 /// It adds the routes to the router for the [`whitelist`](crate::servers::apis::v1::context::whitelist) API context.
@@ -19,6 +28,11 @@ pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
 
     router
         // Whitelisted torrents
+        .route(&prefix, get(get_whitelist_handler).with_state(tracker.clone()))
+        .route(
+            &format!("{prefix}/:info_hash"),
+            get(get_whitelist_entry_handler).with_state(tracker.clone()),
+        )
         .route(
             &format!("{prefix}/:info_hash"),
             post(add_torrent_to_whitelist_handler).with_state(tracker.clone()),
@@ -28,5 +42,19 @@ pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
             delete(remove_torrent_from_whitelist_handler).with_state(tracker.clone()),
         )
         // Whitelist commands
-        .route(&format!("{prefix}/reload"), get(reload_whitelist_handler).with_state(tracker))
+        .route(
+            &format!("{prefix}/reload"),
+            get(reload_whitelist_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/diff"),
+            get(get_whitelist_diff_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/bulk"),
+            post(add_torrents_to_whitelist_handler)
+                .with_state(tracker.clone())
+                .delete(remove_torrents_from_whitelist_handler)
+                .with_state(tracker),
+        )
 }
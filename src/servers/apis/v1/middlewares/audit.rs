@@ -0,0 +1,98 @@
+//! Audit middleware for the API.
+//!
+//! It records the requests that change the tracker state in the tracker
+//! [audit log](crate::core::audit): all the requests except `GET` and `HEAD`
+//! requests, and the `GET` requests to reload the keys and the whitelist in
+//! the API v1.
+//!
+//! It must run after the [authentication middleware](crate::servers::apis::v1::middlewares::auth),
+//! because the entries contain the label of the access token. Requests
+//! rejected by the authorization middleware are also recorded, with the
+//! rejection status code.
+//!
+//! Refer to the [audit log endpoint](crate::servers::apis::v1::context::audit_log)
+//! for more information about the entries.
+use std::sync::Arc;
+
+use axum::extract::{self, MatchedPath, RawPathParams};
+use axum::http::{Method, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use torrust_tracker_clock::clock::Time;
+
+use super::auth::Authenticated;
+use crate::core::{audit, Tracker};
+use crate::servers::apis::API_LOG_TARGET;
+use crate::CurrentClock;
+
+/// Middleware that adds an entry to the audit log for each request that
+/// changes the tracker state. Failing to record the entry does not change the
+/// response, but it's logged as an error.
+pub async fn audit(
+    extract::State(tracker): extract::State<Arc<Tracker>>,
+    matched_path: Option<MatchedPath>,
+    path_params: Option<RawPathParams>,
+    request: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    let (Some(matched_path), Some(authenticated)) = (matched_path, request.extensions().get::<Authenticated>().cloned()) else {
+        return next.run(request).await;
+    };
+
+    if !is_mutating(request.method(), matched_path.as_str()) {
+        return next.run(request).await;
+    }
+
+    let action = format!("{} {}", request.method(), matched_path.as_str());
+
+    let target = path_params
+        .map(|params| params.iter().map(|(_, value)| value).collect::<Vec<_>>().join("/"))
+        .unwrap_or_default();
+
+    let response = next.run(request).await;
+
+    let entry = audit::Entry {
+        timestamp: CurrentClock::now(),
+        label: authenticated.label,
+        action,
+        target,
+        result: response.status().as_u16(),
+    };
+
+    tracing::info!(target: API_LOG_TARGET, label = %entry.label, action = %entry.action, target = %entry.target, result = %entry.result, "audit");
+
+    if let Err(err) = tracker.add_audit_log_entry(&entry) {
+        tracing::error!(target: API_LOG_TARGET, "Could not add the entry to the audit log: {err}");
+    }
+
+    response
+}
+
+/// Returns `true` if the request to the `route` changes the tracker state.
+#[must_use]
+pub fn is_mutating(method: &Method, route: &str) -> bool {
+    match *method {
+        Method::GET | Method::HEAD => route.ends_with("/reload"),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::Method;
+
+    use super::is_mutating;
+
+    #[test]
+    fn requests_that_are_not_get_requests_should_change_the_tracker_state() {
+        assert!(is_mutating(&Method::POST, "/api/v1/whitelist/:info_hash"));
+        assert!(is_mutating(&Method::DELETE, "/api/v2/keys/:key"));
+    }
+
+    #[test]
+    fn get_requests_should_not_change_the_tracker_state_except_the_reloads() {
+        assert!(!is_mutating(&Method::GET, "/api/v1/whitelist"));
+        assert!(is_mutating(&Method::GET, "/api/v1/whitelist/reload"));
+        assert!(is_mutating(&Method::GET, "/api/v1/keys/reload"));
+    }
+}
@@ -1,13 +1,15 @@
 //! Authentication middleware for the API.
 //!
-//! It uses a "token" GET param to authenticate the user. URLs must be of the
-//! form:
+//! It uses an `Authorization: Bearer <token>` header or a "token" GET param to
+//! authenticate the user. URLs using the GET param must be of the form:
 //!
 //! `http://<host>:<port>/api/v1/<context>?token=<token>`.
 //!
 //! > **NOTICE**: the token can be at any position in the URL, not just at the
 //! > beginning or at the end.
 //!
+//! If both are provided, the `Authorization` header takes precedence.
+//!
 //! The token must be one of the `access_tokens` in the tracker
 //! [HTTP API configuration](torrust_tracker_configuration::HttpApi).
 //!
@@ -16,21 +18,53 @@
 //! ```toml
 //! [http_api.access_tokens]
 //! admin = "MyAccessToken"
+//! stats = { token = "MyStatsToken", role = "stats_reader", valid_until = 1767225600 }
 //! ```
 //!
-//! All the tokes have the same permissions, so it is not possible to have
-//! different permissions for different tokens. The label is only used to
-//! identify the token.
-use std::sync::Arc;
+//! Plain tokens are admin tokens that never expire. Tokens defined with a
+//! table have a [`Role`] and, optionally, the unix time (in seconds) when they
+//! expire. The label is only used to identify the token, for example, in the
+//! [audit log](crate::servers::apis::v1::context::audit_log).
+//!
+//! Each API context requires a [`Permission`]. The roles have these
+//! permissions:
+//!
+//! Role             | Permissions
+//! -----------------|-------------------------------------------------------------
+//! `stats_reader`   | [`ReadStats`](Permission::ReadStats)
+//! `torrent_viewer` | [`ReadStats`](Permission::ReadStats), [`ReadTorrents`](Permission::ReadTorrents)
+//! `key_manager`    | [`ReadStats`](Permission::ReadStats), [`ManageKeys`](Permission::ManageKeys)
+//! `admin`          | All, including [`ReadAuditLog`](Permission::ReadAuditLog)
+//!
+//! Requests rejected because the token is missing, unknown or expired are
+//! counted in the `api_auth_failures` tracker statistic and logged.
+//!
+//! The tokens are compared in constant time, using their SHA-256 digests so
+//! the comparison does not depend on the token length. The clients that send
+//! too many unknown tokens are locked out for a while. The lockouts are
+//! counted in the `api_auth_lockouts` tracker statistic. See
+//! [throttling](crate::servers::apis::v1::middlewares::throttling).
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, RwLock};
 
 use axum::extract::{self};
-use axum::http::Request;
+use axum::http::{header, HeaderMap, Request};
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
 use serde::Deserialize;
-use torrust_tracker_configuration::AccessTokens;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use torrust_tracker_clock::clock::Time;
+use torrust_tracker_configuration::{AccessTokens, Role};
 
+use super::throttling::AuthThrottle;
+use crate::core::statistics::Event;
+use crate::core::Tracker;
 use crate::servers::apis::v1::responses::unhandled_rejection_response;
+use crate::servers::apis::API_LOG_TARGET;
+use crate::servers::http::v1::extractors::client_ip_sources::Extract;
+use crate::servers::http::v1::services::peer_ip_resolver::ClientIpSources;
+use crate::CurrentClock;
 
 /// Container for the `token` extracted from the query params.
 #[derive(Deserialize, Debug)]
@@ -38,35 +72,123 @@ pub struct QueryParams {
     pub token: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct State {
-    pub access_tokens: Arc<AccessTokens>,
+    /// They can change when the configuration is reloaded.
+    pub access_tokens: Arc<RwLock<AccessTokens>>,
+    /// Used to count the authentication failures.
+    pub tracker: Arc<Tracker>,
+    /// The invalid tokens sent by each client and the clients locked out.
+    pub throttle: Arc<AuthThrottle>,
+    /// The reverse proxies whose `X-Forwarded-For` header is trusted.
+    pub trusted_proxies: Arc<[IpAddr]>,
+}
+
+/// An action on the API that not all the [roles](Role) are allowed to do.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Permission {
+    /// Read the tracker statistics, metrics and clients breakdown.
+    ReadStats,
+    /// Read the torrents and their peers, and watch the live events.
+    ReadTorrents,
+    /// Add, remove and reload the authentication keys.
+    ManageKeys,
+    /// Add, remove and reload the whitelisted torrents.
+    ManageWhitelist,
+    /// Remove torrents and peers from the swarms.
+    ManageSwarms,
+    /// Reload the configuration.
+    ManageConfig,
+    /// Read the audit log of administrative actions.
+    ReadAuditLog,
+}
+
+/// It checks if a role has a permission.
+#[must_use]
+pub fn is_allowed(role: Role, permission: Permission) -> bool {
+    match role {
+        Role::Admin => true,
+        Role::StatsReader => permission == Permission::ReadStats,
+        Role::TorrentViewer => matches!(permission, Permission::ReadStats | Permission::ReadTorrents),
+        Role::KeyManager => matches!(permission, Permission::ReadStats | Permission::ManageKeys),
+    }
+}
+
+/// The access token a request was authenticated with.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Authenticated {
+    /// The label of the token in the configuration. For example: `admin`.
+    pub label: String,
+    pub role: Role,
 }
 
-/// Middleware for authentication using a "token" GET param.
+/// Middleware for authentication using an `Authorization: Bearer` header or a
+/// "token" GET param.
+///
 /// The token must be one of the tokens in the tracker [HTTP API configuration](torrust_tracker_configuration::HttpApi).
+/// The [`Authenticated`] token is added to the request extensions, so that the
+/// [`authorize`] middleware can check its [`Role`].
 pub async fn auth(
     extract::State(state): extract::State<State>,
     extract::Query(params): extract::Query<QueryParams>,
-    request: Request<axum::body::Body>,
+    Extract(client_ip_sources): Extract,
+    mut request: Request<axum::body::Body>,
     next: Next,
 ) -> Response {
-    let Some(token) = params.token else {
-        return AuthError::Unauthorized.into_response();
-    };
+    let client_ip = client_ip(&state.trusted_proxies, &client_ip_sources);
 
-    if !authenticate(&token, &state.access_tokens) {
-        return AuthError::TokenNotValid.into_response();
+    match authenticate_request(&state, client_ip, request.headers(), params).await {
+        Ok(authenticated) => {
+            request.extensions_mut().insert(authenticated);
+            next.run(request).await
+        }
+        Err(err) => err.into_response(),
     }
+}
 
-    next.run(request).await
+/// It returns the IP used to throttle the requests of an API client.
+///
+/// It's the IP of the connection, unless the connection comes from one of the
+/// `trusted_proxies`. Then it's the rightmost IP in the `X-Forwarded-For`
+/// header, if the proxy has set it. The header sent by any other client is
+/// ignored, so clients can't avoid a lockout by changing or omitting it.
+///
+/// The connection IP is always available in the API server. Without it, all
+/// the requests share the unspecified IP, so they are still throttled.
+#[must_use]
+pub fn client_ip(trusted_proxies: &[IpAddr], sources: &ClientIpSources) -> IpAddr {
+    match sources.connection_info_ip {
+        Some(ip) if trusted_proxies.contains(&ip) => sources.right_most_x_forwarded_for.unwrap_or(ip),
+        Some(ip) => ip,
+        None => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+    }
 }
 
-enum AuthError {
+/// Middleware that rejects the requests whose token [`Role`] does not have the
+/// [`Permission`]. It must run after the [`auth`] middleware.
+pub async fn authorize(
+    extract::State(permission): extract::State<Permission>,
+    request: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    match request.extensions().get::<Authenticated>() {
+        Some(authenticated) if is_allowed(authenticated.role, permission) => next.run(request).await,
+        _ => forbidden_response(),
+    }
+}
+
+/// Why a request could not be authenticated.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AuthError {
     /// Missing token for authentication.
     Unauthorized,
     /// Token was provided but it is not valid.
     TokenNotValid,
+    /// Token was valid but it has expired.
+    TokenExpired,
+    /// The client sent too many invalid tokens and it's locked out. The token
+    /// is not checked.
+    LockedOut,
 }
 
 impl IntoResponse for AuthError {
@@ -74,12 +196,110 @@ impl IntoResponse for AuthError {
         match self {
             AuthError::Unauthorized => unauthorized_response(),
             AuthError::TokenNotValid => token_not_valid_response(),
+            AuthError::TokenExpired => token_expired_response(),
+            AuthError::LockedOut => locked_out_response(),
+        }
+    }
+}
+
+/// It authenticates a request with the token in the `Authorization` header or
+/// in the query params, and returns the label and the [`Role`] of the token.
+///
+/// Requests from locked out clients are rejected without checking the token.
+/// The `client_ip` identifies the client in the throttle (see [`client_ip()`]).
+///
+/// Failures and lockouts are logged and counted in the tracker statistics.
+///
+/// # Errors
+///
+/// Will return an error if the client is locked out, or the token is missing,
+/// unknown or expired.
+///
+/// # Panics
+///
+/// Will panic if the access tokens lock is poisoned.
+pub async fn authenticate_request(
+    state: &State,
+    client_ip: IpAddr,
+    headers: &HeaderMap,
+    params: QueryParams,
+) -> Result<Authenticated, AuthError> {
+    let now = CurrentClock::now().as_secs();
+
+    if state.throttle.is_locked_out(&client_ip, now) {
+        tracing::debug!(target: API_LOG_TARGET, %client_ip, "Rejected request from locked out API client");
+        return Err(AuthError::LockedOut);
+    }
+
+    let result = match bearer_token(headers).or(params.token) {
+        Some(token) => authenticate(
+            &token,
+            &state.access_tokens.read().expect("it should get the access tokens"),
+            now,
+        ),
+        None => Err(AuthError::Unauthorized),
+    };
+
+    match &result {
+        Ok(_) => state.throttle.record_success(&client_ip),
+        Err(err) => {
+            tracing::warn!(target: API_LOG_TARGET, %client_ip, error = ?err, "API authentication failed");
+
+            state.tracker.send_stats_event(Event::ApiAuthFailure).await;
+
+            if *err == AuthError::TokenNotValid && state.throttle.record_failure(client_ip, now) {
+                tracing::warn!(target: API_LOG_TARGET, %client_ip, "API client locked out after too many invalid tokens");
+
+                state.tracker.send_stats_event(Event::ApiAuthLockout).await;
+            }
         }
     }
+
+    result
+}
+
+/// It returns the token in the `Authorization: Bearer <token>` header, if any.
+#[must_use]
+pub fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(|token| token.trim().to_string())
 }
 
-fn authenticate(token: &str, tokens: &AccessTokens) -> bool {
-    tokens.values().any(|t| t == token)
+/// It checks if the token is one of the configured access tokens and it has
+/// not expired at `now` (unix time in seconds). It returns the label and the
+/// role of the token.
+///
+/// The SHA-256 digest of the token is compared with the digests of all the
+/// configured tokens in constant time. The digests have the same size, so the
+/// time it takes does not reveal how much of a token was right, nor its
+/// length.
+///
+/// # Errors
+///
+/// Will return an error if the token is unknown or expired.
+pub fn authenticate(token: &str, tokens: &AccessTokens, now: u64) -> Result<Authenticated, AuthError> {
+    let digest = Sha256::digest(token.as_bytes());
+
+    let (label, access_token) = tokens
+        .iter()
+        .fold(None, |found, (label, t)| {
+            let matches: bool = Sha256::digest(t.secret().as_bytes()).ct_eq(&digest).into();
+            found.or(matches.then_some((label, t)))
+        })
+        .ok_or(AuthError::TokenNotValid)?;
+
+    if access_token.is_expired_at(now) {
+        return Err(AuthError::TokenExpired);
+    }
+
+    Ok(Authenticated {
+        label: label.clone(),
+        role: access_token.role(),
+    })
 }
 
 /// `500` error response returned when the token is missing.
@@ -93,3 +313,184 @@ pub fn unauthorized_response() -> Response {
 pub fn token_not_valid_response() -> Response {
     unhandled_rejection_response("token not valid".to_string())
 }
+
+/// `500` error response when the provided token has expired.
+#[must_use]
+pub fn token_expired_response() -> Response {
+    unhandled_rejection_response("token expired".to_string())
+}
+
+/// `500` error response when the client is locked out after too many invalid
+/// tokens.
+#[must_use]
+pub fn locked_out_response() -> Response {
+    unhandled_rejection_response("too many failed authentication attempts".to_string())
+}
+
+/// `500` error response when the role of the token does not allow the request.
+#[must_use]
+pub fn forbidden_response() -> Response {
+    unhandled_rejection_response("forbidden".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use axum::http::{header, HeaderMap, HeaderValue};
+    use torrust_tracker_configuration::{AccessTokens, HttpApi, Role};
+
+    use super::{authenticate, bearer_token, client_ip, is_allowed, AuthError, Authenticated, Permission};
+    use crate::servers::http::v1::services::peer_ip_resolver::ClientIpSources;
+
+    fn sample_access_tokens() -> AccessTokens {
+        let mut config = HttpApi::default();
+
+        config.add_token("admin", "MyAccessToken");
+        config.add_scoped_token("stats", "MyStatsToken", Role::StatsReader, Some(1000));
+
+        config.access_tokens
+    }
+
+    #[test]
+    fn it_should_return_the_role_of_a_valid_token() {
+        let tokens = sample_access_tokens();
+
+        assert_eq!(authenticate("MyAccessToken", &tokens, 0).map(|a| a.role), Ok(Role::Admin));
+        assert_eq!(
+            authenticate("MyStatsToken", &tokens, 999).map(|a| a.role),
+            Ok(Role::StatsReader)
+        );
+    }
+
+    #[test]
+    fn it_should_return_the_label_of_a_valid_token() {
+        assert_eq!(
+            authenticate("MyStatsToken", &sample_access_tokens(), 0),
+            Ok(Authenticated {
+                label: "stats".to_string(),
+                role: Role::StatsReader,
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_unknown_tokens() {
+        assert_eq!(
+            authenticate("UnknownToken", &sample_access_tokens(), 0),
+            Err(AuthError::TokenNotValid)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_tokens_that_only_share_a_prefix_with_a_valid_token() {
+        let tokens = sample_access_tokens();
+
+        assert_eq!(authenticate("MyAccess", &tokens, 0), Err(AuthError::TokenNotValid));
+        assert_eq!(authenticate("MyAccessTokenX", &tokens, 0), Err(AuthError::TokenNotValid));
+    }
+
+    #[test]
+    fn it_should_reject_expired_tokens() {
+        assert_eq!(
+            authenticate("MyStatsToken", &sample_access_tokens(), 1000),
+            Err(AuthError::TokenExpired)
+        );
+    }
+
+    #[test]
+    fn it_should_extract_the_token_from_the_authorization_bearer_header() {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer MyAccessToken"));
+
+        assert_eq!(bearer_token(&headers), Some("MyAccessToken".to_string()));
+    }
+
+    #[test]
+    fn it_should_ignore_authorization_headers_with_other_schemes() {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Basic YWRtaW46YWRtaW4="));
+
+        assert_eq!(bearer_token(&headers), None);
+    }
+
+    #[test]
+    fn the_client_ip_should_be_the_connection_ip_when_the_connection_does_not_come_from_a_trusted_proxy() {
+        let sources = ClientIpSources {
+            right_most_x_forwarded_for: Some(IpAddr::V4(Ipv4Addr::new(2, 2, 2, 2))),
+            connection_info_ip: Some(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1))),
+        };
+
+        assert_eq!(client_ip(&[], &sources), IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)));
+    }
+
+    #[test]
+    fn the_client_ip_should_be_the_forwarded_ip_when_the_connection_comes_from_a_trusted_proxy() {
+        let proxy_ip = IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1));
+
+        let sources = ClientIpSources {
+            right_most_x_forwarded_for: Some(IpAddr::V4(Ipv4Addr::new(2, 2, 2, 2))),
+            connection_info_ip: Some(proxy_ip),
+        };
+
+        assert_eq!(client_ip(&[proxy_ip], &sources), IpAddr::V4(Ipv4Addr::new(2, 2, 2, 2)));
+    }
+
+    #[test]
+    fn the_client_ip_should_be_the_proxy_ip_when_a_trusted_proxy_does_not_forward_the_client_ip() {
+        let proxy_ip = IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1));
+
+        let sources = ClientIpSources {
+            right_most_x_forwarded_for: None,
+            connection_info_ip: Some(proxy_ip),
+        };
+
+        assert_eq!(client_ip(&[proxy_ip], &sources), proxy_ip);
+    }
+
+    #[test]
+    fn the_client_ip_should_be_the_unspecified_ip_when_there_is_no_connection_info() {
+        let sources = ClientIpSources {
+            right_most_x_forwarded_for: Some(IpAddr::V4(Ipv4Addr::new(2, 2, 2, 2))),
+            connection_info_ip: None,
+        };
+
+        assert_eq!(client_ip(&[], &sources), IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    }
+
+    #[test]
+    fn the_admin_role_should_have_all_the_permissions() {
+        for permission in [
+            Permission::ReadStats,
+            Permission::ReadTorrents,
+            Permission::ManageKeys,
+            Permission::ManageWhitelist,
+            Permission::ManageSwarms,
+            Permission::ManageConfig,
+            Permission::ReadAuditLog,
+        ] {
+            assert!(is_allowed(Role::Admin, permission));
+        }
+    }
+
+    #[test]
+    fn all_the_roles_should_be_allowed_to_read_the_stats() {
+        for role in [Role::StatsReader, Role::TorrentViewer, Role::KeyManager, Role::Admin] {
+            assert!(is_allowed(role, Permission::ReadStats));
+        }
+    }
+
+    #[test]
+    fn the_scoped_roles_should_only_have_their_own_permissions() {
+        assert!(!is_allowed(Role::StatsReader, Permission::ReadTorrents));
+        assert!(is_allowed(Role::TorrentViewer, Permission::ReadTorrents));
+        assert!(!is_allowed(Role::TorrentViewer, Permission::ManageKeys));
+        assert!(is_allowed(Role::KeyManager, Permission::ManageKeys));
+        assert!(!is_allowed(Role::KeyManager, Permission::ManageWhitelist));
+        assert!(!is_allowed(Role::TorrentViewer, Permission::ManageSwarms));
+        assert!(!is_allowed(Role::KeyManager, Permission::ManageConfig));
+        assert!(!is_allowed(Role::KeyManager, Permission::ReadAuditLog));
+    }
+}
@@ -1,2 +1,4 @@
 //! API middlewares. See [Axum middlewares](axum::middleware).
+pub mod audit;
 pub mod auth;
+pub mod throttling;
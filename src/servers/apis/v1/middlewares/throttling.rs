@@ -0,0 +1,323 @@
+//! Protection against clients guessing the API access tokens.
+//!
+//! The [authentication middleware](crate::servers::apis::v1::middlewares::auth)
+//! counts the invalid tokens sent by each client IP. A client that sends too
+//! many invalid tokens in a short time is locked out: all its requests are
+//! rejected for a while, even the ones with a valid token, so the tokens can't
+//! be guessed by brute force.
+//!
+//! The limits are in the [`AuthThrottling`] section of the HTTP API
+//! configuration:
+//!
+//! ```toml
+//! [http_api.auth_throttling]
+//! max_failures = 10
+//! failure_window = 60
+//! lockout_duration = 300
+//! ```
+//!
+//! With these values, a client can send at most 10 invalid tokens per minute,
+//! and it's locked out for 5 minutes after the 10th one. Missing and expired
+//! tokens are not counted, because they are not attempts to guess a token.
+//!
+//! The IPv6 clients are grouped by `/64` network, because a single host
+//! usually gets a whole `/64`. At most [`MAX_CLIENTS`] clients are tracked at
+//! the same time. When there is no room for a new client, its invalid tokens
+//! are not counted until the clients that stopped sending invalid tokens are
+//! forgotten.
+//!
+//! > **NOTICE**: when the API is running behind a reverse proxy, its IP must be
+//! > added to the `http_api.trusted_proxies` option, so the client IP is taken
+//! > from the `X-Forwarded-For` header set by the proxy. Otherwise, all the
+//! > clients would share the IP of the proxy and they would be locked out
+//! > together. The header is ignored in the requests that don't come from a
+//! > trusted proxy.
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv6Addr};
+use std::sync::Mutex;
+
+use torrust_tracker_configuration::AuthThrottling;
+
+/// The maximum number of clients whose invalid tokens are counted.
+pub const MAX_CLIENTS: usize = 65_536;
+
+/// The prefix length of the IPv6 networks that are counted as one client.
+const IPV6_PREFIX_LENGTH: u32 = 64;
+
+/// The invalid tokens sent by one client.
+#[derive(Debug, Default, Clone, Copy)]
+struct Failures {
+    /// Number of invalid tokens in the current window.
+    count: u32,
+    /// When the current window started (unix time in seconds).
+    window_started_at: u64,
+    /// When the lockout ends (unix time in seconds), if the client is locked
+    /// out.
+    locked_until: Option<u64>,
+}
+
+impl Failures {
+    fn is_locked_out_at(&self, now: u64) -> bool {
+        self.locked_until.is_some_and(|locked_until| now < locked_until)
+    }
+
+    /// Whether the entry can be forgotten because the window and the lockout
+    /// are over.
+    fn is_expired_at(&self, now: u64, failure_window: u64) -> bool {
+        !self.is_locked_out_at(now) && now >= self.window_started_at.saturating_add(failure_window)
+    }
+}
+
+/// The IP address that identifies a client: the IPv4 address, or the IPv6
+/// network the address belongs to.
+fn client(ip: &IpAddr) -> IpAddr {
+    match ip {
+        IpAddr::V4(_) => *ip,
+        IpAddr::V6(ip) => {
+            let mask = u128::MAX << (128 - IPV6_PREFIX_LENGTH);
+            IpAddr::V6(Ipv6Addr::from(u128::from(*ip) & mask))
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Clients {
+    failures: HashMap<IpAddr, Failures>,
+    /// The last time the expired entries were removed (unix time in seconds).
+    pruned_at: u64,
+}
+
+/// It keeps the invalid tokens sent by each client and the clients that are
+/// locked out. The times are unix times in seconds.
+#[derive(Debug)]
+pub struct AuthThrottle {
+    config: AuthThrottling,
+    max_clients: usize,
+    clients: Mutex<Clients>,
+}
+
+impl AuthThrottle {
+    #[must_use]
+    pub fn new(config: AuthThrottling) -> Self {
+        Self::with_max_clients(config, MAX_CLIENTS)
+    }
+
+    fn with_max_clients(config: AuthThrottling, max_clients: usize) -> Self {
+        Self {
+            config,
+            max_clients,
+            clients: Mutex::new(Clients::default()),
+        }
+    }
+
+    /// Whether the client is locked out at `now`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the clients lock is poisoned.
+    #[must_use]
+    pub fn is_locked_out(&self, ip: &IpAddr, now: u64) -> bool {
+        self.clients
+            .lock()
+            .expect("it should get the throttled clients")
+            .failures
+            .get(&client(ip))
+            .is_some_and(|failures| failures.is_locked_out_at(now))
+    }
+
+    /// It counts an invalid token sent by the client at `now`. It returns
+    /// `true` if the client has been locked out because of it.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the clients lock is poisoned.
+    pub fn record_failure(&self, ip: IpAddr, now: u64) -> bool {
+        if !self.config.is_enabled() {
+            return false;
+        }
+
+        let client = client(&ip);
+
+        let mut clients = self.clients.lock().expect("it should get the throttled clients");
+
+        if !clients.failures.contains_key(&client) && clients.failures.len() >= self.max_clients {
+            // The entries of the clients that stopped sending invalid tokens
+            // are only removed when there is no room for a new client, and at
+            // most once per second.
+            if now > clients.pruned_at {
+                let failure_window = self.config.failure_window;
+                clients
+                    .failures
+                    .retain(|_, failures| !failures.is_expired_at(now, failure_window));
+                clients.pruned_at = now;
+            }
+
+            if clients.failures.len() >= self.max_clients {
+                return false;
+            }
+        }
+
+        let failures = clients.failures.entry(client).or_default();
+
+        if failures.is_locked_out_at(now) {
+            return false;
+        }
+
+        if now >= failures.window_started_at.saturating_add(self.config.failure_window) {
+            *failures = Failures {
+                count: 0,
+                window_started_at: now,
+                locked_until: None,
+            };
+        }
+
+        failures.count += 1;
+
+        if failures.count < self.config.max_failures {
+            return false;
+        }
+
+        *failures = Failures {
+            count: 0,
+            window_started_at: now,
+            locked_until: Some(now.saturating_add(self.config.lockout_duration)),
+        };
+
+        true
+    }
+
+    /// It forgets the invalid tokens sent by the client, after it has sent a
+    /// valid one.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the clients lock is poisoned.
+    pub fn record_success(&self, ip: &IpAddr) {
+        let client = client(ip);
+
+        let mut clients = self.clients.lock().expect("it should get the throttled clients");
+
+        if clients
+            .failures
+            .get(&client)
+            .is_some_and(|failures| failures.locked_until.is_none())
+        {
+            clients.failures.remove(&client);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use torrust_tracker_configuration::AuthThrottling;
+
+    use super::AuthThrottle;
+
+    fn client_ip() -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1))
+    }
+
+    fn throttle(max_failures: u32) -> AuthThrottle {
+        AuthThrottle::new(AuthThrottling {
+            max_failures,
+            failure_window: 60,
+            lockout_duration: 300,
+        })
+    }
+
+    #[test]
+    fn it_should_lock_a_client_out_after_the_maximum_number_of_failures_in_the_window() {
+        let throttle = throttle(3);
+
+        assert!(!throttle.record_failure(client_ip(), 1000));
+        assert!(!throttle.record_failure(client_ip(), 1010));
+        assert!(!throttle.is_locked_out(&client_ip(), 1020));
+
+        assert!(throttle.record_failure(client_ip(), 1020));
+
+        assert!(throttle.is_locked_out(&client_ip(), 1020));
+    }
+
+    #[test]
+    fn it_should_not_lock_out_other_clients() {
+        let throttle = throttle(1);
+
+        throttle.record_failure(client_ip(), 1000);
+
+        assert!(!throttle.is_locked_out(&IpAddr::V4(Ipv4Addr::new(126, 0, 0, 2)), 1000));
+    }
+
+    #[test]
+    fn it_should_end_the_lockout_after_the_lockout_duration() {
+        let throttle = throttle(1);
+
+        throttle.record_failure(client_ip(), 1000);
+
+        assert!(throttle.is_locked_out(&client_ip(), 1299));
+        assert!(!throttle.is_locked_out(&client_ip(), 1300));
+    }
+
+    #[test]
+    fn it_should_not_count_the_failures_of_a_previous_window() {
+        let throttle = throttle(2);
+
+        assert!(!throttle.record_failure(client_ip(), 1000));
+        assert!(!throttle.record_failure(client_ip(), 1060));
+
+        assert!(!throttle.is_locked_out(&client_ip(), 1060));
+    }
+
+    #[test]
+    fn it_should_forget_the_failures_after_a_valid_token() {
+        let throttle = throttle(2);
+
+        throttle.record_failure(client_ip(), 1000);
+        throttle.record_success(&client_ip());
+
+        assert!(!throttle.record_failure(client_ip(), 1001));
+    }
+
+    #[test]
+    fn it_should_count_the_failures_of_an_ipv6_network_together() {
+        let throttle = throttle(2);
+
+        throttle.record_failure(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)), 1000);
+
+        assert!(throttle.record_failure(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 2)), 1000));
+
+        assert!(throttle.is_locked_out(&IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 1, 0, 0, 3)), 1000));
+        assert!(!throttle.is_locked_out(&IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 1, 0, 0, 0, 1)), 1000));
+    }
+
+    #[test]
+    fn it_should_not_track_more_clients_than_the_maximum() {
+        let throttle = AuthThrottle::with_max_clients(
+            AuthThrottling {
+                max_failures: 1,
+                failure_window: 60,
+                lockout_duration: 300,
+            },
+            1,
+        );
+
+        let other_client_ip = IpAddr::V4(Ipv4Addr::new(126, 0, 0, 2));
+
+        assert!(throttle.record_failure(client_ip(), 1000));
+        assert!(!throttle.record_failure(other_client_ip, 1000));
+        assert!(!throttle.is_locked_out(&other_client_ip, 1000));
+
+        // There is room again once the lockout of the first client is over.
+        assert!(throttle.record_failure(other_client_ip, 1300));
+        assert!(throttle.is_locked_out(&other_client_ip, 1300));
+    }
+
+    #[test]
+    fn it_should_never_lock_a_client_out_when_it_is_disabled() {
+        let throttle = throttle(0);
+
+        assert!(!throttle.record_failure(client_ip(), 1000));
+        assert!(!throttle.is_locked_out(&client_ip(), 1000));
+    }
+}
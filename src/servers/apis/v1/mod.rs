@@ -5,9 +5,16 @@
 //! Context | Description | Version
 //! ---|---|---
 //! `Stats` | Tracker statistics | [`v1`](crate::servers::apis::v1::context::stats)
+//! `Metrics` | Tracker statistics in Prometheus format | [`v1`](crate::servers::apis::v1::context::metrics)
+//! `Clients` | Active peers by client software | [`v1`](crate::servers::apis::v1::context::clients)
 //! `Torrents` | Torrents | [`v1`](crate::servers::apis::v1::context::torrent)
+//! `Peers` | Peers in all the torrent swarms | [`v1`](crate::servers::apis::v1::context::peer)
+//! `Events` | Live tracker events | [`v1`](crate::servers::apis::v1::context::events)
 //! `Whitelist` | Torrents whitelist | [`v1`](crate::servers::apis::v1::context::whitelist)
 //! `Authentication keys` | Authentication keys | [`v1`](crate::servers::apis::v1::context::auth_key)
+//! `Configuration` | Reload the configuration without restarting | [`v1`](crate::servers::apis::v1::context::config)
+//! `Info` | Tracker version, uptime, listeners and effective configuration | [`v1`](crate::servers::apis::v1::context::info)
+//! `Audit log` | Administrative actions done with the API | [`v1`](crate::servers::apis::v1::context::audit_log)
 //!
 //! > **NOTICE**:
 //! - The authentication keys are only used by the HTTP tracker.
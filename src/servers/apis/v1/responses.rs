@@ -56,6 +56,13 @@ pub fn invalid_info_hash_param_response(info_hash: &str) -> Response {
     ))
 }
 
+#[must_use]
+pub fn invalid_peer_id_param_response(peer_id: &str) -> Response {
+    bad_request_response(&format!(
+        "Invalid URL: invalid peer id param: string \"{peer_id}\", expected a 40 character long hex string"
+    ))
+}
+
 #[must_use]
 pub fn invalid_auth_key_param_response(invalid_key: &str) -> Response {
     bad_request_response(&format!("Invalid auth key id param \"{invalid_key}\""))
@@ -1,18 +1,79 @@
 //! Route initialization for the v1 API.
+//!
+//! Each API context requires a [`Permission`]. See the
+//! [authentication middleware](crate::servers::apis::v1::middlewares::auth).
 use std::sync::Arc;
 
-use axum::Router;
+use axum::{middleware, Router};
 
-use super::context::{auth_key, stats, torrent, whitelist};
+use super::context::{audit_log, auth_key, clients, config, events, info, peer, stats, torrent, whitelist};
+use super::middlewares::auth::{authorize, Permission};
+use crate::bootstrap::reload::ConfigReloader;
 use crate::core::Tracker;
+use crate::servers::registar::ServiceRegistry;
 
 /// Add the routes for the v1 API.
-pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+pub fn add(
+    prefix: &str,
+    router: Router,
+    tracker: Arc<Tracker>,
+    reloader: Arc<ConfigReloader>,
+    registry: ServiceRegistry,
+) -> Router {
     let v1_prefix = format!("{prefix}/v1");
 
-    let router = auth_key::routes::add(&v1_prefix, router, tracker.clone());
-    let router = stats::routes::add(&v1_prefix, router, tracker.clone());
-    let router = whitelist::routes::add(&v1_prefix, router, tracker.clone());
+    router
+        .merge(restrict(
+            Permission::ManageKeys,
+            auth_key::routes::add(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadStats,
+            stats::routes::add(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadStats,
+            clients::routes::add(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadStats,
+            info::routes::add(&v1_prefix, Router::new(), tracker.clone(), reloader.clone(), registry),
+        ))
+        .merge(restrict(
+            Permission::ManageWhitelist,
+            whitelist::routes::add(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadTorrents,
+            torrent::routes::add(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadTorrents,
+            events::routes::add(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadTorrents,
+            peer::routes::add_search(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ManageSwarms,
+            torrent::routes::add_management(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ManageSwarms,
+            peer::routes::add(&v1_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadAuditLog,
+            audit_log::routes::add(&v1_prefix, Router::new(), tracker),
+        ))
+        .merge(restrict(
+            Permission::ManageConfig,
+            config::routes::add(&v1_prefix, Router::new(), reloader),
+        ))
+}
 
-    torrent::routes::add(&v1_prefix, router, tracker)
+/// It only allows the requests whose token role has the permission.
+fn restrict(permission: Permission, routes: Router) -> Router {
+    routes.route_layer(middleware::from_fn_with_state(permission, authorize))
 }
@@ -0,0 +1,39 @@
+//! API handlers for the [`audit_log`](crate::servers::apis::v2::context::audit_log)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::Response;
+use axum_extra::extract::{Query, WithRejection};
+
+use super::responses::failed_to_load_audit_log_response;
+use crate::core::Tracker;
+use crate::servers::apis::v1::context::audit_log::handlers::QueryParams;
+use crate::servers::apis::v1::context::audit_log::responses::audit_log_response;
+use crate::servers::apis::v2::responses::Problem;
+
+/// It handles the request to get the audit log.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::audit_log)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/audit_log",
+    tag = "audit_log",
+    params(QueryParams),
+    responses(
+        (status = 200, description = "The audit log entries, from the newest one", body = [AuditLogEntry],
+            headers(("x-total-count" = u64, description = "The number of entries in the whole log"))),
+        (status = 400, description = "Invalid query params", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The audit log could not be loaded from the database", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_audit_log_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<QueryParams>, Problem>,
+) -> Response {
+    match tracker.get_audit_log(&params.pagination()) {
+        Ok(page) => audit_log_response(page),
+        Err(e) => failed_to_load_audit_log_response(e),
+    }
+}
@@ -0,0 +1,20 @@
+//! Audit log API context.
+//!
+//! It returns the same resources as the
+//! [v1 audit log context](crate::servers::apis::v1::context::audit_log).
+//! Only the tokens with the `admin` role can read the audit log.
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `GET` | `/audit_log` | Get the audit log, from the newest entry. Supports the `offset` and `limit` query params
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v2/audit_log?token=MyAccessToken&offset=0&limit=10"
+//! ```
+pub mod handlers;
+pub mod responses;
+pub mod routes;
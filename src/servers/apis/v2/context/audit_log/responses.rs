@@ -0,0 +1,13 @@
+//! API responses for the [`audit_log`](crate::servers::apis::v2::context::audit_log)
+//! API context.
+use std::error::Error;
+
+use axum::response::Response;
+
+use crate::servers::apis::v2::responses::internal_server_error_response;
+
+/// `500` error response when the audit log cannot be loaded from the database.
+#[must_use]
+pub fn failed_to_load_audit_log_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to load audit log: {e}"))
+}
@@ -0,0 +1,17 @@
+//! API routes for the [`audit_log`](crate::servers::apis::v2::context::audit_log) API context.
+//!
+//! - `GET /audit_log`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v2::context::audit_log).
+use std::sync::Arc;
+
+use axum::routing::get;
+use axum::Router;
+
+use super::handlers::get_audit_log_handler;
+use crate::core::Tracker;
+
+/// It adds the routes to the router for the [`audit_log`](crate::servers::apis::v2::context::audit_log) API context.
+pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    router.route(&format!("{prefix}/audit_log"), get(get_audit_log_handler).with_state(tracker))
+}
@@ -0,0 +1,216 @@
+//! API handlers for the [`auth_key`](crate::servers::apis::v2::context::auth_key) API context.
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::extract::{Json, Path, State};
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::{Query, WithRejection};
+
+use super::responses::{
+    auth_key_already_exists_response, auth_key_created_response, auth_key_not_found_response, auth_keys_created_response,
+    failed_to_add_key_response, failed_to_delete_key_response, failed_to_generate_keys_response, failed_to_purge_keys_response,
+    failed_to_reload_keys_response, invalid_auth_key_duration_response, invalid_auth_key_response, invalid_key_count_response,
+};
+use crate::core::auth::Key;
+use crate::core::error::PeerKeyError;
+use crate::core::services::auth_key::{get_auth_key_info, get_auth_keys};
+use crate::core::{AddKeyRequest, Tracker};
+use crate::servers::apis::v1::context::auth_key::forms::{AddKeyForm, GenerateKeysForm, MAX_BULK_KEYS};
+use crate::servers::apis::v1::context::auth_key::handlers::{KeyParam, ListQueryParams};
+use crate::servers::apis::v1::context::auth_key::resources::AuthKey;
+use crate::servers::apis::v1::context::auth_key::responses::{
+    auth_key_detail_response, auth_key_list_response, purged_keys_response,
+};
+use crate::servers::apis::v2::responses::{invalid_auth_key_param_response, no_content_response, Problem};
+
+/// It handles the request to add a new authentication key.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::auth_key)
+/// for more information about this endpoint.
+#[utoipa::path(
+    post,
+    path = "/api/v2/keys",
+    tag = "keys",
+    request_body = AddKeyForm,
+    responses(
+        (status = 201, description = "The new key", body = AuthKey),
+        (status = 400, description = "Invalid key or duration", body = Problem, content_type = "application/problem+json"),
+        (status = 409, description = "The key already exists", body = Problem, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid json body", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The key could not be stored", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn add_auth_key_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Json(add_key_form), _): WithRejection<Json<AddKeyForm>, Problem>,
+) -> Response {
+    match tracker
+        .add_peer_key(AddKeyRequest {
+            opt_key: add_key_form.opt_key.clone(),
+            opt_seconds_valid: add_key_form.opt_seconds_valid,
+        })
+        .await
+    {
+        Ok(auth_key) => auth_key_created_response(AuthKey::from(auth_key)),
+        Err(err) => match err {
+            PeerKeyError::DurationOverflow { seconds_valid } => invalid_auth_key_duration_response(seconds_valid),
+            PeerKeyError::InvalidKey { key, source } => invalid_auth_key_response(&key, source),
+            PeerKeyError::DuplicateKey { key } => auth_key_already_exists_response(&key),
+            PeerKeyError::DatabaseError { source } => failed_to_add_key_response(source),
+        },
+    }
+}
+
+/// It handles the request to generate a batch of authentication keys.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::auth_key)
+/// for more information about this endpoint.
+#[utoipa::path(
+    post,
+    path = "/api/v2/keys/bulk",
+    tag = "keys",
+    request_body = GenerateKeysForm,
+    responses(
+        (status = 201, description = "The new keys", body = [AuthKey]),
+        (status = 400, description = "Invalid number of keys or duration", body = Problem, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid json body", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The keys could not be stored", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn generate_auth_keys_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Json(generate_keys_form), _): WithRejection<Json<GenerateKeysForm>, Problem>,
+) -> Response {
+    if !generate_keys_form.has_valid_count() {
+        return invalid_key_count_response(generate_keys_form.count, MAX_BULK_KEYS);
+    }
+
+    match tracker
+        .generate_auth_keys(generate_keys_form.count, generate_keys_form.opt_seconds_valid)
+        .await
+    {
+        Ok(peer_keys) => auth_keys_created_response(peer_keys),
+        Err(err) => match err {
+            PeerKeyError::DurationOverflow { seconds_valid } => invalid_auth_key_duration_response(seconds_valid),
+            PeerKeyError::InvalidKey { key, source } => invalid_auth_key_response(&key, source),
+            err @ PeerKeyError::DuplicateKey { .. } => failed_to_generate_keys_response(err),
+            PeerKeyError::DatabaseError { source } => failed_to_generate_keys_response(source),
+        },
+    }
+}
+
+/// It handles the request to delete an authentication key.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::auth_key)
+/// for more information about this endpoint.
+#[utoipa::path(
+    delete,
+    path = "/api/v2/keys/{key}",
+    tag = "keys",
+    params(("key" = String, Path, description = "The authentication key", example = "xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6")),
+    responses(
+        (status = 204, description = "The key was deleted"),
+        (status = 400, description = "Invalid key", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "The key does not exist", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The key could not be deleted", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn delete_auth_key_handler(State(tracker): State<Arc<Tracker>>, Path(key): Path<KeyParam>) -> Response {
+    match Key::from_str(&key.0) {
+        Err(_) => invalid_auth_key_param_response(&key.0),
+        Ok(key) if tracker.get_auth_key(&key).await.is_none() => auth_key_not_found_response(),
+        Ok(key) => match tracker.remove_auth_key(&key).await {
+            Ok(()) => no_content_response(),
+            Err(e) => failed_to_delete_key_response(e),
+        },
+    }
+}
+
+/// It handles the request to reload the authentication keys from the database
+/// into memory.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::auth_key)
+/// for more information about this endpoint.
+#[utoipa::path(
+    post,
+    path = "/api/v2/keys/reload",
+    tag = "keys",
+    responses(
+        (status = 204, description = "The keys were reloaded"),
+        (status = 500, description = "The keys could not be loaded from the database", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn reload_keys_handler(State(tracker): State<Arc<Tracker>>) -> Response {
+    match tracker.load_keys_from_database().await {
+        Ok(()) => no_content_response(),
+        Err(e) => failed_to_reload_keys_response(e),
+    }
+}
+
+/// It handles the request to remove the expired authentication keys from
+/// memory and from the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::auth_key)
+/// for more information about this endpoint.
+#[utoipa::path(
+    post,
+    path = "/api/v2/keys/purge",
+    tag = "keys",
+    responses(
+        (status = 200, description = "The number of expired keys removed", body = PurgedKeys),
+        (status = 500, description = "The expired keys could not be removed", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn purge_expired_keys_handler(State(tracker): State<Arc<Tracker>>) -> Response {
+    match tracker.remove_expired_auth_keys().await {
+        Ok(removed_keys) => purged_keys_response(removed_keys as u64).into_response(),
+        Err(e) => failed_to_purge_keys_response(e),
+    }
+}
+
+/// It handles the request to get a list of authentication keys.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::auth_key)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/keys",
+    tag = "keys",
+    params(ListQueryParams),
+    responses(
+        (status = 200, description = "The keys matching the filters, sorted by key", body = [AuthKey]),
+        (status = 400, description = "Invalid query params", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_auth_keys_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<ListQueryParams>, Problem>,
+) -> Response {
+    auth_key_list_response(get_auth_keys(tracker, &params.filter(), Some(&params.pagination())).await).into_response()
+}
+
+/// It handles the request to get all the information about an
+/// authentication key.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::auth_key)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/keys/{key}",
+    tag = "keys",
+    params(("key" = String, Path, description = "The authentication key", example = "xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6")),
+    responses(
+        (status = 200, description = "The key with its expiration and usage", body = AuthKeyDetail),
+        (status = 400, description = "Invalid key", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "The tracker does not have the key", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_auth_key_handler(State(tracker): State<Arc<Tracker>>, Path(key): Path<KeyParam>) -> Response {
+    match Key::from_str(&key.0) {
+        Err(_) => invalid_auth_key_param_response(&key.0),
+        Ok(key) => match get_auth_key_info(tracker, &key).await {
+            Some(info) => auth_key_detail_response(info).into_response(),
+            None => auth_key_not_found_response(),
+        },
+    }
+}
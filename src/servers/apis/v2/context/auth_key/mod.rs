@@ -0,0 +1,38 @@
+//! Authentication keys API context.
+//!
+//! It replaces the v1 `POST /key/:seconds_valid_or_key` and `GET /keys/reload`
+//! endpoints with resource routes. The resources are the same as in the
+//! [v1 authentication keys context](crate::servers::apis::v1::context::auth_key).
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `GET` | `/keys` | List the keys sorted by key. Accepts the same `offset`, `limit`, `expired`, `permanent` and `expiring_before` query params as the [v1 endpoint](crate::servers::apis::v1::context::auth_key#list-authentication-keys)
+//! `POST` | `/keys` | Add a new key, pre-generated or random. Returns `201` with the key, or `409` if the pre-generated key already exists
+//! `GET` | `/keys/:key` | Get a key with its expiration and usage. Returns `404` if the key does not exist
+//! `DELETE` | `/keys/:key` | Delete a key. Returns `204`, or `404` if the key does not exist
+//! `POST` | `/keys/reload` | Reload the keys from the database. Returns `204`
+//! `POST` | `/keys/purge` | Remove the keys that expired more than `core.expired_keys_grace_period` seconds ago. Returns `200` with the number of removed keys
+//! `POST` | `/keys/bulk` | Generate up to 10000 random keys with the same expiration in one transaction. Returns `201` with the keys
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X POST "http://127.0.0.1:1212/api/v2/keys?token=MyAccessToken" \
+//!      -H "Content-Type: application/json" \
+//!      -d '{"key": null, "seconds_valid": 7200}'
+//! ```
+//!
+//! **Example response** `201`
+//!
+//! ```json
+//! {
+//!   "key": "xqD6NWH9TcKrOCwDmqcdH5hF5RrbL0A6",
+//!   "valid_until": 1680009900,
+//!   "expiry_time": "2023-03-28 13:25:00.058085050 UTC"
+//! }
+//! ```
+pub mod handlers;
+pub mod responses;
+pub mod routes;
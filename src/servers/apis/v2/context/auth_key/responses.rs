@@ -0,0 +1,92 @@
+//! API responses for the [`auth_key`](crate::servers::apis::v2::context::auth_key)
+//! API context.
+use std::error::Error;
+
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Json, Response};
+
+use crate::core::auth::PeerKey;
+use crate::servers::apis::v1::context::auth_key::resources::AuthKey;
+use crate::servers::apis::v2::responses::{
+    bad_request_response, conflict_response, internal_server_error_response, not_found_response,
+};
+
+/// `201` response that contains the new [`AuthKey`] resource as json.
+#[must_use]
+pub fn auth_key_created_response(auth_key: AuthKey) -> Response {
+    (StatusCode::CREATED, Json(auth_key)).into_response()
+}
+
+/// `201` response that contains an array with the generated [`AuthKey`]
+/// resources as json.
+#[must_use]
+pub fn auth_keys_created_response(peer_keys: Vec<PeerKey>) -> Response {
+    (
+        StatusCode::CREATED,
+        Json(peer_keys.into_iter().map(AuthKey::from).collect::<Vec<_>>()),
+    )
+        .into_response()
+}
+
+/// `404` error response when the authentication key does not exist.
+#[must_use]
+pub fn auth_key_not_found_response() -> Response {
+    not_found_response("auth key not found")
+}
+
+/// `409` error response when the pre-generated key already exists.
+#[must_use]
+pub fn auth_key_already_exists_response(auth_key: &str) -> Response {
+    conflict_response(&format!("auth key already exists: \"{auth_key}\""))
+}
+
+/// `400` error response when the pre-generated key is not valid.
+#[must_use]
+pub fn invalid_auth_key_response<E: Error>(auth_key: &str, e: E) -> Response {
+    bad_request_response(&format!("invalid auth key: string \"{auth_key}\", {e}"))
+}
+
+/// `400` error response when the key duration overflows the expiry time.
+#[must_use]
+pub fn invalid_auth_key_duration_response(duration: u64) -> Response {
+    bad_request_response(&format!("invalid auth key duration: \"{duration}\""))
+}
+
+/// `400` error response when the number of keys to generate is out of range.
+#[must_use]
+pub fn invalid_key_count_response(count: usize, max: usize) -> Response {
+    bad_request_response(&format!("invalid key count: \"{count}\", expected from 1 to {max}"))
+}
+
+/// `500` error response when a batch of authentication keys cannot be
+/// generated.
+#[must_use]
+pub fn failed_to_generate_keys_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to generate keys: {e}"))
+}
+
+/// `500` error response when the provided key cannot be added.
+#[must_use]
+pub fn failed_to_add_key_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to add key: {e}"))
+}
+
+/// `500` error response when an authentication key cannot be deleted.
+#[must_use]
+pub fn failed_to_delete_key_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to delete key: {e}"))
+}
+
+/// `500` error response when the authentication keys cannot be reloaded from
+/// the database into memory.
+#[must_use]
+pub fn failed_to_reload_keys_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to reload keys: {e}"))
+}
+
+/// `500` error response when the expired authentication keys cannot be
+/// removed.
+#[must_use]
+pub fn failed_to_purge_keys_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to purge keys: {e}"))
+}
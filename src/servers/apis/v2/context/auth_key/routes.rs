@@ -0,0 +1,55 @@
+//! API routes for the [`auth_key`](crate::servers::apis::v2::context::auth_key)
+//! API context.
+//!
+//! - `GET /keys`
+//! - `POST /keys`
+//! - `GET /keys/:key`
+//! - `DELETE /keys/:key`
+//! - `POST /keys/reload`
+//! - `POST /keys/purge`
+//! - `POST /keys/bulk`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v2::context::auth_key).
+use std::sync::Arc;
+
+use axum::routing::{get, post};
+use axum::Router;
+
+use super::handlers::{
+    add_auth_key_handler, delete_auth_key_handler, generate_auth_keys_handler, get_auth_key_handler, get_auth_keys_handler,
+    purge_expired_keys_handler, reload_keys_handler,
+};
+use crate::core::Tracker;
+
+/// It adds the routes to the router for the [`auth_key`](crate::servers::apis::v2::context::auth_key) API context.
+pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    let prefix = format!("{prefix}/keys");
+
+    router
+        .route(
+            &prefix,
+            get(get_auth_keys_handler)
+                .with_state(tracker.clone())
+                .post(add_auth_key_handler)
+                .with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/reload"),
+            post(reload_keys_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/purge"),
+            post(purge_expired_keys_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/bulk"),
+            post(generate_auth_keys_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/:key"),
+            get(get_auth_key_handler)
+                .with_state(tracker.clone())
+                .delete(delete_auth_key_handler)
+                .with_state(tracker),
+        )
+}
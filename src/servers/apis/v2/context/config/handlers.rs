@@ -0,0 +1,33 @@
+//! API handlers for the [`config`](crate::servers::apis::v2::context::config)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::{IntoResponse, Response};
+
+use super::responses::{failed_to_reload_config_response, restart_required_response};
+use crate::bootstrap::reload::{ConfigReloader, Error};
+use crate::servers::apis::v1::context::config::responses::config_reload_response;
+
+/// It handles the request to reload the configuration.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::config)
+/// for more information about this endpoint.
+#[utoipa::path(
+    post,
+    path = "/api/v2/config/reload",
+    tag = "config",
+    responses(
+        (status = 200, description = "The configuration was reloaded", body = ConfigReload),
+        (status = 409, description = "Some of the changed options require a restart", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The configuration could not be loaded or it is not valid", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+#[allow(clippy::unused_async)]
+pub async fn reload_config_handler(State(reloader): State<Arc<ConfigReloader>>) -> Response {
+    match reloader.reload() {
+        Ok(changed_options) => config_reload_response(changed_options).into_response(),
+        Err(Error::RestartRequired { options }) => restart_required_response(&options),
+        Err(e) => failed_to_reload_config_response(e),
+    }
+}
@@ -0,0 +1,20 @@
+//! Configuration API context.
+//!
+//! It reloads the configuration like the
+//! [v1 config context](crate::servers::apis::v1::context::config). Refer to
+//! it for the options that can be changed without restarting the tracker.
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `POST` | `/config/reload` | Reload the configuration. Returns the changed options, or a `409` problem if some of them require a restart
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X POST "http://127.0.0.1:1212/api/v2/config/reload?token=MyAccessToken"
+//! ```
+pub mod handlers;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,24 @@
+//! API responses for the [`config`](crate::servers::apis::v2::context::config)
+//! API context.
+use std::error::Error;
+
+use axum::response::Response;
+
+use crate::servers::apis::v2::responses::{conflict_response, internal_server_error_response};
+
+/// `409` error response when some of the changed options can't be applied
+/// without restarting the tracker.
+#[must_use]
+pub fn restart_required_response(options: &[String]) -> Response {
+    conflict_response(&format!(
+        "unable to reload the configuration, these options require a restart: {}",
+        options.join(", ")
+    ))
+}
+
+/// `500` error response when the configuration cannot be loaded or it's not
+/// valid.
+#[must_use]
+pub fn failed_to_reload_config_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to reload configuration: {e}"))
+}
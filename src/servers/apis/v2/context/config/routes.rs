@@ -0,0 +1,20 @@
+//! API routes for the [`config`](crate::servers::apis::v2::context::config) API context.
+//!
+//! - `POST /config/reload`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v2::context::config).
+use std::sync::Arc;
+
+use axum::routing::post;
+use axum::Router;
+
+use super::handlers::reload_config_handler;
+use crate::bootstrap::reload::ConfigReloader;
+
+/// It adds the routes to the router for the [`config`](crate::servers::apis::v2::context::config) API context.
+pub fn add(prefix: &str, router: Router, reloader: Arc<ConfigReloader>) -> Router {
+    router.route(
+        &format!("{prefix}/config/reload"),
+        post(reload_config_handler).with_state(reloader),
+    )
+}
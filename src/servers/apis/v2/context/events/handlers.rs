@@ -0,0 +1,40 @@
+//! API handlers for the [`events`](crate::servers::apis::v2::context::events)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::{Query, WithRejection};
+
+use super::responses::{invalid_protocol_param_response, invalid_type_param_response};
+use crate::core::Tracker;
+use crate::servers::apis::v1::context::events::handlers::{QueryParamError, QueryParams};
+use crate::servers::apis::v1::context::events::responses::event_stream_response;
+use crate::servers::apis::v2::responses::{invalid_info_hash_param_response, Problem};
+
+/// It handles the request to watch the live tracker events.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::events)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/events",
+    tag = "events",
+    params(QueryParams),
+    responses(
+        (status = 200, description = "A stream of Server-Sent Events. The data of each event is an Event resource, or a Lagged resource for the `lagged` events",
+            body = Event, content_type = "text/event-stream"),
+        (status = 400, description = "Invalid query params", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_events_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<QueryParams>, Problem>,
+) -> Response {
+    match params.filter() {
+        Ok(filter) => event_stream_response(tracker.subscribe_to_events(filter)).into_response(),
+        Err(QueryParamError::InvalidInfoHash { info_hash }) => invalid_info_hash_param_response(&info_hash),
+        Err(QueryParamError::InvalidType { source }) => invalid_type_param_response(&source.to_string()),
+        Err(QueryParamError::InvalidProtocol { source }) => invalid_protocol_param_response(&source.to_string()),
+    }
+}
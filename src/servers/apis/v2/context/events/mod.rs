@@ -0,0 +1,20 @@
+//! Live events API context.
+//!
+//! It streams the same Server-Sent Events as the
+//! [v1 events context](crate::servers::apis::v1::context::events), with the
+//! same query parameters. Invalid query parameters return a `400` problem.
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `GET` | `/events` | Watch the live tracker events
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -N "http://127.0.0.1:1212/api/v2/events?token=MyAccessToken&protocol=http"
+//! ```
+pub mod handlers;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,17 @@
+//! API responses for the [`events`](crate::servers::apis::v2::context::events)
+//! API context.
+use axum::response::Response;
+
+use crate::servers::apis::v2::responses::bad_request_response;
+
+/// `400` error response when an event type is unknown.
+#[must_use]
+pub fn invalid_type_param_response(error: &str) -> Response {
+    bad_request_response(&format!("invalid type param: {error}"))
+}
+
+/// `400` error response when a protocol is unknown.
+#[must_use]
+pub fn invalid_protocol_param_response(error: &str) -> Response {
+    bad_request_response(&format!("invalid protocol param: {error}"))
+}
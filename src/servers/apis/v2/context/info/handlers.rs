@@ -0,0 +1,30 @@
+//! API handlers for the [`info`](crate::servers::apis::v2::context::info)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::Json;
+
+use crate::bootstrap::reload::ConfigReloader;
+use crate::core::Tracker;
+use crate::servers::apis::v1::context::info::resources::TrackerInfo;
+use crate::servers::apis::v1::context::info::responses::tracker_info_response;
+use crate::servers::registar::ServiceRegistry;
+
+/// It handles the request to get the tracker info.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::info)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/info",
+    tag = "info",
+    responses((status = 200, description = "The tracker info", body = TrackerInfo))
+)]
+pub async fn get_tracker_info_handler(
+    State((tracker, reloader, registry)): State<(Arc<Tracker>, Arc<ConfigReloader>, ServiceRegistry)>,
+) -> Json<TrackerInfo> {
+    let registrations = registry.lock().await.values().cloned().collect::<Vec<_>>();
+
+    tracker_info_response(&tracker, reloader.current(), &registrations)
+}
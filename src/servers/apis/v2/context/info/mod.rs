@@ -0,0 +1,19 @@
+//! Tracker info API context.
+//!
+//! It returns the same resource as the
+//! [v1 info context](crate::servers::apis::v1::context::info). Refer to it for
+//! the description of the attributes.
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `GET` | `/info` | Get which tracker is deployed and how it's running
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v2/info?token=MyAccessToken"
+//! ```
+pub mod handlers;
+pub mod routes;
@@ -0,0 +1,14 @@
+//! API is organized in resource groups called contexts.
+//!
+//! Each context is a module that contains the API endpoints related to a
+//! specific resource group. The v2 contexts use the same resources as the
+//! [v1 contexts](crate::servers::apis::v1::context).
+pub mod audit_log;
+pub mod auth_key;
+pub mod config;
+pub mod events;
+pub mod info;
+pub mod peer;
+pub mod stats;
+pub mod torrent;
+pub mod whitelist;
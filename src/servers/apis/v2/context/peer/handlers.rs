@@ -0,0 +1,65 @@
+//! API handlers for the [`peer`](crate::servers::apis::v2::context::peer)
+//! API context.
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::{Query, WithRejection};
+
+use super::responses::{
+    invalid_ip_network_param_response, invalid_ip_param_response, invalid_peer_id_prefix_param_response,
+    missing_search_params_response,
+};
+use crate::core::Tracker;
+use crate::servers::apis::v1::context::peer::handlers::{SearchParamError, SearchQueryParams};
+use crate::servers::apis::v1::context::peer::responses::{peer_matches_response, removed_peers_response};
+use crate::servers::apis::v2::responses::Problem;
+
+/// It handles the request to search the peers in all the swarms.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::peer)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/peers",
+    tag = "peers",
+    params(SearchQueryParams),
+    responses(
+        (status = 200, description = "The peers matching the search with the torrent they are in", body = [PeerMatch]),
+        (status = 400, description = "Missing or invalid search params", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn search_peers_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<SearchQueryParams>, Problem>,
+) -> Response {
+    match params.search() {
+        Ok(search) => peer_matches_response(&tracker.search_peers(&search, Some(&params.pagination()))).into_response(),
+        Err(SearchParamError::MissingCriteria) => missing_search_params_response(),
+        Err(SearchParamError::InvalidIp { ip }) => invalid_ip_network_param_response(&ip),
+        Err(SearchParamError::InvalidPeerId { peer_id }) => invalid_peer_id_prefix_param_response(&peer_id),
+    }
+}
+
+/// It handles the request to remove all the peers with an IP from all the
+/// swarms.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::peer)
+/// for more information about this endpoint.
+#[utoipa::path(
+    delete,
+    path = "/api/v2/peers/{ip}",
+    tag = "peers",
+    params(("ip" = String, Path, description = "The IPv4 or IPv6 address of the peers", example = "192.168.1.88")),
+    responses(
+        (status = 200, description = "The number of peers removed from all the swarms", body = RemovedPeers),
+        (status = 400, description = "Invalid IP address", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn remove_peers_with_ip_handler(State(tracker): State<Arc<Tracker>>, Path(ip): Path<String>) -> Response {
+    match ip.parse::<IpAddr>() {
+        Ok(ip) => removed_peers_response(tracker.remove_peers_with_ip(&ip)).into_response(),
+        Err(_) => invalid_ip_param_response(&ip),
+    }
+}
@@ -0,0 +1,21 @@
+//! Peers API context.
+//!
+//! It returns the same resources as the
+//! [v1 peers context](crate::servers::apis::v1::context::peer).
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `GET` | `/peers` | Search the peers in all the swarms by IP address, network or peer ID
+//! `DELETE` | `/peers/:ip` | Remove the peers with an IP from all the swarms
+//!
+//! **Example requests**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v2/peers?token=MyAccessToken&ip=192.168.1.0/24&peer_id=2d7142"
+//! curl -X DELETE "http://127.0.0.1:1212/api/v2/peers/192.168.1.88?token=MyAccessToken"
+//! ```
+pub mod handlers;
+pub mod responses;
+pub mod routes;
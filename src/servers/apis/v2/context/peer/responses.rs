@@ -0,0 +1,37 @@
+//! API responses for the [`peer`](crate::servers::apis::v2::context::peer)
+//! API context.
+use axum::response::Response;
+
+use crate::servers::apis::v2::responses::bad_request_response;
+
+/// `400` error response when the IP path param is not a valid IP address.
+#[must_use]
+pub fn invalid_ip_param_response(ip: &str) -> Response {
+    bad_request_response(&format!(
+        "invalid ip param: string \"{ip}\", expected an IPv4 or IPv6 address"
+    ))
+}
+
+/// `400` error response when the peer search has neither an IP nor a peer ID.
+#[must_use]
+pub fn missing_search_params_response() -> Response {
+    bad_request_response("missing search params, expected an ip or a peer_id param")
+}
+
+/// `400` error response when the `ip` query param is neither an IP address
+/// nor a network.
+#[must_use]
+pub fn invalid_ip_network_param_response(ip: &str) -> Response {
+    bad_request_response(&format!(
+        "invalid ip param: string \"{ip}\", expected an IP address or a network in CIDR notation"
+    ))
+}
+
+/// `400` error response when the `peer_id` query param is not a peer ID
+/// prefix.
+#[must_use]
+pub fn invalid_peer_id_prefix_param_response(peer_id: &str) -> Response {
+    bad_request_response(&format!(
+        "invalid peer_id param: string \"{peer_id}\", expected a hex string of up to 40 characters"
+    ))
+}
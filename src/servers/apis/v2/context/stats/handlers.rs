@@ -0,0 +1,61 @@
+//! API handlers for the [`stats`](crate::servers::apis::v2::context::stats)
+//! API context.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::response::{IntoResponse, Json, Response};
+use axum_extra::extract::{Query, WithRejection};
+
+use super::responses::{failed_to_load_stats_history_response, invalid_days_param_response};
+use crate::core::services::statistics::get_metrics;
+use crate::core::Tracker;
+use crate::servers::apis::v1::context::stats::handlers::{HistoryQueryParams, DEFAULT_HISTORY_DAYS, MAX_HISTORY_DAYS};
+use crate::servers::apis::v1::context::stats::resources::Stats;
+use crate::servers::apis::v1::context::stats::responses::{stats_history_response, stats_response};
+use crate::servers::apis::v2::responses::Problem;
+
+/// It handles the request to get the tracker statistics.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::stats)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/stats",
+    tag = "stats",
+    responses((status = 200, description = "The tracker statistics", body = Stats))
+)]
+pub async fn get_stats_handler(State(tracker): State<Arc<Tracker>>) -> Json<Stats> {
+    stats_response(get_metrics(tracker.clone()).await)
+}
+
+/// It handles the request to get the daily totals of the persisted usage
+/// statistics.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::stats)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/stats/history",
+    tag = "stats",
+    params(HistoryQueryParams),
+    responses(
+        (status = 200, description = "The daily totals, from the oldest day", body = [DailyStats]),
+        (status = 400, description = "Number of days out of range", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The history could not be loaded from the database", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_stats_history_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<HistoryQueryParams>, Problem>,
+) -> Response {
+    let days = params.days.unwrap_or(DEFAULT_HISTORY_DAYS);
+
+    if !(1..=MAX_HISTORY_DAYS).contains(&days) {
+        return invalid_days_param_response(days, MAX_HISTORY_DAYS);
+    }
+
+    match tracker.get_statistics_history(days) {
+        Ok(daily_counters) => stats_history_response(&daily_counters).into_response(),
+        Err(e) => failed_to_load_stats_history_response(e),
+    }
+}
@@ -0,0 +1,20 @@
+//! Tracker statistics API context.
+//!
+//! It returns the same resources as the
+//! [v1 statistics context](crate::servers::apis::v1::context::stats).
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `GET` | `/stats` | Get tracker statistics
+//! `GET` | `/stats/history` | Get the daily statistics history. Supports the `days` query param
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v2/stats/history?token=MyAccessToken&days=7"
+//! ```
+pub mod handlers;
+pub mod responses;
+pub mod routes;
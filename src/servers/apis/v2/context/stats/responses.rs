@@ -0,0 +1,21 @@
+//! API responses for the [`stats`](crate::servers::apis::v2::context::stats)
+//! API context.
+use std::error::Error;
+
+use axum::response::Response;
+
+use crate::servers::apis::v2::responses::{bad_request_response, internal_server_error_response};
+
+/// `400` error response when the number of days is out of range.
+#[must_use]
+pub fn invalid_days_param_response(days: u64, max_days: u64) -> Response {
+    bad_request_response(&format!(
+        "invalid days param {days}, expected a number between 1 and {max_days}"
+    ))
+}
+
+/// `500` error response when the statistics history cannot be loaded from the database.
+#[must_use]
+pub fn failed_to_load_stats_history_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to load statistics history: {e}"))
+}
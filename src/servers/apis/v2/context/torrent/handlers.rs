@@ -0,0 +1,231 @@
+//! API handlers for the [`torrent`](crate::servers::apis::v2::context::torrent)
+//! API context.
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::{Query, WithRejection};
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker_primitives::pagination::Pagination;
+
+use super::responses::{
+    failed_to_remove_torrent_response, invalid_format_param_response, invalid_info_hash_prefix_param_response,
+    invalid_limit_param_response, invalid_order_param_response, invalid_ranking_param_response, invalid_sort_param_response,
+    peer_not_known_response, torrent_not_known_response,
+};
+use crate::core::services::torrent::{get_filtered_torrents, get_filtered_torrents_by_info_hash, get_torrent_info};
+use crate::core::torrent::activity::Ranking;
+use crate::core::Tracker;
+use crate::servers::apis::v1::context::torrent::handlers::{
+    parse_info_hashes, ExportQueryParams, QueryParamError, QueryParams, RemoveQueryParams, TopQueryParams,
+    DEFAULT_TOP_TORRENTS_LIMIT, MAX_TOP_TORRENTS_LIMIT,
+};
+use crate::servers::apis::v1::context::torrent::responses::{
+    top_torrents_response, torrent_info_response, torrent_page_response, torrents_export_response,
+};
+use crate::servers::apis::v2::responses::{
+    invalid_info_hash_param_response, invalid_peer_id_param_response, no_content_response, Problem,
+};
+use crate::servers::apis::{InfoHashParam, PeerIdParam};
+
+/// It handles the request to get the torrent data.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::torrent)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/torrents/{info_hash}",
+    tag = "torrents",
+    params(("info_hash" = String, Path, description = "The info-hash v1", example = "5452869be36f9f3350ccee6b4544e7e76caaadab")),
+    responses(
+        (status = 200, description = "The torrent with its peers", body = Torrent),
+        (status = 400, description = "Invalid info-hash", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "The tracker does not have the torrent", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_torrent_handler(State(tracker): State<Arc<Tracker>>, Path(info_hash): Path<InfoHashParam>) -> Response {
+    match InfoHash::from_str(&info_hash.0) {
+        Err(_) => invalid_info_hash_param_response(&info_hash.0),
+        Ok(info_hash) => match get_torrent_info(tracker.clone(), &info_hash).await {
+            Some(info) => torrent_info_response(info).into_response(),
+            None => torrent_not_known_response(),
+        },
+    }
+}
+
+/// It handles the request to get a list of torrents.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::torrent)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/torrents",
+    tag = "torrents",
+    params(QueryParams),
+    responses(
+        (status = 200, description = "The torrents without their peers", body = [ListItem],
+            headers(("x-total-count" = u64, description = "The number of torrents matching the filters, without pagination"))),
+        (status = 400, description = "Invalid query params", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_torrents_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<QueryParams>, Problem>,
+) -> Response {
+    let (filter, sort) = match (params.filter(), params.sort()) {
+        (Ok(filter), Ok(sort)) => (filter, sort),
+        (Err(err), _) | (_, Err(err)) => return query_param_error_response(err),
+    };
+
+    if params.info_hashes.is_empty() {
+        let pagination = Pagination::new_with_options(params.offset, params.limit);
+
+        torrent_page_response(&get_filtered_torrents(tracker.clone(), &filter, sort, &pagination).await)
+    } else {
+        match parse_info_hashes(params.info_hashes) {
+            Ok(info_hashes) => {
+                torrent_page_response(&get_filtered_torrents_by_info_hash(tracker.clone(), &info_hashes, &filter, sort).await)
+            }
+            Err(err) => query_param_error_response(err),
+        }
+    }
+}
+
+fn query_param_error_response(err: QueryParamError) -> Response {
+    match err {
+        QueryParamError::InvalidInfoHash { info_hash } => invalid_info_hash_param_response(&info_hash),
+        QueryParamError::InvalidInfoHashPrefix { prefix } => invalid_info_hash_prefix_param_response(&prefix),
+        QueryParamError::InvalidSort { source } => invalid_sort_param_response(&source.to_string()),
+        QueryParamError::InvalidOrder { source } => invalid_order_param_response(&source.to_string()),
+    }
+}
+
+/// It handles the request to get the torrents with the highest score for a
+/// given ranking.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::torrent)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/torrents/top",
+    tag = "torrents",
+    params(TopQueryParams),
+    responses(
+        (status = 200, description = "The top torrents, from the highest score", body = [RankedTorrent]),
+        (status = 400, description = "Unknown ranking or limit out of range", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_top_torrents_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<TopQueryParams>, Problem>,
+) -> Response {
+    let ranking = match params.by.as_deref().map(Ranking::from_str).transpose() {
+        Ok(ranking) => ranking.unwrap_or(Ranking::Seeders),
+        Err(err) => return invalid_ranking_param_response(&err.to_string()),
+    };
+
+    let limit = params.limit.unwrap_or(DEFAULT_TOP_TORRENTS_LIMIT);
+
+    if !(1..=MAX_TOP_TORRENTS_LIMIT).contains(&limit) {
+        return invalid_limit_param_response(limit, MAX_TOP_TORRENTS_LIMIT);
+    }
+
+    top_torrents_response(&tracker.get_top_torrents(ranking, limit)).into_response()
+}
+
+/// It handles the request to export all the torrents.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::torrent)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/torrents/export",
+    tag = "torrents",
+    params(ExportQueryParams),
+    responses(
+        (status = 200, description = "All the torrents, streamed as one Torrent resource per line in NDJSON, or as CSV rows",
+            body = Torrent, content_type = ["application/x-ndjson", "text/csv"]),
+        (status = 400, description = "Unknown format", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn export_torrents_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<ExportQueryParams>, Problem>,
+) -> Response {
+    match params.format() {
+        Ok(format) => torrents_export_response(tracker, format, params.peers),
+        Err(err) => invalid_format_param_response(&err.to_string()),
+    }
+}
+
+/// It handles the request to remove a torrent and its swarm.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::torrent)
+/// for more information about this endpoint.
+#[utoipa::path(
+    delete,
+    path = "/api/v2/torrents/{info_hash}",
+    tag = "torrents",
+    params(
+        ("info_hash" = String, Path, description = "The info-hash v1", example = "5452869be36f9f3350ccee6b4544e7e76caaadab"),
+        RemoveQueryParams,
+    ),
+    responses(
+        (status = 204, description = "The torrent was removed"),
+        (status = 400, description = "Invalid info-hash or query params", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "The tracker does not have the torrent", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The torrent could not be removed from the database", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn remove_torrent_handler(
+    State(tracker): State<Arc<Tracker>>,
+    Path(info_hash): Path<InfoHashParam>,
+    WithRejection(Query(params), _): WithRejection<Query<RemoveQueryParams>, Problem>,
+) -> Response {
+    match InfoHash::from_str(&info_hash.0) {
+        Err(_) => invalid_info_hash_param_response(&info_hash.0),
+        Ok(info_hash) => match tracker.remove_torrent(&info_hash, params.remove_completed) {
+            Ok(true) => no_content_response(),
+            Ok(false) => torrent_not_known_response(),
+            Err(e) => failed_to_remove_torrent_response(e),
+        },
+    }
+}
+
+/// It handles the request to remove a peer from a torrent swarm.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::torrent)
+/// for more information about this endpoint.
+#[utoipa::path(
+    delete,
+    path = "/api/v2/torrents/{info_hash}/peers/{peer_id}",
+    tag = "torrents",
+    params(
+        ("info_hash" = String, Path, description = "The info-hash v1", example = "5452869be36f9f3350ccee6b4544e7e76caaadab"),
+        ("peer_id" = String, Path, description = "The peer ID in hex format, with or without the `0x` prefix", example = "0x2d7142343431302d2a64465a3844484944704579"),
+    ),
+    responses(
+        (status = 204, description = "The peer was removed from the swarm"),
+        (status = 400, description = "Invalid info-hash or peer ID", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "The peer is not in the swarm", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn remove_peer_handler(
+    State(tracker): State<Arc<Tracker>>,
+    Path((info_hash, peer_id)): Path<(InfoHashParam, PeerIdParam)>,
+) -> Response {
+    let Ok(info_hash) = InfoHash::from_str(&info_hash.0) else {
+        return invalid_info_hash_param_response(&info_hash.0);
+    };
+
+    let Some(peer_id) = peer_id.peer_id() else {
+        return invalid_peer_id_param_response(&peer_id.0);
+    };
+
+    if tracker.remove_peer(&info_hash, &peer_id) {
+        no_content_response()
+    } else {
+        peer_not_known_response()
+    }
+}
@@ -0,0 +1,41 @@
+//! Torrents API context.
+//!
+//! This API context is responsible for handling all the requests related to
+//! the torrents data stored by the tracker. It returns the same resources as
+//! the [v1 torrents context](crate::servers::apis::v1::context::torrent).
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `GET` | `/torrents` | List torrents. Supports the same pagination, filter and sort query params as the [v1 endpoint](crate::servers::apis::v1::context::torrent#list-torrents), and returns the `X-Total-Count` header
+//! `GET` | `/torrents/top` | Top torrents. Supports the `by` and `limit` query params
+//! `GET` | `/torrents/export` | Export all the torrents as NDJSON or CSV. Supports the same `format` and `peers` query params as the [v1 endpoint](crate::servers::apis::v1::context::torrent#export-torrents)
+//! `GET` | `/torrents/:info_hash` | Get a torrent
+//! `DELETE` | `/torrents/:info_hash` | Remove a torrent and its swarm. Supports the `remove_completed` query param
+//! `DELETE` | `/torrents/:info_hash/peers/:peer_id` | Remove a peer from the torrent swarm
+//!
+//! The `DELETE` endpoints require the same permission as in the
+//! [v1 API](crate::servers::apis::v1::context::torrent#remove-a-torrent).
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl "http://127.0.0.1:1212/api/v2/torrents/5452869be36f9f3350ccee6b4544e7e76caaadab?token=MyAccessToken"
+//! ```
+//!
+//! **Not Found response** `404`
+//!
+//! This response is returned when the tracker does not have the torrent.
+//!
+//! ```json
+//! {
+//!   "type": "about:blank",
+//!   "title": "Not Found",
+//!   "status": 404,
+//!   "detail": "torrent not known"
+//! }
+//! ```
+pub mod handlers;
+pub mod responses;
+pub mod routes;
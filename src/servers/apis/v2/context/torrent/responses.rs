@@ -0,0 +1,66 @@
+//! API responses for the [`torrent`](crate::servers::apis::v2::context::torrent)
+//! API context.
+use std::error::Error;
+
+use axum::response::Response;
+
+use crate::servers::apis::v2::responses::{bad_request_response, internal_server_error_response, not_found_response};
+
+/// `404` error response returned when a torrent is not found.
+#[must_use]
+pub fn torrent_not_known_response() -> Response {
+    not_found_response("torrent not known")
+}
+
+/// `404` error response returned when a peer is not in the torrent swarm.
+#[must_use]
+pub fn peer_not_known_response() -> Response {
+    not_found_response("peer not known")
+}
+
+/// `500` error response when a torrent cannot be removed from the database.
+#[must_use]
+pub fn failed_to_remove_torrent_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to remove torrent: {e}"))
+}
+
+/// `400` error response when the ranking for the top torrents is unknown.
+#[must_use]
+pub fn invalid_ranking_param_response(error: &str) -> Response {
+    bad_request_response(&format!("invalid by param: {error}"))
+}
+
+/// `400` error response when the number of top torrents requested is out of
+/// range.
+#[must_use]
+pub fn invalid_limit_param_response(limit: usize, max_limit: usize) -> Response {
+    bad_request_response(&format!(
+        "invalid limit param {limit}, expected a number between 1 and {max_limit}"
+    ))
+}
+
+/// `400` error response when the info-hash prefix is not a hex string.
+#[must_use]
+pub fn invalid_info_hash_prefix_param_response(prefix: &str) -> Response {
+    bad_request_response(&format!(
+        "invalid info_hash_prefix param: string \"{prefix}\", expected a hex string of up to 40 characters"
+    ))
+}
+
+/// `400` error response when the sort field is unknown.
+#[must_use]
+pub fn invalid_sort_param_response(error: &str) -> Response {
+    bad_request_response(&format!("invalid sort param: {error}"))
+}
+
+/// `400` error response when the sort order is unknown.
+#[must_use]
+pub fn invalid_order_param_response(error: &str) -> Response {
+    bad_request_response(&format!("invalid order param: {error}"))
+}
+
+/// `400` error response when the export format is unknown.
+#[must_use]
+pub fn invalid_format_param_response(error: &str) -> Response {
+    bad_request_response(&format!("invalid format param: {error}"))
+}
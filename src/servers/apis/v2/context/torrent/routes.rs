@@ -0,0 +1,53 @@
+//! API routes for the [`torrent`](crate::servers::apis::v2::context::torrent) API context.
+//!
+//! - `GET /torrents`
+//! - `GET /torrents/top`
+//! - `GET /torrents/export`
+//! - `GET /torrents/:info_hash`
+//! - `DELETE /torrents/:info_hash`
+//! - `DELETE /torrents/:info_hash/peers/:peer_id`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v2::context::torrent).
+use std::sync::Arc;
+
+use axum::routing::{delete, get};
+use axum::Router;
+
+use super::handlers::{
+    export_torrents_handler, get_top_torrents_handler, get_torrent_handler, get_torrents_handler, remove_peer_handler,
+    remove_torrent_handler,
+};
+use crate::core::Tracker;
+
+/// It adds the routes to the router for the [`torrent`](crate::servers::apis::v2::context::torrent) API context.
+pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    let prefix = format!("{prefix}/torrents");
+
+    router
+        .route(&prefix, get(get_torrents_handler).with_state(tracker.clone()))
+        .route(
+            &format!("{prefix}/top"),
+            get(get_top_torrents_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/export"),
+            get(export_torrents_handler).with_state(tracker.clone()),
+        )
+        .route(&format!("{prefix}/:info_hash"), get(get_torrent_handler).with_state(tracker))
+}
+
+/// It adds the routes to remove torrents and peers from the swarms. They
+/// require a different permission than the routes to read the torrents.
+pub fn add_management(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    let prefix = format!("{prefix}/torrents");
+
+    router
+        .route(
+            &format!("{prefix}/:info_hash"),
+            delete(remove_torrent_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/:info_hash/peers/:peer_id"),
+            delete(remove_peer_handler).with_state(tracker),
+        )
+}
@@ -0,0 +1,240 @@
+//! API handlers for the [`whitelist`](crate::servers::apis::v2::context::whitelist)
+//! API context.
+use std::str::FromStr;
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::{Query, WithRejection};
+use bittorrent_primitives::info_hash::InfoHash;
+
+use super::responses::{
+    failed_to_load_whitelist_response, failed_to_reload_whitelist_response, failed_to_remove_torrent_from_whitelist_response,
+    failed_to_remove_torrents_from_whitelist_response, failed_to_whitelist_torrent_response,
+    failed_to_whitelist_torrents_response, invalid_info_hash_list_response, torrent_not_whitelisted_response,
+};
+use crate::core::services::whitelist::{add_torrents_to_whitelist, remove_torrents_from_whitelist};
+use crate::core::Tracker;
+use crate::servers::apis::v1::context::whitelist::forms::InfoHashList;
+use crate::servers::apis::v1::context::whitelist::handlers::{content_type, ListQueryParams};
+use crate::servers::apis::v1::context::whitelist::responses::{
+    bulk_whitelist_response, whitelist_diff_response, whitelist_entry_response, whitelist_response,
+};
+use crate::servers::apis::v2::responses::{invalid_info_hash_param_response, no_content_response, Problem};
+use crate::servers::apis::InfoHashParam;
+
+/// It handles the request to add a torrent to the whitelist.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist)
+/// for more information about this endpoint.
+#[utoipa::path(
+    put,
+    path = "/api/v2/whitelist/{info_hash}",
+    tag = "whitelist",
+    params(("info_hash" = String, Path, description = "The info-hash v1", example = "5452869be36f9f3350ccee6b4544e7e76caaadab")),
+    responses(
+        (status = 204, description = "The torrent is whitelisted"),
+        (status = 400, description = "Invalid info-hash", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The torrent could not be whitelisted", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn add_torrent_to_whitelist_handler(
+    State(tracker): State<Arc<Tracker>>,
+    Path(info_hash): Path<InfoHashParam>,
+) -> Response {
+    match InfoHash::from_str(&info_hash.0) {
+        Err(_) => invalid_info_hash_param_response(&info_hash.0),
+        Ok(info_hash) => match tracker.add_torrent_to_whitelist(&info_hash).await {
+            Ok(()) => no_content_response(),
+            Err(e) => failed_to_whitelist_torrent_response(e),
+        },
+    }
+}
+
+/// It handles the request to remove a torrent from the whitelist.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist)
+/// for more information about this endpoint.
+#[utoipa::path(
+    delete,
+    path = "/api/v2/whitelist/{info_hash}",
+    tag = "whitelist",
+    params(("info_hash" = String, Path, description = "The info-hash v1", example = "5452869be36f9f3350ccee6b4544e7e76caaadab")),
+    responses(
+        (status = 204, description = "The torrent is not whitelisted"),
+        (status = 400, description = "Invalid info-hash", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The torrent could not be removed from the whitelist", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn remove_torrent_from_whitelist_handler(
+    State(tracker): State<Arc<Tracker>>,
+    Path(info_hash): Path<InfoHashParam>,
+) -> Response {
+    match InfoHash::from_str(&info_hash.0) {
+        Err(_) => invalid_info_hash_param_response(&info_hash.0),
+        Ok(info_hash) => match tracker.remove_torrent_from_whitelist(&info_hash).await {
+            Ok(()) => no_content_response(),
+            Err(e) => failed_to_remove_torrent_from_whitelist_response(e),
+        },
+    }
+}
+
+/// It handles the request to reload the torrent whitelist from the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist)
+/// for more information about this endpoint.
+#[utoipa::path(
+    post,
+    path = "/api/v2/whitelist/reload",
+    tag = "whitelist",
+    responses(
+        (status = 204, description = "The whitelist was reloaded"),
+        (status = 500, description = "The whitelist could not be loaded from the database", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn reload_whitelist_handler(State(tracker): State<Arc<Tracker>>) -> Response {
+    match tracker.load_whitelist_from_database().await {
+        Ok(()) => no_content_response(),
+        Err(e) => failed_to_reload_whitelist_response(e),
+    }
+}
+
+/// It handles the request to get the list of whitelisted torrents.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/whitelist",
+    tag = "whitelist",
+    params(ListQueryParams),
+    responses(
+        (status = 200, description = "The torrents whitelisted in memory or in the database, sorted by info-hash", body = [WhitelistEntry]),
+        (status = 400, description = "Invalid query params", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The whitelist could not be loaded from the database", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_whitelist_handler(
+    State(tracker): State<Arc<Tracker>>,
+    WithRejection(Query(params), _): WithRejection<Query<ListQueryParams>, Problem>,
+) -> Response {
+    match tracker.get_whitelist(Some(&params.pagination())).await {
+        Ok(entries) => whitelist_response(entries).into_response(),
+        Err(e) => failed_to_load_whitelist_response(e),
+    }
+}
+
+/// It handles the request to get a whitelisted torrent.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/whitelist/{info_hash}",
+    tag = "whitelist",
+    params(("info_hash" = String, Path, description = "The info-hash v1", example = "5452869be36f9f3350ccee6b4544e7e76caaadab")),
+    responses(
+        (status = 200, description = "Where the torrent is whitelisted", body = WhitelistEntry),
+        (status = 400, description = "Invalid info-hash", body = Problem, content_type = "application/problem+json"),
+        (status = 404, description = "The torrent is neither whitelisted in memory nor in the database", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The whitelist could not be checked in the database", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_whitelist_entry_handler(State(tracker): State<Arc<Tracker>>, Path(info_hash): Path<InfoHashParam>) -> Response {
+    match InfoHash::from_str(&info_hash.0) {
+        Err(_) => invalid_info_hash_param_response(&info_hash.0),
+        Ok(info_hash) => match tracker.get_whitelist_entry(&info_hash).await {
+            Ok(entry) if entry.in_memory || entry.in_database => whitelist_entry_response(entry).into_response(),
+            Ok(_) => torrent_not_whitelisted_response(),
+            Err(e) => failed_to_load_whitelist_response(e),
+        },
+    }
+}
+
+/// It handles the request to compare the whitelist in memory with the
+/// whitelist in the database.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist)
+/// for more information about this endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/v2/whitelist/diff",
+    tag = "whitelist",
+    responses(
+        (status = 200, description = "The torrents whitelisted only in memory or only in the database", body = WhitelistDiff),
+        (status = 500, description = "The whitelist could not be loaded from the database", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn get_whitelist_diff_handler(State(tracker): State<Arc<Tracker>>) -> Response {
+    match tracker.get_whitelist_diff().await {
+        Ok(diff) => whitelist_diff_response(diff).into_response(),
+        Err(e) => failed_to_load_whitelist_response(e),
+    }
+}
+
+/// It handles the request to add a list of torrents to the whitelist.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist)
+/// for more information about this endpoint.
+#[utoipa::path(
+    post,
+    path = "/api/v2/whitelist/bulk",
+    tag = "whitelist",
+    request_body(
+        content = [String],
+        description = "A json array of info-hashes, or one info-hash per line with the `application/x-ndjson` or `text/plain` content types",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "The result for each info-hash, in the same order as the request", body = [BulkWhitelistItem]),
+        (status = 400, description = "Invalid list of info-hashes", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The torrents could not be whitelisted", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn add_torrents_to_whitelist_handler(
+    State(tracker): State<Arc<Tracker>>,
+    headers: HeaderMap,
+    WithRejection(body, _): WithRejection<String, Problem>,
+) -> Response {
+    match InfoHashList::parse(content_type(&headers), &body) {
+        Err(e) => invalid_info_hash_list_response(&e),
+        Ok(list) => match add_torrents_to_whitelist(tracker, list.0).await {
+            Ok(results) => bulk_whitelist_response(results).into_response(),
+            Err(e) => failed_to_whitelist_torrents_response(e),
+        },
+    }
+}
+
+/// It handles the request to remove a list of torrents from the whitelist.
+///
+/// Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist)
+/// for more information about this endpoint.
+#[utoipa::path(
+    delete,
+    path = "/api/v2/whitelist/bulk",
+    tag = "whitelist",
+    request_body(
+        content = [String],
+        description = "A json array of info-hashes, or one info-hash per line with the `application/x-ndjson` or `text/plain` content types",
+        content_type = "application/json"
+    ),
+    responses(
+        (status = 200, description = "The result for each info-hash, in the same order as the request", body = [BulkWhitelistItem]),
+        (status = 400, description = "Invalid list of info-hashes", body = Problem, content_type = "application/problem+json"),
+        (status = 500, description = "The torrents could not be removed from the whitelist", body = Problem, content_type = "application/problem+json"),
+    )
+)]
+pub async fn remove_torrents_from_whitelist_handler(
+    State(tracker): State<Arc<Tracker>>,
+    headers: HeaderMap,
+    WithRejection(body, _): WithRejection<String, Problem>,
+) -> Response {
+    match InfoHashList::parse(content_type(&headers), &body) {
+        Err(e) => invalid_info_hash_list_response(&e),
+        Ok(list) => match remove_torrents_from_whitelist(tracker, list.0).await {
+            Ok(results) => bulk_whitelist_response(results).into_response(),
+            Err(e) => failed_to_remove_torrents_from_whitelist_response(e),
+        },
+    }
+}
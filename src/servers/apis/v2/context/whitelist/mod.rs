@@ -0,0 +1,33 @@
+//! Whitelist API context.
+//!
+//! It replaces the v1 whitelist endpoints with resource routes. Refer to the
+//! [v1 whitelist context](crate::servers::apis::v1::context::whitelist) for
+//! more information about the whitelist.
+//!
+//! # Endpoints
+//!
+//! Method | Path | Description
+//! ---|---|---
+//! `GET` | `/whitelist` | List the torrents whitelisted in memory or in the database. Accepts `offset` and `limit`
+//! `GET` | `/whitelist/:info_hash` | Show whether a torrent is whitelisted in memory and in the database. Returns `404` if it is in neither
+//! `GET` | `/whitelist/diff` | List the torrents whitelisted only in memory or only in the database
+//! `PUT` | `/whitelist/:info_hash` | Add a torrent to the whitelist. Returns `204`
+//! `DELETE` | `/whitelist/:info_hash` | Remove a torrent from the whitelist. Returns `204`
+//! `POST` | `/whitelist/reload` | Reload the whitelist from the database. Returns `204`
+//! `POST` | `/whitelist/bulk` | Add up to 10000 torrents to the whitelist in one transaction. Returns the result for each info-hash
+//! `DELETE` | `/whitelist/bulk` | Remove up to 10000 torrents from the whitelist in one transaction. Returns the result for each info-hash
+//!
+//! Adding a torrent that is already whitelisted or removing one that is not
+//! whitelisted also returns `204`.
+//!
+//! The bulk endpoints accept the same json or newline-delimited bodies as the
+//! [v1 endpoints](crate::servers::apis::v1::context::whitelist#add-a-list-of-torrents-to-the-whitelist).
+//!
+//! **Example request**
+//!
+//! ```bash
+//! curl -X PUT "http://127.0.0.1:1212/api/v2/whitelist/5452869be36f9f3350ccee6b4544e7e76caaadab?token=MyAccessToken"
+//! ```
+pub mod handlers;
+pub mod responses;
+pub mod routes;
@@ -0,0 +1,60 @@
+//! API responses for the [`whitelist`](crate::servers::apis::v2::context::whitelist)
+//! API context.
+use std::error::Error;
+
+use axum::response::Response;
+
+use crate::servers::apis::v1::context::whitelist::forms::InfoHashListError;
+use crate::servers::apis::v2::responses::{bad_request_response, internal_server_error_response, not_found_response};
+
+/// `404` error response when the torrent is neither whitelisted in memory nor
+/// in the database.
+#[must_use]
+pub fn torrent_not_whitelisted_response() -> Response {
+    not_found_response("torrent not whitelisted")
+}
+
+/// `500` error response when a torrent cannot be removed from the whitelist.
+#[must_use]
+pub fn failed_to_remove_torrent_from_whitelist_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to remove torrent from whitelist: {e}"))
+}
+
+/// `500` error response when a torrent cannot be added to the whitelist.
+#[must_use]
+pub fn failed_to_whitelist_torrent_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to whitelist torrent: {e}"))
+}
+
+/// `500` error response when the whitelist cannot be reloaded from the database.
+#[must_use]
+pub fn failed_to_reload_whitelist_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to reload whitelist: {e}"))
+}
+
+/// `500` error response when the whitelist cannot be loaded from the database.
+#[must_use]
+pub fn failed_to_load_whitelist_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to load whitelist: {e}"))
+}
+
+/// `500` error response when the torrents of a bulk request cannot be added to
+/// the whitelist.
+#[must_use]
+pub fn failed_to_whitelist_torrents_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to whitelist torrents: {e}"))
+}
+
+/// `500` error response when the torrents of a bulk request cannot be removed
+/// from the whitelist.
+#[must_use]
+pub fn failed_to_remove_torrents_from_whitelist_response<E: Error>(e: E) -> Response {
+    internal_server_error_response(&format!("failed to remove torrents from whitelist: {e}"))
+}
+
+/// `400` error response when the body of a bulk request is not a valid list of
+/// info-hashes.
+#[must_use]
+pub fn invalid_info_hash_list_response(e: &InfoHashListError) -> Response {
+    bad_request_response(&format!("invalid body: {e}"))
+}
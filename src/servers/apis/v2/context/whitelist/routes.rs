@@ -0,0 +1,55 @@
+//! API routes for the [`whitelist`](crate::servers::apis::v2::context::whitelist) API context.
+//!
+//! - `GET /whitelist`
+//! - `GET /whitelist/:info_hash`
+//! - `PUT /whitelist/:info_hash`
+//! - `DELETE /whitelist/:info_hash`
+//! - `POST /whitelist/reload`
+//! - `GET /whitelist/diff`
+//! - `POST /whitelist/bulk`
+//! - `DELETE /whitelist/bulk`
+//!
+//! Refer to the [API endpoint documentation](crate::servers::apis::v2::context::whitelist).
+use std::sync::Arc;
+
+use axum::routing::{get, post};
+use axum::Router;
+
+use super::handlers::{
+    add_torrent_to_whitelist_handler, add_torrents_to_whitelist_handler, get_whitelist_diff_handler, get_whitelist_entry_handler,
+    get_whitelist_handler, reload_whitelist_handler, remove_torrent_from_whitelist_handler,
+    remove_torrents_from_whitelist_handler,
+};
+use crate::core::Tracker;
+
+/// It adds the routes to the router for the [`whitelist`](crate::servers::apis::v2::context::whitelist) API context.
+pub fn add(prefix: &str, router: Router, tracker: Arc<Tracker>) -> Router {
+    let prefix = format!("{prefix}/whitelist");
+
+    router
+        .route(&prefix, get(get_whitelist_handler).with_state(tracker.clone()))
+        .route(
+            &format!("{prefix}/diff"),
+            get(get_whitelist_diff_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/reload"),
+            post(reload_whitelist_handler).with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/bulk"),
+            post(add_torrents_to_whitelist_handler)
+                .with_state(tracker.clone())
+                .delete(remove_torrents_from_whitelist_handler)
+                .with_state(tracker.clone()),
+        )
+        .route(
+            &format!("{prefix}/:info_hash"),
+            get(get_whitelist_entry_handler)
+                .with_state(tracker.clone())
+                .put(add_torrent_to_whitelist_handler)
+                .with_state(tracker.clone())
+                .delete(remove_torrent_from_whitelist_handler)
+                .with_state(tracker),
+        )
+}
@@ -0,0 +1,56 @@
+//! Authentication middleware for the API v2.
+//!
+//! It uses the same `Authorization: Bearer` header, `token` GET param,
+//! `access_tokens` and [roles](torrust_tracker_configuration::Role) as the
+//! [v1 authentication middleware](crate::servers::apis::v1::middlewares::auth),
+//! but the errors are `401` and `403` [problem details](crate::servers::apis::v2::responses::Problem)
+//! responses instead of `500` plain text responses. Locked out clients get a
+//! `429` response.
+use axum::extract::{self};
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::servers::apis::v1::middlewares::auth::{
+    authenticate_request, client_ip, is_allowed, AuthError, Authenticated, Permission, QueryParams, State,
+};
+use crate::servers::apis::v2::responses::{forbidden_response, too_many_requests_response, unauthorized_response};
+use crate::servers::http::v1::extractors::client_ip_sources::Extract;
+
+/// Middleware for authentication using an `Authorization: Bearer` header or a
+/// "token" GET param.
+///
+/// The token must be one of the tokens in the tracker [HTTP API configuration](torrust_tracker_configuration::HttpApi).
+pub async fn auth(
+    extract::State(state): extract::State<State>,
+    extract::Query(params): extract::Query<QueryParams>,
+    Extract(client_ip_sources): Extract,
+    mut request: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    let client_ip = client_ip(&state.trusted_proxies, &client_ip_sources);
+
+    match authenticate_request(&state, client_ip, request.headers(), params).await {
+        Ok(authenticated) => {
+            request.extensions_mut().insert(authenticated);
+            next.run(request).await
+        }
+        Err(AuthError::Unauthorized) => unauthorized_response("missing token"),
+        Err(AuthError::TokenNotValid) => unauthorized_response("token not valid"),
+        Err(AuthError::TokenExpired) => unauthorized_response("token expired"),
+        Err(AuthError::LockedOut) => too_many_requests_response("too many failed authentication attempts"),
+    }
+}
+
+/// Middleware that rejects the requests whose token [`Role`](torrust_tracker_configuration::Role) does not have the
+/// [`Permission`]. It must run after the [`auth`] middleware.
+pub async fn authorize(
+    extract::State(permission): extract::State<Permission>,
+    request: Request<axum::body::Body>,
+    next: Next,
+) -> Response {
+    match request.extensions().get::<Authenticated>() {
+        Some(authenticated) if is_allowed(authenticated.role, permission) => next.run(request).await,
+        _ => forbidden_response("the token role does not allow this request"),
+    }
+}
@@ -0,0 +1,2 @@
+//! API middlewares. See [Axum middlewares](axum::middleware).
+pub mod auth;
@@ -0,0 +1,39 @@
+//! The API version `v2`.
+//!
+//! It exposes the same data as [v1](crate::servers::apis::v1), but:
+//!
+//! - Each endpoint is a resource route with a proper HTTP method. There are no
+//!   mutating `GET` requests and the same path is not used for different
+//!   resources.
+//! - Actions that do not return data respond with `204 No Content` and new
+//!   resources with `201 Created`.
+//! - All the errors are [problem details](crate::servers::apis::v2::responses::Problem)
+//!   json objects with the right status code. For example, `404` for unknown
+//!   torrents and `401` for missing or invalid tokens.
+//! - There is a machine-readable [`OpenAPI` document](crate::servers::apis::v2::openapi).
+//!
+//! The API is organized in the following contexts:
+//!
+//! Context | Description | Version
+//! ---|---|---
+//! `Stats` | Tracker statistics | [`v2`](crate::servers::apis::v2::context::stats)
+//! `Info` | Which tracker is deployed and how it's running | [`v2`](crate::servers::apis::v2::context::info)
+//! `Torrents` | Torrents | [`v2`](crate::servers::apis::v2::context::torrent)
+//! `Peers` | Peers in all the torrent swarms | [`v2`](crate::servers::apis::v2::context::peer)
+//! `Events` | Live tracker events | [`v2`](crate::servers::apis::v2::context::events)
+//! `Whitelist` | Torrents whitelist | [`v2`](crate::servers::apis::v2::context::whitelist)
+//! `Authentication keys` | Authentication keys | [`v2`](crate::servers::apis::v2::context::auth_key)
+//! `Audit log` | Administrative actions done with the API | [`v2`](crate::servers::apis::v2::context::audit_log)
+//! `Configuration` | Reload the configuration without restarting | [`v2`](crate::servers::apis::v2::context::config)
+//!
+//! The Prometheus metrics, the clients breakdown and the health check are only
+//! available in [v1](crate::servers::apis::v1). The changes done with both API
+//! versions are recorded in the same audit log.
+//!
+//! Refer to the [authentication middleware](crate::servers::apis::v2::middlewares::auth)
+//! for more information about the authentication process.
+pub mod context;
+pub mod middlewares;
+pub mod openapi;
+pub mod responses;
+pub mod routes;
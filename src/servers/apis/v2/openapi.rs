@@ -0,0 +1,232 @@
+//! The `OpenAPI` document for the API v2.
+//!
+//! The document is generated from the handler and resource types, so it's
+//! always in sync with the code. It's served without authentication at:
+//!
+//! `GET /api/v2/openapi.json`
+
+// The code generated by the `OpenApi` derive macro uses `for_each`.
+#![allow(clippy::needless_for_each)]
+use axum::response::Json;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, Http, HttpAuthScheme, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use super::context::{audit_log, auth_key, config, events, info, peer, stats, torrent, whitelist};
+use super::responses::Problem;
+use crate::servers::apis::v1::context::audit_log::resources::AuditLogEntry;
+use crate::servers::apis::v1::context::auth_key::forms::{AddKeyForm, GenerateKeysForm};
+use crate::servers::apis::v1::context::auth_key::resources::{AuthKey, AuthKeyDetail, PurgedKeys};
+use crate::servers::apis::v1::context::config::resources::ConfigReload;
+use crate::servers::apis::v1::context::events::resources::{Event, Lagged};
+use crate::servers::apis::v1::context::info::resources::{Listener, TrackerInfo};
+use crate::servers::apis::v1::context::peer::resources::{PeerMatch, RemovedPeers};
+use crate::servers::apis::v1::context::stats::resources::{
+    DailyStats, Errors, Latency, ListenerStats, RequestRate, RequestRates, RequestStats, Stats,
+};
+use crate::servers::apis::v1::context::torrent::resources::peer::{Id, Peer};
+use crate::servers::apis::v1::context::torrent::resources::torrent::{ListItem, RankedTorrent, Torrent};
+use crate::servers::apis::v1::context::whitelist::resources::{
+    BulkWhitelistItem, BulkWhitelistStatus, WhitelistDiff, WhitelistEntry,
+};
+
+/// Name of the security scheme for the `token` query param.
+pub const TOKEN_SECURITY_SCHEME: &str = "token";
+
+/// Name of the security scheme for the `Authorization: Bearer` header.
+pub const BEARER_SECURITY_SCHEME: &str = "bearer";
+
+/// The `OpenAPI` document for the API v2.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Torrust Tracker API",
+        description = "REST API to manage the tracker. Errors are returned as problem details (RFC 9457)."
+    ),
+    paths(
+        torrent::handlers::get_torrents_handler,
+        torrent::handlers::get_top_torrents_handler,
+        torrent::handlers::export_torrents_handler,
+        torrent::handlers::get_torrent_handler,
+        torrent::handlers::remove_torrent_handler,
+        torrent::handlers::remove_peer_handler,
+        peer::handlers::search_peers_handler,
+        peer::handlers::remove_peers_with_ip_handler,
+        events::handlers::get_events_handler,
+        stats::handlers::get_stats_handler,
+        stats::handlers::get_stats_history_handler,
+        info::handlers::get_tracker_info_handler,
+        auth_key::handlers::get_auth_keys_handler,
+        auth_key::handlers::add_auth_key_handler,
+        auth_key::handlers::generate_auth_keys_handler,
+        auth_key::handlers::get_auth_key_handler,
+        auth_key::handlers::delete_auth_key_handler,
+        auth_key::handlers::reload_keys_handler,
+        auth_key::handlers::purge_expired_keys_handler,
+        whitelist::handlers::get_whitelist_handler,
+        whitelist::handlers::get_whitelist_entry_handler,
+        whitelist::handlers::get_whitelist_diff_handler,
+        whitelist::handlers::add_torrent_to_whitelist_handler,
+        whitelist::handlers::remove_torrent_from_whitelist_handler,
+        whitelist::handlers::reload_whitelist_handler,
+        whitelist::handlers::add_torrents_to_whitelist_handler,
+        whitelist::handlers::remove_torrents_from_whitelist_handler,
+        audit_log::handlers::get_audit_log_handler,
+        config::handlers::reload_config_handler,
+    ),
+    components(schemas(
+        Problem,
+        Torrent,
+        ListItem,
+        RankedTorrent,
+        Peer,
+        Id,
+        PeerMatch,
+        RemovedPeers,
+        Event,
+        Lagged,
+        Stats,
+        ListenerStats,
+        RequestStats,
+        RequestRates,
+        RequestRate,
+        Errors,
+        Latency,
+        DailyStats,
+        TrackerInfo,
+        Listener,
+        AuthKey,
+        AuthKeyDetail,
+        PurgedKeys,
+        AddKeyForm,
+        GenerateKeysForm,
+        WhitelistEntry,
+        WhitelistDiff,
+        BulkWhitelistItem,
+        BulkWhitelistStatus,
+        AuditLogEntry,
+        ConfigReload,
+    )),
+    modifiers(&TokenSecurity),
+    security(("token" = []), ("bearer" = [])),
+    tags(
+        (name = "torrents", description = "Torrents tracked by the tracker"),
+        (name = "peers", description = "Peers in all the torrent swarms"),
+        (name = "events", description = "Live tracker events"),
+        (name = "stats", description = "Tracker statistics"),
+        (name = "info", description = "Which tracker is deployed and how it's running"),
+        (name = "keys", description = "Authentication keys for the private HTTP tracker"),
+        (name = "whitelist", description = "Torrents allowed in the listed modes"),
+        (name = "audit_log", description = "Administrative actions done with the API"),
+        (name = "config", description = "Tracker configuration"),
+    )
+)]
+pub struct ApiDoc;
+
+/// It adds the `token` query param and the `Authorization: Bearer` header
+/// security schemes to the document.
+struct TokenSecurity;
+
+impl Modify for TokenSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                TOKEN_SECURITY_SCHEME,
+                SecurityScheme::ApiKey(ApiKey::Query(ApiKeyValue::new(TOKEN_SECURITY_SCHEME))),
+            );
+            components.add_security_scheme(
+                BEARER_SECURITY_SCHEME,
+                SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+            );
+        }
+    }
+}
+
+/// It handles the request to get the `OpenAPI` document.
+pub async fn openapi_handler() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::OpenApi;
+
+    use super::ApiDoc;
+
+    #[test]
+    fn it_should_document_all_the_endpoints() {
+        let openapi = ApiDoc::openapi();
+
+        assert_eq!(
+            openapi.paths.paths.keys().map(String::as_str).collect::<Vec<_>>(),
+            vec![
+                "/api/v2/audit_log",
+                "/api/v2/config/reload",
+                "/api/v2/events",
+                "/api/v2/info",
+                "/api/v2/keys",
+                "/api/v2/keys/bulk",
+                "/api/v2/keys/purge",
+                "/api/v2/keys/reload",
+                "/api/v2/keys/{key}",
+                "/api/v2/peers",
+                "/api/v2/peers/{ip}",
+                "/api/v2/stats",
+                "/api/v2/stats/history",
+                "/api/v2/torrents",
+                "/api/v2/torrents/export",
+                "/api/v2/torrents/top",
+                "/api/v2/torrents/{info_hash}",
+                "/api/v2/torrents/{info_hash}/peers/{peer_id}",
+                "/api/v2/whitelist",
+                "/api/v2/whitelist/bulk",
+                "/api/v2/whitelist/diff",
+                "/api/v2/whitelist/reload",
+                "/api/v2/whitelist/{info_hash}",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_include_the_schemas_of_all_the_resources() {
+        let openapi = ApiDoc::openapi();
+
+        let schemas = openapi.components.unwrap().schemas;
+
+        for schema in [
+            "Problem",
+            "Torrent",
+            "ListItem",
+            "RankedTorrent",
+            "PeerMatch",
+            "RemovedPeers",
+            "Event",
+            "Lagged",
+            "Stats",
+            "TrackerInfo",
+            "Listener",
+            "AuthKey",
+            "AuthKeyDetail",
+            "PurgedKeys",
+            "AddKeyForm",
+            "GenerateKeysForm",
+            "WhitelistEntry",
+            "WhitelistDiff",
+            "BulkWhitelistItem",
+            "BulkWhitelistStatus",
+            "AuditLogEntry",
+            "ConfigReload",
+        ] {
+            assert!(schemas.contains_key(schema), "missing schema {schema}");
+        }
+    }
+
+    #[test]
+    fn it_should_document_the_token_query_param_and_the_bearer_header_security_schemes() {
+        let openapi = ApiDoc::openapi();
+
+        let security_schemes = openapi.components.unwrap().security_schemes;
+
+        assert!(security_schemes.contains_key("token"));
+        assert!(security_schemes.contains_key("bearer"));
+    }
+}
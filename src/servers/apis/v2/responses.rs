@@ -0,0 +1,184 @@
+//! Common responses for the API v2 shared by all the contexts.
+//!
+//! All the error responses are [problem details](https://www.rfc-editor.org/rfc/rfc9457)
+//! json objects with the `application/problem+json` content type. For example:
+//!
+//! ```json
+//! {
+//!   "type": "about:blank",
+//!   "title": "Not Found",
+//!   "status": 404,
+//!   "detail": "torrent not known"
+//! }
+//! ```
+use axum::extract::rejection::{JsonRejection, StringRejection};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum_extra::extract::QueryRejection;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Content type of the [`Problem`] responses.
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/// The problem type used by all the errors. It means the problem has no
+/// additional semantics beyond the status code.
+pub const BLANK_PROBLEM_TYPE: &str = "about:blank";
+
+/// A problem details object describing an error.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, ToSchema)]
+pub struct Problem {
+    /// A URI reference that identifies the problem type. It's always
+    /// `about:blank`.
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    /// The reason phrase of the status code. For example: `Not Found`.
+    pub title: String,
+    /// The HTTP status code.
+    pub status: u16,
+    /// A human-readable explanation specific to this occurrence of the problem.
+    pub detail: String,
+}
+
+impl Problem {
+    #[must_use]
+    pub fn new(status: StatusCode, detail: &str) -> Self {
+        Self {
+            problem_type: BLANK_PROBLEM_TYPE.to_string(),
+            title: status.canonical_reason().unwrap_or_default().to_string(),
+            status: status.as_u16(),
+            detail: detail.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for Problem {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+            [(header::CONTENT_TYPE, PROBLEM_CONTENT_TYPE)],
+            serde_json::to_string(&self).expect("a problem should always be serializable to json"),
+        )
+            .into_response()
+    }
+}
+
+impl From<QueryRejection> for Problem {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::new(
+            StatusCode::BAD_REQUEST,
+            &format!("Failed to deserialize query string: {rejection}"),
+        )
+    }
+}
+
+impl From<JsonRejection> for Problem {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::new(rejection.status(), &rejection.body_text())
+    }
+}
+
+impl From<StringRejection> for Problem {
+    fn from(rejection: StringRejection) -> Self {
+        Self::new(rejection.status(), &rejection.body_text())
+    }
+}
+
+// Success responses
+
+/// `204` response for actions that do not return any data.
+#[must_use]
+pub fn no_content_response() -> Response {
+    StatusCode::NO_CONTENT.into_response()
+}
+
+// Error responses
+
+#[must_use]
+pub fn invalid_info_hash_param_response(info_hash: &str) -> Response {
+    bad_request_response(&format!(
+        "invalid infohash param: string \"{info_hash}\", expected a 40 character long string"
+    ))
+}
+
+#[must_use]
+pub fn invalid_peer_id_param_response(peer_id: &str) -> Response {
+    bad_request_response(&format!(
+        "invalid peer id param: string \"{peer_id}\", expected a 40 character long hex string"
+    ))
+}
+
+#[must_use]
+pub fn invalid_auth_key_param_response(invalid_key: &str) -> Response {
+    bad_request_response(&format!("invalid auth key id param \"{invalid_key}\""))
+}
+
+/// `400` problem response.
+#[must_use]
+pub fn bad_request_response(detail: &str) -> Response {
+    Problem::new(StatusCode::BAD_REQUEST, detail).into_response()
+}
+
+/// `401` problem response.
+#[must_use]
+pub fn unauthorized_response(detail: &str) -> Response {
+    Problem::new(StatusCode::UNAUTHORIZED, detail).into_response()
+}
+
+/// `403` problem response.
+#[must_use]
+pub fn forbidden_response(detail: &str) -> Response {
+    Problem::new(StatusCode::FORBIDDEN, detail).into_response()
+}
+
+/// `404` problem response.
+#[must_use]
+pub fn not_found_response(detail: &str) -> Response {
+    Problem::new(StatusCode::NOT_FOUND, detail).into_response()
+}
+
+/// `409` problem response.
+#[must_use]
+pub fn conflict_response(detail: &str) -> Response {
+    Problem::new(StatusCode::CONFLICT, detail).into_response()
+}
+
+/// `429` problem response.
+#[must_use]
+pub fn too_many_requests_response(detail: &str) -> Response {
+    Problem::new(StatusCode::TOO_MANY_REQUESTS, detail).into_response()
+}
+
+/// `500` problem response.
+#[must_use]
+pub fn internal_server_error_response(detail: &str) -> Response {
+    Problem::new(StatusCode::INTERNAL_SERVER_ERROR, detail).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+
+    use super::Problem;
+
+    #[test]
+    fn a_problem_should_use_the_reason_phrase_of_the_status_code_as_title() {
+        assert_eq!(
+            Problem::new(StatusCode::NOT_FOUND, "torrent not known"),
+            Problem {
+                problem_type: "about:blank".to_string(),
+                title: "Not Found".to_string(),
+                status: 404,
+                detail: "torrent not known".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn a_problem_should_be_converted_into_json() {
+        assert_eq!(
+            serde_json::to_string(&Problem::new(StatusCode::BAD_REQUEST, "invalid")).unwrap(),
+            r#"{"type":"about:blank","title":"Bad Request","status":400,"detail":"invalid"}"#
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Route initialization for the v2 API.
+//!
+//! Each API context requires the same [`Permission`] as in the v1 API. See the
+//! [authentication middleware](crate::servers::apis::v1::middlewares::auth).
+use std::sync::Arc;
+
+use axum::{middleware, Router};
+
+use super::context::{audit_log, auth_key, config, events, info, peer, stats, torrent, whitelist};
+use super::middlewares::auth::authorize;
+use crate::bootstrap::reload::ConfigReloader;
+use crate::core::Tracker;
+use crate::servers::apis::v1::middlewares::auth::Permission;
+use crate::servers::registar::ServiceRegistry;
+
+/// Add the routes for the v2 API.
+///
+/// The `OpenAPI` document route is not included because it does not require
+/// authentication. See [`openapi`](crate::servers::apis::v2::openapi).
+pub fn add(
+    prefix: &str,
+    router: Router,
+    tracker: Arc<Tracker>,
+    reloader: Arc<ConfigReloader>,
+    registry: ServiceRegistry,
+) -> Router {
+    let v2_prefix = format!("{prefix}/v2");
+
+    router
+        .merge(restrict(
+            Permission::ManageKeys,
+            auth_key::routes::add(&v2_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadStats,
+            stats::routes::add(&v2_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadStats,
+            info::routes::add(&v2_prefix, Router::new(), tracker.clone(), reloader.clone(), registry),
+        ))
+        .merge(restrict(
+            Permission::ManageWhitelist,
+            whitelist::routes::add(&v2_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadTorrents,
+            torrent::routes::add(&v2_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadTorrents,
+            events::routes::add(&v2_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadTorrents,
+            peer::routes::add_search(&v2_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ManageSwarms,
+            torrent::routes::add_management(&v2_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ManageSwarms,
+            peer::routes::add(&v2_prefix, Router::new(), tracker.clone()),
+        ))
+        .merge(restrict(
+            Permission::ReadAuditLog,
+            audit_log::routes::add(&v2_prefix, Router::new(), tracker),
+        ))
+        .merge(restrict(
+            Permission::ManageConfig,
+            config::routes::add(&v2_prefix, Router::new(), reloader),
+        ))
+}
+
+/// It only allows the requests whose token role has the permission.
+fn restrict(permission: Permission, routes: Router) -> Router {
+    routes.route_layer(middleware::from_fn_with_state(permission, authorize))
+}
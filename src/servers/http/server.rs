@@ -11,11 +11,12 @@ use tracing::instrument;
 
 use super::v1::routes::router;
 use crate::bootstrap::jobs::Started;
+use crate::core::statistics::Scheme;
 use crate::core::Tracker;
 use crate::servers::custom_axum_server::{self, TimeoutAcceptor};
 use crate::servers::http::HTTP_TRACKER_LOG_TARGET;
 use crate::servers::logging::STARTED_ON;
-use crate::servers::registar::{ServiceHealthCheckJob, ServiceRegistration, ServiceRegistrationForm};
+use crate::servers::registar::{Service, ServiceHealthCheckJob, ServiceRegistration, ServiceRegistrationForm};
 use crate::servers::signals::{graceful_shutdown, Halted};
 
 /// Error that can occur when starting or stopping the HTTP server.
@@ -60,6 +61,8 @@ impl Launcher {
 
         tracing::info!(target: HTTP_TRACKER_LOG_TARGET, "Starting on: {protocol}://{}", address);
 
+        tracker.register_stats_listener(Scheme::Http, address);
+
         let app = router(tracker, address);
 
         let running = Box::pin(async {
@@ -169,7 +172,7 @@ impl HttpServer<Stopped> {
 
         let binding = rx_start.await.expect("it should be able to start the service").address;
 
-        form.send(ServiceRegistration::new(binding, check_fn))
+        form.send(ServiceRegistration::new(binding, Service::HttpTracker, check_fn))
             .expect("it should be able to send service registration");
 
         Ok(HttpServer {
@@ -31,27 +31,27 @@ use crate::CurrentClock;
 /// authentication (no PATH `key` parameter required).
 #[allow(clippy::unused_async)]
 pub async fn handle_without_key(
-    State(tracker): State<Arc<Tracker>>,
+    State((tracker, server_socket_addr)): State<(Arc<Tracker>, SocketAddr)>,
     ExtractRequest(announce_request): ExtractRequest,
     ExtractClientIpSources(client_ip_sources): ExtractClientIpSources,
 ) -> Response {
     tracing::debug!("http announce request: {:#?}", announce_request);
 
-    handle(&tracker, &announce_request, &client_ip_sources, None).await
+    handle(&tracker, server_socket_addr, &announce_request, &client_ip_sources, None).await
 }
 
 /// It handles the `announce` request when the HTTP tracker requires
 /// authentication (PATH `key` parameter required).
 #[allow(clippy::unused_async)]
 pub async fn handle_with_key(
-    State(tracker): State<Arc<Tracker>>,
+    State((tracker, server_socket_addr)): State<(Arc<Tracker>, SocketAddr)>,
     ExtractRequest(announce_request): ExtractRequest,
     ExtractClientIpSources(client_ip_sources): ExtractClientIpSources,
     ExtractKey(key): ExtractKey,
 ) -> Response {
     tracing::debug!("http announce request: {:#?}", announce_request);
 
-    handle(&tracker, &announce_request, &client_ip_sources, Some(key)).await
+    handle(&tracker, server_socket_addr, &announce_request, &client_ip_sources, Some(key)).await
 }
 
 /// It handles the `announce` request.
@@ -60,11 +60,12 @@ pub async fn handle_with_key(
 /// `unauthenticated` modes.
 async fn handle(
     tracker: &Arc<Tracker>,
+    server_socket_addr: SocketAddr,
     announce_request: &Announce,
     client_ip_sources: &ClientIpSources,
     maybe_key: Option<Key>,
 ) -> Response {
-    let announce_data = match handle_announce(tracker, announce_request, client_ip_sources, maybe_key).await {
+    let announce_data = match handle_announce(tracker, server_socket_addr, announce_request, client_ip_sources, maybe_key).await {
         Ok(announce_data) => announce_data,
         Err(error) => return error.into_response(),
     };
@@ -79,14 +80,15 @@ async fn handle(
 
 async fn handle_announce(
     tracker: &Arc<Tracker>,
+    server_socket_addr: SocketAddr,
     announce_request: &Announce,
     client_ip_sources: &ClientIpSources,
     maybe_key: Option<Key>,
 ) -> Result<AnnounceData, responses::error::Error> {
     // Authentication
     if tracker.requires_authentication() {
-        match maybe_key {
-            Some(key) => match tracker.authenticate(&key).await {
+        match &maybe_key {
+            Some(key) => match tracker.authenticate(key).await {
                 Ok(()) => (),
                 Err(error) => return Err(responses::error::Error::from(error)),
             },
@@ -115,7 +117,20 @@ async fn handle_announce(
         None => PeersWanted::All,
     };
 
-    let announce_data = services::announce::invoke(tracker.clone(), announce_request.info_hash, &mut peer, &peers_wanted).await;
+    let announce_data = services::announce::invoke(
+        tracker.clone(),
+        announce_request.info_hash,
+        &mut peer,
+        &peers_wanted,
+        server_socket_addr,
+    )
+    .await;
+
+    if tracker.requires_authentication() {
+        if let Some(key) = &maybe_key {
+            tracker.record_key_announce(key, &announce_request.info_hash, &peer.peer_id);
+        }
+    }
 
     Ok(announce_data)
 }
@@ -172,6 +187,7 @@ pub fn map_to_torrust_event(event: &Option<Event>) -> AnnounceEvent {
 
 #[cfg(test)]
 mod tests {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
     use aquatic_udp_protocol::PeerId;
     use bittorrent_primitives::info_hash::InfoHash;
@@ -213,6 +229,10 @@ mod tests {
         }
     }
 
+    fn sample_server_socket_addr() -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 7070)
+    }
+
     fn sample_client_ip_sources() -> ClientIpSources {
         ClientIpSources {
             right_most_x_forwarded_for: None,
@@ -232,7 +252,7 @@ mod tests {
         use std::str::FromStr;
         use std::sync::Arc;
 
-        use super::{private_tracker, sample_announce_request, sample_client_ip_sources};
+        use super::{private_tracker, sample_announce_request, sample_client_ip_sources, sample_server_socket_addr};
         use crate::core::auth;
         use crate::servers::http::v1::handlers::announce::handle_announce;
         use crate::servers::http::v1::handlers::announce::tests::assert_error_response;
@@ -243,9 +263,15 @@ mod tests {
 
             let maybe_key = None;
 
-            let response = handle_announce(&tracker, &sample_announce_request(), &sample_client_ip_sources(), maybe_key)
-                .await
-                .unwrap_err();
+            let response = handle_announce(
+                &tracker,
+                sample_server_socket_addr(),
+                &sample_announce_request(),
+                &sample_client_ip_sources(),
+                maybe_key,
+            )
+            .await
+            .unwrap_err();
 
             assert_error_response(
                 &response,
@@ -261,9 +287,15 @@ mod tests {
 
             let maybe_key = Some(unregistered_key);
 
-            let response = handle_announce(&tracker, &sample_announce_request(), &sample_client_ip_sources(), maybe_key)
-                .await
-                .unwrap_err();
+            let response = handle_announce(
+                &tracker,
+                sample_server_socket_addr(),
+                &sample_announce_request(),
+                &sample_client_ip_sources(),
+                maybe_key,
+            )
+            .await
+            .unwrap_err();
 
             assert_error_response(&response, "Authentication error: Failed to read key");
         }
@@ -273,7 +305,7 @@ mod tests {
 
         use std::sync::Arc;
 
-        use super::{sample_announce_request, sample_client_ip_sources, whitelisted_tracker};
+        use super::{sample_announce_request, sample_client_ip_sources, sample_server_socket_addr, whitelisted_tracker};
         use crate::servers::http::v1::handlers::announce::handle_announce;
         use crate::servers::http::v1::handlers::announce::tests::assert_error_response;
 
@@ -283,9 +315,15 @@ mod tests {
 
             let announce_request = sample_announce_request();
 
-            let response = handle_announce(&tracker, &announce_request, &sample_client_ip_sources(), None)
-                .await
-                .unwrap_err();
+            let response = handle_announce(
+                &tracker,
+                sample_server_socket_addr(),
+                &announce_request,
+                &sample_client_ip_sources(),
+                None,
+            )
+            .await
+            .unwrap_err();
 
             assert_error_response(
                 &response,
@@ -301,7 +339,7 @@ mod tests {
 
         use std::sync::Arc;
 
-        use super::{sample_announce_request, tracker_on_reverse_proxy};
+        use super::{sample_announce_request, sample_server_socket_addr, tracker_on_reverse_proxy};
         use crate::servers::http::v1::handlers::announce::handle_announce;
         use crate::servers::http::v1::handlers::announce::tests::assert_error_response;
         use crate::servers::http::v1::services::peer_ip_resolver::ClientIpSources;
@@ -315,9 +353,15 @@ mod tests {
                 connection_info_ip: None,
             };
 
-            let response = handle_announce(&tracker, &sample_announce_request(), &client_ip_sources, None)
-                .await
-                .unwrap_err();
+            let response = handle_announce(
+                &tracker,
+                sample_server_socket_addr(),
+                &sample_announce_request(),
+                &client_ip_sources,
+                None,
+            )
+            .await
+            .unwrap_err();
 
             assert_error_response(
                 &response,
@@ -330,7 +374,7 @@ mod tests {
 
         use std::sync::Arc;
 
-        use super::{sample_announce_request, tracker_not_on_reverse_proxy};
+        use super::{sample_announce_request, sample_server_socket_addr, tracker_not_on_reverse_proxy};
         use crate::servers::http::v1::handlers::announce::handle_announce;
         use crate::servers::http::v1::handlers::announce::tests::assert_error_response;
         use crate::servers::http::v1::services::peer_ip_resolver::ClientIpSources;
@@ -344,9 +388,15 @@ mod tests {
                 connection_info_ip: None,
             };
 
-            let response = handle_announce(&tracker, &sample_announce_request(), &client_ip_sources, None)
-                .await
-                .unwrap_err();
+            let response = handle_announce(
+                &tracker,
+                sample_server_socket_addr(),
+                &sample_announce_request(),
+                &client_ip_sources,
+                None,
+            )
+            .await
+            .unwrap_err();
 
             assert_error_response(
                 &response,
@@ -6,6 +6,7 @@ use std::panic::Location;
 use thiserror::Error;
 
 use crate::core::auth;
+use crate::core::statistics::ErrorReason;
 use crate::servers::http::v1::responses;
 
 /// Authentication error.
@@ -27,6 +28,7 @@ impl From<Error> for responses::error::Error {
     fn from(err: Error) -> Self {
         responses::error::Error {
             failure_reason: format!("Authentication error: {err}"),
+            reason: ErrorReason::AuthenticationFailed,
         }
     }
 }
@@ -35,6 +37,7 @@ impl From<auth::Error> for responses::error::Error {
     fn from(err: auth::Error) -> Self {
         responses::error::Error {
             failure_reason: format!("Authentication error: {err}"),
+            reason: ErrorReason::AuthenticationFailed,
         }
     }
 }
@@ -4,6 +4,7 @@
 //! contains the logic to convert those
 //! [`PeerIpResolutionError`]
 //! errors into responses.
+use crate::core::statistics::ErrorReason;
 use crate::servers::http::v1::responses;
 use crate::servers::http::v1::services::peer_ip_resolver::PeerIpResolutionError;
 
@@ -11,6 +12,7 @@ impl From<PeerIpResolutionError> for responses::error::Error {
     fn from(err: PeerIpResolutionError) -> Self {
         responses::error::Error {
             failure_reason: format!("Error resolving peer IP: {err}"),
+            reason: ErrorReason::BadRequest,
         }
     }
 }
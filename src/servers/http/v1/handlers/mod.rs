@@ -4,6 +4,7 @@
 //! more information about the HTTP tracker.
 use super::responses;
 use crate::core::error::Error;
+use crate::core::statistics::ErrorReason;
 
 pub mod announce;
 pub mod common;
@@ -14,6 +15,10 @@ impl From<Error> for responses::error::Error {
     fn from(err: Error) -> Self {
         responses::error::Error {
             failure_reason: format!("Tracker error: {err}"),
+            reason: match err {
+                Error::PeerKeyNotValid { .. } | Error::PeerNotAuthenticated { .. } => ErrorReason::AuthenticationFailed,
+                Error::TorrentNotWhitelisted { .. } => ErrorReason::TorrentNotWhitelisted,
+            },
         }
     }
 }
@@ -5,6 +5,7 @@
 //!
 //! The handlers perform the authentication and authorization of the request,
 //! and resolve the client IP address.
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use axum::extract::State;
@@ -23,13 +24,13 @@ use crate::servers::http::v1::{responses, services};
 /// to run in `public` mode.
 #[allow(clippy::unused_async)]
 pub async fn handle_without_key(
-    State(tracker): State<Arc<Tracker>>,
+    State((tracker, server_socket_addr)): State<(Arc<Tracker>, SocketAddr)>,
     ExtractRequest(scrape_request): ExtractRequest,
     ExtractClientIpSources(client_ip_sources): ExtractClientIpSources,
 ) -> Response {
     tracing::debug!("http scrape request: {:#?}", &scrape_request);
 
-    handle(&tracker, &scrape_request, &client_ip_sources, None).await
+    handle(&tracker, server_socket_addr, &scrape_request, &client_ip_sources, None).await
 }
 
 /// It handles the `scrape` request when the HTTP tracker is configured
@@ -38,23 +39,24 @@ pub async fn handle_without_key(
 /// In this case, the authentication `key` parameter is required.
 #[allow(clippy::unused_async)]
 pub async fn handle_with_key(
-    State(tracker): State<Arc<Tracker>>,
+    State((tracker, server_socket_addr)): State<(Arc<Tracker>, SocketAddr)>,
     ExtractRequest(scrape_request): ExtractRequest,
     ExtractClientIpSources(client_ip_sources): ExtractClientIpSources,
     ExtractKey(key): ExtractKey,
 ) -> Response {
     tracing::debug!("http scrape request: {:#?}", &scrape_request);
 
-    handle(&tracker, &scrape_request, &client_ip_sources, Some(key)).await
+    handle(&tracker, server_socket_addr, &scrape_request, &client_ip_sources, Some(key)).await
 }
 
 async fn handle(
     tracker: &Arc<Tracker>,
+    server_socket_addr: SocketAddr,
     scrape_request: &Scrape,
     client_ip_sources: &ClientIpSources,
     maybe_key: Option<Key>,
 ) -> Response {
-    let scrape_data = match handle_scrape(tracker, scrape_request, client_ip_sources, maybe_key).await {
+    let scrape_data = match handle_scrape(tracker, server_socket_addr, scrape_request, client_ip_sources, maybe_key).await {
         Ok(scrape_data) => scrape_data,
         Err(error) => return error.into_response(),
     };
@@ -69,6 +71,7 @@ async fn handle(
 
 async fn handle_scrape(
     tracker: &Arc<Tracker>,
+    server_socket_addr: SocketAddr,
     scrape_request: &Scrape,
     client_ip_sources: &ClientIpSources,
     maybe_key: Option<Key>,
@@ -95,9 +98,9 @@ async fn handle_scrape(
     };
 
     if return_real_scrape_data {
-        Ok(services::scrape::invoke(tracker, &scrape_request.info_hashes, &peer_ip).await)
+        Ok(services::scrape::invoke(tracker, &scrape_request.info_hashes, &peer_ip, server_socket_addr).await)
     } else {
-        Ok(services::scrape::fake(tracker, &scrape_request.info_hashes, &peer_ip).await)
+        Ok(services::scrape::fake(tracker, &scrape_request.info_hashes, &peer_ip, server_socket_addr).await)
     }
 }
 
@@ -107,7 +110,7 @@ fn build_response(scrape_data: ScrapeData) -> Response {
 
 #[cfg(test)]
 mod tests {
-    use std::net::IpAddr;
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
     use std::str::FromStr;
 
     use bittorrent_primitives::info_hash::InfoHash;
@@ -141,6 +144,10 @@ mod tests {
         }
     }
 
+    fn sample_server_socket_addr() -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 7070)
+    }
+
     fn sample_client_ip_sources() -> ClientIpSources {
         ClientIpSources {
             right_most_x_forwarded_for: Some(IpAddr::from_str("203.0.113.195").unwrap()),
@@ -159,7 +166,7 @@ mod tests {
         use std::str::FromStr;
         use std::sync::Arc;
 
-        use super::{private_tracker, sample_client_ip_sources, sample_scrape_request};
+        use super::{private_tracker, sample_client_ip_sources, sample_scrape_request, sample_server_socket_addr};
         use crate::core::{auth, ScrapeData};
         use crate::servers::http::v1::handlers::scrape::handle_scrape;
 
@@ -170,9 +177,15 @@ mod tests {
             let scrape_request = sample_scrape_request();
             let maybe_key = None;
 
-            let scrape_data = handle_scrape(&tracker, &scrape_request, &sample_client_ip_sources(), maybe_key)
-                .await
-                .unwrap();
+            let scrape_data = handle_scrape(
+                &tracker,
+                sample_server_socket_addr(),
+                &scrape_request,
+                &sample_client_ip_sources(),
+                maybe_key,
+            )
+            .await
+            .unwrap();
 
             let expected_scrape_data = ScrapeData::zeroed(&scrape_request.info_hashes);
 
@@ -187,9 +200,15 @@ mod tests {
             let unregistered_key = auth::Key::from_str("YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ").unwrap();
             let maybe_key = Some(unregistered_key);
 
-            let scrape_data = handle_scrape(&tracker, &scrape_request, &sample_client_ip_sources(), maybe_key)
-                .await
-                .unwrap();
+            let scrape_data = handle_scrape(
+                &tracker,
+                sample_server_socket_addr(),
+                &scrape_request,
+                &sample_client_ip_sources(),
+                maybe_key,
+            )
+            .await
+            .unwrap();
 
             let expected_scrape_data = ScrapeData::zeroed(&scrape_request.info_hashes);
 
@@ -201,7 +220,7 @@ mod tests {
 
         use std::sync::Arc;
 
-        use super::{sample_client_ip_sources, sample_scrape_request, whitelisted_tracker};
+        use super::{sample_client_ip_sources, sample_scrape_request, sample_server_socket_addr, whitelisted_tracker};
         use crate::core::ScrapeData;
         use crate::servers::http::v1::handlers::scrape::handle_scrape;
 
@@ -211,9 +230,15 @@ mod tests {
 
             let scrape_request = sample_scrape_request();
 
-            let scrape_data = handle_scrape(&tracker, &scrape_request, &sample_client_ip_sources(), None)
-                .await
-                .unwrap();
+            let scrape_data = handle_scrape(
+                &tracker,
+                sample_server_socket_addr(),
+                &scrape_request,
+                &sample_client_ip_sources(),
+                None,
+            )
+            .await
+            .unwrap();
 
             let expected_scrape_data = ScrapeData::zeroed(&scrape_request.info_hashes);
 
@@ -224,7 +249,7 @@ mod tests {
     mod with_tracker_on_reverse_proxy {
         use std::sync::Arc;
 
-        use super::{sample_scrape_request, tracker_on_reverse_proxy};
+        use super::{sample_scrape_request, sample_server_socket_addr, tracker_on_reverse_proxy};
         use crate::servers::http::v1::handlers::scrape::handle_scrape;
         use crate::servers::http::v1::handlers::scrape::tests::assert_error_response;
         use crate::servers::http::v1::services::peer_ip_resolver::ClientIpSources;
@@ -238,9 +263,15 @@ mod tests {
                 connection_info_ip: None,
             };
 
-            let response = handle_scrape(&tracker, &sample_scrape_request(), &client_ip_sources, None)
-                .await
-                .unwrap_err();
+            let response = handle_scrape(
+                &tracker,
+                sample_server_socket_addr(),
+                &sample_scrape_request(),
+                &client_ip_sources,
+                None,
+            )
+            .await
+            .unwrap_err();
 
             assert_error_response(
                 &response,
@@ -252,7 +283,7 @@ mod tests {
     mod with_tracker_not_on_reverse_proxy {
         use std::sync::Arc;
 
-        use super::{sample_scrape_request, tracker_not_on_reverse_proxy};
+        use super::{sample_scrape_request, sample_server_socket_addr, tracker_not_on_reverse_proxy};
         use crate::servers::http::v1::handlers::scrape::handle_scrape;
         use crate::servers::http::v1::handlers::scrape::tests::assert_error_response;
         use crate::servers::http::v1::services::peer_ip_resolver::ClientIpSources;
@@ -266,9 +297,15 @@ mod tests {
                 connection_info_ip: None,
             };
 
-            let response = handle_scrape(&tracker, &sample_scrape_request(), &client_ip_sources, None)
-                .await
-                .unwrap_err();
+            let response = handle_scrape(
+                &tracker,
+                sample_server_socket_addr(),
+                &sample_scrape_request(),
+                &client_ip_sources,
+                None,
+            )
+            .await
+            .unwrap_err();
 
             assert_error_response(
                 &response,
@@ -0,0 +1,28 @@
+//! Middleware to count the error responses.
+//!
+//! Error responses contain the [`ErrorReason`] as a response extension. See
+//! [`responses::error::Error`](crate::servers::http::v1::responses::error::Error).
+//! The middleware sends a [`TcpError`](crate::core::statistics::Event::TcpError)
+//! event with that reason to the tracker statistics.
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::core::statistics::{self, ErrorReason};
+use crate::core::Tracker;
+
+/// Middleware that counts the error responses by reason.
+pub async fn record_errors(State(tracker): State<Arc<Tracker>>, request: Request<axum::body::Body>, next: Next) -> Response {
+    let response = next.run(request).await;
+
+    if let Some(reason) = response.extensions().get::<ErrorReason>() {
+        tracker
+            .send_stats_event(statistics::Event::TcpError { reason: *reason })
+            .await;
+    }
+
+    response
+}
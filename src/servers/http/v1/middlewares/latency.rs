@@ -0,0 +1,76 @@
+//! Middleware to measure the time it takes to handle `announce` and `scrape`
+//! requests.
+//!
+//! The latency is sent to the tracker statistics with a
+//! [`Latency`](crate::core::statistics::Event::Latency) event. Other requests,
+//! like the health check, are not measured.
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::extract::State;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+
+use crate::core::statistics::{self, RequestType};
+use crate::core::Tracker;
+
+/// Middleware that records the latency of `announce` and `scrape` requests.
+pub async fn record_latency(State(tracker): State<Arc<Tracker>>, request: Request<axum::body::Body>, next: Next) -> Response {
+    let Some(request_type) = request_type(request.uri().path()) else {
+        return next.run(request).await;
+    };
+
+    let start_time = Instant::now();
+
+    let response = next.run(request).await;
+
+    tracker
+        .send_stats_event(statistics::Event::Latency {
+            request: request_type,
+            latency: start_time.elapsed(),
+        })
+        .await;
+
+    response
+}
+
+fn request_type(path: &str) -> Option<RequestType> {
+    let first_segment = path.trim_start_matches('/').split('/').next().unwrap_or_default();
+
+    match first_segment {
+        "announce" => Some(RequestType::HttpAnnounce),
+        "scrape" => Some(RequestType::HttpScrape),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::request_type;
+    use crate::core::statistics::RequestType;
+
+    #[test]
+    fn it_should_measure_announce_requests_with_and_without_key() {
+        assert_eq!(request_type("/announce"), Some(RequestType::HttpAnnounce));
+        assert_eq!(
+            request_type("/announce/YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ"),
+            Some(RequestType::HttpAnnounce)
+        );
+    }
+
+    #[test]
+    fn it_should_measure_scrape_requests_with_and_without_key() {
+        assert_eq!(request_type("/scrape"), Some(RequestType::HttpScrape));
+        assert_eq!(
+            request_type("/scrape/YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ"),
+            Some(RequestType::HttpScrape)
+        );
+    }
+
+    #[test]
+    fn it_should_not_measure_other_requests() {
+        assert_eq!(request_type("/health_check"), None);
+        assert_eq!(request_type("/announcements"), None);
+    }
+}
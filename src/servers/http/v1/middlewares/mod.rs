@@ -0,0 +1,3 @@
+//! HTTP tracker middlewares. See [Axum middlewares](axum::middleware).
+pub mod errors;
+pub mod latency;
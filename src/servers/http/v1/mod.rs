@@ -4,6 +4,7 @@
 //! more information about the endpoints and their usage.
 pub mod extractors;
 pub mod handlers;
+pub mod middlewares;
 pub mod query;
 pub mod requests;
 pub mod responses;
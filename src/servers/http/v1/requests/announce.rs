@@ -11,6 +11,7 @@ use thiserror::Error;
 use torrust_tracker_located_error::{Located, LocatedError};
 use torrust_tracker_primitives::peer;
 
+use crate::core::statistics::ErrorReason;
 use crate::servers::http::percent_encoding::{percent_decode_info_hash, percent_decode_peer_id};
 use crate::servers::http::v1::query::{ParseQueryError, Query};
 use crate::servers::http::v1::responses;
@@ -227,6 +228,7 @@ impl From<ParseQueryError> for responses::error::Error {
     fn from(err: ParseQueryError) -> Self {
         responses::error::Error {
             failure_reason: format!("Cannot parse query params: {err}"),
+            reason: ErrorReason::BadRequest,
         }
     }
 }
@@ -235,6 +237,7 @@ impl From<ParseAnnounceQueryError> for responses::error::Error {
     fn from(err: ParseAnnounceQueryError) -> Self {
         responses::error::Error {
             failure_reason: format!("Cannot parse query params for announce request: {err}"),
+            reason: ErrorReason::BadRequest,
         }
     }
 }
@@ -7,6 +7,7 @@ use bittorrent_primitives::info_hash::{self, InfoHash};
 use thiserror::Error;
 use torrust_tracker_located_error::{Located, LocatedError};
 
+use crate::core::statistics::ErrorReason;
 use crate::servers::http::percent_encoding::percent_decode_info_hash;
 use crate::servers::http::v1::query::Query;
 use crate::servers::http::v1::responses;
@@ -40,6 +41,7 @@ impl From<ParseScrapeQueryError> for responses::error::Error {
     fn from(err: ParseScrapeQueryError) -> Self {
         responses::error::Error {
             failure_reason: format!("Cannot parse query params for scrape request: {err}"),
+            reason: ErrorReason::BadRequest,
         }
     }
 }
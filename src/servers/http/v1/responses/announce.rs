@@ -305,7 +305,7 @@ mod tests {
     use torrust_tracker_primitives::peer::fixture::PeerBuilder;
     use torrust_tracker_primitives::swarm_metadata::SwarmMetadata;
 
-    use crate::core::AnnounceData;
+    use crate::core::{AnnounceData, SwarmChange};
     use crate::servers::http::v1::responses::announce::{Announce, Compact, Normal, Response};
 
     // Some ascii values used in tests:
@@ -339,7 +339,7 @@ mod tests {
         let peers = vec![Arc::new(peer_ipv4), Arc::new(peer_ipv6)];
         let stats = SwarmMetadata::new(333, 333, 444);
 
-        AnnounceData::new(peers, stats, policy)
+        AnnounceData::new(peers, stats, policy, SwarmChange::default())
     }
 
     #[test]
@@ -15,22 +15,30 @@ use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::Serialize;
 
+use crate::core::statistics::ErrorReason;
+
 /// `Error` response for the [`HTTP tracker`](crate::servers::http).
 #[derive(Serialize, Debug, PartialEq)]
 pub struct Error {
     /// Human readable string which explains why the request failed.
     #[serde(rename = "failure reason")]
     pub failure_reason: String,
+    /// Why the request failed. It's not included in the response body, it's
+    /// only used for the tracker statistics.
+    #[serde(skip)]
+    pub reason: ErrorReason,
 }
 
 impl Error {
     /// Returns the bencoded representation of the `Error` struct.
     ///
     /// ```rust
+    /// use torrust_tracker::core::statistics::ErrorReason;
     /// use torrust_tracker::servers::http::v1::responses::error::Error;
     ///
     /// let err = Error {
     ///    failure_reason: "error message".to_owned(),
+    ///    reason: ErrorReason::Internal,
     /// };
     ///
     /// // cspell:disable-next-line
@@ -48,22 +56,42 @@ impl Error {
 }
 
 impl IntoResponse for Error {
+    /// The [`ErrorReason`] is added as a response extension, so that the
+    /// [`errors`](crate::servers::http::v1::middlewares::errors) middleware
+    /// can count it.
     fn into_response(self) -> Response {
-        (StatusCode::OK, self.write()).into_response()
+        let mut response = (StatusCode::OK, self.write()).into_response();
+        response.extensions_mut().insert(self.reason);
+        response
     }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use axum::response::IntoResponse;
+
     use super::Error;
+    use crate::core::statistics::ErrorReason;
 
     #[test]
     fn http_tracker_errors_can_be_bencoded() {
         let err = Error {
             failure_reason: "error message".to_owned(),
+            reason: ErrorReason::Internal,
         };
 
         assert_eq!(err.write(), "d14:failure reason13:error messagee"); // cspell:disable-line
     }
+
+    #[test]
+    fn http_tracker_error_responses_should_include_the_error_reason_as_an_extension() {
+        let response = Error {
+            failure_reason: "error message".to_owned(),
+            reason: ErrorReason::BadRequest,
+        }
+        .into_response();
+
+        assert_eq!(response.extensions().get::<ErrorReason>(), Some(&ErrorReason::BadRequest));
+    }
 }
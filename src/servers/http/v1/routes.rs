@@ -5,6 +5,7 @@ use std::time::Duration;
 
 use axum::error_handling::HandleErrorLayer;
 use axum::http::HeaderName;
+use axum::middleware;
 use axum::response::Response;
 use axum::routing::get;
 use axum::{BoxError, Router};
@@ -20,6 +21,7 @@ use tower_http::trace::{DefaultMakeSpan, TraceLayer};
 use tracing::{instrument, Level, Span};
 
 use super::handlers::{announce, health_check, scrape};
+use super::middlewares::{errors, latency};
 use crate::core::Tracker;
 use crate::servers::http::HTTP_TRACKER_LOG_TARGET;
 
@@ -34,11 +36,15 @@ pub fn router(tracker: Arc<Tracker>, server_socket_addr: SocketAddr) -> Router {
         // Health check
         .route("/health_check", get(health_check::handler))
         // Announce request
-        .route("/announce", get(announce::handle_without_key).with_state(tracker.clone()))
-        .route("/announce/:key", get(announce::handle_with_key).with_state(tracker.clone()))
+        .route("/announce", get(announce::handle_without_key).with_state((tracker.clone(), server_socket_addr)))
+        .route("/announce/:key", get(announce::handle_with_key).with_state((tracker.clone(), server_socket_addr)))
         // Scrape request
-        .route("/scrape", get(scrape::handle_without_key).with_state(tracker.clone()))
-        .route("/scrape/:key", get(scrape::handle_with_key).with_state(tracker))
+        .route("/scrape", get(scrape::handle_without_key).with_state((tracker.clone(), server_socket_addr)))
+        .route("/scrape/:key", get(scrape::handle_with_key).with_state((tracker.clone(), server_socket_addr)))
+        // Record the time it takes to handle announce and scrape requests
+        .layer(middleware::from_fn_with_state(tracker.clone(), latency::record_latency))
+        // Count the error responses by reason
+        .layer(middleware::from_fn_with_state(tracker, errors::record_errors))
         // Add extension to get the client IP from the connection info
         .layer(SecureClientIpSource::ConnectInfo.into_extension())
         .layer(CompressionLayer::new())
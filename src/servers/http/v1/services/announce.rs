@@ -7,14 +7,16 @@
 //! by the [`Tracker`].
 //!
 //! It also sends an [`statistics::Event`]
-//! because events are specific for the HTTP tracker.
-use std::net::IpAddr;
+//! because events are specific for the HTTP tracker, and it publishes the
+//! live [`events`](crate::core::events) for the request.
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use bittorrent_primitives::info_hash::InfoHash;
 use torrust_tracker_primitives::peer;
 
-use crate::core::{statistics, AnnounceData, PeersWanted, Tracker};
+use crate::core::statistics::{self, RequestKind, Scheme};
+use crate::core::{AnnounceData, PeersWanted, Tracker};
 
 /// The HTTP tracker `announce` service.
 ///
@@ -31,20 +33,23 @@ pub async fn invoke(
     info_hash: InfoHash,
     peer: &mut peer::Peer,
     peers_wanted: &PeersWanted,
+    server_socket_addr: SocketAddr,
 ) -> AnnounceData {
     let original_peer_ip = peer.peer_addr.ip();
 
     // The tracker could change the original peer ip
     let announce_data = tracker.announce(&info_hash, peer, &original_peer_ip, peers_wanted);
 
-    match original_peer_ip {
-        IpAddr::V4(_) => {
-            tracker.send_stats_event(statistics::Event::Tcp4Announce).await;
-        }
-        IpAddr::V6(_) => {
-            tracker.send_stats_event(statistics::Event::Tcp6Announce).await;
-        }
-    }
+    tracker.publish_announce_events(Scheme::Http, &info_hash, peer, &announce_data);
+
+    tracker
+        .send_stats_event(statistics::Event::Request {
+            scheme: Scheme::Http,
+            ip_version: original_peer_ip.into(),
+            kind: RequestKind::Announce,
+            listener: server_socket_addr,
+        })
+        .await;
 
     announce_data
 }
@@ -59,7 +64,7 @@ mod tests {
     use torrust_tracker_test_helpers::configuration;
 
     use crate::core::services::tracker_factory;
-    use crate::core::Tracker;
+    use crate::core::{statistics, Tracker};
 
     fn public_tracker() -> Tracker {
         tracker_factory(&configuration::ephemeral_public())
@@ -82,6 +87,19 @@ mod tests {
         peer
     }
 
+    fn sample_server_socket_addr() -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 7070)
+    }
+
+    fn sample_announce_event(ip_version: statistics::IpVersion) -> statistics::Event {
+        statistics::Event::Request {
+            scheme: statistics::Scheme::Http,
+            ip_version,
+            kind: statistics::RequestKind::Announce,
+            listener: sample_server_socket_addr(),
+        }
+    }
+
     fn sample_peer() -> peer::Peer {
         peer::Peer {
             peer_id: PeerId(*b"-qB00000000000000000"),
@@ -105,9 +123,11 @@ mod tests {
         use torrust_tracker_test_helpers::configuration;
 
         use super::{sample_peer_using_ipv4, sample_peer_using_ipv6};
-        use crate::core::{statistics, AnnounceData, PeersWanted, Tracker};
+        use crate::core::{statistics, AnnounceData, PeersWanted, SwarmChange, Tracker};
         use crate::servers::http::v1::services::announce::invoke;
-        use crate::servers::http::v1::services::announce::tests::{public_tracker, sample_info_hash, sample_peer};
+        use crate::servers::http::v1::services::announce::tests::{
+            public_tracker, sample_announce_event, sample_info_hash, sample_peer, sample_server_socket_addr,
+        };
 
         #[tokio::test]
         async fn it_should_return_the_announce_data() {
@@ -115,7 +135,14 @@ mod tests {
 
             let mut peer = sample_peer();
 
-            let announce_data = invoke(tracker.clone(), sample_info_hash(), &mut peer, &PeersWanted::All).await;
+            let announce_data = invoke(
+                tracker.clone(),
+                sample_info_hash(),
+                &mut peer,
+                &PeersWanted::All,
+                sample_server_socket_addr(),
+            )
+            .await;
 
             let expected_announce_data = AnnounceData {
                 peers: vec![],
@@ -125,6 +152,10 @@ mod tests {
                     incomplete: 0,
                 },
                 policy: tracker.get_announce_policy(),
+                swarm_change: SwarmChange {
+                    torrent_added: true,
+                    completed: false,
+                },
             };
 
             assert_eq!(announce_data, expected_announce_data);
@@ -135,7 +166,7 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Tcp4Announce))
+                .with(eq(sample_announce_event(statistics::IpVersion::V4)))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -151,7 +182,14 @@ mod tests {
 
             let mut peer = sample_peer_using_ipv4();
 
-            let _announce_data = invoke(tracker, sample_info_hash(), &mut peer, &PeersWanted::All).await;
+            let _announce_data = invoke(
+                tracker,
+                sample_info_hash(),
+                &mut peer,
+                &PeersWanted::All,
+                sample_server_socket_addr(),
+            )
+            .await;
         }
 
         fn tracker_with_an_ipv6_external_ip(stats_event_sender: Box<dyn statistics::EventSender>) -> Tracker {
@@ -179,7 +217,7 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Tcp4Announce))
+                .with(eq(sample_announce_event(statistics::IpVersion::V4)))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -191,6 +229,7 @@ mod tests {
                 sample_info_hash(),
                 &mut peer,
                 &PeersWanted::All,
+                sample_server_socket_addr(),
             )
             .await;
         }
@@ -201,7 +240,7 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Tcp6Announce))
+                .with(eq(sample_announce_event(statistics::IpVersion::V6)))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -217,7 +256,14 @@ mod tests {
 
             let mut peer = sample_peer_using_ipv6();
 
-            let _announce_data = invoke(tracker, sample_info_hash(), &mut peer, &PeersWanted::All).await;
+            let _announce_data = invoke(
+                tracker,
+                sample_info_hash(),
+                &mut peer,
+                &PeersWanted::All,
+                sample_server_socket_addr(),
+            )
+            .await;
         }
     }
 }
@@ -8,12 +8,13 @@
 //!
 //! It also sends an [`statistics::Event`]
 //! because events are specific for the HTTP tracker.
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 
 use bittorrent_primitives::info_hash::InfoHash;
 
-use crate::core::{statistics, ScrapeData, Tracker};
+use crate::core::statistics::{self, RequestKind, Scheme};
+use crate::core::{ScrapeData, Tracker};
 
 /// The HTTP tracker `scrape` service.
 ///
@@ -25,10 +26,15 @@ use crate::core::{statistics, ScrapeData, Tracker};
 /// > **NOTICE**: as the HTTP tracker does not requires a connection request
 /// > like the UDP tracker, the number of TCP connections is incremented for
 /// > each `scrape` request.
-pub async fn invoke(tracker: &Arc<Tracker>, info_hashes: &Vec<InfoHash>, original_peer_ip: &IpAddr) -> ScrapeData {
+pub async fn invoke(
+    tracker: &Arc<Tracker>,
+    info_hashes: &Vec<InfoHash>,
+    original_peer_ip: &IpAddr,
+    server_socket_addr: SocketAddr,
+) -> ScrapeData {
     let scrape_data = tracker.scrape(info_hashes).await;
 
-    send_scrape_event(original_peer_ip, tracker).await;
+    send_scrape_event(original_peer_ip, server_socket_addr, tracker).await;
 
     scrape_data
 }
@@ -39,21 +45,26 @@ pub async fn invoke(tracker: &Arc<Tracker>, info_hashes: &Vec<InfoHash>, origina
 /// the tracker returns empty stats for all the torrents.
 ///
 /// > **NOTICE**: tracker statistics are not updated in this case.
-pub async fn fake(tracker: &Arc<Tracker>, info_hashes: &Vec<InfoHash>, original_peer_ip: &IpAddr) -> ScrapeData {
-    send_scrape_event(original_peer_ip, tracker).await;
+pub async fn fake(
+    tracker: &Arc<Tracker>,
+    info_hashes: &Vec<InfoHash>,
+    original_peer_ip: &IpAddr,
+    server_socket_addr: SocketAddr,
+) -> ScrapeData {
+    send_scrape_event(original_peer_ip, server_socket_addr, tracker).await;
 
     ScrapeData::zeroed(info_hashes)
 }
 
-async fn send_scrape_event(original_peer_ip: &IpAddr, tracker: &Arc<Tracker>) {
-    match original_peer_ip {
-        IpAddr::V4(_) => {
-            tracker.send_stats_event(statistics::Event::Tcp4Scrape).await;
-        }
-        IpAddr::V6(_) => {
-            tracker.send_stats_event(statistics::Event::Tcp6Scrape).await;
-        }
-    }
+async fn send_scrape_event(original_peer_ip: &IpAddr, server_socket_addr: SocketAddr, tracker: &Arc<Tracker>) {
+    tracker
+        .send_stats_event(statistics::Event::Request {
+            scheme: Scheme::Http,
+            ip_version: (*original_peer_ip).into(),
+            kind: RequestKind::Scrape,
+            listener: server_socket_addr,
+        })
+        .await;
 }
 
 #[cfg(test)]
@@ -67,7 +78,7 @@ mod tests {
     use torrust_tracker_test_helpers::configuration;
 
     use crate::core::services::tracker_factory;
-    use crate::core::Tracker;
+    use crate::core::{statistics, Tracker};
 
     fn public_tracker() -> Tracker {
         tracker_factory(&configuration::ephemeral_public())
@@ -81,6 +92,19 @@ mod tests {
         "3b245504cf5f11bbdbe1201cea6a6bf45aee1bc0".parse::<InfoHash>().unwrap()
     }
 
+    fn sample_server_socket_addr() -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 7070)
+    }
+
+    fn sample_scrape_event(ip_version: statistics::IpVersion) -> statistics::Event {
+        statistics::Event::Request {
+            scheme: statistics::Scheme::Http,
+            ip_version,
+            kind: statistics::RequestKind::Scrape,
+            listener: sample_server_socket_addr(),
+        }
+    }
+
     fn sample_peer() -> peer::Peer {
         peer::Peer {
             peer_id: PeerId(*b"-qB00000000000000000"),
@@ -106,7 +130,7 @@ mod tests {
         use crate::core::{statistics, PeersWanted, ScrapeData, Tracker};
         use crate::servers::http::v1::services::scrape::invoke;
         use crate::servers::http::v1::services::scrape::tests::{
-            public_tracker, sample_info_hash, sample_info_hashes, sample_peer,
+            public_tracker, sample_info_hash, sample_info_hashes, sample_peer, sample_scrape_event, sample_server_socket_addr,
         };
 
         #[tokio::test]
@@ -121,7 +145,7 @@ mod tests {
             let original_peer_ip = peer.ip();
             tracker.announce(&info_hash, &mut peer, &original_peer_ip, &PeersWanted::All);
 
-            let scrape_data = invoke(&tracker, &info_hashes, &original_peer_ip).await;
+            let scrape_data = invoke(&tracker, &info_hashes, &original_peer_ip, sample_server_socket_addr()).await;
 
             let mut expected_scrape_data = ScrapeData::empty();
             expected_scrape_data.add_file(
@@ -141,7 +165,7 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Tcp4Scrape))
+                .with(eq(sample_scrape_event(statistics::IpVersion::V4)))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -157,7 +181,7 @@ mod tests {
 
             let peer_ip = IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1));
 
-            invoke(&tracker, &sample_info_hashes(), &peer_ip).await;
+            invoke(&tracker, &sample_info_hashes(), &peer_ip, sample_server_socket_addr()).await;
         }
 
         #[tokio::test]
@@ -165,7 +189,7 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Tcp6Scrape))
+                .with(eq(sample_scrape_event(statistics::IpVersion::V6)))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -181,7 +205,7 @@ mod tests {
 
             let peer_ip = IpAddr::V6(Ipv6Addr::new(0x6969, 0x6969, 0x6969, 0x6969, 0x6969, 0x6969, 0x6969, 0x6969));
 
-            invoke(&tracker, &sample_info_hashes(), &peer_ip).await;
+            invoke(&tracker, &sample_info_hashes(), &peer_ip, sample_server_socket_addr()).await;
         }
     }
 
@@ -197,7 +221,7 @@ mod tests {
         use crate::core::{statistics, PeersWanted, ScrapeData, Tracker};
         use crate::servers::http::v1::services::scrape::fake;
         use crate::servers::http::v1::services::scrape::tests::{
-            public_tracker, sample_info_hash, sample_info_hashes, sample_peer,
+            public_tracker, sample_info_hash, sample_info_hashes, sample_peer, sample_scrape_event, sample_server_socket_addr,
         };
 
         #[tokio::test]
@@ -212,7 +236,7 @@ mod tests {
             let original_peer_ip = peer.ip();
             tracker.announce(&info_hash, &mut peer, &original_peer_ip, &PeersWanted::All);
 
-            let scrape_data = fake(&tracker, &info_hashes, &original_peer_ip).await;
+            let scrape_data = fake(&tracker, &info_hashes, &original_peer_ip, sample_server_socket_addr()).await;
 
             let expected_scrape_data = ScrapeData::zeroed(&info_hashes);
 
@@ -224,7 +248,7 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Tcp4Scrape))
+                .with(eq(sample_scrape_event(statistics::IpVersion::V4)))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -240,7 +264,7 @@ mod tests {
 
             let peer_ip = IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1));
 
-            fake(&tracker, &sample_info_hashes(), &peer_ip).await;
+            fake(&tracker, &sample_info_hashes(), &peer_ip, sample_server_socket_addr()).await;
         }
 
         #[tokio::test]
@@ -248,7 +272,7 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Tcp6Scrape))
+                .with(eq(sample_scrape_event(statistics::IpVersion::V6)))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -264,7 +288,7 @@ mod tests {
 
             let peer_ip = IpAddr::V6(Ipv6Addr::new(0x6969, 0x6969, 0x6969, 0x6969, 0x6969, 0x6969, 0x6969, 0x6969));
 
-            fake(&tracker, &sample_info_hashes(), &peer_ip).await;
+            fake(&tracker, &sample_info_hashes(), &peer_ip, sample_server_socket_addr()).await;
         }
     }
 }
@@ -1,6 +1,7 @@
 //! Registar. Registers Services for Health Check.
 
 use std::collections::HashMap;
+use std::fmt;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
@@ -26,16 +27,46 @@ pub struct ServiceHealthCheckJob {
 /// A function fulfilling this specification will spawn a new [`ServiceHealthCheckJob`].
 pub type FnSpawnServiceHeathCheck = fn(&SocketAddr) -> ServiceHealthCheckJob;
 
+/// The kind of [`ServiceRegistration`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Service {
+    UdpTracker,
+    HttpTracker,
+    HttpApi,
+}
+
+impl fmt::Display for Service {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Service::UdpTracker => write!(f, "udp_tracker"),
+            Service::HttpTracker => write!(f, "http_tracker"),
+            Service::HttpApi => write!(f, "http_api"),
+        }
+    }
+}
+
 /// A [`ServiceRegistration`] is provided to the [`Registar`] for registration.
 ///
 /// Each registration includes a function that fulfils the [`FnSpawnServiceHeathCheck`] specification.
 #[derive(Clone, Debug, Constructor)]
 pub struct ServiceRegistration {
     binding: SocketAddr,
+    service: Service,
     check_fn: FnSpawnServiceHeathCheck,
 }
 
 impl ServiceRegistration {
+    /// The address the service is bound to.
+    #[must_use]
+    pub fn binding(&self) -> SocketAddr {
+        self.binding
+    }
+
+    #[must_use]
+    pub fn service(&self) -> Service {
+        self.service
+    }
+
     #[must_use]
     pub fn spawn_check(&self) -> ServiceHealthCheckJob {
         (self.check_fn)(&self.binding)
@@ -4,6 +4,8 @@ use std::panic::Location;
 use thiserror::Error;
 use torrust_tracker_located_error::LocatedError;
 
+use crate::core::statistics::ErrorReason;
+
 /// Error returned by the UDP server.
 #[derive(Error, Debug)]
 pub enum Error {
@@ -34,3 +36,17 @@ pub enum Error {
     #[error("domain tracker requires authentication but is not supported in current UDP implementation. Location: {location}")]
     TrackerAuthenticationRequired { location: &'static Location<'static> },
 }
+
+impl From<&Error> for ErrorReason {
+    fn from(error: &Error) -> Self {
+        match error {
+            // The only tracker error returned by the handlers is the
+            // authorization error for non-whitelisted torrents.
+            Error::TrackerError { .. } => ErrorReason::TorrentNotWhitelisted,
+            Error::InternalServer { .. } => ErrorReason::Internal,
+            Error::InvalidConnectionId { .. } => ErrorReason::InvalidConnectionId,
+            Error::BadRequest { .. } => ErrorReason::BadRequest,
+            Error::TrackerAuthenticationRequired { .. } => ErrorReason::AuthenticationFailed,
+        }
+    }
+}
@@ -18,7 +18,8 @@ use zerocopy::network_endian::I32;
 
 use super::connection_cookie::{check, from_connection_id, into_connection_id, make};
 use super::RawRequest;
-use crate::core::{statistics, PeersWanted, ScrapeData, Tracker};
+use crate::core::statistics::{self, RequestKind, Scheme};
+use crate::core::{PeersWanted, ScrapeData, Tracker};
 use crate::servers::udp::error::Error;
 use crate::servers::udp::logging::{log_bad_request, log_error_response, log_request, log_response};
 use crate::servers::udp::peer_builder;
@@ -55,9 +56,9 @@ pub(crate) async fn handle_packet(udp_request: RawRequest, tracker: &Tracker, lo
                 Request::Scrape(scrape_request) => scrape_request.transaction_id,
             };
 
-            let response = match handle_request(request, udp_request.from, tracker).await {
+            let response = match handle_request(request, udp_request.from, local_addr, tracker).await {
                 Ok(response) => response,
-                Err(e) => handle_error(&e, transaction_id),
+                Err(e) => handle_error(&e, transaction_id, tracker).await,
             };
 
             let latency = start_time.elapsed();
@@ -74,7 +75,9 @@ pub(crate) async fn handle_packet(udp_request: RawRequest, tracker: &Tracker, lo
                     source: (Arc::new(e) as DynError).into(),
                 },
                 TransactionId(I32::new(0)),
-            );
+                tracker,
+            )
+            .await;
 
             log_error_response(&request_id);
 
@@ -88,14 +91,19 @@ pub(crate) async fn handle_packet(udp_request: RawRequest, tracker: &Tracker, lo
 /// # Errors
 ///
 /// If a error happens in the `handle_request` function, it will just return the  `ServerError`.
-#[instrument(skip(request, remote_addr, tracker))]
-pub async fn handle_request(request: Request, remote_addr: SocketAddr, tracker: &Tracker) -> Result<Response, Error> {
+#[instrument(skip(request, remote_addr, server_socket_addr, tracker))]
+pub async fn handle_request(
+    request: Request,
+    remote_addr: SocketAddr,
+    server_socket_addr: SocketAddr,
+    tracker: &Tracker,
+) -> Result<Response, Error> {
     tracing::trace!("handle request");
 
     match request {
-        Request::Connect(connect_request) => handle_connect(remote_addr, &connect_request, tracker).await,
-        Request::Announce(announce_request) => handle_announce(remote_addr, &announce_request, tracker).await,
-        Request::Scrape(scrape_request) => handle_scrape(remote_addr, &scrape_request, tracker).await,
+        Request::Connect(connect_request) => handle_connect(remote_addr, server_socket_addr, &connect_request, tracker).await,
+        Request::Announce(announce_request) => handle_announce(remote_addr, server_socket_addr, &announce_request, tracker).await,
+        Request::Scrape(scrape_request) => handle_scrape(remote_addr, server_socket_addr, &scrape_request, tracker).await,
     }
 }
 
@@ -106,7 +114,12 @@ pub async fn handle_request(request: Request, remote_addr: SocketAddr, tracker:
 ///
 /// This function does not ever return an error.
 #[instrument(skip(tracker), err, ret(level = Level::TRACE))]
-pub async fn handle_connect(remote_addr: SocketAddr, request: &ConnectRequest, tracker: &Tracker) -> Result<Response, Error> {
+pub async fn handle_connect(
+    remote_addr: SocketAddr,
+    server_socket_addr: SocketAddr,
+    request: &ConnectRequest,
+    tracker: &Tracker,
+) -> Result<Response, Error> {
     tracing::trace!("handle connect");
 
     let connection_cookie = make(&remote_addr);
@@ -117,15 +130,7 @@ pub async fn handle_connect(remote_addr: SocketAddr, request: &ConnectRequest, t
         connection_id,
     };
 
-    // send stats event
-    match remote_addr {
-        SocketAddr::V4(_) => {
-            tracker.send_stats_event(statistics::Event::Udp4Connect).await;
-        }
-        SocketAddr::V6(_) => {
-            tracker.send_stats_event(statistics::Event::Udp6Connect).await;
-        }
-    }
+    send_request_event(tracker, remote_addr, server_socket_addr, RequestKind::Connect).await;
 
     Ok(Response::from(response))
 }
@@ -139,6 +144,7 @@ pub async fn handle_connect(remote_addr: SocketAddr, request: &ConnectRequest, t
 #[instrument(skip(tracker), err, ret(level = Level::TRACE))]
 pub async fn handle_announce(
     remote_addr: SocketAddr,
+    server_socket_addr: SocketAddr,
     announce_request: &AnnounceRequest,
     tracker: &Tracker,
 ) -> Result<Response, Error> {
@@ -166,21 +172,16 @@ pub async fn handle_announce(
 
     let response = tracker.announce(&info_hash, &mut peer, &remote_client_ip, &peers_wanted);
 
-    match remote_client_ip {
-        IpAddr::V4(_) => {
-            tracker.send_stats_event(statistics::Event::Udp4Announce).await;
-        }
-        IpAddr::V6(_) => {
-            tracker.send_stats_event(statistics::Event::Udp6Announce).await;
-        }
-    }
+    tracker.publish_announce_events(Scheme::Udp, &info_hash, &peer, &response);
+
+    send_request_event(tracker, remote_addr, server_socket_addr, RequestKind::Announce).await;
 
     #[allow(clippy::cast_possible_truncation)]
     if remote_addr.is_ipv4() {
         let announce_response = AnnounceResponse {
             fixed: AnnounceResponseFixedData {
                 transaction_id: announce_request.transaction_id,
-                announce_interval: AnnounceInterval(I32::new(i64::from(tracker.get_announce_policy().interval) as i32)),
+                announce_interval: AnnounceInterval(I32::new(i64::from(response.policy.interval) as i32)),
                 leechers: NumberOfPeers(I32::new(i64::from(response.stats.incomplete) as i32)),
                 seeders: NumberOfPeers(I32::new(i64::from(response.stats.complete) as i32)),
             },
@@ -205,7 +206,7 @@ pub async fn handle_announce(
         let announce_response = AnnounceResponse {
             fixed: AnnounceResponseFixedData {
                 transaction_id: announce_request.transaction_id,
-                announce_interval: AnnounceInterval(I32::new(i64::from(tracker.get_announce_policy().interval) as i32)),
+                announce_interval: AnnounceInterval(I32::new(i64::from(response.policy.interval) as i32)),
                 leechers: NumberOfPeers(I32::new(i64::from(response.stats.incomplete) as i32)),
                 seeders: NumberOfPeers(I32::new(i64::from(response.stats.complete) as i32)),
             },
@@ -236,7 +237,12 @@ pub async fn handle_announce(
 ///
 /// This function does not ever return an error.
 #[instrument(skip(tracker), err, ret(level = Level::TRACE))]
-pub async fn handle_scrape(remote_addr: SocketAddr, request: &ScrapeRequest, tracker: &Tracker) -> Result<Response, Error> {
+pub async fn handle_scrape(
+    remote_addr: SocketAddr,
+    server_socket_addr: SocketAddr,
+    request: &ScrapeRequest,
+    tracker: &Tracker,
+) -> Result<Response, Error> {
     tracing::trace!("handle scrape");
 
     // Convert from aquatic infohashes
@@ -268,15 +274,7 @@ pub async fn handle_scrape(remote_addr: SocketAddr, request: &ScrapeRequest, tra
         torrent_stats.push(scrape_entry);
     }
 
-    // send stats event
-    match remote_addr {
-        SocketAddr::V4(_) => {
-            tracker.send_stats_event(statistics::Event::Udp4Scrape).await;
-        }
-        SocketAddr::V6(_) => {
-            tracker.send_stats_event(statistics::Event::Udp6Scrape).await;
-        }
-    }
+    send_request_event(tracker, remote_addr, server_socket_addr, RequestKind::Scrape).await;
 
     let response = ScrapeResponse {
         transaction_id: request.transaction_id,
@@ -286,7 +284,22 @@ pub async fn handle_scrape(remote_addr: SocketAddr, request: &ScrapeRequest, tra
     Ok(Response::from(response))
 }
 
-fn handle_error(e: &Error, transaction_id: TransactionId) -> Response {
+async fn send_request_event(tracker: &Tracker, remote_addr: SocketAddr, server_socket_addr: SocketAddr, kind: RequestKind) {
+    tracker
+        .send_stats_event(statistics::Event::Request {
+            scheme: Scheme::Udp,
+            ip_version: remote_addr.ip().into(),
+            kind,
+            listener: server_socket_addr,
+        })
+        .await;
+}
+
+async fn handle_error(e: &Error, transaction_id: TransactionId, tracker: &Tracker) -> Response {
+    tracker
+        .send_stats_event(statistics::Event::UdpError { reason: e.into() })
+        .await;
+
     let message = e.to_string();
     Response::from(ErrorResponse {
         transaction_id,
@@ -323,7 +336,7 @@ mod tests {
     use torrust_tracker_test_helpers::configuration;
 
     use crate::core::services::tracker_factory;
-    use crate::core::Tracker;
+    use crate::core::{statistics, Tracker};
     use crate::CurrentClock;
 
     fn tracker_configuration() -> Configuration {
@@ -358,6 +371,19 @@ mod tests {
         sample_ipv6_socket_address()
     }
 
+    fn sample_server_socket_addr() -> SocketAddr {
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 6969)
+    }
+
+    fn sample_request_event(ip_version: statistics::IpVersion, kind: statistics::RequestKind) -> statistics::Event {
+        statistics::Event::Request {
+            scheme: statistics::Scheme::Udp,
+            ip_version,
+            kind,
+            listener: sample_server_socket_addr(),
+        }
+    }
+
     fn sample_ipv4_socket_address() -> SocketAddr {
         SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8080)
     }
@@ -436,7 +462,10 @@ mod tests {
         use aquatic_udp_protocol::{ConnectRequest, ConnectResponse, Response, TransactionId};
         use mockall::predicate::eq;
 
-        use super::{sample_ipv4_socket_address, sample_ipv6_remote_addr, tracker_configuration};
+        use super::{
+            sample_ipv4_socket_address, sample_ipv6_remote_addr, sample_request_event, sample_server_socket_addr,
+            tracker_configuration,
+        };
         use crate::core::{self, statistics};
         use crate::servers::udp::connection_cookie::{into_connection_id, make};
         use crate::servers::udp::handlers::handle_connect;
@@ -454,9 +483,14 @@ mod tests {
                 transaction_id: TransactionId(0i32.into()),
             };
 
-            let response = handle_connect(sample_ipv4_remote_addr(), &request, &public_tracker())
-                .await
-                .unwrap();
+            let response = handle_connect(
+                sample_ipv4_remote_addr(),
+                sample_server_socket_addr(),
+                &request,
+                &public_tracker(),
+            )
+            .await
+            .unwrap();
 
             assert_eq!(
                 response,
@@ -473,9 +507,14 @@ mod tests {
                 transaction_id: TransactionId(0i32.into()),
             };
 
-            let response = handle_connect(sample_ipv4_remote_addr(), &request, &public_tracker())
-                .await
-                .unwrap();
+            let response = handle_connect(
+                sample_ipv4_remote_addr(),
+                sample_server_socket_addr(),
+                &request,
+                &public_tracker(),
+            )
+            .await
+            .unwrap();
 
             assert_eq!(
                 response,
@@ -491,7 +530,10 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Udp4Connect))
+                .with(eq(sample_request_event(
+                    statistics::IpVersion::V4,
+                    statistics::RequestKind::Connect,
+                )))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -506,9 +548,14 @@ mod tests {
                 )
                 .unwrap(),
             );
-            handle_connect(client_socket_address, &sample_connect_request(), &torrent_tracker)
-                .await
-                .unwrap();
+            handle_connect(
+                client_socket_address,
+                sample_server_socket_addr(),
+                &sample_connect_request(),
+                &torrent_tracker,
+            )
+            .await
+            .unwrap();
         }
 
         #[tokio::test]
@@ -516,7 +563,10 @@ mod tests {
             let mut stats_event_sender_mock = statistics::MockEventSender::new();
             stats_event_sender_mock
                 .expect_send_event()
-                .with(eq(statistics::Event::Udp6Connect))
+                .with(eq(sample_request_event(
+                    statistics::IpVersion::V6,
+                    statistics::RequestKind::Connect,
+                )))
                 .times(1)
                 .returning(|_| Box::pin(future::ready(Some(Ok(())))));
             let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -529,9 +579,14 @@ mod tests {
                 )
                 .unwrap(),
             );
-            handle_connect(sample_ipv6_remote_addr(), &sample_connect_request(), &torrent_tracker)
-                .await
-                .unwrap();
+            handle_connect(
+                sample_ipv6_remote_addr(),
+                sample_server_socket_addr(),
+                &sample_connect_request(),
+                &torrent_tracker,
+            )
+            .await
+            .unwrap();
         }
     }
 
@@ -624,7 +679,8 @@ mod tests {
             use crate::servers::udp::connection_cookie::{into_connection_id, make};
             use crate::servers::udp::handlers::tests::announce_request::AnnounceRequestBuilder;
             use crate::servers::udp::handlers::tests::{
-                public_tracker, sample_ipv4_socket_address, tracker_configuration, TorrentPeerBuilder,
+                public_tracker, sample_ipv4_socket_address, sample_request_event, sample_server_socket_addr,
+                tracker_configuration, TorrentPeerBuilder,
             };
             use crate::servers::udp::handlers::{handle_announce, AnnounceResponseFixedData};
 
@@ -647,7 +703,9 @@ mod tests {
                     .with_port(client_port)
                     .into();
 
-                handle_announce(remote_addr, &request, &tracker).await.unwrap();
+                handle_announce(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                    .await
+                    .unwrap();
 
                 let peers = tracker.get_torrent_peers(&info_hash.0.into());
 
@@ -667,7 +725,9 @@ mod tests {
                     .with_connection_id(into_connection_id(&make(&remote_addr)))
                     .into();
 
-                let response = handle_announce(remote_addr, &request, &public_tracker()).await.unwrap();
+                let response = handle_announce(remote_addr, sample_server_socket_addr(), &request, &public_tracker())
+                    .await
+                    .unwrap();
 
                 let empty_peer_vector: Vec<ResponsePeer<Ipv4AddrBytes>> = vec![];
                 assert_eq!(
@@ -710,7 +770,9 @@ mod tests {
                     .with_port(client_port)
                     .into();
 
-                handle_announce(remote_addr, &request, &tracker).await.unwrap();
+                handle_announce(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                    .await
+                    .unwrap();
 
                 let peers = tracker.get_torrent_peers(&info_hash.0.into());
 
@@ -739,7 +801,9 @@ mod tests {
                     .with_connection_id(into_connection_id(&make(&remote_addr)))
                     .into();
 
-                handle_announce(remote_addr, &request, &tracker).await.unwrap()
+                handle_announce(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                    .await
+                    .unwrap()
             }
 
             #[tokio::test]
@@ -764,7 +828,10 @@ mod tests {
                 let mut stats_event_sender_mock = statistics::MockEventSender::new();
                 stats_event_sender_mock
                     .expect_send_event()
-                    .with(eq(statistics::Event::Udp4Announce))
+                    .with(eq(sample_request_event(
+                        statistics::IpVersion::V4,
+                        statistics::RequestKind::Announce,
+                    )))
                     .times(1)
                     .returning(|_| Box::pin(future::ready(Some(Ok(())))));
                 let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -780,6 +847,7 @@ mod tests {
 
                 handle_announce(
                     sample_ipv4_socket_address(),
+                    sample_server_socket_addr(),
                     &AnnounceRequestBuilder::default().into(),
                     &tracker,
                 )
@@ -796,7 +864,7 @@ mod tests {
                 use crate::servers::udp::connection_cookie::{into_connection_id, make};
                 use crate::servers::udp::handlers::handle_announce;
                 use crate::servers::udp::handlers::tests::announce_request::AnnounceRequestBuilder;
-                use crate::servers::udp::handlers::tests::{public_tracker, TorrentPeerBuilder};
+                use crate::servers::udp::handlers::tests::{public_tracker, sample_server_socket_addr, TorrentPeerBuilder};
 
                 #[tokio::test]
                 async fn the_peer_ip_should_be_changed_to_the_external_ip_in_the_tracker_configuration_if_defined() {
@@ -817,7 +885,9 @@ mod tests {
                         .with_port(client_port)
                         .into();
 
-                    handle_announce(remote_addr, &request, &tracker).await.unwrap();
+                    handle_announce(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                        .await
+                        .unwrap();
 
                     let peers = tracker.get_torrent_peers(&info_hash.0.into());
 
@@ -849,7 +919,8 @@ mod tests {
             use crate::servers::udp::connection_cookie::{into_connection_id, make};
             use crate::servers::udp::handlers::tests::announce_request::AnnounceRequestBuilder;
             use crate::servers::udp::handlers::tests::{
-                public_tracker, sample_ipv6_remote_addr, tracker_configuration, TorrentPeerBuilder,
+                public_tracker, sample_ipv6_remote_addr, sample_request_event, sample_server_socket_addr, tracker_configuration,
+                TorrentPeerBuilder,
             };
             use crate::servers::udp::handlers::{handle_announce, AnnounceResponseFixedData};
 
@@ -873,7 +944,9 @@ mod tests {
                     .with_port(client_port)
                     .into();
 
-                handle_announce(remote_addr, &request, &tracker).await.unwrap();
+                handle_announce(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                    .await
+                    .unwrap();
 
                 let peers = tracker.get_torrent_peers(&info_hash.0.into());
 
@@ -896,7 +969,9 @@ mod tests {
                     .with_connection_id(into_connection_id(&make(&remote_addr)))
                     .into();
 
-                let response = handle_announce(remote_addr, &request, &public_tracker()).await.unwrap();
+                let response = handle_announce(remote_addr, sample_server_socket_addr(), &request, &public_tracker())
+                    .await
+                    .unwrap();
 
                 let empty_peer_vector: Vec<ResponsePeer<Ipv6AddrBytes>> = vec![];
                 assert_eq!(
@@ -939,7 +1014,9 @@ mod tests {
                     .with_port(client_port)
                     .into();
 
-                handle_announce(remote_addr, &request, &tracker).await.unwrap();
+                handle_announce(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                    .await
+                    .unwrap();
 
                 let peers = tracker.get_torrent_peers(&info_hash.0.into());
 
@@ -971,7 +1048,9 @@ mod tests {
                     .with_connection_id(into_connection_id(&make(&remote_addr)))
                     .into();
 
-                handle_announce(remote_addr, &request, &tracker).await.unwrap()
+                handle_announce(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                    .await
+                    .unwrap()
             }
 
             #[tokio::test]
@@ -996,7 +1075,10 @@ mod tests {
                 let mut stats_event_sender_mock = statistics::MockEventSender::new();
                 stats_event_sender_mock
                     .expect_send_event()
-                    .with(eq(statistics::Event::Udp6Announce))
+                    .with(eq(sample_request_event(
+                        statistics::IpVersion::V6,
+                        statistics::RequestKind::Announce,
+                    )))
                     .times(1)
                     .returning(|_| Box::pin(future::ready(Some(Ok(())))));
                 let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -1016,7 +1098,9 @@ mod tests {
                     .with_connection_id(into_connection_id(&make(&remote_addr)))
                     .into();
 
-                handle_announce(remote_addr, &announce_request, &tracker).await.unwrap();
+                handle_announce(remote_addr, sample_server_socket_addr(), &announce_request, &tracker)
+                    .await
+                    .unwrap();
             }
 
             mod from_a_loopback_ip {
@@ -1030,7 +1114,7 @@ mod tests {
                 use crate::servers::udp::connection_cookie::{into_connection_id, make};
                 use crate::servers::udp::handlers::handle_announce;
                 use crate::servers::udp::handlers::tests::announce_request::AnnounceRequestBuilder;
-                use crate::servers::udp::handlers::tests::TrackerConfigurationBuilder;
+                use crate::servers::udp::handlers::tests::{sample_server_socket_addr, TrackerConfigurationBuilder};
 
                 #[tokio::test]
                 async fn the_peer_ip_should_be_changed_to_the_external_ip_in_the_tracker_configuration() {
@@ -1059,7 +1143,9 @@ mod tests {
                         .with_port(client_port)
                         .into();
 
-                    handle_announce(remote_addr, &request, &tracker).await.unwrap();
+                    handle_announce(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                        .await
+                        .unwrap();
 
                     let peers = tracker.get_torrent_peers(&info_hash.0.into());
 
@@ -1091,7 +1177,7 @@ mod tests {
         use crate::core::{self};
         use crate::servers::udp::connection_cookie::{into_connection_id, make};
         use crate::servers::udp::handlers::handle_scrape;
-        use crate::servers::udp::handlers::tests::{public_tracker, sample_ipv4_remote_addr};
+        use crate::servers::udp::handlers::tests::{public_tracker, sample_ipv4_remote_addr, sample_server_socket_addr};
 
         fn zeroed_torrent_statistics() -> TorrentScrapeStatistics {
             TorrentScrapeStatistics {
@@ -1114,7 +1200,9 @@ mod tests {
                 info_hashes,
             };
 
-            let response = handle_scrape(remote_addr, &request, &public_tracker()).await.unwrap();
+            let response = handle_scrape(remote_addr, sample_server_socket_addr(), &request, &public_tracker())
+                .await
+                .unwrap();
 
             let expected_torrent_stats = vec![zeroed_torrent_statistics()];
 
@@ -1157,7 +1245,9 @@ mod tests {
 
             let request = build_scrape_request(&remote_addr, &info_hash);
 
-            handle_scrape(remote_addr, &request, &tracker).await.unwrap()
+            handle_scrape(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                .await
+                .unwrap()
         }
 
         fn match_scrape_response(response: Response) -> Option<ScrapeResponse> {
@@ -1197,7 +1287,7 @@ mod tests {
             use crate::servers::udp::handlers::tests::scrape_request::{
                 add_a_sample_seeder_and_scrape, build_scrape_request, match_scrape_response, zeroed_torrent_statistics,
             };
-            use crate::servers::udp::handlers::tests::{private_tracker, sample_ipv4_remote_addr};
+            use crate::servers::udp::handlers::tests::{private_tracker, sample_ipv4_remote_addr, sample_server_socket_addr};
 
             #[tokio::test]
             async fn should_return_zeroed_statistics_when_the_tracker_does_not_have_the_requested_torrent() {
@@ -1208,7 +1298,12 @@ mod tests {
 
                 let request = build_scrape_request(&remote_addr, &non_existing_info_hash);
 
-                let torrent_stats = match_scrape_response(handle_scrape(remote_addr, &request, &tracker).await.unwrap()).unwrap();
+                let torrent_stats = match_scrape_response(
+                    handle_scrape(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                        .await
+                        .unwrap(),
+                )
+                .unwrap();
 
                 let expected_torrent_stats = vec![zeroed_torrent_statistics()];
 
@@ -1235,7 +1330,7 @@ mod tests {
             use crate::servers::udp::handlers::tests::scrape_request::{
                 add_a_seeder, build_scrape_request, match_scrape_response, zeroed_torrent_statistics,
             };
-            use crate::servers::udp::handlers::tests::{sample_ipv4_remote_addr, whitelisted_tracker};
+            use crate::servers::udp::handlers::tests::{sample_ipv4_remote_addr, sample_server_socket_addr, whitelisted_tracker};
 
             #[tokio::test]
             async fn should_return_the_torrent_statistics_when_the_requested_torrent_is_whitelisted() {
@@ -1250,7 +1345,12 @@ mod tests {
 
                 let request = build_scrape_request(&remote_addr, &info_hash);
 
-                let torrent_stats = match_scrape_response(handle_scrape(remote_addr, &request, &tracker).await.unwrap()).unwrap();
+                let torrent_stats = match_scrape_response(
+                    handle_scrape(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                        .await
+                        .unwrap(),
+                )
+                .unwrap();
 
                 let expected_torrent_stats = vec![TorrentScrapeStatistics {
                     seeders: NumberOfPeers(1.into()),
@@ -1272,7 +1372,12 @@ mod tests {
 
                 let request = build_scrape_request(&remote_addr, &info_hash);
 
-                let torrent_stats = match_scrape_response(handle_scrape(remote_addr, &request, &tracker).await.unwrap()).unwrap();
+                let torrent_stats = match_scrape_response(
+                    handle_scrape(remote_addr, sample_server_socket_addr(), &request, &tracker)
+                        .await
+                        .unwrap(),
+                )
+                .unwrap();
 
                 let expected_torrent_stats = vec![zeroed_torrent_statistics()];
 
@@ -1300,14 +1405,19 @@ mod tests {
             use super::sample_scrape_request;
             use crate::core::{self, statistics};
             use crate::servers::udp::handlers::handle_scrape;
-            use crate::servers::udp::handlers::tests::{sample_ipv4_remote_addr, tracker_configuration};
+            use crate::servers::udp::handlers::tests::{
+                sample_ipv4_remote_addr, sample_request_event, sample_server_socket_addr, tracker_configuration,
+            };
 
             #[tokio::test]
             async fn should_send_the_upd4_scrape_event() {
                 let mut stats_event_sender_mock = statistics::MockEventSender::new();
                 stats_event_sender_mock
                     .expect_send_event()
-                    .with(eq(statistics::Event::Udp4Scrape))
+                    .with(eq(sample_request_event(
+                        statistics::IpVersion::V4,
+                        statistics::RequestKind::Scrape,
+                    )))
                     .times(1)
                     .returning(|_| Box::pin(future::ready(Some(Ok(())))));
                 let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -1322,9 +1432,14 @@ mod tests {
                     .unwrap(),
                 );
 
-                handle_scrape(remote_addr, &sample_scrape_request(&remote_addr), &tracker)
-                    .await
-                    .unwrap();
+                handle_scrape(
+                    remote_addr,
+                    sample_server_socket_addr(),
+                    &sample_scrape_request(&remote_addr),
+                    &tracker,
+                )
+                .await
+                .unwrap();
             }
         }
 
@@ -1337,14 +1452,19 @@ mod tests {
             use super::sample_scrape_request;
             use crate::core::{self, statistics};
             use crate::servers::udp::handlers::handle_scrape;
-            use crate::servers::udp::handlers::tests::{sample_ipv6_remote_addr, tracker_configuration};
+            use crate::servers::udp::handlers::tests::{
+                sample_ipv6_remote_addr, sample_request_event, sample_server_socket_addr, tracker_configuration,
+            };
 
             #[tokio::test]
             async fn should_send_the_upd6_scrape_event() {
                 let mut stats_event_sender_mock = statistics::MockEventSender::new();
                 stats_event_sender_mock
                     .expect_send_event()
-                    .with(eq(statistics::Event::Udp6Scrape))
+                    .with(eq(sample_request_event(
+                        statistics::IpVersion::V6,
+                        statistics::RequestKind::Scrape,
+                    )))
                     .times(1)
                     .returning(|_| Box::pin(future::ready(Some(Ok(())))));
                 let stats_event_sender = Box::new(stats_event_sender_mock);
@@ -1359,9 +1479,14 @@ mod tests {
                     .unwrap(),
                 );
 
-                handle_scrape(remote_addr, &sample_scrape_request(&remote_addr), &tracker)
-                    .await
-                    .unwrap();
+                handle_scrape(
+                    remote_addr,
+                    sample_server_socket_addr(),
+                    &sample_scrape_request(&remote_addr),
+                    &tracker,
+                )
+                .await
+                .unwrap();
             }
         }
     }
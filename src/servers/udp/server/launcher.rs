@@ -11,6 +11,7 @@ use tracing::instrument;
 
 use super::request_buffer::ActiveRequests;
 use crate::bootstrap::jobs::Started;
+use crate::core::statistics::Scheme;
 use crate::core::Tracker;
 use crate::servers::logging::STARTED_ON;
 use crate::servers::registar::ServiceHealthCheckJob;
@@ -109,6 +110,8 @@ impl Launcher {
         let addr = receiver.bound_socket_address();
         let local_addr = format!("udp://{addr}");
 
+        tracker.register_stats_listener(Scheme::Udp, addr);
+
         loop {
             let processor = Processor::new(receiver.socket.clone(), tracker.clone());
 
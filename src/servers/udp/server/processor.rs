@@ -1,11 +1,13 @@
 use std::io::Cursor;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Instant;
 
 use aquatic_udp_protocol::Response;
 use tracing::{instrument, Level};
 
 use super::bound_socket::BoundSocket;
+use crate::core::statistics::{self, RequestType};
 use crate::core::Tracker;
 use crate::servers::udp::{handlers, RawRequest};
 
@@ -22,10 +24,32 @@ impl Processor {
     #[instrument(skip(self, request))]
     pub async fn process_request(self, request: RawRequest) {
         let from = request.from;
+        let start_time = Instant::now();
         let response = handlers::handle_packet(request, &self.tracker, self.socket.address()).await;
+        self.record_latency(&response, start_time).await;
         self.send_response(from, response).await;
     }
 
+    /// It sends the time it took to handle the request to the statistics.
+    ///
+    /// Error responses are not recorded because we do not always know the
+    /// type of the request that failed.
+    async fn record_latency(&self, response: &Response, start_time: Instant) {
+        let request = match response {
+            Response::Connect(_) => RequestType::UdpConnect,
+            Response::AnnounceIpv4(_) | Response::AnnounceIpv6(_) => RequestType::UdpAnnounce,
+            Response::Scrape(_) => RequestType::UdpScrape,
+            Response::Error(_) => return,
+        };
+
+        self.tracker
+            .send_stats_event(statistics::Event::Latency {
+                request,
+                latency: start_time.elapsed(),
+            })
+            .await;
+    }
+
     #[instrument(skip(self))]
     async fn send_response(self, target: SocketAddr, response: Response) {
         tracing::debug!("send response");
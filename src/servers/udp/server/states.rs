@@ -11,7 +11,7 @@ use super::spawner::Spawner;
 use super::{Server, UdpError};
 use crate::bootstrap::jobs::Started;
 use crate::core::Tracker;
-use crate::servers::registar::{ServiceRegistration, ServiceRegistrationForm};
+use crate::servers::registar::{Service, ServiceRegistration, ServiceRegistrationForm};
 use crate::servers::signals::Halted;
 use crate::servers::udp::server::launcher::Launcher;
 use crate::servers::udp::UDP_TRACKER_LOG_TARGET;
@@ -73,7 +73,7 @@ impl Server<Stopped> {
 
         let local_addr = rx_start.await.expect("it should be able to start the service").address;
 
-        form.send(ServiceRegistration::new(local_addr, Launcher::check))
+        form.send(ServiceRegistration::new(local_addr, Service::UdpTracker, Launcher::check))
             .expect("it should be able to send service registration");
 
         let running_udp_server: Server<Running> = Server {
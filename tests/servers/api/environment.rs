@@ -5,7 +5,9 @@ use bittorrent_primitives::info_hash::InfoHash;
 use futures::executor::block_on;
 use torrust_tracker::bootstrap::app::initialize_with_configuration;
 use torrust_tracker::bootstrap::jobs::make_rust_tls;
-use torrust_tracker::core::Tracker;
+use torrust_tracker::bootstrap::reload::ConfigReloader;
+use torrust_tracker::core::statistics::Scheme;
+use torrust_tracker::core::{PeersWanted, Tracker};
 use torrust_tracker::servers::apis::server::{ApiServer, Launcher, Running, Stopped};
 use torrust_tracker::servers::registar::Registar;
 use torrust_tracker_configuration::{Configuration, HttpApi};
@@ -19,6 +21,7 @@ where
 {
     pub config: Arc<HttpApi>,
     pub tracker: Arc<Tracker>,
+    pub reloader: Arc<ConfigReloader>,
     pub registar: Registar,
     pub server: ApiServer<S>,
 }
@@ -31,12 +34,23 @@ where
     pub fn add_torrent_peer(&self, info_hash: &InfoHash, peer: &peer::Peer) {
         self.tracker.upsert_peer_and_get_stats(info_hash, peer);
     }
+
+    /// Announce a peer like the HTTP or UDP trackers do, publishing the live
+    /// events.
+    pub fn announce_peer(&self, scheme: Scheme, info_hash: &InfoHash, peer: &peer::Peer) {
+        let mut peer = *peer;
+        let peer_ip = peer.peer_addr.ip();
+        let announce_data = self.tracker.announce(info_hash, &mut peer, &peer_ip, &PeersWanted::All);
+        self.tracker.publish_announce_events(scheme, info_hash, &peer, &announce_data);
+    }
 }
 
 impl Environment<Stopped> {
     pub fn new(configuration: &Arc<Configuration>) -> Self {
         let tracker = initialize_with_configuration(configuration);
 
+        let reloader = Arc::new(ConfigReloader::new(configuration, tracker.clone()));
+
         let config = Arc::new(configuration.http_api.clone().expect("missing API configuration"));
 
         let bind_to = config.bind_address;
@@ -48,21 +62,27 @@ impl Environment<Stopped> {
         Self {
             config,
             tracker,
+            reloader,
             registar: Registar::default(),
             server,
         }
     }
 
     pub async fn start(self) -> Environment<Running> {
-        let access_tokens = Arc::new(self.config.access_tokens.clone());
-
         Environment {
-            config: self.config,
+            config: self.config.clone(),
             tracker: self.tracker.clone(),
+            reloader: self.reloader.clone(),
             registar: self.registar.clone(),
             server: self
                 .server
-                .start(self.tracker, self.registar.give_form(), access_tokens)
+                .start(
+                    self.tracker,
+                    self.registar.give_form(),
+                    self.config,
+                    self.reloader,
+                    self.registar.entries(),
+                )
                 .await
                 .unwrap(),
         }
@@ -78,6 +98,7 @@ impl Environment<Running> {
         Environment {
             config: self.config,
             tracker: self.tracker,
+            reloader: self.reloader,
             registar: Registar::default(),
             server: self.server.stop().await.unwrap(),
         }
@@ -86,7 +107,7 @@ impl Environment<Running> {
     pub fn get_connection_info(&self) -> ConnectionInfo {
         ConnectionInfo {
             bind_address: self.server.state.local_addr.to_string(),
-            api_token: self.config.access_tokens.get("admin").cloned(),
+            api_token: self.config.access_tokens.get("admin").map(|token| token.secret().to_string()),
         }
     }
 
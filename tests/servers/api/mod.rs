@@ -6,6 +6,7 @@ use torrust_tracker::servers::apis::server;
 pub mod connection_info;
 pub mod environment;
 pub mod v1;
+pub mod v2;
 
 pub type Started = environment::Environment<server::Running>;
 
@@ -1,9 +1,11 @@
 // code-review: should we use macros to return the exact line where the assert fails?
 
 use reqwest::Response;
-use torrust_tracker::servers::apis::v1::context::auth_key::resources::AuthKey;
+use torrust_tracker::servers::apis::v1::context::auth_key::resources::{AuthKey, AuthKeyDetail, PurgedKeys};
+use torrust_tracker::servers::apis::v1::context::peer::resources::{PeerMatch, RemovedPeers};
 use torrust_tracker::servers::apis::v1::context::stats::resources::Stats;
-use torrust_tracker::servers::apis::v1::context::torrent::resources::torrent::{ListItem, Torrent};
+use torrust_tracker::servers::apis::v1::context::torrent::resources::torrent::{ListItem, RankedTorrent, Torrent};
+use torrust_tracker::servers::apis::v1::context::whitelist::resources::{BulkWhitelistItem, WhitelistDiff, WhitelistEntry};
 
 // Resource responses
 
@@ -13,18 +15,57 @@ pub async fn assert_stats(response: Response, stats: Stats) {
     assert_eq!(response.json::<Stats>().await.unwrap(), stats);
 }
 
+pub async fn assert_prometheus_metrics(response: Response) -> String {
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "text/plain; version=0.0.4; charset=utf-8"
+    );
+    response.text().await.unwrap()
+}
+
 pub async fn assert_torrent_list(response: Response, torrents: Vec<ListItem>) {
     assert_eq!(response.status(), 200);
     assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
     assert_eq!(response.json::<Vec<ListItem>>().await.unwrap(), torrents);
 }
 
+pub async fn assert_top_torrents(response: Response) -> Vec<RankedTorrent> {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    response.json::<Vec<RankedTorrent>>().await.unwrap()
+}
+
+pub async fn assert_torrents_export(response: Response, content_type: &str) -> String {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), content_type);
+    response.text().await.unwrap()
+}
+
 pub async fn assert_torrent_info(response: Response, torrent: Torrent) {
     assert_eq!(response.status(), 200);
     assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
     assert_eq!(response.json::<Torrent>().await.unwrap(), torrent);
 }
 
+pub async fn assert_removed_peers(response: Response, removed_peers: u64) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(response.json::<RemovedPeers>().await.unwrap(), RemovedPeers { removed_peers });
+}
+
+pub async fn assert_purged_keys(response: Response, removed_keys: u64) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(response.json::<PurgedKeys>().await.unwrap(), PurgedKeys { removed_keys });
+}
+
+pub async fn assert_peer_matches(response: Response) -> Vec<PeerMatch> {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    response.json::<Vec<PeerMatch>>().await.unwrap()
+}
+
 pub async fn assert_auth_key_utf8(response: Response) -> AuthKey {
     assert_eq!(response.status(), 200);
     assert_eq!(
@@ -34,6 +75,42 @@ pub async fn assert_auth_key_utf8(response: Response) -> AuthKey {
     response.json::<AuthKey>().await.unwrap()
 }
 
+pub async fn assert_auth_key_list(response: Response) -> Vec<AuthKey> {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    response.json::<Vec<AuthKey>>().await.unwrap()
+}
+
+pub async fn assert_auth_key_detail(response: Response) -> AuthKeyDetail {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    response.json::<AuthKeyDetail>().await.unwrap()
+}
+
+pub async fn assert_whitelist(response: Response) -> Vec<WhitelistEntry> {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    response.json::<Vec<WhitelistEntry>>().await.unwrap()
+}
+
+pub async fn assert_whitelist_entry(response: Response, entry: WhitelistEntry) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(response.json::<WhitelistEntry>().await.unwrap(), entry);
+}
+
+pub async fn assert_whitelist_diff(response: Response, diff: WhitelistDiff) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(response.json::<WhitelistDiff>().await.unwrap(), diff);
+}
+
+pub async fn assert_bulk_whitelist(response: Response, items: Vec<BulkWhitelistItem>) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(response.json::<Vec<BulkWhitelistItem>>().await.unwrap(), items);
+}
+
 // OK response
 
 pub async fn assert_ok(response: Response) {
@@ -73,6 +150,19 @@ pub async fn assert_unprocessable_content(response: Response, text: &str) {
     assert!(response.text().await.unwrap().contains(text));
 }
 
+pub async fn assert_restart_required(response: Response, option: &str) {
+    assert_eq!(response.status(), 409);
+    assert_eq!(response.headers().get("content-type").unwrap(), "text/plain; charset=utf-8");
+
+    let text = response.text().await.unwrap();
+
+    assert!(
+        text.starts_with("Unable to reload the configuration, these options require a restart: "),
+        "{text}"
+    );
+    assert!(text.contains(option), "{text} does not contain {option}");
+}
+
 pub async fn assert_not_found(response: Response) {
     assert_eq!(response.status(), 404);
     // todo: missing header in the response
@@ -86,6 +176,24 @@ pub async fn assert_torrent_not_known(response: Response) {
     assert_eq!(response.text().await.unwrap(), "\"torrent not known\"");
 }
 
+pub async fn assert_peer_not_known(response: Response) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(response.text().await.unwrap(), "\"peer not known\"");
+}
+
+pub async fn assert_auth_key_not_known(response: Response) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(response.text().await.unwrap(), "\"key not known\"");
+}
+
+pub async fn assert_torrent_not_whitelisted(response: Response) {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    assert_eq!(response.text().await.unwrap(), "\"torrent not whitelisted\"");
+}
+
 pub async fn assert_invalid_infohash_param(response: Response, invalid_infohash: &str) {
     assert_bad_request(
         response,
@@ -94,6 +202,22 @@ pub async fn assert_invalid_infohash_param(response: Response, invalid_infohash:
     .await;
 }
 
+pub async fn assert_invalid_peer_id_param(response: Response, invalid_peer_id: &str) {
+    assert_bad_request(
+        response,
+        &format!("Invalid URL: invalid peer id param: string \"{invalid_peer_id}\", expected a 40 character long hex string"),
+    )
+    .await;
+}
+
+pub async fn assert_invalid_ip_param(response: Response, invalid_ip: &str) {
+    assert_bad_request(
+        response,
+        &format!("Invalid URL: invalid ip param: string \"{invalid_ip}\", expected an IPv4 or IPv6 address"),
+    )
+    .await;
+}
+
 pub async fn assert_invalid_auth_key_get_param(response: Response, invalid_auth_key: &str) {
     assert_bad_request(response, &format!("Invalid auth key id param \"{}\"", &invalid_auth_key)).await;
 }
@@ -130,6 +254,18 @@ pub async fn assert_unauthorized(response: Response) {
     assert_unhandled_rejection(response, "unauthorized").await;
 }
 
+pub async fn assert_token_expired(response: Response) {
+    assert_unhandled_rejection(response, "token expired").await;
+}
+
+pub async fn assert_forbidden(response: Response) {
+    assert_unhandled_rejection(response, "forbidden").await;
+}
+
+pub async fn assert_locked_out(response: Response) {
+    assert_unhandled_rejection(response, "too many failed authentication attempts").await;
+}
+
 pub async fn assert_failed_to_remove_torrent_from_whitelist(response: Response) {
     assert_unhandled_rejection(response, "failed to remove torrent from whitelist").await;
 }
@@ -138,14 +274,26 @@ pub async fn assert_failed_to_whitelist_torrent(response: Response) {
     assert_unhandled_rejection(response, "failed to whitelist torrent").await;
 }
 
+pub async fn assert_failed_to_whitelist_torrents(response: Response) {
+    assert_unhandled_rejection(response, "failed to whitelist torrents").await;
+}
+
 pub async fn assert_failed_to_reload_whitelist(response: Response) {
     assert_unhandled_rejection(response, "failed to reload whitelist").await;
 }
 
+pub async fn assert_failed_to_load_whitelist(response: Response) {
+    assert_unhandled_rejection(response, "failed to load whitelist").await;
+}
+
 pub async fn assert_failed_to_generate_key(response: Response) {
     assert_unhandled_rejection(response, "failed to generate key").await;
 }
 
+pub async fn assert_failed_to_generate_keys(response: Response) {
+    assert_unhandled_rejection(response, "failed to generate keys").await;
+}
+
 pub async fn assert_failed_to_delete_key(response: Response) {
     assert_unhandled_rejection(response, "failed to delete key").await;
 }
@@ -154,6 +302,10 @@ pub async fn assert_failed_to_reload_keys(response: Response) {
     assert_unhandled_rejection(response, "failed to reload keys").await;
 }
 
+pub async fn assert_failed_to_purge_keys(response: Response) {
+    assert_unhandled_rejection(response, "failed to purge keys").await;
+}
+
 async fn assert_unhandled_rejection(response: Response, reason: &str) {
     assert_eq!(response.status(), 500);
     assert_eq!(response.headers().get("content-type").unwrap(), "text/plain; charset=utf-8");
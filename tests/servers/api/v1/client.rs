@@ -1,4 +1,6 @@
-use reqwest::Response;
+use std::time::Duration;
+
+use reqwest::{Method, Response};
 use serde::Serialize;
 
 use crate::common::http::{Query, QueryParam, ReqwestQuery};
@@ -26,38 +28,145 @@ impl Client {
         self.post_form("keys", &add_key_form).await
     }
 
+    pub async fn generate_auth_keys(&self, generate_keys_form: GenerateKeysForm) -> Response {
+        self.post_form("keys/bulk", &generate_keys_form).await
+    }
+
+    pub async fn get_auth_keys(&self, params: Query) -> Response {
+        self.get("keys", params).await
+    }
+
+    pub async fn get_auth_key(&self, key: &str) -> Response {
+        self.get(&format!("key/{}", &key), Query::default()).await
+    }
+
     pub async fn delete_auth_key(&self, key: &str) -> Response {
-        self.delete(&format!("key/{}", &key)).await
+        self.delete(&format!("key/{}", &key), Query::default()).await
     }
 
     pub async fn reload_keys(&self) -> Response {
         self.get("keys/reload", Query::default()).await
     }
 
+    pub async fn purge_expired_keys(&self) -> Response {
+        self.post_empty("keys/purge").await
+    }
+
+    pub async fn get_whitelist(&self, params: Query) -> Response {
+        self.get("whitelist", params).await
+    }
+
+    pub async fn get_whitelist_entry(&self, info_hash: &str) -> Response {
+        self.get(&format!("whitelist/{}", &info_hash), Query::default()).await
+    }
+
+    pub async fn get_whitelist_diff(&self) -> Response {
+        self.get("whitelist/diff", Query::default()).await
+    }
+
     pub async fn whitelist_a_torrent(&self, info_hash: &str) -> Response {
         self.post_empty(&format!("whitelist/{}", &info_hash)).await
     }
 
     pub async fn remove_torrent_from_whitelist(&self, info_hash: &str) -> Response {
-        self.delete(&format!("whitelist/{}", &info_hash)).await
+        self.delete(&format!("whitelist/{}", &info_hash), Query::default()).await
+    }
+
+    pub async fn whitelist_torrents(&self, content_type: &str, body: &str) -> Response {
+        self.send_body(Method::POST, "whitelist/bulk", content_type, body).await
+    }
+
+    pub async fn remove_torrents_from_whitelist(&self, content_type: &str, body: &str) -> Response {
+        self.send_body(Method::DELETE, "whitelist/bulk", content_type, body).await
     }
 
     pub async fn reload_whitelist(&self) -> Response {
         self.get("whitelist/reload", Query::default()).await
     }
 
+    pub async fn reload_config(&self) -> Response {
+        self.post_empty("config/reload").await
+    }
+
     pub async fn get_torrent(&self, info_hash: &str) -> Response {
         self.get(&format!("torrent/{}", &info_hash), Query::default()).await
     }
 
+    pub async fn remove_torrent(&self, info_hash: &str, params: Query) -> Response {
+        self.delete(&format!("torrent/{}", &info_hash), params).await
+    }
+
+    pub async fn remove_peer(&self, info_hash: &str, peer_id: &str) -> Response {
+        self.delete(&format!("torrent/{}/peer/{}", &info_hash, &peer_id), Query::default())
+            .await
+    }
+
+    pub async fn search_peers(&self, params: Query) -> Response {
+        self.get("peers", params).await
+    }
+
+    pub async fn remove_peers_with_ip(&self, ip: &str) -> Response {
+        self.delete(&format!("peers/{}", &ip), Query::default()).await
+    }
+
     pub async fn get_torrents(&self, params: Query) -> Response {
         self.get("torrents", params).await
     }
 
+    pub async fn get_top_torrents(&self, params: Query) -> Response {
+        self.get("torrents/top", params).await
+    }
+
+    pub async fn export_torrents(&self, params: Query) -> Response {
+        self.get("torrents/export", params).await
+    }
+
+    pub async fn get_events(&self, params: Query) -> Response {
+        self.get("events", params).await
+    }
+
     pub async fn get_tracker_statistics(&self) -> Response {
         self.get("stats", Query::default()).await
     }
 
+    /// It gets the tracker statistics sending an `X-Forwarded-For` header.
+    pub async fn get_tracker_statistics_forwarded_for(&self, ip: &str) -> Response {
+        reqwest::Client::new()
+            .get(self.base_url("stats"))
+            .query(&ReqwestQuery::from(self.query_with_token()))
+            .header("X-Forwarded-For", ip)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_tracker_info(&self) -> Response {
+        self.get("info", Query::default()).await
+    }
+
+    pub async fn get_audit_log(&self, params: Query) -> Response {
+        self.get("audit_log", params).await
+    }
+
+    pub async fn get_clients_breakdown(&self) -> Response {
+        self.get("clients", Query::default()).await
+    }
+
+    pub async fn get_torrent_clients_breakdown(&self, info_hash: &str) -> Response {
+        self.get(&format!("clients/{}", &info_hash), Query::default()).await
+    }
+
+    pub async fn get_tracker_statistics_history(&self, params: Query) -> Response {
+        self.get("stats/history", params).await
+    }
+
+    pub async fn get_prometheus_metrics(&self) -> Response {
+        // The metrics endpoint does not use the API prefix
+        let url = format!("http://{}/metrics", &self.connection_info.bind_address);
+
+        get(&url, Some(self.query_with_token())).await
+    }
+
     pub async fn get(&self, path: &str, params: Query) -> Response {
         let mut query: Query = params;
 
@@ -87,10 +196,27 @@ impl Client {
             .unwrap()
     }
 
-    async fn delete(&self, path: &str) -> Response {
+    pub async fn send_body(&self, method: Method, path: &str, content_type: &str, body: &str) -> Response {
         reqwest::Client::new()
-            .delete(self.base_url(path).clone())
+            .request(method, self.base_url(path))
             .query(&ReqwestQuery::from(self.query_with_token()))
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body.to_string())
+            .send()
+            .await
+            .unwrap()
+    }
+
+    async fn delete(&self, path: &str, params: Query) -> Response {
+        let mut query: Query = params;
+
+        if let Some(token) = &self.connection_info.api_token {
+            query.add_param(QueryParam::new("token", token));
+        };
+
+        reqwest::Client::new()
+            .delete(self.base_url(path).clone())
+            .query(&ReqwestQuery::from(query))
             .send()
             .await
             .unwrap()
@@ -104,6 +230,15 @@ impl Client {
         get(&self.base_url(path), None).await
     }
 
+    pub async fn get_request_with_bearer_token(&self, path: &str, token: &str) -> Response {
+        reqwest::Client::new()
+            .get(self.base_url(path))
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap()
+    }
+
     fn query_with_token(&self) -> Query {
         match &self.connection_info.api_token {
             Some(token) => Query::params([QueryParam::new("token", token)].to_vec()),
@@ -130,9 +265,47 @@ pub async fn get(path: &str, query: Option<Query>) -> Response {
     }
 }
 
+/// It reads the next Server-Sent Event from a streaming response and returns
+/// its name and data.
+///
+/// # Panics
+///
+/// Will panic if the event is not received in five seconds.
+pub async fn next_sse_event(response: &mut Response) -> (String, String) {
+    let mut received = String::new();
+
+    while !received.contains("\n\n") {
+        let chunk = tokio::time::timeout(Duration::from_secs(5), response.chunk())
+            .await
+            .expect("it should receive an event before the timeout")
+            .unwrap()
+            .expect("the stream should not end");
+
+        received.push_str(&String::from_utf8_lossy(&chunk));
+    }
+
+    let (mut name, mut data) = (String::new(), String::new());
+
+    for line in received.split("\n\n").next().unwrap().lines() {
+        if let Some(value) = line.strip_prefix("event:") {
+            name = value.trim().to_string();
+        } else if let Some(value) = line.strip_prefix("data:") {
+            data = value.trim().to_string();
+        }
+    }
+
+    (name, data)
+}
+
 #[derive(Serialize, Debug)]
 pub struct AddKeyForm {
     #[serde(rename = "key")]
     pub opt_key: Option<String>,
     pub seconds_valid: Option<u64>,
 }
+
+#[derive(Serialize, Debug)]
+pub struct GenerateKeysForm {
+    pub count: usize,
+    pub seconds_valid: Option<u64>,
+}
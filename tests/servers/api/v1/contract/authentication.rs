@@ -1,12 +1,31 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use torrust_tracker_configuration::{Configuration, Role};
 use torrust_tracker_test_helpers::configuration;
 use tracing::level_filters::LevelFilter;
 
 use crate::common::http::{Query, QueryParam};
 use crate::common::logging::{tracing_stderr_init, INIT};
-use crate::servers::api::v1::asserts::{assert_token_not_valid, assert_unauthorized};
+use crate::servers::api::connection_info::{connection_with_invalid_token, ConnectionInfo};
+use crate::servers::api::v1::asserts::{
+    assert_forbidden, assert_locked_out, assert_token_expired, assert_token_not_valid, assert_unauthorized,
+};
 use crate::servers::api::v1::client::Client;
 use crate::servers::api::Started;
 
+/// A configuration with an extra token for the API.
+fn configuration_with_scoped_token(token: &str, role: Role, valid_until: Option<u64>) -> Configuration {
+    let mut configuration = configuration::ephemeral();
+
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .add_scoped_token("scoped", token, role, valid_until);
+
+    configuration
+}
+
 #[tokio::test]
 async fn should_authenticate_requests_by_using_a_token_query_param() {
     INIT.call_once(|| {
@@ -103,3 +122,268 @@ async fn should_allow_the_token_query_param_to_be_at_any_position_in_the_url_que
 
     env.stop().await;
 }
+
+#[tokio::test]
+async fn should_authenticate_requests_by_using_an_authorization_bearer_header() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let token = env.get_connection_info().api_token.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .get_request_with_bearer_token("stats", &token)
+        .await;
+
+    assert_eq!(response.status(), 200);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_authenticate_requests_with_an_invalid_authorization_bearer_header() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_request_with_bearer_token("stats", "INVALID TOKEN")
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_authenticate_requests_when_the_token_has_expired() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_scoped_token("ExpiredToken", Role::Admin, Some(1)).into()).await;
+
+    let response = Client::new(ConnectionInfo::authenticated(
+        &env.get_connection_info().bind_address,
+        "ExpiredToken",
+    ))
+    .get_tracker_statistics()
+    .await;
+
+    assert_token_expired(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_only_allow_the_requests_permitted_by_the_token_role() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_scoped_token("StatsToken", Role::StatsReader, None).into()).await;
+
+    let client = Client::new(ConnectionInfo::authenticated(
+        &env.get_connection_info().bind_address,
+        "StatsToken",
+    ));
+
+    assert_eq!(client.get_tracker_statistics().await.status(), 200);
+    assert_eq!(client.get_prometheus_metrics().await.status(), 200);
+
+    assert_forbidden(client.get_torrents(Query::default()).await).await;
+    assert_forbidden(client.reload_keys().await).await;
+    assert_forbidden(client.reload_whitelist().await).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_count_the_failed_authentication_attempts() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let client = Client::new(env.get_connection_info());
+
+    client
+        .get_request_with_query("stats", Query::params([QueryParam::new("token", "INVALID TOKEN")].to_vec()))
+        .await;
+    client.get_request_with_query("stats", Query::default()).await;
+
+    assert_eq!(env.tracker.get_stats().api_auth_failures, 2);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_only_allow_the_admin_tokens_to_manage_the_swarms_and_the_configuration() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_scoped_token("ViewerToken", Role::TorrentViewer, None).into()).await;
+
+    let client = Client::new(ConnectionInfo::authenticated(
+        &env.get_connection_info().bind_address,
+        "ViewerToken",
+    ));
+
+    let info_hash = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d";
+
+    assert_eq!(client.get_torrent(info_hash).await.status(), 200);
+
+    assert_forbidden(client.remove_torrent(info_hash, Query::default()).await).await;
+    assert_forbidden(
+        client
+            .remove_peer(info_hash, "0x2d71423030303030303030303030303030303030")
+            .await,
+    )
+    .await;
+    assert_forbidden(client.remove_peers_with_ip("127.0.0.1").await).await;
+    assert_forbidden(client.reload_config().await).await;
+
+    env.stop().await;
+}
+
+/// A configuration that locks the API clients out after two invalid tokens.
+fn configuration_with_lockout_after_two_failures() -> Configuration {
+    let mut configuration = configuration::ephemeral();
+
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .auth_throttling
+        .max_failures = 2;
+
+    configuration
+}
+
+#[tokio::test]
+async fn should_lock_a_client_out_after_too_many_invalid_tokens() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_lockout_after_two_failures().into()).await;
+
+    let invalid_client = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()));
+
+    assert_token_not_valid(invalid_client.get_tracker_statistics().await).await;
+    assert_token_not_valid(invalid_client.get_tracker_statistics().await).await;
+
+    // Even the requests with a valid token are rejected
+    assert_locked_out(Client::new(env.get_connection_info()).get_tracker_statistics().await).await;
+
+    let stats = env.tracker.get_stats();
+
+    assert_eq!(stats.api_auth_failures, 2);
+    assert_eq!(stats.api_auth_lockouts, 1);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_lock_a_client_out_for_missing_tokens() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_lockout_after_two_failures().into()).await;
+
+    let client = Client::new(env.get_connection_info());
+
+    assert_unauthorized(client.get_request_with_query("stats", Query::default()).await).await;
+    assert_unauthorized(client.get_request_with_query("stats", Query::default()).await).await;
+
+    assert_eq!(client.get_tracker_statistics().await.status(), 200);
+
+    env.stop().await;
+}
+
+/// A configuration that locks the API clients out after two invalid tokens,
+/// with the tracker running behind a reverse proxy.
+fn configuration_with_lockout_behind_a_reverse_proxy() -> Configuration {
+    let mut configuration = configuration_with_lockout_after_two_failures();
+
+    configuration.core.net.on_reverse_proxy = true;
+
+    configuration
+}
+
+#[tokio::test]
+async fn should_lock_a_client_out_when_it_does_not_send_the_forwarded_for_header_behind_a_reverse_proxy() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_lockout_behind_a_reverse_proxy().into()).await;
+
+    let invalid_client = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()));
+
+    assert_token_not_valid(invalid_client.get_tracker_statistics().await).await;
+    assert_token_not_valid(invalid_client.get_tracker_statistics().await).await;
+
+    assert_locked_out(Client::new(env.get_connection_info()).get_tracker_statistics().await).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_lock_a_client_out_when_it_changes_the_forwarded_for_header_and_it_is_not_a_trusted_proxy() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_lockout_behind_a_reverse_proxy().into()).await;
+
+    let invalid_client = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()));
+
+    assert_token_not_valid(invalid_client.get_tracker_statistics_forwarded_for("203.0.113.1").await).await;
+    assert_token_not_valid(invalid_client.get_tracker_statistics_forwarded_for("203.0.113.2").await).await;
+
+    assert_locked_out(
+        Client::new(env.get_connection_info())
+            .get_tracker_statistics_forwarded_for("203.0.113.3")
+            .await,
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_take_the_client_ip_from_the_forwarded_for_header_sent_by_a_trusted_proxy() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let mut configuration = configuration_with_lockout_after_two_failures();
+
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .trusted_proxies = vec![IpAddr::V4(Ipv4Addr::LOCALHOST)];
+
+    let env = Started::new(&configuration.into()).await;
+
+    let invalid_client = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()));
+
+    assert_token_not_valid(invalid_client.get_tracker_statistics_forwarded_for("203.0.113.1").await).await;
+    assert_token_not_valid(invalid_client.get_tracker_statistics_forwarded_for("203.0.113.1").await).await;
+
+    let client = Client::new(env.get_connection_info());
+
+    assert_locked_out(client.get_tracker_statistics_forwarded_for("203.0.113.1").await).await;
+    assert_eq!(client.get_tracker_statistics_forwarded_for("203.0.113.2").await.status(), 200);
+
+    env.stop().await;
+}
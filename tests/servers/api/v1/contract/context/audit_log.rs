@@ -0,0 +1,164 @@
+use torrust_tracker::servers::apis::v1::context::audit_log::resources::AuditLogEntry;
+use torrust_tracker_configuration::{Configuration, Role};
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token, ConnectionInfo};
+use crate::servers::api::v1::asserts::{assert_forbidden, assert_token_not_valid, assert_unauthorized};
+use crate::servers::api::v1::client::Client;
+use crate::servers::api::Started;
+
+/// A configuration with an extra token for the API.
+fn configuration_with_scoped_token(token: &str, role: Role) -> Configuration {
+    let mut configuration = configuration::ephemeral();
+
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .add_scoped_token("scoped", token, role, None);
+
+    configuration
+}
+
+#[tokio::test]
+async fn should_record_the_requests_that_change_the_tracker_state_in_the_audit_log() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d";
+
+    let client = Client::new(env.get_connection_info());
+
+    assert_eq!(client.whitelist_a_torrent(info_hash).await.status(), 200);
+
+    let response = client.get_audit_log(Query::default()).await;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("x-total-count").unwrap(), "1");
+
+    let entries = response.json::<Vec<AuditLogEntry>>().await.unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].label, "admin");
+    assert_eq!(entries[0].action, "POST /api/v1/whitelist/:info_hash");
+    assert_eq!(entries[0].target, info_hash);
+    assert_eq!(entries[0].result, 200);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_record_the_read_only_requests_in_the_audit_log() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let client = Client::new(env.get_connection_info());
+
+    assert_eq!(client.get_tracker_statistics().await.status(), 200);
+    assert_eq!(client.get_whitelist(Query::default()).await.status(), 200);
+
+    let response = client.get_audit_log(Query::default()).await;
+
+    assert_eq!(response.json::<Vec<AuditLogEntry>>().await.unwrap(), vec![]);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_record_the_rejected_requests_in_the_audit_log() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_scoped_token("StatsToken", Role::StatsReader).into()).await;
+
+    let scoped_client = Client::new(ConnectionInfo::authenticated(
+        &env.get_connection_info().bind_address,
+        "StatsToken",
+    ));
+
+    assert_forbidden(scoped_client.reload_whitelist().await).await;
+
+    let response = Client::new(env.get_connection_info()).get_audit_log(Query::default()).await;
+
+    let entries = response.json::<Vec<AuditLogEntry>>().await.unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].label, "scoped");
+    assert_eq!(entries[0].action, "GET /api/v1/whitelist/reload");
+    assert_eq!(entries[0].result, 500);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_paginating_the_audit_log_from_the_newest_entry() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let client = Client::new(env.get_connection_info());
+
+    client.whitelist_a_torrent("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").await;
+    client.whitelist_a_torrent("0b3aea4adc213ce32295be85d3883a63bca25446").await;
+
+    let response = client
+        .get_audit_log(Query::params(
+            [QueryParam::new("offset", "0"), QueryParam::new("limit", "1")].to_vec(),
+        ))
+        .await;
+
+    assert_eq!(response.headers().get("x-total-count").unwrap(), "2");
+
+    let entries = response.json::<Vec<AuditLogEntry>>().await.unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].target, "0b3aea4adc213ce32295be85d3883a63bca25446");
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_only_allow_the_admin_tokens_to_get_the_audit_log() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration_with_scoped_token("ViewerToken", Role::TorrentViewer).into()).await;
+
+    let bind_address = env.get_connection_info().bind_address;
+
+    assert_forbidden(
+        Client::new(ConnectionInfo::authenticated(&bind_address, "ViewerToken"))
+            .get_audit_log(Query::default())
+            .await,
+    )
+    .await;
+
+    assert_token_not_valid(
+        Client::new(connection_with_invalid_token(bind_address.as_str()))
+            .get_audit_log(Query::default())
+            .await,
+    )
+    .await;
+
+    assert_unauthorized(
+        Client::new(connection_with_no_token(bind_address.as_str()))
+            .get_audit_log(Query::default())
+            .await,
+    )
+    .await;
+
+    env.stop().await;
+}
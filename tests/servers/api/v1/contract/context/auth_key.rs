@@ -1,18 +1,20 @@
 use std::time::Duration;
 
 use serde::Serialize;
-use torrust_tracker::core::auth::Key;
+use torrust_tracker::core::auth::{generate_permanent_key, Key};
 use torrust_tracker_test_helpers::configuration;
 use tracing::level_filters::LevelFilter;
 
+use crate::common::http::{Query, QueryParam};
 use crate::common::logging::{tracing_stderr_init, INIT};
 use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token};
 use crate::servers::api::v1::asserts::{
-    assert_auth_key_utf8, assert_failed_to_delete_key, assert_failed_to_generate_key, assert_failed_to_reload_keys,
-    assert_invalid_auth_key_get_param, assert_invalid_auth_key_post_param, assert_ok, assert_token_not_valid,
-    assert_unauthorized, assert_unprocessable_auth_key_duration_param,
+    assert_auth_key_detail, assert_auth_key_list, assert_auth_key_not_known, assert_auth_key_utf8, assert_bad_request,
+    assert_failed_to_delete_key, assert_failed_to_generate_key, assert_failed_to_generate_keys, assert_failed_to_purge_keys,
+    assert_failed_to_reload_keys, assert_invalid_auth_key_get_param, assert_invalid_auth_key_post_param, assert_ok,
+    assert_purged_keys, assert_token_not_valid, assert_unauthorized, assert_unprocessable_auth_key_duration_param,
 };
-use crate::servers::api::v1::client::{AddKeyForm, Client};
+use crate::servers::api::v1::client::{AddKeyForm, Client, GenerateKeysForm};
 use crate::servers::api::{force_database_error, Started};
 
 #[tokio::test]
@@ -386,6 +388,343 @@ async fn should_not_allow_reloading_keys_for_unauthenticated_users() {
     env.stop().await;
 }
 
+#[tokio::test]
+async fn should_allow_purging_the_expired_keys() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let expired_key = generate_permanent_key().key();
+    env.tracker
+        .add_auth_key(expired_key.clone(), Some(Duration::from_secs(1)))
+        .await
+        .unwrap();
+    let expiring_key = env.tracker.generate_auth_key(Some(Duration::from_secs(60))).await.unwrap();
+    let permanent_key = env.tracker.generate_permanent_auth_key().await.unwrap();
+
+    let response = Client::new(env.get_connection_info()).purge_expired_keys().await;
+
+    assert_purged_keys(response, 1).await;
+
+    assert!(env.tracker.get_auth_key(&expired_key).await.is_none());
+    assert!(env.tracker.get_auth_key(&expiring_key.key()).await.is_some());
+    assert!(env.tracker.get_auth_key(&permanent_key.key()).await.is_some());
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_when_the_expired_keys_cannot_be_purged() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    force_database_error(&env.tracker);
+
+    let response = Client::new(env.get_connection_info()).purge_expired_keys().await;
+
+    assert_failed_to_purge_keys(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_purging_the_expired_keys_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .purge_expired_keys()
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .purge_expired_keys()
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_listing_the_auth_keys() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let permanent_key = env.tracker.generate_permanent_auth_key().await.unwrap();
+    let expiring_key = env.tracker.generate_auth_key(Some(Duration::from_secs(60))).await.unwrap();
+
+    let mut expected_keys = vec![permanent_key.key.to_string(), expiring_key.key.to_string()];
+    expected_keys.sort();
+
+    let response = Client::new(env.get_connection_info()).get_auth_keys(Query::empty()).await;
+
+    let keys = assert_auth_key_list(response).await;
+
+    assert_eq!(keys.into_iter().map(|k| k.key).collect::<Vec<_>>(), expected_keys);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_filtering_the_auth_keys() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let permanent_key = env.tracker.generate_permanent_auth_key().await.unwrap();
+    let expired_key = env
+        .tracker
+        .add_auth_key(
+            "Xc1L4PbQJSFGlrgSRZl8wxSFAuMa21z5".parse::<Key>().unwrap(),
+            Some(Duration::from_secs(1)),
+        )
+        .await
+        .unwrap();
+
+    let client = Client::new(env.get_connection_info());
+
+    let keys = assert_auth_key_list(
+        client
+            .get_auth_keys(Query::params([QueryParam::new("permanent", "true")].to_vec()))
+            .await,
+    )
+    .await;
+
+    assert_eq!(
+        keys.into_iter().map(|k| k.key).collect::<Vec<_>>(),
+        vec![permanent_key.key.to_string()]
+    );
+
+    let keys = assert_auth_key_list(
+        client
+            .get_auth_keys(Query::params([QueryParam::new("expired", "true")].to_vec()))
+            .await,
+    )
+    .await;
+
+    assert_eq!(
+        keys.into_iter().map(|k| k.key).collect::<Vec<_>>(),
+        vec![expired_key.key.to_string()]
+    );
+
+    let keys = assert_auth_key_list(
+        client
+            .get_auth_keys(Query::params([QueryParam::new("expiring_before", "2")].to_vec()))
+            .await,
+    )
+    .await;
+
+    assert_eq!(
+        keys.into_iter().map(|k| k.key).collect::<Vec<_>>(),
+        vec![expired_key.key.to_string()]
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_getting_an_auth_key() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let auth_key = env.tracker.generate_auth_key(Some(Duration::from_secs(60))).await.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .get_auth_key(&auth_key.key.to_string())
+        .await;
+
+    let detail = assert_auth_key_detail(response).await;
+
+    assert_eq!(detail.key, auth_key.key.to_string());
+    assert_eq!(
+        detail.valid_until,
+        auth_key.valid_until.map(|valid_until| valid_until.as_secs())
+    );
+    assert!(!detail.expired);
+    assert_eq!(detail.active_peers, 0);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_an_auth_key_when_the_tracker_does_not_have_it() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_auth_key("Xc1L4PbQJSFGlrgSRZl8wxSFAuMa21z5")
+        .await;
+
+    assert_auth_key_not_known(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_an_auth_key_when_the_key_id_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).get_auth_key("INVALID KEY").await;
+
+    assert_invalid_auth_key_get_param(response, "INVALID KEY").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_listing_the_auth_keys_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_auth_keys(Query::empty())
+        .await;
+
+    assert_unauthorized(response).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get_auth_keys(Query::empty())
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_generating_a_batch_of_auth_keys() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .generate_auth_keys(GenerateKeysForm {
+            count: 3,
+            seconds_valid: Some(60),
+        })
+        .await;
+
+    let auth_keys = assert_auth_key_list(response).await;
+
+    assert_eq!(auth_keys.len(), 3);
+    assert!(auth_keys[0].expiry_time.is_some());
+    assert!(auth_keys
+        .iter()
+        .all(|auth_key| auth_key.expiry_time == auth_keys[0].expiry_time));
+    for auth_key in auth_keys {
+        assert!(env.tracker.authenticate(&auth_key.key.parse::<Key>().unwrap()).await.is_ok());
+    }
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_generating_a_batch_of_auth_keys_when_the_number_of_keys_is_out_of_range() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for count in [0, 10_001] {
+        let response = Client::new(env.get_connection_info())
+            .generate_auth_keys(GenerateKeysForm {
+                count,
+                seconds_valid: None,
+            })
+            .await;
+
+        assert_bad_request(
+            response,
+            &format!("Invalid body: invalid key count: \"{count}\", expected from 1 to 10000"),
+        )
+        .await;
+    }
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_when_the_batch_of_auth_keys_cannot_be_generated() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    force_database_error(&env.tracker);
+
+    let response = Client::new(env.get_connection_info())
+        .generate_auth_keys(GenerateKeysForm {
+            count: 3,
+            seconds_valid: None,
+        })
+        .await;
+
+    assert_failed_to_generate_keys(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_generating_a_batch_of_auth_keys_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .generate_auth_keys(GenerateKeysForm {
+            count: 3,
+            seconds_valid: None,
+        })
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .generate_auth_keys(GenerateKeysForm {
+            count: 3,
+            seconds_valid: None,
+        })
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
+
 mod deprecated_generate_key_endpoint {
 
     use torrust_tracker::core::auth::Key;
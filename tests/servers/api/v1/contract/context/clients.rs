@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use aquatic_udp_protocol::PeerId;
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker::servers::apis::v1::context::clients::resources::ClientsBreakdown;
+use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::connection_with_invalid_token;
+use crate::servers::api::v1::asserts::{assert_invalid_infohash_param, assert_token_not_valid, assert_torrent_not_known};
+use crate::servers::api::v1::client::Client;
+use crate::servers::api::v1::contract::fixtures::invalid_infohashes_returning_bad_request;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_getting_the_clients_breakdown_for_a_torrent() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d";
+
+    env.add_torrent_peer(
+        &InfoHash::from_str(info_hash).unwrap(),
+        &PeerBuilder::default().with_peer_id(&PeerId(*b"-qB4500-000000000001")).into(),
+    );
+
+    env.tracker.update_clients_breakdown().await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrent_clients_breakdown(info_hash)
+        .await;
+
+    assert_eq!(response.status(), 200);
+
+    let breakdown = response.json::<ClientsBreakdown>().await.unwrap();
+
+    assert_eq!(breakdown.peers, 1);
+    assert_eq!(breakdown.clients.len(), 1);
+    assert_eq!(breakdown.clients[0].name, "qBittorrent");
+    assert_eq!(breakdown.clients[0].seeders, 1);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_getting_the_clients_breakdown_for_the_whole_tracker_once_it_has_been_built() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    env.add_torrent_peer(
+        &InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap(),
+        &PeerBuilder::default().into(),
+    );
+
+    env.tracker.update_clients_breakdown().await;
+
+    let response = Client::new(env.get_connection_info()).get_clients_breakdown().await;
+
+    assert_eq!(response.status(), 200);
+
+    let breakdown = response.json::<ClientsBreakdown>().await.unwrap();
+
+    assert_eq!(breakdown.peers, 1);
+    assert!(breakdown.updated_at.is_some());
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_the_clients_breakdown_for_a_torrent_when_the_torrent_is_not_known() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrent_clients_breakdown("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d")
+        .await;
+
+    assert_torrent_not_known(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_the_clients_breakdown_for_a_torrent_when_the_infohash_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for invalid_infohash in &invalid_infohashes_returning_bad_request() {
+        let response = Client::new(env.get_connection_info())
+            .get_torrent_clients_breakdown(invalid_infohash)
+            .await;
+
+        assert_invalid_infohash_param(response, invalid_infohash).await;
+    }
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_getting_the_clients_breakdown_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get_clients_breakdown()
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    env.stop().await;
+}
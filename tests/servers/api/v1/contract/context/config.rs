@@ -0,0 +1,84 @@
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token, ConnectionInfo};
+use crate::servers::api::v1::asserts::{assert_restart_required, assert_token_not_valid, assert_unauthorized};
+use crate::servers::api::v1::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_refuse_reloading_the_configuration_when_a_changed_option_requires_a_restart() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    // The configuration is loaded again from the default configuration file,
+    // which binds the API to a different address than the test environment.
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).reload_config().await;
+
+    assert_restart_required(response, "http_api.bind_address").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_authenticate_the_requests_with_the_access_tokens_of_the_reloaded_configuration() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let configuration = configuration::ephemeral();
+
+    let env = Started::new(&configuration.clone().into()).await;
+
+    let mut new_configuration = configuration;
+    new_configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .add_token("admin", "NewAccessToken");
+
+    let changed_options = env.reloader.apply(new_configuration).unwrap();
+
+    assert_eq!(changed_options, vec!["http_api.access_tokens.admin".to_string()]);
+
+    let bind_address = env.get_connection_info().bind_address;
+
+    let response = Client::new(ConnectionInfo::authenticated(&bind_address, "NewAccessToken"))
+        .get_tracker_statistics()
+        .await;
+
+    assert_eq!(response.status(), 200);
+
+    let response = Client::new(env.get_connection_info()).get_tracker_statistics().await;
+
+    assert_token_not_valid(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_reloading_the_configuration_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .reload_config()
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .reload_config()
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use aquatic_udp_protocol::PeerId;
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker::core::statistics::Scheme;
+use torrust_tracker::servers::apis::v1::context::events::resources::Event;
+use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token};
+use crate::servers::api::v1::asserts::{assert_bad_request, assert_token_not_valid, assert_unauthorized};
+use crate::servers::api::v1::client::{next_sse_event, Client};
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_stream_the_live_tracker_events() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap(); // DevSkim: ignore DS173237
+    let other_info_hash = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap(); // DevSkim: ignore DS173237
+
+    let mut response = Client::new(env.get_connection_info())
+        .get_events(Query::params(
+            [
+                QueryParam::new("info_hash", &info_hash.to_hex_string()),
+                QueryParam::new("type", "announce"),
+            ]
+            .to_vec(),
+        ))
+        .await;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "text/event-stream");
+
+    let peer = PeerBuilder::default().with_peer_id(&PeerId(*b"-qB00000000000000001")).build();
+
+    env.announce_peer(Scheme::Udp, &other_info_hash, &peer);
+    env.announce_peer(Scheme::Udp, &info_hash, &peer);
+
+    let (name, data) = next_sse_event(&mut response).await;
+
+    let event: Event = serde_json::from_str(&data).unwrap();
+
+    assert_eq!(name, "announce");
+    assert_eq!(event.kind, "announce");
+    assert_eq!(event.protocol, "udp");
+    assert_eq!(event.info_hash, Some(info_hash.to_hex_string()));
+    assert_eq!(
+        event.peer_id.and_then(|peer_id| peer_id.id),
+        Some("0x2d71423030303030303030303030303030303031".to_string())
+    );
+
+    // Close the stream, otherwise the server waits for it to end when stopping.
+    drop(response);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_watching_the_events_when_a_filter_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_events(Query::params([QueryParam::new("type", "scrape")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid type param: unknown event type scrape, expected one of: announce, completed, torrent_added, error",
+    )
+    .await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_events(Query::params([QueryParam::new("protocol", "tcp")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid protocol param: unknown protocol tcp, expected one of: http, udp",
+    )
+    .await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_events(Query::params([QueryParam::new("info_hash", "INVALID")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid URL: invalid infohash param: string \"INVALID\", expected a 40 character long string",
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_watching_the_events_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get_events(Query::default())
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_events(Query::default())
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
@@ -0,0 +1,79 @@
+use torrust_tracker::servers::apis::v1::context::info::resources::{Listener, TrackerInfo, VERSION};
+use torrust_tracker_configuration::v2_0_0::database::Driver;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token};
+use crate::servers::api::v1::asserts::{assert_token_not_valid, assert_unauthorized};
+use crate::servers::api::v1::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_getting_the_tracker_info() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).get_tracker_info().await;
+
+    assert_eq!(response.status(), 200);
+
+    let info = response.json::<TrackerInfo>().await.unwrap();
+
+    assert_eq!(info.version, VERSION);
+    assert!(!info.git_commit.is_empty());
+    assert_eq!(
+        info.listeners,
+        vec![Listener {
+            service: "http_api".to_string(),
+            binding: env.get_connection_info().bind_address,
+        }]
+    );
+    assert_eq!(info.torrent_repository, "skip_map_mutex_std");
+    assert_eq!(info.database_driver, Driver::Sqlite3);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_mask_the_secrets_of_the_effective_configuration_in_the_tracker_info() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).get_tracker_info().await;
+
+    let info = response.json::<TrackerInfo>().await.unwrap();
+
+    assert_eq!(info.config["http_api"]["access_tokens"]["admin"], "***");
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_getting_the_tracker_info_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get_tracker_info()
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_tracker_info()
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
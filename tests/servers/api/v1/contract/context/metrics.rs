@@ -0,0 +1,92 @@
+use std::str::FromStr;
+
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token};
+use crate::servers::api::v1::asserts::{assert_prometheus_metrics, assert_token_not_valid, assert_unauthorized};
+use crate::servers::api::v1::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_getting_tracker_metrics_in_prometheus_format() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    env.add_torrent_peer(
+        &InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap(),
+        &PeerBuilder::default().into(),
+    );
+
+    let response = Client::new(env.get_connection_info()).get_prometheus_metrics().await;
+
+    let metrics = assert_prometheus_metrics(response).await;
+
+    assert!(metrics.contains("# TYPE torrust_tracker_torrents gauge\ntorrust_tracker_torrents 1\n"));
+    assert!(metrics.contains("torrust_tracker_seeders 1\n"));
+    assert!(
+        metrics.contains("torrust_tracker_requests_handled_total{protocol=\"udp\",ip_version=\"ipv4\",request=\"announce\"} 0\n")
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_getting_tracker_metrics_for_unauthenticated_users_by_default() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get_prometheus_metrics()
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_prometheus_metrics()
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_getting_tracker_metrics_without_token_when_the_endpoint_is_public() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let mut configuration = configuration::ephemeral();
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .metrics_require_token = false;
+
+    let env = Started::new(&configuration.into()).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_prometheus_metrics()
+        .await;
+
+    assert_prometheus_metrics(response).await;
+
+    // The rest of the API still requires a token
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_tracker_statistics()
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
@@ -1,5 +1,12 @@
+pub mod audit_log;
 pub mod auth_key;
+pub mod clients;
+pub mod config;
+pub mod events;
 pub mod health_check;
+pub mod info;
+pub mod metrics;
+pub mod peer;
 pub mod stats;
 pub mod torrent;
 pub mod whitelist;
@@ -0,0 +1,238 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+
+use aquatic_udp_protocol::PeerId;
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token};
+use crate::servers::api::v1::asserts::{
+    assert_bad_request, assert_invalid_ip_param, assert_peer_matches, assert_removed_peers, assert_token_not_valid,
+    assert_unauthorized,
+};
+use crate::servers::api::v1::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_searching_the_peers_in_all_the_swarms_by_ip_network() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash_1 = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    let info_hash_2 = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+
+    let reported_peer = PeerBuilder::default()
+        .with_peer_id(&PeerId(*b"-qB00000000000000001"))
+        .with_peer_addr(&SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080))
+        .build();
+    let other_peer = PeerBuilder::default()
+        .with_peer_id(&PeerId(*b"-qB00000000000000002"))
+        .with_peer_addr(&SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8080))
+        .build();
+
+    env.add_torrent_peer(&info_hash_1, &reported_peer);
+    env.add_torrent_peer(&info_hash_1, &other_peer);
+    env.add_torrent_peer(&info_hash_2, &reported_peer);
+
+    let response = Client::new(env.get_connection_info())
+        .search_peers(Query::params([QueryParam::new("ip", "126.0.0.0/8")].to_vec()))
+        .await;
+
+    let peer_matches = assert_peer_matches(response).await;
+
+    assert_eq!(
+        peer_matches
+            .iter()
+            .map(|peer_match| (peer_match.info_hash.clone(), peer_match.peer.peer_addr.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (info_hash_2.to_string(), "126.0.0.1:8080".to_string()),
+            (info_hash_1.to_string(), "126.0.0.1:8080".to_string()),
+        ]
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_searching_the_peers_in_all_the_swarms_by_peer_id_prefix() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    let qbittorrent_peer = PeerBuilder::default()
+        .with_peer_id(&PeerId(*b"-qB00000000000000001"))
+        .with_peer_addr(&SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080))
+        .build();
+    let transmission_peer = PeerBuilder::default()
+        .with_peer_id(&PeerId(*b"-TR00000000000000002"))
+        .with_peer_addr(&SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 2)), 8080))
+        .build();
+
+    env.add_torrent_peer(&info_hash, &qbittorrent_peer);
+    env.add_torrent_peer(&info_hash, &transmission_peer);
+
+    // `-qB` in hex format
+    let response = Client::new(env.get_connection_info())
+        .search_peers(Query::params([QueryParam::new("peer_id", "0x2d7142")].to_vec()))
+        .await;
+
+    let peer_matches = assert_peer_matches(response).await;
+
+    assert_eq!(peer_matches.len(), 1);
+    assert_eq!(peer_matches[0].info_hash, info_hash.to_string());
+    assert_eq!(peer_matches[0].peer.peer_addr, "126.0.0.1:8080");
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_searching_the_peers_without_criteria() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).search_peers(Query::empty()).await;
+
+    assert_bad_request(
+        response,
+        "Invalid URL: missing search params, expected an ip or a peer_id param",
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_searching_the_peers_when_the_criteria_are_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .search_peers(Query::params([QueryParam::new("ip", "126.0.0.0/33")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid URL: invalid ip param: string \"126.0.0.0/33\", expected an IP address or a network in CIDR notation",
+    )
+    .await;
+
+    let response = Client::new(env.get_connection_info())
+        .search_peers(Query::params([QueryParam::new("peer_id", "-qB")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid URL: invalid peer_id param: string \"-qB\", expected a hex string of up to 40 characters",
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_removing_the_peers_with_an_ip_from_all_the_swarms() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash_1 = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    let info_hash_2 = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+
+    let abusive_peer = PeerBuilder::default()
+        .with_peer_id(&PeerId(*b"-qB00000000000000001"))
+        .with_peer_addr(&SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080))
+        .build();
+    let other_peer = PeerBuilder::default()
+        .with_peer_id(&PeerId(*b"-qB00000000000000002"))
+        .with_peer_addr(&SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 2)), 8080))
+        .build();
+
+    env.add_torrent_peer(&info_hash_1, &abusive_peer);
+    env.add_torrent_peer(&info_hash_1, &other_peer);
+    env.add_torrent_peer(&info_hash_2, &abusive_peer);
+
+    let response = Client::new(env.get_connection_info()).remove_peers_with_ip("126.0.0.1").await;
+
+    assert_removed_peers(response, 2).await;
+
+    assert!(!env.tracker.is_peer_active(&info_hash_1, &abusive_peer.peer_id));
+    assert!(!env.tracker.is_peer_active(&info_hash_2, &abusive_peer.peer_id));
+    assert!(env.tracker.is_peer_active(&info_hash_1, &other_peer.peer_id));
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_zero_removed_peers_when_no_peer_has_the_ip() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).remove_peers_with_ip("::1").await;
+
+    assert_removed_peers(response, 0).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_removing_the_peers_with_an_ip_when_the_ip_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for invalid_ip in ["126.0.0", "126.0.0.1:8080", "INVALID"] {
+        let response = Client::new(env.get_connection_info()).remove_peers_with_ip(invalid_ip).await;
+
+        assert_invalid_ip_param(response, invalid_ip).await;
+    }
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_removing_the_peers_with_an_ip_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .remove_peers_with_ip("126.0.0.1")
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .remove_peers_with_ip("126.0.0.1")
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
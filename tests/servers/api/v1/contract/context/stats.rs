@@ -1,14 +1,15 @@
 use std::str::FromStr;
 
 use bittorrent_primitives::info_hash::InfoHash;
-use torrust_tracker::servers::apis::v1::context::stats::resources::Stats;
+use torrust_tracker::servers::apis::v1::context::stats::resources::{DailyStats, Errors, Latency, RequestRates, Stats};
 use torrust_tracker_primitives::peer::fixture::PeerBuilder;
 use torrust_tracker_test_helpers::configuration;
 use tracing::level_filters::LevelFilter;
 
+use crate::common::http::{Query, QueryParam};
 use crate::common::logging::{tracing_stderr_init, INIT};
 use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token};
-use crate::servers::api::v1::asserts::{assert_stats, assert_token_not_valid, assert_unauthorized};
+use crate::servers::api::v1::asserts::{assert_bad_request, assert_stats, assert_token_not_valid, assert_unauthorized};
 use crate::servers::api::v1::client::Client;
 use crate::servers::api::Started;
 
@@ -46,6 +47,17 @@ async fn should_allow_getting_tracker_statistics() {
             udp6_connections_handled: 0,
             udp6_announces_handled: 0,
             udp6_scrapes_handled: 0,
+            tcp_errors: Errors::default(),
+            udp_errors: Errors::default(),
+            udp_connect_latency: Latency::default(),
+            udp_announce_latency: Latency::default(),
+            udp_scrape_latency: Latency::default(),
+            http_announce_latency: Latency::default(),
+            http_scrape_latency: Latency::default(),
+            request_rates: RequestRates::default(),
+            listeners: vec![],
+            api_auth_failures: 0,
+            api_auth_lockouts: 0,
         },
     )
     .await;
@@ -75,3 +87,48 @@ async fn should_not_allow_getting_tracker_statistics_for_unauthenticated_users()
 
     env.stop().await;
 }
+
+#[tokio::test]
+async fn should_allow_getting_the_tracker_statistics_history() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    env.tracker.persist_statistics().await.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .get_tracker_statistics_history(Query::params([QueryParam::new("days", "1")].to_vec()))
+        .await;
+
+    assert_eq!(response.status(), 200);
+
+    // Days without requests are omitted
+    assert_eq!(response.json::<Vec<DailyStats>>().await.unwrap(), vec![]);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_the_tracker_statistics_history_when_the_number_of_days_is_out_of_range() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for days in ["0", "367"] {
+        let response = Client::new(env.get_connection_info())
+            .get_tracker_statistics_history(Query::params([QueryParam::new("days", days)].to_vec()))
+            .await;
+
+        assert_bad_request(
+            response,
+            &format!("Invalid days param {days}, expected a number between 1 and 366"),
+        )
+        .await;
+    }
+
+    env.stop().await;
+}
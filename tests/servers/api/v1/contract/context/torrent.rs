@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use aquatic_udp_protocol::PeerId;
 use bittorrent_primitives::info_hash::InfoHash;
 use torrust_tracker::servers::apis::v1::context::torrent::resources::peer::Peer;
 use torrust_tracker::servers::apis::v1::context::torrent::resources::torrent::{self, Torrent};
@@ -11,8 +12,9 @@ use crate::common::http::{Query, QueryParam};
 use crate::common::logging::{tracing_stderr_init, INIT};
 use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token};
 use crate::servers::api::v1::asserts::{
-    assert_bad_request, assert_invalid_infohash_param, assert_not_found, assert_token_not_valid, assert_torrent_info,
-    assert_torrent_list, assert_torrent_not_known, assert_unauthorized,
+    assert_bad_request, assert_invalid_infohash_param, assert_invalid_peer_id_param, assert_not_found, assert_ok,
+    assert_peer_not_known, assert_token_not_valid, assert_top_torrents, assert_torrent_info, assert_torrent_list,
+    assert_torrent_not_known, assert_torrents_export, assert_unauthorized,
 };
 use crate::servers::api::v1::client::Client;
 use crate::servers::api::v1::contract::fixtures::{
@@ -227,6 +229,130 @@ async fn should_fail_getting_torrents_when_the_info_hash_parameter_is_invalid()
     env.stop().await;
 }
 
+#[tokio::test]
+async fn should_allow_filtering_and_sorting_the_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash_1 = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    let info_hash_2 = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+    let info_hash_3 = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4e").unwrap();
+
+    env.add_torrent_peer(&info_hash_1, &PeerBuilder::seeder().into());
+    env.add_torrent_peer(&info_hash_2, &PeerBuilder::leecher().into());
+    env.add_torrent_peer(&info_hash_3, &PeerBuilder::seeder().into());
+    env.add_torrent_peer(
+        &info_hash_3,
+        &PeerBuilder::seeder().with_peer_id(&PeerId(*b"-qB00000000000000003")).into(),
+    );
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrents(Query::params(
+            [
+                QueryParam::new("min_seeders", "1"),
+                QueryParam::new("has_peers", "true"),
+                QueryParam::new("info_hash_prefix", "9E02"),
+                QueryParam::new("sort", "seeders"),
+                QueryParam::new("order", "desc"),
+                QueryParam::new("limit", "1"),
+            ]
+            .to_vec(),
+        ))
+        .await;
+
+    assert_eq!(response.headers().get("x-total-count").unwrap(), "2");
+    assert_torrent_list(
+        response,
+        vec![torrent::ListItem {
+            info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4e".to_string(),
+            seeders: 2,
+            completed: 0,
+            leechers: 0,
+        }],
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_the_total_number_of_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    env.add_torrent_peer(
+        &InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap(),
+        &PeerBuilder::default().into(),
+    );
+    env.add_torrent_peer(
+        &InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap(),
+        &PeerBuilder::default().into(),
+    );
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrents(Query::params([QueryParam::new("limit", "1")].to_vec()))
+        .await;
+
+    assert_eq!(response.headers().get("x-total-count").unwrap(), "2");
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_torrents_when_the_filter_or_sort_parameters_are_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let api_client = Client::new(env.get_connection_info());
+
+    let response = api_client
+        .get_torrents(Query::params([QueryParam::new("info_hash_prefix", "INVALID")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid info_hash_prefix param: string \"INVALID\", expected a hex string of up to 40 characters",
+    )
+    .await;
+
+    let response = api_client
+        .get_torrents(Query::params([QueryParam::new("sort", "peers")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid sort param: unknown sort field peers, expected one of: info_hash, seeders, leechers, completed",
+    )
+    .await;
+
+    let response = api_client
+        .get_torrents(Query::params([QueryParam::new("order", "up")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid order param: unknown sort order up, expected one of: asc, desc",
+    )
+    .await;
+
+    let response = api_client
+        .get_torrents(Query::params([QueryParam::new("min_seeders", "-1")].to_vec()))
+        .await;
+
+    assert_bad_request(response, "Failed to deserialize query string: invalid digit found in string").await;
+
+    env.stop().await;
+}
+
 #[tokio::test]
 async fn should_not_allow_getting_torrents_for_unauthenticated_users() {
     INIT.call_once(|| {
@@ -250,6 +376,223 @@ async fn should_not_allow_getting_torrents_for_unauthenticated_users() {
     env.stop().await;
 }
 
+#[tokio::test]
+async fn should_allow_getting_the_top_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash_1 = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    let info_hash_2 = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+
+    env.add_torrent_peer(&info_hash_1, &PeerBuilder::leecher().into());
+    env.add_torrent_peer(&info_hash_2, &PeerBuilder::leecher().into());
+    env.add_torrent_peer(
+        &info_hash_2,
+        &PeerBuilder::leecher().with_peer_id(&PeerId(*b"-qB00000000000000003")).into(),
+    );
+
+    let response = Client::new(env.get_connection_info())
+        .get_top_torrents(Query::params(
+            [QueryParam::new("by", "leechers"), QueryParam::new("limit", "1")].to_vec(),
+        ))
+        .await;
+
+    let top_torrents = assert_top_torrents(response).await;
+
+    assert_eq!(top_torrents.len(), 1);
+    assert_eq!(top_torrents[0].info_hash, "0b3aea4adc213ce32295be85d3883a63bca25446");
+    assert_eq!(top_torrents[0].leechers, 2);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_the_top_torrents_when_the_ranking_is_unknown() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_top_torrents(Query::params([QueryParam::new("by", "INVALID")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid by param: unknown ranking INVALID, expected one of: seeders, leechers, completed, announce_rate, trending",
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_the_top_torrents_when_the_limit_is_out_of_range() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for invalid_limit in ["0", "101"] {
+        let response = Client::new(env.get_connection_info())
+            .get_top_torrents(Query::params([QueryParam::new("limit", invalid_limit)].to_vec()))
+            .await;
+
+        assert_bad_request(
+            response,
+            &format!("Invalid limit param {invalid_limit}, expected a number between 1 and 100"),
+        )
+        .await;
+    }
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_getting_the_top_torrents_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get_top_torrents(Query::empty())
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_top_torrents(Query::default())
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_exporting_all_the_torrents_as_ndjson() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash_1 = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    let info_hash_2 = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+
+    let peer = PeerBuilder::default().into();
+
+    env.add_torrent_peer(&info_hash_1, &peer);
+    env.add_torrent_peer(&info_hash_2, &peer);
+
+    let response = Client::new(env.get_connection_info())
+        .export_torrents(Query::params([QueryParam::new("peers", "true")].to_vec()))
+        .await;
+
+    let body = assert_torrents_export(response, "application/x-ndjson").await;
+
+    let torrents: Vec<Torrent> = body.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+
+    assert_eq!(
+        torrents,
+        vec![
+            Torrent {
+                info_hash: "0b3aea4adc213ce32295be85d3883a63bca25446".to_string(),
+                seeders: 1,
+                completed: 0,
+                leechers: 0,
+                peers: Some(vec![Peer::from(peer)]),
+            },
+            Torrent {
+                info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+                seeders: 1,
+                completed: 0,
+                leechers: 0,
+                peers: Some(vec![Peer::from(peer)]),
+            }
+        ]
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_exporting_all_the_torrents_as_csv() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let response = Client::new(env.get_connection_info())
+        .export_torrents(Query::params([QueryParam::new("format", "csv")].to_vec()))
+        .await;
+
+    let body = assert_torrents_export(response, "text/csv; charset=utf-8").await;
+
+    assert_eq!(
+        body,
+        "info_hash,seeders,completed,leechers\n9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d,1,0,0\n"
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_exporting_the_torrents_when_the_format_is_unknown() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .export_torrents(Query::params([QueryParam::new("format", "xml")].to_vec()))
+        .await;
+
+    assert_bad_request(
+        response,
+        "Invalid format param: unknown export format xml, expected one of: ndjson, csv",
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_exporting_the_torrents_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .export_torrents(Query::empty())
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .export_torrents(Query::default())
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
+
 #[tokio::test]
 async fn should_allow_getting_a_torrent_info() {
     INIT.call_once(|| {
@@ -351,3 +694,164 @@ async fn should_not_allow_getting_a_torrent_info_for_unauthenticated_users() {
 
     env.stop().await;
 }
+
+#[tokio::test]
+async fn should_allow_removing_a_torrent() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let response = Client::new(env.get_connection_info())
+        .remove_torrent(
+            &info_hash.to_string(),
+            Query::params([QueryParam::new("remove_completed", "true")].to_vec()),
+        )
+        .await;
+
+    assert_ok(response).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrent(&info_hash.to_string())
+        .await;
+
+    assert_torrent_not_known(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_removing_a_torrent_when_the_torrent_does_not_exist() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .remove_torrent("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", Query::default())
+        .await;
+
+    assert_torrent_not_known(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_removing_a_torrent_when_the_provided_infohash_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for invalid_infohash in &invalid_infohashes_returning_bad_request() {
+        let response = Client::new(env.get_connection_info())
+            .remove_torrent(invalid_infohash, Query::default())
+            .await;
+
+        assert_invalid_infohash_param(response, invalid_infohash).await;
+    }
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_removing_a_torrent_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .remove_torrent(&info_hash.to_string(), Query::default())
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .remove_torrent(&info_hash.to_string(), Query::default())
+        .await;
+
+    assert_unauthorized(response).await;
+
+    assert!(env
+        .tracker
+        .is_peer_active(&info_hash, &PeerBuilder::default().build().peer_id));
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_removing_a_peer_from_a_torrent() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    let peer_id = PeerId(*b"-qB00000000000000000");
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().with_peer_id(&peer_id).into());
+
+    let response = Client::new(env.get_connection_info())
+        .remove_peer(&info_hash.to_string(), "0x2d71423030303030303030303030303030303030")
+        .await;
+
+    assert_ok(response).await;
+
+    assert!(!env.tracker.is_peer_active(&info_hash, &peer_id));
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_removing_a_peer_when_the_peer_is_not_in_the_swarm() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let response = Client::new(env.get_connection_info())
+        .remove_peer(&info_hash.to_string(), "0x2d71423030303030303030303030303030303031")
+        .await;
+
+    assert_peer_not_known(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_removing_a_peer_when_the_provided_peer_id_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for invalid_peer_id in ["0x2d71", "-qB00000000000000000", "0x2d7142303030303030303030303030303030303z"] {
+        let response = Client::new(env.get_connection_info())
+            .remove_peer("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", invalid_peer_id)
+            .await;
+
+        assert_invalid_peer_id_param(response, invalid_peer_id).await;
+    }
+
+    env.stop().await;
+}
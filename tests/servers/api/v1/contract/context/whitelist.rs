@@ -1,14 +1,20 @@
 use std::str::FromStr;
 
 use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker::servers::apis::v1::context::whitelist::resources::{
+    BulkWhitelistItem, BulkWhitelistStatus, WhitelistDiff, WhitelistEntry,
+};
 use torrust_tracker_test_helpers::configuration;
 use tracing::level_filters::LevelFilter;
 
+use crate::common::http::{Query, QueryParam};
 use crate::common::logging::{tracing_stderr_init, INIT};
 use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token};
 use crate::servers::api::v1::asserts::{
-    assert_failed_to_reload_whitelist, assert_failed_to_remove_torrent_from_whitelist, assert_failed_to_whitelist_torrent,
-    assert_invalid_infohash_param, assert_not_found, assert_ok, assert_token_not_valid, assert_unauthorized,
+    assert_bad_request_with_text, assert_bulk_whitelist, assert_failed_to_load_whitelist, assert_failed_to_reload_whitelist,
+    assert_failed_to_remove_torrent_from_whitelist, assert_failed_to_whitelist_torrent, assert_failed_to_whitelist_torrents,
+    assert_invalid_infohash_param, assert_not_found, assert_ok, assert_token_not_valid, assert_torrent_not_whitelisted,
+    assert_unauthorized, assert_whitelist, assert_whitelist_diff, assert_whitelist_entry,
 };
 use crate::servers::api::v1::client::Client;
 use crate::servers::api::v1::contract::fixtures::{
@@ -298,3 +304,368 @@ async fn should_fail_when_the_whitelist_cannot_be_reloaded_from_the_database() {
 
     env.stop().await;
 }
+
+#[tokio::test]
+async fn should_allow_getting_the_whitelisted_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    env.tracker.add_torrent_to_whitelist(&info_hash).await.unwrap();
+
+    let response = Client::new(env.get_connection_info()).get_whitelist(Query::empty()).await;
+
+    assert_eq!(
+        assert_whitelist(response).await,
+        vec![WhitelistEntry {
+            info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+            in_memory: true,
+            in_database: true,
+        }]
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_the_whitelisted_torrents_pagination() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for hash in [
+        "0b3aea4adc213ce32295be85d3883a63bca25446",
+        "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d",
+    ] {
+        env.tracker
+            .add_torrent_to_whitelist(&InfoHash::from_str(hash).unwrap())
+            .await
+            .unwrap();
+    }
+
+    let response = Client::new(env.get_connection_info())
+        .get_whitelist(Query::params(
+            [QueryParam::new("offset", "1"), QueryParam::new("limit", "1")].to_vec(),
+        ))
+        .await;
+
+    let entries = assert_whitelist(response).await;
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].info_hash, "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d");
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_getting_where_a_torrent_is_whitelisted() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let hash = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_owned();
+    let info_hash = InfoHash::from_str(&hash).unwrap();
+    env.tracker.add_torrent_to_whitelist(&info_hash).await.unwrap();
+    env.tracker.remove_torrent_from_memory_whitelist(&info_hash).await;
+
+    let response = Client::new(env.get_connection_info()).get_whitelist_entry(&hash).await;
+
+    assert_whitelist_entry(
+        response,
+        WhitelistEntry {
+            info_hash: hash,
+            in_memory: false,
+            in_database: true,
+        },
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_not_whitelisted_message_when_the_torrent_is_not_whitelisted() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_whitelist_entry("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d")
+        .await;
+
+    assert_torrent_not_whitelisted(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_getting_a_whitelisted_torrent_when_the_provided_infohash_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for invalid_infohash in &invalid_infohashes_returning_bad_request() {
+        let response = Client::new(env.get_connection_info())
+            .get_whitelist_entry(invalid_infohash)
+            .await;
+
+        assert_invalid_infohash_param(response, invalid_infohash).await;
+    }
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_report_the_torrents_whitelisted_only_in_memory_or_only_in_the_database() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let only_in_database = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    env.tracker.add_torrent_to_whitelist(&only_in_database).await.unwrap();
+    env.tracker.remove_torrent_from_memory_whitelist(&only_in_database).await;
+
+    let only_in_memory = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+    env.tracker.add_torrent_to_memory_whitelist(&only_in_memory).await;
+
+    let response = Client::new(env.get_connection_info()).get_whitelist_diff().await;
+
+    assert_whitelist_diff(
+        response,
+        WhitelistDiff {
+            only_in_memory: vec!["0b3aea4adc213ce32295be85d3883a63bca25446".to_string()],
+            only_in_database: vec!["9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string()],
+        },
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_when_the_whitelist_cannot_be_loaded_from_the_database() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    force_database_error(&env.tracker);
+
+    let response = Client::new(env.get_connection_info()).get_whitelist(Query::empty()).await;
+
+    assert_failed_to_load_whitelist(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_getting_the_whitelist_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get_whitelist(Query::empty())
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_whitelist_diff()
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
+
+fn bulk_item(info_hash: &str, status: BulkWhitelistStatus) -> BulkWhitelistItem {
+    BulkWhitelistItem {
+        info_hash: info_hash.to_string(),
+        status,
+    }
+}
+
+#[tokio::test]
+async fn should_allow_whitelisting_a_json_array_of_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let already_whitelisted = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+    env.tracker.add_torrent_to_whitelist(&already_whitelisted).await.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .whitelist_torrents(
+            "application/json",
+            r#"["9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", "0b3aea4adc213ce32295be85d3883a63bca25446", "INVALID"]"#,
+        )
+        .await;
+
+    assert_bulk_whitelist(
+        response,
+        vec![
+            bulk_item("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", BulkWhitelistStatus::Added),
+            bulk_item(
+                "0b3aea4adc213ce32295be85d3883a63bca25446",
+                BulkWhitelistStatus::AlreadyWhitelisted,
+            ),
+            bulk_item("INVALID", BulkWhitelistStatus::Invalid),
+        ],
+    )
+    .await;
+    assert!(
+        env.tracker
+            .is_info_hash_whitelisted(&InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap())
+            .await
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_whitelisting_a_newline_delimited_list_of_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .whitelist_torrents(
+            "application/x-ndjson",
+            "\"9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d\"\n\n0b3aea4adc213ce32295be85d3883a63bca25446\n",
+        )
+        .await;
+
+    assert_bulk_whitelist(
+        response,
+        vec![
+            bulk_item("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", BulkWhitelistStatus::Added),
+            bulk_item("0b3aea4adc213ce32295be85d3883a63bca25446", BulkWhitelistStatus::Added),
+        ],
+    )
+    .await;
+    assert_eq!(env.tracker.get_whitelist(None).await.unwrap().len(), 2);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_removing_a_list_of_torrents_from_the_whitelist() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    env.tracker.add_torrent_to_whitelist(&info_hash).await.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .remove_torrents_from_whitelist(
+            "text/plain",
+            "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d\n0b3aea4adc213ce32295be85d3883a63bca25446",
+        )
+        .await;
+
+    assert_bulk_whitelist(
+        response,
+        vec![
+            bulk_item("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", BulkWhitelistStatus::Removed),
+            bulk_item(
+                "0b3aea4adc213ce32295be85d3883a63bca25446",
+                BulkWhitelistStatus::NotWhitelisted,
+            ),
+        ],
+    )
+    .await;
+    assert!(!env.tracker.is_info_hash_whitelisted(&info_hash).await);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_whitelisting_a_list_of_torrents_when_the_body_is_not_valid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let api_client = Client::new(env.get_connection_info());
+
+    let response = api_client.whitelist_torrents("application/json", "[]").await;
+    assert_bad_request_with_text(response, "Invalid body: the list of info-hashes is empty").await;
+
+    let response = api_client.whitelist_torrents("application/json", "{}").await;
+    assert_bad_request_with_text(response, "Invalid body: invalid json array of info-hashes").await;
+
+    let response = api_client.whitelist_torrents("application/xml", "<info-hashes/>").await;
+    assert_bad_request_with_text(response, "Invalid body: unsupported content type").await;
+
+    let response = api_client.remove_torrents_from_whitelist("text/plain", "\n").await;
+    assert_bad_request_with_text(response, "Invalid body: the list of info-hashes is empty").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_fail_when_the_list_of_torrents_cannot_be_whitelisted() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    force_database_error(&env.tracker);
+
+    let response = Client::new(env.get_connection_info())
+        .whitelist_torrents("text/plain", "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d")
+        .await;
+
+    assert_failed_to_whitelist_torrents(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_not_allow_whitelisting_a_list_of_torrents_for_unauthenticated_users() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .whitelist_torrents("text/plain", "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d")
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .remove_torrents_from_whitelist("text/plain", "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d")
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
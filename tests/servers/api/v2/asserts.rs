@@ -0,0 +1,72 @@
+use reqwest::Response;
+use torrust_tracker::servers::apis::v2::responses::Problem;
+
+// Resource responses
+
+pub async fn assert_json<T: serde::de::DeserializeOwned>(response: Response) -> T {
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    response.json::<T>().await.unwrap()
+}
+
+pub async fn assert_created<T: serde::de::DeserializeOwned>(response: Response) -> T {
+    assert_eq!(response.status(), 201);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+    response.json::<T>().await.unwrap()
+}
+
+pub async fn assert_no_content(response: Response) {
+    assert_eq!(response.status(), 204);
+    assert_eq!(response.text().await.unwrap(), "");
+}
+
+// Problem responses
+
+pub async fn assert_problem(response: Response, status: u16, detail: &str) {
+    assert_eq!(response.status(), status);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/problem+json");
+
+    let problem = response.json::<Problem>().await.unwrap();
+
+    assert_eq!(problem.problem_type, "about:blank");
+    assert_eq!(problem.status, status);
+    assert_eq!(problem.detail, detail);
+}
+
+pub async fn assert_problem_containing(response: Response, status: u16, text: &str) {
+    assert_eq!(response.status(), status);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/problem+json");
+
+    let problem = response.json::<Problem>().await.unwrap();
+
+    assert!(problem.detail.contains(text), "{} does not contain {text}", problem.detail);
+}
+
+pub async fn assert_unauthorized(response: Response) {
+    assert_problem(response, 401, "missing token").await;
+}
+
+pub async fn assert_token_not_valid(response: Response) {
+    assert_problem(response, 401, "token not valid").await;
+}
+
+pub async fn assert_token_expired(response: Response) {
+    assert_problem(response, 401, "token expired").await;
+}
+
+pub async fn assert_forbidden(response: Response) {
+    assert_problem(response, 403, "the token role does not allow this request").await;
+}
+
+pub async fn assert_locked_out(response: Response) {
+    assert_problem(response, 429, "too many failed authentication attempts").await;
+}
+
+pub async fn assert_invalid_infohash_param(response: Response, invalid_infohash: &str) {
+    assert_problem(
+        response,
+        400,
+        &format!("invalid infohash param: string \"{invalid_infohash}\", expected a 40 character long string"),
+    )
+    .await;
+}
@@ -0,0 +1,209 @@
+use reqwest::{Method, Response};
+use serde::Serialize;
+
+use crate::common::http::{Query, QueryParam, ReqwestQuery};
+use crate::servers::api::connection_info::ConnectionInfo;
+use crate::servers::api::v1::client::{AddKeyForm, GenerateKeysForm};
+
+/// API v2 Client
+pub struct Client {
+    connection_info: ConnectionInfo,
+    base_path: String,
+}
+
+impl Client {
+    pub fn new(connection_info: ConnectionInfo) -> Self {
+        Self {
+            connection_info,
+            base_path: "/api/v2/".to_string(),
+        }
+    }
+
+    pub async fn add_auth_key(&self, add_key_form: AddKeyForm) -> Response {
+        self.request_with_json(Method::POST, "keys", &add_key_form).await
+    }
+
+    pub async fn generate_auth_keys(&self, generate_keys_form: GenerateKeysForm) -> Response {
+        self.request_with_json(Method::POST, "keys/bulk", &generate_keys_form).await
+    }
+
+    pub async fn get_auth_keys(&self, params: Query) -> Response {
+        self.get("keys", params).await
+    }
+
+    pub async fn get_auth_key(&self, key: &str) -> Response {
+        self.get(&format!("keys/{key}"), Query::default()).await
+    }
+
+    pub async fn delete_auth_key(&self, key: &str) -> Response {
+        self.request(Method::DELETE, &format!("keys/{key}"), Query::default()).await
+    }
+
+    pub async fn reload_keys(&self) -> Response {
+        self.request(Method::POST, "keys/reload", Query::default()).await
+    }
+
+    pub async fn purge_expired_keys(&self) -> Response {
+        self.request(Method::POST, "keys/purge", Query::default()).await
+    }
+
+    pub async fn get_whitelist(&self, params: Query) -> Response {
+        self.get("whitelist", params).await
+    }
+
+    pub async fn get_whitelist_entry(&self, info_hash: &str) -> Response {
+        self.get(&format!("whitelist/{info_hash}"), Query::default()).await
+    }
+
+    pub async fn get_whitelist_diff(&self) -> Response {
+        self.get("whitelist/diff", Query::default()).await
+    }
+
+    pub async fn whitelist_a_torrent(&self, info_hash: &str) -> Response {
+        self.request(Method::PUT, &format!("whitelist/{info_hash}"), Query::default())
+            .await
+    }
+
+    pub async fn remove_torrent_from_whitelist(&self, info_hash: &str) -> Response {
+        self.request(Method::DELETE, &format!("whitelist/{info_hash}"), Query::default())
+            .await
+    }
+
+    pub async fn whitelist_torrents(&self, content_type: &str, body: &str) -> Response {
+        self.request_with_body(Method::POST, "whitelist/bulk", content_type, body)
+            .await
+    }
+
+    pub async fn remove_torrents_from_whitelist(&self, content_type: &str, body: &str) -> Response {
+        self.request_with_body(Method::DELETE, "whitelist/bulk", content_type, body)
+            .await
+    }
+
+    pub async fn reload_whitelist(&self) -> Response {
+        self.request(Method::POST, "whitelist/reload", Query::default()).await
+    }
+
+    pub async fn reload_config(&self) -> Response {
+        self.request(Method::POST, "config/reload", Query::default()).await
+    }
+
+    pub async fn get_torrent(&self, info_hash: &str) -> Response {
+        self.get(&format!("torrents/{info_hash}"), Query::default()).await
+    }
+
+    pub async fn remove_torrent(&self, info_hash: &str, params: Query) -> Response {
+        self.request(Method::DELETE, &format!("torrents/{info_hash}"), params).await
+    }
+
+    pub async fn remove_peer(&self, info_hash: &str, peer_id: &str) -> Response {
+        self.request(
+            Method::DELETE,
+            &format!("torrents/{info_hash}/peers/{peer_id}"),
+            Query::default(),
+        )
+        .await
+    }
+
+    pub async fn search_peers(&self, params: Query) -> Response {
+        self.get("peers", params).await
+    }
+
+    pub async fn remove_peers_with_ip(&self, ip: &str) -> Response {
+        self.request(Method::DELETE, &format!("peers/{ip}"), Query::default()).await
+    }
+
+    pub async fn get_torrents(&self, params: Query) -> Response {
+        self.get("torrents", params).await
+    }
+
+    pub async fn get_top_torrents(&self, params: Query) -> Response {
+        self.get("torrents/top", params).await
+    }
+
+    pub async fn export_torrents(&self, params: Query) -> Response {
+        self.get("torrents/export", params).await
+    }
+
+    pub async fn get_events(&self, params: Query) -> Response {
+        self.get("events", params).await
+    }
+
+    pub async fn get_tracker_statistics(&self) -> Response {
+        self.get("stats", Query::default()).await
+    }
+
+    pub async fn get_tracker_statistics_history(&self, params: Query) -> Response {
+        self.get("stats/history", params).await
+    }
+
+    pub async fn get_tracker_info(&self) -> Response {
+        self.get("info", Query::default()).await
+    }
+
+    pub async fn get_audit_log(&self, params: Query) -> Response {
+        self.get("audit_log", params).await
+    }
+
+    pub async fn get_openapi_document(&self) -> Response {
+        reqwest::Client::new()
+            .get(self.base_url("openapi.json"))
+            .send()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get(&self, path: &str, params: Query) -> Response {
+        self.request(Method::GET, path, params).await
+    }
+
+    pub async fn get_with_bearer_token(&self, path: &str, token: &str) -> Response {
+        reqwest::Client::new()
+            .get(self.base_url(path))
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    async fn request(&self, method: Method, path: &str, params: Query) -> Response {
+        reqwest::Client::new()
+            .request(method, self.base_url(path))
+            .query(&ReqwestQuery::from(self.query_with_token(params)))
+            .send()
+            .await
+            .unwrap()
+    }
+
+    async fn request_with_json<T: Serialize + ?Sized>(&self, method: Method, path: &str, json: &T) -> Response {
+        reqwest::Client::new()
+            .request(method, self.base_url(path))
+            .query(&ReqwestQuery::from(self.query_with_token(Query::default())))
+            .json(json)
+            .send()
+            .await
+            .unwrap()
+    }
+
+    async fn request_with_body(&self, method: Method, path: &str, content_type: &str, body: &str) -> Response {
+        reqwest::Client::new()
+            .request(method, self.base_url(path))
+            .query(&ReqwestQuery::from(self.query_with_token(Query::default())))
+            .header(reqwest::header::CONTENT_TYPE, content_type)
+            .body(body.to_string())
+            .send()
+            .await
+            .unwrap()
+    }
+
+    fn query_with_token(&self, mut params: Query) -> Query {
+        if let Some(token) = &self.connection_info.api_token {
+            params.add_param(QueryParam::new("token", token));
+        };
+
+        params
+    }
+
+    fn base_url(&self, path: &str) -> String {
+        format!("http://{}{}{path}", &self.connection_info.bind_address, &self.base_path)
+    }
+}
@@ -0,0 +1,173 @@
+use torrust_tracker_configuration::Role;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::Query;
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::{connection_with_invalid_token, connection_with_no_token, ConnectionInfo};
+use crate::servers::api::v2::asserts::{
+    assert_forbidden, assert_locked_out, assert_token_expired, assert_token_not_valid, assert_unauthorized,
+};
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_authenticate_requests_by_using_a_token_query_param() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).get("stats", Query::default()).await;
+
+    assert_eq!(response.status(), 200);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_401_problem_when_the_token_is_missing() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get("stats", Query::default())
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_401_problem_when_the_token_is_not_valid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get("stats", Query::default())
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_authenticate_requests_by_using_an_authorization_bearer_header() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let token = env.get_connection_info().api_token.unwrap();
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_with_bearer_token("stats", &token)
+        .await;
+
+    assert_eq!(response.status(), 200);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_401_problem_when_the_token_has_expired() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let mut configuration = configuration::ephemeral();
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .add_scoped_token("expired", "ExpiredToken", Role::Admin, Some(1));
+
+    let env = Started::new(&configuration.into()).await;
+
+    let response = Client::new(ConnectionInfo::authenticated(
+        &env.get_connection_info().bind_address,
+        "ExpiredToken",
+    ))
+    .get_tracker_statistics()
+    .await;
+
+    assert_token_expired(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_403_problem_when_the_token_role_does_not_allow_the_request() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let mut configuration = configuration::ephemeral();
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .add_scoped_token("viewer", "ViewerToken", Role::TorrentViewer, None);
+
+    let env = Started::new(&configuration.into()).await;
+
+    let client = Client::new(ConnectionInfo::authenticated(
+        &env.get_connection_info().bind_address,
+        "ViewerToken",
+    ));
+
+    assert_eq!(client.get_tracker_statistics().await.status(), 200);
+    assert_eq!(client.get_torrents(Query::default()).await.status(), 200);
+
+    assert_forbidden(client.reload_keys().await).await;
+    assert_forbidden(client.reload_whitelist().await).await;
+    assert_forbidden(
+        client
+            .remove_torrent("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", Query::default())
+            .await,
+    )
+    .await;
+    assert_forbidden(client.remove_peers_with_ip("127.0.0.1").await).await;
+    assert_forbidden(client.reload_config().await).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_429_problem_when_the_client_is_locked_out_after_too_many_invalid_tokens() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let mut configuration = configuration::ephemeral();
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .auth_throttling
+        .max_failures = 1;
+
+    let env = Started::new(&configuration.into()).await;
+
+    let response = Client::new(connection_with_invalid_token(env.get_connection_info().bind_address.as_str()))
+        .get_tracker_statistics()
+        .await;
+
+    assert_token_not_valid(response).await;
+
+    let response = Client::new(env.get_connection_info()).get_tracker_statistics().await;
+
+    assert_locked_out(response).await;
+
+    env.stop().await;
+}
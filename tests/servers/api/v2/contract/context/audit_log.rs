@@ -0,0 +1,83 @@
+use torrust_tracker::servers::apis::v1::context::audit_log::resources::AuditLogEntry;
+use torrust_tracker_configuration::Role;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::ConnectionInfo;
+use crate::servers::api::v2::asserts::{assert_forbidden, assert_json, assert_no_content, assert_problem_containing};
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_getting_the_audit_log_with_the_requests_that_changed_the_tracker_state() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d";
+
+    let client = Client::new(env.get_connection_info());
+
+    assert_no_content(client.whitelist_a_torrent(info_hash).await).await;
+
+    let response = client.get_audit_log(Query::default()).await;
+
+    assert_eq!(response.headers().get("x-total-count").unwrap(), "1");
+
+    let entries: Vec<AuditLogEntry> = assert_json(response).await;
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].action, "PUT /api/v2/whitelist/:info_hash");
+    assert_eq!(entries[0].target, info_hash);
+    assert_eq!(entries[0].result, 204);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_pagination_params_are_not_valid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_audit_log(Query::params([QueryParam::new("limit", "INVALID LIMIT")].to_vec()))
+        .await;
+
+    assert_problem_containing(response, 400, "Failed to deserialize query string").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_403_problem_when_the_token_is_not_an_admin_token() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let mut configuration = configuration::ephemeral();
+    configuration
+        .http_api
+        .as_mut()
+        .expect("it should have the API configuration")
+        .add_scoped_token("viewer", "ViewerToken", Role::TorrentViewer, None);
+
+    let env = Started::new(&configuration.into()).await;
+
+    let response = Client::new(ConnectionInfo::authenticated(
+        &env.get_connection_info().bind_address,
+        "ViewerToken",
+    ))
+    .get_audit_log(Query::default())
+    .await;
+
+    assert_forbidden(response).await;
+
+    env.stop().await;
+}
@@ -0,0 +1,297 @@
+use std::time::Duration;
+
+use torrust_tracker::core::auth::{generate_permanent_key, KeyFilter};
+use torrust_tracker::servers::apis::v1::context::auth_key::resources::{AuthKey, AuthKeyDetail, PurgedKeys};
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::v1::client::{AddKeyForm, GenerateKeysForm};
+use crate::servers::api::v2::asserts::{
+    assert_created, assert_json, assert_no_content, assert_problem, assert_problem_containing,
+};
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_adding_a_new_random_auth_key() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .add_auth_key(AddKeyForm {
+            opt_key: None,
+            seconds_valid: Some(60),
+        })
+        .await;
+
+    let auth_key: AuthKey = assert_created(response).await;
+
+    assert_eq!(auth_key.key.len(), 32);
+    assert!(auth_key.expiry_time.is_some());
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_provided_key_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .add_auth_key(AddKeyForm {
+            opt_key: Some("INVALID KEY".to_string()),
+            seconds_valid: Some(60),
+        })
+        .await;
+
+    assert_problem_containing(response, 400, "invalid auth key: string \"INVALID KEY\"").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_409_problem_when_the_provided_key_already_exists() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let key = "IaWDneuFNZi8IB4MPA3qW1CD0M30EZSM"; // cspell:disable-line
+
+    let client = Client::new(env.get_connection_info());
+
+    let add_key_form = || AddKeyForm {
+        opt_key: Some(key.to_string()),
+        seconds_valid: None,
+    };
+
+    let _: AuthKey = assert_created(client.add_auth_key(add_key_form()).await).await;
+
+    assert_problem(
+        client.add_auth_key(add_key_form()).await,
+        409,
+        &format!("auth key already exists: \"{key}\""),
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_generating_a_batch_of_auth_keys() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .generate_auth_keys(GenerateKeysForm {
+            count: 2,
+            seconds_valid: None,
+        })
+        .await;
+
+    let auth_keys: Vec<AuthKey> = assert_created(response).await;
+
+    assert_eq!(auth_keys.len(), 2);
+    assert!(auth_keys.iter().all(|auth_key| auth_key.expiry_time.is_none()));
+    assert_eq!(env.tracker.get_auth_keys(&KeyFilter::default(), None).await.len(), 2);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_number_of_keys_to_generate_is_out_of_range() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .generate_auth_keys(GenerateKeysForm {
+            count: 0,
+            seconds_valid: None,
+        })
+        .await;
+
+    assert_problem(response, 400, "invalid key count: \"0\", expected from 1 to 10000").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_deleting_an_auth_key() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let auth_key = env.tracker.generate_auth_key(Some(Duration::from_secs(60))).await.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .delete_auth_key(&auth_key.key.to_string())
+        .await;
+
+    assert_no_content(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_404_problem_when_deleting_an_auth_key_that_does_not_exist() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .delete_auth_key("IaWDneuFNZi8IB4MPA3qW1CD0M30EZSM") // cspell:disable-line
+        .await;
+
+    assert_problem(response, 404, "auth key not found").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_reloading_keys() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).reload_keys().await;
+
+    assert_no_content(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_purging_the_expired_keys() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let expired_key = generate_permanent_key().key();
+    env.tracker
+        .add_auth_key(expired_key.clone(), Some(Duration::from_secs(1)))
+        .await
+        .unwrap();
+    let permanent_key = env.tracker.generate_permanent_auth_key().await.unwrap();
+
+    let response = Client::new(env.get_connection_info()).purge_expired_keys().await;
+
+    assert_eq!(assert_json::<PurgedKeys>(response).await, PurgedKeys { removed_keys: 1 });
+
+    assert!(env.tracker.get_auth_key(&expired_key).await.is_none());
+    assert!(env.tracker.get_auth_key(&permanent_key.key()).await.is_some());
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_listing_the_auth_keys_with_filters() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let permanent_key = env.tracker.generate_permanent_auth_key().await.unwrap();
+    env.tracker.generate_auth_key(Some(Duration::from_secs(60))).await.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .get_auth_keys(Query::params([QueryParam::new("permanent", "true")].to_vec()))
+        .await;
+
+    let keys: Vec<AuthKey> = assert_json(response).await;
+
+    assert_eq!(
+        keys.into_iter().map(|k| k.key).collect::<Vec<_>>(),
+        vec![permanent_key.key.to_string()]
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_list_filters_are_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_auth_keys(Query::params([QueryParam::new("expired", "maybe")].to_vec()))
+        .await;
+
+    assert_problem_containing(response, 400, "Failed to deserialize query string").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_getting_an_auth_key() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let auth_key = env.tracker.generate_permanent_auth_key().await.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .get_auth_key(&auth_key.key.to_string())
+        .await;
+
+    let detail: AuthKeyDetail = assert_json(response).await;
+
+    assert_eq!(
+        detail,
+        AuthKeyDetail {
+            key: auth_key.key.to_string(),
+            valid_until: None,
+            expiry_time: None,
+            expired: false,
+            last_used_time: None,
+            active_peers: 0,
+        }
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_404_problem_when_getting_an_auth_key_that_does_not_exist() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_auth_key("IaWDneuFNZi8IB4MPA3qW1CD0M30EZSM") // cspell:disable-line
+        .await;
+
+    assert_problem(response, 404, "auth key not found").await;
+
+    env.stop().await;
+}
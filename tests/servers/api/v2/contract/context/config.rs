@@ -0,0 +1,29 @@
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::v2::asserts::assert_problem_containing;
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_return_a_409_problem_when_a_changed_option_requires_a_restart() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    // The configuration is loaded again from the default configuration file,
+    // which binds the API to a different address than the test environment.
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).reload_config().await;
+
+    assert_problem_containing(
+        response,
+        409,
+        "unable to reload the configuration, these options require a restart: ",
+    )
+    .await;
+
+    env.stop().await;
+}
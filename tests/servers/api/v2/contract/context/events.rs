@@ -0,0 +1,72 @@
+use std::str::FromStr;
+
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker::core::statistics::Scheme;
+use torrust_tracker::servers::apis::v1::context::events::resources::Event;
+use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::v1::client::next_sse_event;
+use crate::servers::api::v2::asserts::assert_problem;
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_stream_the_live_tracker_events() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap(); // DevSkim: ignore DS173237
+
+    let mut response = Client::new(env.get_connection_info())
+        .get_events(Query::params([QueryParam::new("protocol", "http")].to_vec()))
+        .await;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "text/event-stream");
+
+    let peer = PeerBuilder::default().build();
+
+    env.announce_peer(Scheme::Udp, &info_hash, &peer);
+    env.announce_peer(Scheme::Http, &info_hash, &peer);
+
+    let (name, data) = next_sse_event(&mut response).await;
+
+    let event: Event = serde_json::from_str(&data).unwrap();
+
+    assert_eq!(name, "announce");
+    assert_eq!(event.protocol, "http");
+
+    // Close the stream, otherwise the server waits for it to end when stopping.
+    drop(response);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_event_type_is_unknown() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_events(Query::params([QueryParam::new("type", "scrape")].to_vec()))
+        .await;
+
+    assert_problem(
+        response,
+        400,
+        "invalid type param: unknown event type scrape, expected one of: announce, completed, torrent_added, error",
+    )
+    .await;
+
+    env.stop().await;
+}
@@ -0,0 +1,51 @@
+use torrust_tracker::servers::apis::v1::context::info::resources::{Listener, TrackerInfo, VERSION};
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::connection_info::connection_with_no_token;
+use crate::servers::api::v2::asserts::{assert_json, assert_unauthorized};
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_getting_the_tracker_info() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).get_tracker_info().await;
+
+    let info: TrackerInfo = assert_json(response).await;
+
+    assert_eq!(info.version, VERSION);
+    assert_eq!(
+        info.listeners,
+        vec![Listener {
+            service: "http_api".to_string(),
+            binding: env.get_connection_info().bind_address,
+        }]
+    );
+    assert_eq!(info.config["http_api"]["access_tokens"]["admin"], "***");
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_401_problem_when_getting_the_tracker_info_without_a_token() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(connection_with_no_token(env.get_connection_info().bind_address.as_str()))
+        .get_tracker_info()
+        .await;
+
+    assert_unauthorized(response).await;
+
+    env.stop().await;
+}
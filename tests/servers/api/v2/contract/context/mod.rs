@@ -0,0 +1,9 @@
+pub mod audit_log;
+pub mod auth_key;
+pub mod config;
+pub mod events;
+pub mod info;
+pub mod peer;
+pub mod stats;
+pub mod torrent;
+pub mod whitelist;
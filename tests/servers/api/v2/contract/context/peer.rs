@@ -0,0 +1,103 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker::servers::apis::v1::context::peer::resources::{PeerMatch, RemovedPeers};
+use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::v2::asserts::{assert_json, assert_problem};
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_searching_the_peers_in_all_the_swarms() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    let peer = PeerBuilder::default()
+        .with_peer_addr(&SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080))
+        .build();
+
+    env.add_torrent_peer(&info_hash, &peer);
+
+    let response = Client::new(env.get_connection_info())
+        .search_peers(Query::params([QueryParam::new("ip", "126.0.0.1")].to_vec()))
+        .await;
+
+    let peer_matches: Vec<PeerMatch> = assert_json(response).await;
+
+    assert_eq!(peer_matches.len(), 1);
+    assert_eq!(peer_matches[0].info_hash, info_hash.to_string());
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_peer_search_has_no_criteria() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).search_peers(Query::empty()).await;
+
+    assert_problem(response, 400, "missing search params, expected an ip or a peer_id param").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_removing_the_peers_with_an_ip_from_all_the_swarms() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    let peer = PeerBuilder::default()
+        .with_peer_addr(&SocketAddr::new(IpAddr::V4(Ipv4Addr::new(126, 0, 0, 1)), 8080))
+        .build();
+
+    env.add_torrent_peer(&info_hash, &peer);
+
+    let response = Client::new(env.get_connection_info()).remove_peers_with_ip("126.0.0.1").await;
+
+    let removed_peers: RemovedPeers = assert_json(response).await;
+
+    assert_eq!(removed_peers, RemovedPeers { removed_peers: 1 });
+    assert!(!env.tracker.is_peer_active(&info_hash, &peer.peer_id));
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_ip_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).remove_peers_with_ip("INVALID").await;
+
+    assert_problem(
+        response,
+        400,
+        "invalid ip param: string \"INVALID\", expected an IPv4 or IPv6 address",
+    )
+    .await;
+
+    env.stop().await;
+}
@@ -0,0 +1,43 @@
+use torrust_tracker::servers::apis::v1::context::stats::resources::Stats;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::v2::asserts::{assert_json, assert_problem};
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_getting_tracker_statistics() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).get_tracker_statistics().await;
+
+    let stats: Stats = assert_json(response).await;
+
+    assert_eq!(stats.torrents, 0);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_history_days_are_out_of_range() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_tracker_statistics_history(Query::params([QueryParam::new("days", "0")].to_vec()))
+        .await;
+
+    assert_problem(response, 400, "invalid days param 0, expected a number between 1 and 366").await;
+
+    env.stop().await;
+}
@@ -0,0 +1,393 @@
+use std::str::FromStr;
+
+use aquatic_udp_protocol::PeerId;
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker::servers::apis::v1::context::torrent::resources::torrent::{ListItem, RankedTorrent, Torrent};
+use torrust_tracker_primitives::peer::fixture::PeerBuilder;
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::v2::asserts::{
+    assert_invalid_infohash_param, assert_json, assert_no_content, assert_problem, assert_problem_containing,
+};
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::Started;
+
+#[tokio::test]
+async fn should_allow_getting_all_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let response = Client::new(env.get_connection_info()).get_torrents(Query::empty()).await;
+
+    let torrents: Vec<ListItem> = assert_json(response).await;
+
+    assert_eq!(
+        torrents,
+        vec![ListItem {
+            info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+            seeders: 1,
+            completed: 0,
+            leechers: 0,
+        }]
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_torrents_query_params_cannot_be_parsed() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrents(Query::params([QueryParam::new("limit", "INVALID LIMIT")].to_vec()))
+        .await;
+
+    assert_problem_containing(response, 400, "Failed to deserialize query string").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_exporting_all_the_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let response = Client::new(env.get_connection_info()).export_torrents(Query::empty()).await;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers().get("content-type").unwrap(), "application/x-ndjson");
+    assert_eq!(
+        response.text().await.unwrap(),
+        "{\"info_hash\":\"9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d\",\"seeders\":1,\"completed\":0,\"leechers\":0}\n"
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_export_format_is_unknown() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .export_torrents(Query::params([QueryParam::new("format", "xml")].to_vec()))
+        .await;
+
+    assert_problem(
+        response,
+        400,
+        "invalid format param: unknown export format xml, expected one of: ndjson, csv",
+    )
+    .await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_filtering_and_sorting_the_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash_1 = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    let info_hash_2 = InfoHash::from_str("0b3aea4adc213ce32295be85d3883a63bca25446").unwrap();
+
+    env.add_torrent_peer(&info_hash_1, &PeerBuilder::seeder().into());
+    env.add_torrent_peer(&info_hash_2, &PeerBuilder::leecher().into());
+    env.add_torrent_peer(
+        &info_hash_2,
+        &PeerBuilder::leecher().with_peer_id(&PeerId(*b"-qB00000000000000003")).into(),
+    );
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrents(Query::params(
+            [
+                QueryParam::new("min_leechers", "1"),
+                QueryParam::new("sort", "leechers"),
+                QueryParam::new("order", "desc"),
+            ]
+            .to_vec(),
+        ))
+        .await;
+
+    assert_eq!(response.headers().get("x-total-count").unwrap(), "1");
+
+    let torrents: Vec<ListItem> = assert_json(response).await;
+
+    assert_eq!(
+        torrents,
+        vec![ListItem {
+            info_hash: "0b3aea4adc213ce32295be85d3883a63bca25446".to_string(),
+            seeders: 0,
+            completed: 0,
+            leechers: 2,
+        }]
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_torrents_sort_param_is_unknown() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrents(Query::params([QueryParam::new("sort", "peers")].to_vec()))
+        .await;
+
+    assert_problem_containing(response, 400, "invalid sort param: unknown sort field peers").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_getting_a_torrent_info() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrent(&info_hash.to_string())
+        .await;
+
+    let torrent: Torrent = assert_json(response).await;
+
+    assert_eq!(torrent.info_hash, "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d");
+    assert_eq!(torrent.seeders, 1);
+    assert_eq!(torrent.peers.unwrap().len(), 1);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_404_problem_when_the_torrent_does_not_exist() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_torrent("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d")
+        .await;
+
+    assert_problem(response, 404, "torrent not known").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_infohash_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).get_torrent("INVALID").await;
+
+    assert_invalid_infohash_param(response, "INVALID").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_getting_the_top_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let response = Client::new(env.get_connection_info())
+        .get_top_torrents(Query::params([QueryParam::new("by", "seeders")].to_vec()))
+        .await;
+
+    let top_torrents: Vec<RankedTorrent> = assert_json(response).await;
+
+    assert_eq!(top_torrents.len(), 1);
+    assert_eq!(top_torrents[0].info_hash, "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d");
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_top_torrents_ranking_is_unknown() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_top_torrents(Query::params([QueryParam::new("by", "INVALID")].to_vec()))
+        .await;
+
+    assert_problem_containing(response, 400, "unknown ranking INVALID").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_removing_a_torrent() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().into());
+
+    let client = Client::new(env.get_connection_info());
+
+    assert_no_content(
+        client
+            .remove_torrent(
+                &info_hash.to_string(),
+                Query::params([QueryParam::new("remove_completed", "true")].to_vec()),
+            )
+            .await,
+    )
+    .await;
+
+    assert_problem(client.get_torrent(&info_hash.to_string()).await, 404, "torrent not known").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_404_problem_when_removing_a_torrent_that_does_not_exist() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .remove_torrent("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", Query::default())
+        .await;
+
+    assert_problem(response, 404, "torrent not known").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_remove_completed_query_param_cannot_be_parsed() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .remove_torrent(
+            "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d",
+            Query::params([QueryParam::new("remove_completed", "INVALID")].to_vec()),
+        )
+        .await;
+
+    assert_problem_containing(response, 400, "Failed to deserialize query string").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_removing_a_peer_from_a_torrent() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    let peer_id = PeerId(*b"-qB00000000000000000");
+
+    env.add_torrent_peer(&info_hash, &PeerBuilder::default().with_peer_id(&peer_id).into());
+
+    let client = Client::new(env.get_connection_info());
+
+    assert_no_content(
+        client
+            .remove_peer(&info_hash.to_string(), "2d71423030303030303030303030303030303030")
+            .await,
+    )
+    .await;
+
+    assert_problem(
+        client
+            .remove_peer(&info_hash.to_string(), "2d71423030303030303030303030303030303030")
+            .await,
+        404,
+        "peer not known",
+    )
+    .await;
+
+    assert!(!env.tracker.is_peer_active(&info_hash, &peer_id));
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_peer_id_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .remove_peer("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", "0x2d71")
+        .await;
+
+    assert_problem(
+        response,
+        400,
+        "invalid peer id param: string \"0x2d71\", expected a 40 character long hex string",
+    )
+    .await;
+
+    env.stop().await;
+}
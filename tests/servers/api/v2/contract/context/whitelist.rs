@@ -0,0 +1,337 @@
+use std::str::FromStr;
+
+use bittorrent_primitives::info_hash::InfoHash;
+use torrust_tracker::servers::apis::v1::context::whitelist::resources::{
+    BulkWhitelistItem, BulkWhitelistStatus, WhitelistDiff, WhitelistEntry,
+};
+use torrust_tracker_test_helpers::configuration;
+use tracing::level_filters::LevelFilter;
+
+use crate::common::http::{Query, QueryParam};
+use crate::common::logging::{tracing_stderr_init, INIT};
+use crate::servers::api::v2::asserts::{
+    assert_invalid_infohash_param, assert_json, assert_no_content, assert_problem, assert_problem_containing,
+};
+use crate::servers::api::v2::client::Client;
+use crate::servers::api::{force_database_error, Started};
+
+#[tokio::test]
+async fn should_allow_whitelisting_a_torrent() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_owned();
+
+    let api_client = Client::new(env.get_connection_info());
+
+    assert_no_content(api_client.whitelist_a_torrent(&info_hash).await).await;
+
+    // Adding it again is idempotent
+    assert_no_content(api_client.whitelist_a_torrent(&info_hash).await).await;
+
+    assert!(
+        env.tracker
+            .is_info_hash_whitelisted(&InfoHash::from_str(&info_hash).unwrap())
+            .await
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_removing_a_torrent_from_the_whitelist() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let hash = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_owned();
+    let info_hash = InfoHash::from_str(&hash).unwrap();
+    env.tracker.add_torrent_to_whitelist(&info_hash).await.unwrap();
+
+    let response = Client::new(env.get_connection_info())
+        .remove_torrent_from_whitelist(&hash)
+        .await;
+
+    assert_no_content(response).await;
+    assert!(!env.tracker.is_info_hash_whitelisted(&info_hash).await);
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_infohash_is_invalid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).whitelist_a_torrent("INVALID").await;
+
+    assert_invalid_infohash_param(response, "INVALID").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_reloading_the_whitelist() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info()).reload_whitelist().await;
+
+    assert_no_content(response).await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_500_problem_when_the_whitelist_cannot_be_reloaded() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    force_database_error(&env.tracker);
+
+    let response = Client::new(env.get_connection_info()).reload_whitelist().await;
+
+    assert_problem_containing(response, 500, "failed to reload whitelist").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_listing_the_whitelisted_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    for hash in [
+        "0b3aea4adc213ce32295be85d3883a63bca25446",
+        "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d",
+    ] {
+        env.tracker
+            .add_torrent_to_whitelist(&InfoHash::from_str(hash).unwrap())
+            .await
+            .unwrap();
+    }
+
+    let response = Client::new(env.get_connection_info())
+        .get_whitelist(Query::params([QueryParam::new("limit", "1")].to_vec()))
+        .await;
+
+    assert_eq!(
+        assert_json::<Vec<WhitelistEntry>>(response).await,
+        vec![WhitelistEntry {
+            info_hash: "0b3aea4adc213ce32295be85d3883a63bca25446".to_string(),
+            in_memory: true,
+            in_database: true,
+        }]
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_getting_where_a_torrent_is_whitelisted() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let hash = "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_owned();
+    env.tracker
+        .add_torrent_to_memory_whitelist(&InfoHash::from_str(&hash).unwrap())
+        .await;
+
+    let response = Client::new(env.get_connection_info()).get_whitelist_entry(&hash).await;
+
+    assert_eq!(
+        assert_json::<WhitelistEntry>(response).await,
+        WhitelistEntry {
+            info_hash: hash,
+            in_memory: true,
+            in_database: false,
+        }
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_404_problem_when_the_torrent_is_not_whitelisted() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let response = Client::new(env.get_connection_info())
+        .get_whitelist_entry("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d")
+        .await;
+
+    assert_problem(response, 404, "torrent not whitelisted").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_report_the_torrents_whitelisted_only_in_memory_or_only_in_the_database() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let info_hash = InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap();
+    env.tracker.add_torrent_to_whitelist(&info_hash).await.unwrap();
+    env.tracker.remove_torrent_from_memory_whitelist(&info_hash).await;
+
+    let api_client = Client::new(env.get_connection_info());
+
+    assert_eq!(
+        assert_json::<WhitelistDiff>(api_client.get_whitelist_diff().await).await,
+        WhitelistDiff {
+            only_in_memory: vec![],
+            only_in_database: vec!["9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string()],
+        }
+    );
+
+    // Reloading the whitelist fixes the drift
+    assert_no_content(api_client.reload_whitelist().await).await;
+
+    assert_eq!(
+        assert_json::<WhitelistDiff>(api_client.get_whitelist_diff().await).await,
+        WhitelistDiff {
+            only_in_memory: vec![],
+            only_in_database: vec![],
+        }
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_500_problem_when_the_whitelist_cannot_be_loaded() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    force_database_error(&env.tracker);
+
+    let response = Client::new(env.get_connection_info()).get_whitelist_diff().await;
+
+    assert_problem_containing(response, 500, "failed to load whitelist").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_allow_whitelisting_and_removing_a_list_of_torrents() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let api_client = Client::new(env.get_connection_info());
+
+    let response = api_client
+        .whitelist_torrents(
+            "application/json",
+            r#"["9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d", "INVALID"]"#,
+        )
+        .await;
+
+    let items: Vec<BulkWhitelistItem> = assert_json(response).await;
+
+    assert_eq!(
+        items,
+        vec![
+            BulkWhitelistItem {
+                info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+                status: BulkWhitelistStatus::Added,
+            },
+            BulkWhitelistItem {
+                info_hash: "INVALID".to_string(),
+                status: BulkWhitelistStatus::Invalid,
+            },
+        ]
+    );
+
+    let response = api_client
+        .remove_torrents_from_whitelist("application/x-ndjson", "\"9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d\"\n")
+        .await;
+
+    let items: Vec<BulkWhitelistItem> = assert_json(response).await;
+
+    assert_eq!(
+        items,
+        vec![BulkWhitelistItem {
+            info_hash: "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d".to_string(),
+            status: BulkWhitelistStatus::Removed,
+        }]
+    );
+    assert!(
+        !env.tracker
+            .is_info_hash_whitelisted(&InfoHash::from_str("9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d").unwrap())
+            .await
+    );
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_400_problem_when_the_list_of_torrents_is_not_valid() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    let api_client = Client::new(env.get_connection_info());
+
+    let response = api_client.whitelist_torrents("text/plain", "").await;
+    assert_problem(response, 400, "invalid body: the list of info-hashes is empty").await;
+
+    let response = api_client
+        .remove_torrents_from_whitelist("application/xml", "<info-hashes/>")
+        .await;
+    assert_problem_containing(response, 400, "invalid body: unsupported content type").await;
+
+    env.stop().await;
+}
+
+#[tokio::test]
+async fn should_return_a_500_problem_when_the_list_of_torrents_cannot_be_whitelisted() {
+    INIT.call_once(|| {
+        tracing_stderr_init(LevelFilter::ERROR);
+    });
+
+    let env = Started::new(&configuration::ephemeral().into()).await;
+
+    force_database_error(&env.tracker);
+
+    let response = Client::new(env.get_connection_info())
+        .whitelist_torrents("text/plain", "9e0217d0fa71c87332cd8bf9dbeabcb2c2cf3c4d")
+        .await;
+
+    assert_problem_containing(response, 500, "failed to whitelist torrents").await;
+
+    env.stop().await;
+}
@@ -0,0 +1,3 @@
+pub mod authentication;
+pub mod context;
+pub mod openapi;
@@ -0,0 +1,3 @@
+pub mod asserts;
+pub mod client;
+pub mod contract;
@@ -730,7 +730,7 @@ mod for_all_config_modes {
                 .announce(&QueryBuilder::default().query())
                 .await;
 
-            let stats = env.tracker.get_stats().await;
+            let stats = env.tracker.get_stats();
 
             assert_eq!(stats.tcp4_connections_handled, 1);
 
@@ -758,7 +758,7 @@ mod for_all_config_modes {
                 .announce(&QueryBuilder::default().query())
                 .await;
 
-            let stats = env.tracker.get_stats().await;
+            let stats = env.tracker.get_stats();
 
             assert_eq!(stats.tcp6_connections_handled, 1);
 
@@ -785,7 +785,7 @@ mod for_all_config_modes {
                 )
                 .await;
 
-            let stats = env.tracker.get_stats().await;
+            let stats = env.tracker.get_stats();
 
             assert_eq!(stats.tcp6_connections_handled, 0);
 
@@ -806,7 +806,7 @@ mod for_all_config_modes {
                 .announce(&QueryBuilder::default().query())
                 .await;
 
-            let stats = env.tracker.get_stats().await;
+            let stats = env.tracker.get_stats();
 
             assert_eq!(stats.tcp4_announces_handled, 1);
 
@@ -834,7 +834,7 @@ mod for_all_config_modes {
                 .announce(&QueryBuilder::default().query())
                 .await;
 
-            let stats = env.tracker.get_stats().await;
+            let stats = env.tracker.get_stats();
 
             assert_eq!(stats.tcp6_announces_handled, 1);
 
@@ -861,7 +861,7 @@ mod for_all_config_modes {
                 )
                 .await;
 
-            let stats = env.tracker.get_stats().await;
+            let stats = env.tracker.get_stats();
 
             assert_eq!(stats.tcp6_announces_handled, 0);
 
@@ -1250,7 +1250,7 @@ mod for_all_config_modes {
                 )
                 .await;
 
-            let stats = env.tracker.get_stats().await;
+            let stats = env.tracker.get_stats();
 
             assert_eq!(stats.tcp4_scrapes_handled, 1);
 
@@ -1284,7 +1284,7 @@ mod for_all_config_modes {
                 )
                 .await;
 
-            let stats = env.tracker.get_stats().await;
+            let stats = env.tracker.get_stats();
 
             assert_eq!(stats.tcp6_scrapes_handled, 1);
 
@@ -1329,6 +1329,29 @@ mod configured_as_whitelisted {
             env.stop().await;
         }
 
+        #[tokio::test]
+        async fn should_increase_the_number_of_non_whitelisted_torrent_errors_in_statistics() {
+            INIT.call_once(|| {
+                tracing_stderr_init(LevelFilter::ERROR);
+            });
+
+            let env = Started::new(&configuration::ephemeral_listed().into()).await;
+
+            let info_hash = InfoHash::from_str("9c38422213e30bff212b30c360d26f9a02136422").unwrap();
+
+            Client::new(*env.bind_address())
+                .announce(&QueryBuilder::default().with_info_hash(&info_hash).query())
+                .await;
+
+            let stats = env.tracker.get_stats();
+
+            assert_eq!(stats.tcp_errors.torrent_not_whitelisted, 1);
+
+            drop(stats);
+
+            env.stop().await;
+        }
+
         #[tokio::test]
         async fn should_allow_announcing_a_whitelisted_torrent() {
             INIT.call_once(|| {